@@ -0,0 +1,287 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use comfy_table::presets::{ASCII_FULL, ASCII_MARKDOWN, NOTHING, UTF8_FULL};
+use comfy_table::*;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum FuzzWidth {
+    Fixed(u16),
+    Percentage(u16),
+    TerminalFraction(u16, u16),
+}
+
+impl From<FuzzWidth> for Width {
+    fn from(width: FuzzWidth) -> Self {
+        match width {
+            FuzzWidth::Fixed(width) => Width::Fixed(width),
+            FuzzWidth::Percentage(percent) => Width::Percentage(percent),
+            FuzzWidth::TerminalFraction(numerator, denominator) => {
+                Width::TerminalFraction(numerator, denominator)
+            }
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzConstraint {
+    ContentWidth,
+    Absolute(FuzzWidth),
+    LowerBoundary(FuzzWidth),
+    UpperBoundary(FuzzWidth),
+    Boundaries { lower: FuzzWidth, upper: FuzzWidth },
+    Hidden,
+}
+
+impl From<FuzzConstraint> for ColumnConstraint {
+    fn from(constraint: FuzzConstraint) -> Self {
+        match constraint {
+            FuzzConstraint::ContentWidth => ColumnConstraint::ContentWidth,
+            FuzzConstraint::Absolute(width) => ColumnConstraint::Absolute(width.into()),
+            FuzzConstraint::LowerBoundary(width) => ColumnConstraint::LowerBoundary(width.into()),
+            FuzzConstraint::UpperBoundary(width) => ColumnConstraint::UpperBoundary(width.into()),
+            FuzzConstraint::Boundaries { lower, upper } => ColumnConstraint::Boundaries {
+                lower: lower.into(),
+                upper: upper.into(),
+            },
+            FuzzConstraint::Hidden => ColumnConstraint::Hidden,
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzColumn {
+    constraint: Option<FuzzConstraint>,
+    padding: (u16, u16),
+    delimiter: Option<char>,
+    truncation_indicator: Option<String>,
+    size_to_data_only: bool,
+    elision_marker: Option<String>,
+    hanging_indent: u16,
+    key_column: bool,
+    delimiters: Vec<char>,
+    number_format: Option<(Option<u8>, Option<char>, bool)>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzCell {
+    content: String,
+    preformatted: bool,
+    preserve_whitespace: bool,
+    padding: Option<(u16, u16)>,
+    truncate: bool,
+    url: Option<String>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzRow {
+    section: Option<String>,
+    cells: Vec<FuzzCell>,
+    max_height: Option<u8>,
+    min_height: Option<u8>,
+    separator_after: Option<bool>,
+    separator_char: Option<char>,
+    hidden: bool,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    header: Option<Vec<String>>,
+    rows: Vec<FuzzRow>,
+    columns: Vec<FuzzColumn>,
+    width: Option<u16>,
+    min_width: Option<u8>,
+    arrangement: u8,
+    overflow_policy: u8,
+    trim_mode: u8,
+    wrap_policy: u8,
+    right_bias: bool,
+    padding_collapse: bool,
+    column_order: Option<Vec<u8>>,
+    preset: u8,
+    delimiter: Option<char>,
+    truncation_indicator: Option<String>,
+    truncation_markers: bool,
+    escape_border_chars: bool,
+    empty_message: Option<String>,
+    max_height: Option<u8>,
+    title: Option<String>,
+    title_in_border: bool,
+    caption: Option<String>,
+    styled: bool,
+    width_function: u8,
+    truncation_mode: Option<u8>,
+}
+
+fuzz_target!(|input: Input| {
+    let mut table = Table::new();
+    table.force_no_tty();
+    if input.styled {
+        table.enforce_styling();
+    }
+    if let Some(mode) = input.truncation_mode {
+        table.set_truncation_strategy(Box::new(match mode % 4 {
+            0 => TruncationMode::End,
+            1 => TruncationMode::Start,
+            2 => TruncationMode::Middle,
+            _ => TruncationMode::WordBoundary,
+        }));
+    }
+    match input.width_function % 4 {
+        0 => (),
+        1 => {
+            table.set_width_function(|text| text.chars().count());
+        }
+        2 => {
+            table.set_width_function(str::len);
+        }
+        _ => {
+            table.set_width_function(|_| 0);
+        }
+    }
+
+    let preset = match input.preset % 4 {
+        0 => ASCII_FULL,
+        1 => UTF8_FULL,
+        2 => ASCII_MARKDOWN,
+        _ => NOTHING,
+    };
+    table.load_preset(preset);
+    table.set_content_arrangement(match input.arrangement % 3 {
+        0 => ContentArrangement::Disabled,
+        1 => ContentArrangement::Dynamic,
+        _ => ContentArrangement::DynamicFullWidth,
+    });
+    table.set_overflow_policy(match input.overflow_policy % 3 {
+        0 => OverflowPolicy::Overflow,
+        1 => OverflowPolicy::HideColumns,
+        _ => OverflowPolicy::Vertical,
+    });
+    table.set_trim_mode(match input.trim_mode % 4 {
+        0 => TrimMode::None,
+        1 => TrimMode::Leading,
+        2 => TrimMode::Trailing,
+        _ => TrimMode::Both,
+    });
+    table.set_wrap_policy(match input.wrap_policy % 3 {
+        0 => WrapPolicy::BreakWords,
+        1 => WrapPolicy::KeepWords,
+        _ => WrapPolicy::Hyphenate,
+    });
+    if let Some(width) = input.width {
+        table.set_width(width);
+    }
+    if let Some(min_width) = input.min_width {
+        table.set_min_width(min_width.into());
+    }
+    if let Some(delimiter) = input.delimiter {
+        table.set_delimiter(delimiter);
+    }
+    if let Some(indicator) = input.truncation_indicator {
+        // Indicators that are too wide are rejected.
+        let _ = table.set_truncation_indicator(&indicator);
+    }
+    table.set_truncation_markers(input.truncation_markers);
+    if input.right_bias {
+        table.set_center_bias(Bias::Right);
+    }
+    table.allow_padding_collapse(input.padding_collapse);
+    if let Some(order) = input.column_order {
+        let order: Vec<usize> = order.into_iter().map(usize::from).collect();
+        table.set_column_order(&order);
+    }
+    table.escape_border_chars(input.escape_border_chars);
+    if let Some(title) = input.title {
+        table.set_title(title);
+    }
+    table.set_title_in_border(input.title_in_border);
+    if let Some(caption) = input.caption {
+        table.set_caption(caption);
+    }
+    if let Some(max_height) = input.max_height {
+        table.set_max_height(max_height.into());
+    }
+    if let Some(message) = input.empty_message {
+        table.set_empty_message(message);
+    }
+
+    if let Some(header) = input.header {
+        table.set_header(header);
+    }
+    for fuzz_row in input.rows {
+        if let Some(section) = fuzz_row.section {
+            table.add_section(section);
+        }
+        let mut row = Row::from(fuzz_row.cells.into_iter().map(|cell| {
+            let mut new_cell = Cell::new(cell.content)
+                .preformatted(cell.preformatted)
+                .preserve_whitespace(cell.preserve_whitespace)
+                .set_truncate(cell.truncate);
+            if let Some(padding) = cell.padding {
+                new_cell = new_cell.set_padding(padding);
+            }
+            if let Some(url) = cell.url {
+                new_cell = new_cell.set_url(url);
+            }
+            new_cell
+        }));
+        if let Some(max_height) = fuzz_row.max_height {
+            row.max_height(max_height.into());
+        }
+        if let Some(min_height) = fuzz_row.min_height {
+            row.min_height(min_height.into());
+        }
+        if let Some(separator) = fuzz_row.separator_after {
+            row.set_separator_after(separator);
+        }
+        if let Some(character) = fuzz_row.separator_char {
+            row.set_separator_char(character);
+        }
+        row.set_hidden(fuzz_row.hidden);
+        table.add_row(row);
+    }
+
+    for (column, fuzz_column) in table.column_iter_mut().zip(input.columns) {
+        if let Some(constraint) = fuzz_column.constraint {
+            column.set_constraint(constraint.into());
+        }
+        column.set_padding(fuzz_column.padding);
+        if let Some(delimiter) = fuzz_column.delimiter {
+            column.set_delimiter(delimiter);
+        }
+        if let Some(indicator) = fuzz_column.truncation_indicator {
+            let _ = column.set_truncation_indicator(&indicator);
+        }
+        column.size_to_data_only(fuzz_column.size_to_data_only);
+        if let Some(marker) = fuzz_column.elision_marker {
+            column.fit_to_pattern(&marker);
+        }
+        column.set_hanging_indent(fuzz_column.hanging_indent);
+        column.set_key_column(fuzz_column.key_column);
+        column.set_delimiters(&fuzz_column.delimiters);
+        if let Some((precision, separator, align)) = fuzz_column.number_format {
+            let mut format = NumberFormat::new();
+            if let Some(precision) = precision {
+                format = format.precision(precision.into());
+            }
+            if let Some(separator) = separator {
+                format = format.thousands_separator(separator);
+            }
+            if align {
+                format = format.align_decimal_point();
+            }
+            column.set_number_format(format);
+        }
+    }
+
+    let _ = Table::parse(&table.to_string(), preset);
+    let _ = table.truncated_content();
+    let _ = table.rendered_rows();
+    let _ = table.column_display_widths();
+    if let Some(width) = input.width {
+        for chunk in table.split_by_width(width) {
+            let _ = chunk.to_string();
+        }
+    }
+});
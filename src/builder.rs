@@ -0,0 +1,157 @@
+use crate::column::Column;
+use crate::row::Row;
+use crate::style::{ColumnConstraint, ContentArrangement};
+use crate::table::Table;
+
+/// Build a [Table] in a single expression.
+///
+/// The setters of [Table] take `&mut self`, which requires a `mut` binding and separate
+/// statements. The builder offers the most common of them in an owned flavor instead.
+/// All other settings are available via [TableBuilder::configure] and
+/// [TableBuilder::configure_column].
+///
+/// ```
+/// use comfy_table::presets::UTF8_FULL;
+/// use comfy_table::{CellAlignment, Table};
+///
+/// fn inventory(items: &[(&str, u32)]) -> Table {
+///     Table::builder()
+///         .load_preset(UTF8_FULL)
+///         .set_header(vec!["Item", "Count"])
+///         .add_rows(items.iter().map(|(item, count)| vec![item.to_string(), count.to_string()]))
+///         .configure_column(1, |column| {
+///             column.set_cell_alignment(CellAlignment::Right);
+///         })
+///         .build()
+/// }
+///
+/// let table = inventory(&[("Apples", 3), ("Pears", 12)]);
+/// assert_eq!(table.row_count(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TableBuilder {
+    table: Table,
+}
+
+impl TableBuilder {
+    /// Start with a new table, see [Table::new].
+    pub fn new() -> Self {
+        Self {
+            table: Table::new(),
+        }
+    }
+
+    /// See [Table::set_header].
+    #[must_use]
+    pub fn set_header<T: Into<Row>>(mut self, row: T) -> Self {
+        self.table.set_header(row);
+
+        self
+    }
+
+    /// See [Table::add_row].
+    #[must_use]
+    pub fn add_row<T: Into<Row>>(mut self, row: T) -> Self {
+        self.table.add_row(row);
+
+        self
+    }
+
+    /// See [Table::add_rows].
+    #[must_use]
+    pub fn add_rows<I>(mut self, rows: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Row>,
+    {
+        self.table.add_rows(rows);
+
+        self
+    }
+
+    /// See [Table::load_preset].
+    #[must_use]
+    pub fn load_preset(mut self, preset: &str) -> Self {
+        self.table.load_preset(preset);
+
+        self
+    }
+
+    /// See [Table::apply_modifier].
+    #[must_use]
+    pub fn apply_modifier(mut self, modifier: &str) -> Self {
+        self.table.apply_modifier(modifier);
+
+        self
+    }
+
+    /// See [Table::set_content_arrangement].
+    #[must_use]
+    pub fn set_content_arrangement(mut self, arrangement: ContentArrangement) -> Self {
+        self.table.set_content_arrangement(arrangement);
+
+        self
+    }
+
+    /// See [Table::set_width].
+    #[must_use]
+    pub fn set_width(mut self, width: u16) -> Self {
+        self.table.set_width(width);
+
+        self
+    }
+
+    /// See [Table::set_constraints].
+    #[must_use]
+    pub fn set_constraints<T: IntoIterator<Item = ColumnConstraint>>(
+        mut self,
+        constraints: T,
+    ) -> Self {
+        self.table.set_constraints(constraints);
+
+        self
+    }
+
+    /// Change any other setting of the table.
+    ///
+    /// ```
+    /// use comfy_table::{Table, TrimMode};
+    ///
+    /// let table = Table::builder()
+    ///     .configure(|table| {
+    ///         table.set_trim_mode(TrimMode::Both).set_delimiter('-');
+    ///     })
+    ///     .build();
+    /// assert_eq!(table.trim_mode(), TrimMode::Both);
+    /// ```
+    #[must_use]
+    pub fn configure(mut self, configure: impl FnOnce(&mut Table)) -> Self {
+        configure(&mut self.table);
+
+        self
+    }
+
+    /// Change the settings of a column.
+    ///
+    /// Columns are created when rows or a header are added, so this should be called
+    /// afterwards. Columns that don't exist are ignored.
+    #[must_use]
+    pub fn configure_column(mut self, index: usize, configure: impl FnOnce(&mut Column)) -> Self {
+        if let Some(column) = self.table.column_mut(index) {
+            configure(column);
+        }
+
+        self
+    }
+
+    /// Return the finished table.
+    pub fn build(self) -> Table {
+        self.table
+    }
+}
+
+impl From<TableBuilder> for Table {
+    fn from(builder: TableBuilder) -> Self {
+        builder.build()
+    }
+}
@@ -1,7 +1,11 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
 #[cfg(feature = "tty")]
-use crate::{Attribute, Color};
+use crate::style::{CellAttribute, CellColor};
 
-use crate::style::CellAlignment;
+use crate::style::{CellAlignment, CellVerticalAlignment, TrimMode};
+use crate::utils::formatting::templates::fill_template;
 
 /// A stylable table cell with content.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -14,12 +18,31 @@ pub struct Cell {
     /// The default is ` `.
     pub(crate) delimiter: Option<char>,
     pub(crate) alignment: Option<CellAlignment>,
+    pub(crate) vertical_alignment: Option<CellVerticalAlignment>,
+    /// Left/right padding, which overrides the padding of the cell's column.
+    pub(crate) padding: Option<(u16, u16)>,
+    /// Preformatted content is neither split nor aligned.
+    pub(crate) preformatted: bool,
+    /// Never trim whitespaces, regardless of the table's [TrimMode].
+    pub(crate) preserve_whitespace: bool,
+    /// ANSI escape sequences in the content are passed through as they are.
+    pub(crate) raw_ansi: bool,
+    /// Cut off overflowing content instead of splitting it into multiple lines.
+    pub(crate) truncate: bool,
+    /// The number of a [footnote](crate::Table::add_footnote) this cell refers to.
+    pub(crate) footnote_ref: Option<usize>,
+    /// A semantic tag, which is used to look up styles and by exporters.
+    pub(crate) class: Option<String>,
+    /// The target of a hyperlink for this cell's content.
+    pub(crate) url: Option<String>,
+    /// A template, whose placeholders are resolved while the table is rendered.
+    pub(crate) template: Option<CellTemplate>,
     #[cfg(feature = "tty")]
-    pub(crate) fg: Option<Color>,
+    pub(crate) fg: Option<CellColor>,
     #[cfg(feature = "tty")]
-    pub(crate) bg: Option<Color>,
+    pub(crate) bg: Option<CellColor>,
     #[cfg(feature = "tty")]
-    pub(crate) attributes: Vec<Attribute>,
+    pub(crate) attributes: Vec<CellAttribute>,
 }
 
 impl Cell {
@@ -27,17 +50,60 @@ impl Cell {
     #[allow(clippy::needless_pass_by_value)]
     pub fn new<T: ToString>(content: T) -> Self {
         let content = content.to_string();
-        #[cfg_attr(not(feature = "custom_styling"), allow(unused_mut))]
-        let mut split_content: Vec<String> = content.split('\n').map(ToString::to_string).collect();
+        let split_content: Vec<String> = content.split('\n').map(ToString::to_string).collect();
 
-        // Correct ansi codes so style is terminated and resumed around the split
-        #[cfg(feature = "custom_styling")]
-        crate::utils::formatting::content_split::fix_style_in_split_str(&mut split_content);
+        Self::from_split_content(split_content)
+    }
+
+    /// Create a new Cell from content that's already split into lines.
+    ///
+    /// This avoids joining the lines into a single string, which would be split again right
+    /// away. Lines that still contain newlines are split as usual.
+    ///
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::from_lines(vec!["first line", "second line"]);
+    /// assert_eq!(cell.content(), "first line\nsecond line");
+    /// ```
+    pub fn from_lines<I, T>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let mut split_content = Vec::new();
+        for line in lines {
+            let line = line.into();
+            if line.contains('\n') {
+                split_content.extend(line.split('\n').map(ToString::to_string));
+            } else {
+                split_content.push(line);
+            }
+        }
 
+        // A cell always contains at least one line, even if it's empty.
+        if split_content.is_empty() {
+            split_content.push(String::new());
+        }
+
+        Self::from_split_content(split_content)
+    }
+
+    fn from_split_content(split_content: Vec<String>) -> Self {
         Self {
             content: split_content,
             delimiter: None,
             alignment: None,
+            vertical_alignment: None,
+            padding: None,
+            preformatted: false,
+            preserve_whitespace: false,
+            raw_ansi: false,
+            truncate: false,
+            footnote_ref: None,
+            class: None,
+            url: None,
+            template: None,
             #[cfg(feature = "tty")]
             fg: None,
             #[cfg(feature = "tty")]
@@ -47,6 +113,68 @@ impl Cell {
         }
     }
 
+    /// Create a new Cell, whose content is a template with `{name}` placeholders.
+    ///
+    /// Placeholders are replaced by the given values. In addition, the following placeholders
+    /// are resolved while the table is rendered:
+    /// - `{total}`: The sum of all numeric cells in the same column, excluding the header.
+    ///   Template cells count with their `value`.
+    /// - `{percent}`: The cell's `value` in percent of `{total}`, rounded to an integer.
+    ///
+    /// Placeholders that cannot be resolved are kept as they are.
+    /// Literal braces are written as `{{` and `}}`.\
+    /// Until the table is rendered, [Cell::content] only contains the given values.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Size"]);
+    /// for size in [30, 10] {
+    ///     table.add_row(vec![Cell::new_template("{value} kb ({percent}%)", [("value", size)])]);
+    /// }
+    ///
+    /// let expected = "
+    /// +-------------+
+    /// | Size        |
+    /// +=============+
+    /// | 30 kb (75%) |
+    /// |-------------|
+    /// | 10 kb (25%) |
+    /// +-------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn new_template<T, I, K, V>(template: T, values: I) -> Self
+    where
+        T: ToString,
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        let template = CellTemplate {
+            template: template.to_string(),
+            values: values
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        };
+
+        let content = fill_template(&template.template, |name| {
+            template.values.get(name).cloned()
+        });
+        let mut cell = Self::new(content);
+        cell.template = Some(template);
+
+        cell
+    }
+
+    /// Get the template of this cell, if it has been created via [Cell::new_template].
+    pub fn template(&self) -> Option<&str> {
+        self.template
+            .as_ref()
+            .map(|template| template.template.as_str())
+    }
+
     /// Return a copy of the content contained in this cell.
     pub fn content(&self) -> String {
         self.content.join("\n")
@@ -80,9 +208,291 @@ impl Cell {
         self
     }
 
+    /// Set the vertical alignment of content for this cell.
+    ///
+    /// This determines where the cell's lines are placed, if other cells of the same row
+    /// have more lines. Setting this overwrites the vertical alignment of the
+    /// [Column](crate::column::Column::set_cell_vertical_alignment) for this specific cell.
+    /// ```
+    /// use comfy_table::{Cell, CellVerticalAlignment};
+    ///
+    /// let mut cell = Cell::new("Some content")
+    ///     .set_vertical_alignment(CellVerticalAlignment::Middle);
+    /// ```
+    #[must_use]
+    pub fn set_vertical_alignment(mut self, alignment: CellVerticalAlignment) -> Self {
+        self.vertical_alignment = Some(alignment);
+
+        self
+    }
+
+    /// Set the left/right padding of this cell in spaces.
+    ///
+    /// Setting this overwrites the [padding of the Column](crate::column::Column::set_padding)
+    /// for this specific cell. The cell still occupies the full width of its column, so only
+    /// the space that's available for its content changes.
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::new("Indented content").set_padding((4, 1));
+    /// assert_eq!(cell.padding(), Some((4, 1)));
+    /// ```
+    #[must_use]
+    pub fn set_padding(mut self, padding: (u16, u16)) -> Self {
+        self.padding = Some(padding);
+
+        self
+    }
+
+    /// Get the padding of this cell, if it overrides the column's padding.
+    pub fn padding(&self) -> Option<(u16, u16)> {
+        self.padding
+    }
+
+    /// Mark the content of this cell as preformatted.
+    ///
+    /// Preformatted content is never split, wrapped or aligned and all whitespaces are preserved
+    /// exactly as they are. This is useful for ASCII-art, aligned code or small diagrams.\
+    /// The lines of the cell are only padded to the width of the column.
+    ///
+    /// The column will be at least as wide as the widest line of preformatted content,
+    /// unless an upper boundary [ColumnConstraint](crate::ColumnConstraint) forces it to be smaller.
+    /// In that case, lines are cut off at the column's width.
+    ///
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let mut cell = Cell::new("  /\\\n /  \\\n/____\\")
+    ///     .preformatted(true);
+    /// ```
+    #[must_use]
+    pub fn preformatted(mut self, preformatted: bool) -> Self {
+        self.preformatted = preformatted;
+
+        self
+    }
+
+    /// Returns whether the content of this cell is [preformatted](Cell::preformatted).
+    pub fn is_preformatted(&self) -> bool {
+        self.preformatted
+    }
+
+    /// Display this cell on a single line. Content that doesn't fit is cut off and the
+    /// truncation indicator is appended, instead of splitting it into multiple lines.
+    ///
+    /// Only the first line of the content is displayed, further lines are truncated as well.\
+    /// This uses the same indicator as rows with a [maximum height](crate::Row::max_height),
+    /// which is only shown if the column is wide enough, see
+    /// [Table::set_truncation_indicator](crate::Table::set_truncation_indicator).
+    ///
+    /// ```
+    /// use comfy_table::{Cell, ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(20)
+    ///     .add_row(vec![Cell::new("A very long value that is elided").set_truncate(true)]);
+    ///
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| A very long v... |");
+    /// ```
+    #[must_use]
+    pub fn set_truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+
+        self
+    }
+
+    /// Returns whether overflowing content of this cell is [truncated](Cell::set_truncate).
+    pub fn truncates(&self) -> bool {
+        self.truncate
+    }
+
+    /// Attach a reference to a [footnote](crate::Table::add_footnote) to this cell.
+    ///
+    /// A marker such as `[1]` will be displayed at the end of the cell's content.\
+    /// Footnotes are numbered in the order they're added to the table, starting at `1`.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec![Cell::new("Revenue").set_footnote_ref(1)])
+    ///     .add_footnote("Numbers are preliminary.");
+    /// ```
+    #[must_use]
+    pub fn set_footnote_ref(mut self, reference: usize) -> Self {
+        self.footnote_ref = Some(reference);
+
+        self
+    }
+
+    /// Get the number of the footnote this cell refers to, if there is one.
+    pub fn footnote_ref(&self) -> Option<usize> {
+        self.footnote_ref
+    }
+
+    /// Tag this cell with a semantic class, such as `"warning"` or `"total"`.
+    ///
+    /// Classes decouple the meaning of data from its concrete presentation.
+    /// The styles for a class are defined on the table via
+    /// [Table::set_class_style](crate::Table::set_class_style).
+    ///
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::new("Disk almost full").set_class("warning");
+    /// assert_eq!(cell.class(), Some("warning"));
+    /// ```
+    #[must_use]
+    pub fn set_class<T: ToString>(mut self, class: T) -> Self {
+        self.class = Some(class.to_string());
+
+        self
+    }
+
+    /// Get the class of this cell, if there is one.
+    pub fn class(&self) -> Option<&str> {
+        self.class.as_deref()
+    }
+
+    /// Turn the content of this cell into a hyperlink to the given URL.
+    ///
+    /// If the table is [styled](crate::Table::should_style), each line of the content is wrapped
+    /// in OSC 8 escape sequences, which make it clickable in terminals that support them.
+    /// The escape sequences don't take up any space.\
+    /// Control characters are removed from the URL, as they could end the escape sequence early.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .force_no_tty()
+    ///     .enforce_styling()
+    ///     .add_row(vec![Cell::new("#42").set_url("https://example.com/issues/42")]);
+    ///
+    /// let expected = "
+    /// +-----+
+    /// | \x1b]8;;https://example.com/issues/42\x1b\\#42\x1b]8;;\x1b\\ |
+    /// +-----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    #[must_use]
+    pub fn set_url<T: ToString>(mut self, url: T) -> Self {
+        let url = url.to_string();
+        self.url = Some(url.chars().filter(|c| !c.is_control()).collect());
+
+        self
+    }
+
+    /// Get the URL of this cell's hyperlink, if there is one.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Keep all leading and trailing whitespaces of this cell's content, regardless of the
+    /// table's [TrimMode](crate::TrimMode).
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table, TrimMode};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_trim_mode(TrimMode::Both)
+    ///     .add_row(vec![Cell::new("  indented").preserve_whitespace(true)]);
+    /// ```
+    #[must_use]
+    pub fn preserve_whitespace(mut self, preserve: bool) -> Self {
+        self.preserve_whitespace = preserve;
+
+        self
+    }
+
+    /// Pass ANSI escape sequences in the content of this cell through as they are.
+    ///
+    /// By default, comfy-table terminates styles at the end of each line and resumes them on the
+    /// next line, so styles never leak into borders or other cells.
+    /// This might interfere with content that has been produced by other formatters, which
+    /// manage their own state.\
+    /// Raw cells are neither restyled nor are any resets inserted, not even when their content
+    /// is split. The escape sequences are only ignored when measuring the content.
+    /// Styles of the cell, its class and the table aren't applied to raw cells either.
+    ///
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::new("\x1b[31mSome red text").raw_ansi(true);
+    /// assert!(cell.is_raw_ansi());
+    /// ```
+    #[cfg(feature = "custom_styling")]
+    #[must_use]
+    pub fn raw_ansi(mut self, raw_ansi: bool) -> Self {
+        self.raw_ansi = raw_ansi;
+
+        self
+    }
+
+    /// Returns whether ANSI escape sequences in this cell are [passed through](Cell::raw_ansi).
+    #[cfg(feature = "custom_styling")]
+    pub fn is_raw_ansi(&self) -> bool {
+        self.raw_ansi
+    }
+
+    /// Check whether styles have to be terminated and resumed on each line of the content.
+    fn needs_style_fix(&self) -> bool {
+        #[cfg(feature = "custom_styling")]
+        {
+            !self.raw_ansi && self.content.iter().any(|line| line.contains('\x1b'))
+        }
+        #[cfg(not(feature = "custom_styling"))]
+        {
+            false
+        }
+    }
+
+    /// Returns the lines of this cell the way they'll be displayed.
+    ///
+    /// This differs from the actual content, if whitespaces are trimmed due to the table's
+    /// [TrimMode] or if there's additional content that's added during rendering,
+    /// such as footnote markers.
+    pub(crate) fn display_lines(&self, trim_mode: TrimMode) -> Cow<'_, [String]> {
+        let trim = trim_mode != TrimMode::None && !self.preserve_whitespace && !self.preformatted;
+        let fix_style = self.needs_style_fix();
+        if !trim && !fix_style && self.footnote_ref.is_none() {
+            return Cow::Borrowed(&self.content);
+        }
+
+        let mut lines = self.content.clone();
+        // Correct ansi codes so style is terminated and resumed around the newlines.
+        #[cfg(feature = "custom_styling")]
+        if fix_style {
+            crate::utils::formatting::content_split::fix_style_in_split_str(&mut lines);
+        }
+        if trim {
+            for line in lines.iter_mut() {
+                *line = trim_mode.trim(line).to_string();
+            }
+        }
+
+        let reference = match self.footnote_ref {
+            Some(reference) => reference,
+            None => return Cow::Owned(lines),
+        };
+
+        let marker = footnote_marker(reference);
+        match lines.last_mut() {
+            Some(line) => line.push_str(&marker),
+            None => lines.push(marker),
+        }
+
+        Cow::Owned(lines)
+    }
+
     /// Set the foreground text color for this cell.
     ///
-    /// Look at [Color](crate::Color) for a list of all possible Colors.
+    /// Look at [CellColor](crate::CellColor) for a list of all possible Colors.
     /// ```
     /// use comfy_table::Color;
     /// use comfy_table::Cell;
@@ -92,15 +502,15 @@ impl Cell {
     /// ```
     #[cfg(feature = "tty")]
     #[must_use]
-    pub fn fg(mut self, color: Color) -> Self {
-        self.fg = Some(color);
+    pub fn fg<C: Into<CellColor>>(mut self, color: C) -> Self {
+        self.fg = Some(color.into());
 
         self
     }
 
     /// Set the background color for this cell.
     ///
-    /// Look at [Color](crate::Color) for a list of all possible Colors.
+    /// Look at [CellColor](crate::CellColor) for a list of all possible Colors.
     /// ```
     /// use comfy_table::Color;
     /// use comfy_table::Cell;
@@ -110,8 +520,8 @@ impl Cell {
     /// ```
     #[cfg(feature = "tty")]
     #[must_use]
-    pub fn bg(mut self, color: Color) -> Self {
-        self.bg = Some(color);
+    pub fn bg<C: Into<CellColor>>(mut self, color: C) -> Self {
+        self.bg = Some(color.into());
 
         self
     }
@@ -119,7 +529,7 @@ impl Cell {
     /// Add a styling attribute to the content cell.\
     /// Those can be **bold**, _italic_, blinking and many more.
     ///
-    /// Look at [Attribute](crate::Attribute) for a list of all possible Colors.
+    /// Look at [CellAttribute](crate::CellAttribute) for a list of all possible attributes.
     /// ```
     /// use comfy_table::Attribute;
     /// use comfy_table::Cell;
@@ -129,22 +539,35 @@ impl Cell {
     /// ```
     #[cfg(feature = "tty")]
     #[must_use]
-    pub fn add_attribute(mut self, attribute: Attribute) -> Self {
-        self.attributes.push(attribute);
+    pub fn add_attribute<A: Into<CellAttribute>>(mut self, attribute: A) -> Self {
+        self.attributes.push(attribute.into());
 
         self
     }
 
-    /// Same as add_attribute, but you can pass a vector of [Attributes](Attribute)
+    /// Same as add_attribute, but you can pass a vector of [Attributes](CellAttribute)
     #[cfg(feature = "tty")]
     #[must_use]
-    pub fn add_attributes(mut self, mut attribute: Vec<Attribute>) -> Self {
-        self.attributes.append(&mut attribute);
+    pub fn add_attributes<A: Into<CellAttribute>>(mut self, attributes: Vec<A>) -> Self {
+        self.attributes
+            .extend(attributes.into_iter().map(Into::into));
 
         self
     }
 }
 
+/// The template of a [Cell] and the values of its placeholders, see [Cell::new_template].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CellTemplate {
+    pub(crate) template: String,
+    pub(crate) values: BTreeMap<String, String>,
+}
+
+/// The marker that's used to reference a footnote, e.g. `[1]`.
+pub(crate) fn footnote_marker(reference: usize) -> String {
+    format!("[{reference}]")
+}
+
 /// Convert anything with [ToString] to a new [Cell].
 ///
 /// ```
@@ -195,4 +618,13 @@ mod tests {
 
         assert_eq!(cell.content(), content);
     }
+
+    #[test]
+    fn test_from_lines() {
+        let cell = Cell::from_lines(vec!["This is", "some multiline\nstring"]);
+        assert_eq!(cell, Cell::new("This is\nsome multiline\nstring"));
+
+        let cell = Cell::from_lines(Vec::<String>::new());
+        assert_eq!(cell, Cell::new(""));
+    }
 }
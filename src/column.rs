@@ -1,4 +1,11 @@
-use crate::style::{CellAlignment, ColumnConstraint};
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use crate::error::{validate_truncation_indicator, TruncationIndicatorError};
+use crate::style::{CellAlignment, CellVerticalAlignment, ColumnConstraint, NumberFormat};
+use crate::utils::arrangement::helper::{count_visible_columns, min_possible_column_width};
+use crate::utils::formatting::content_split::DEFAULT_WIDTH_FUNCTION;
+use crate::Table;
 
 /// A representation of a table's column.
 /// Useful for styling and specifying constraints how big a column should be.
@@ -8,7 +15,8 @@ use crate::style::{CellAlignment, ColumnConstraint};
 /// 3. Default alignment for cells in this column
 ///
 /// Columns are generated when adding rows or a header to a table.\
-/// As a result columns can only be modified after the table is populated by some data.
+/// As a result columns can only be modified after the table is populated by some data,
+/// unless they're configured up front and added via [Table::add_column].
 ///
 /// ```
 /// use comfy_table::{Width::*, CellAlignment, ColumnConstraint::*, Table};
@@ -38,7 +46,28 @@ pub struct Column {
     pub(crate) delimiter: Option<char>,
     /// Define the [CellAlignment] for all cells of this column
     pub(crate) cell_alignment: Option<CellAlignment>,
+    /// Define the [CellVerticalAlignment] for all cells of this column
+    pub(crate) cell_vertical_alignment: Option<CellVerticalAlignment>,
     pub(crate) constraint: Option<ColumnConstraint>,
+    /// Overwrite the table's truncation indicator for this column
+    pub(crate) truncation_indicator: Option<String>,
+    /// Ignore the header when determining the width of the column's content.
+    pub(crate) size_to_data_only: bool,
+    /// Repeat this column in every chunk of [Table::split_by_width].
+    pub(crate) key_column: bool,
+    /// Replace the content that all cells of this column have in common with this marker.
+    pub(crate) elision_marker: Option<String>,
+    /// Indent lines that continue a wrapped line by this amount of spaces.
+    pub(crate) hanging_indent: u16,
+    /// Split content right after each of these characters instead of at a delimiter.
+    pub(crate) delimiters: Vec<char>,
+    /// Reformat the numbers in this column's body.
+    pub(crate) number_format: Option<NumberFormat>,
+    /// The name of the column, which can be used as its header.
+    pub(crate) name: Option<String>,
+    /// Split content at the end of each match instead of at a delimiter.
+    #[cfg(feature = "regex")]
+    pub(crate) split_regex: Option<Regex>,
 }
 
 impl Column {
@@ -49,6 +78,17 @@ impl Column {
             delimiter: None,
             constraint: None,
             cell_alignment: None,
+            cell_vertical_alignment: None,
+            truncation_indicator: None,
+            size_to_data_only: false,
+            key_column: false,
+            elision_marker: None,
+            hanging_indent: 0,
+            delimiters: Vec::new(),
+            number_format: None,
+            name: None,
+            #[cfg(feature = "regex")]
+            split_regex: None,
         }
     }
 
@@ -78,6 +118,92 @@ impl Column {
         self
     }
 
+    /// Allow the content of this column's cells to be split right after any of these characters.
+    ///
+    /// The characters are kept, so `&[':', ',']` splits `foo::bar, baz` into `foo:`, `:`,
+    /// `bar,` and ` baz`, which are then joined without a delimiter.
+    /// Whitespace at the end of each resulting line and at the start of wrapped lines is removed.\
+    /// Elements that are still too long will be split mid-word as usual.
+    ///
+    /// This overwrites any [delimiter](Column::set_delimiter).
+    /// Passing an empty slice splits content at the delimiter again.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(15)
+    ///     .add_row(vec!["std::fmt::Display"]);
+    /// table.column_mut(0).unwrap().set_delimiters(&[':']);
+    ///
+    /// let expected = "
+    /// +-------------+
+    /// | std::fmt::  |
+    /// | Display     |
+    /// +-------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_delimiters(&mut self, delimiters: &[char]) -> &mut Self {
+        self.delimiters = delimiters.to_vec();
+
+        self
+    }
+
+    /// Get the characters after which the content of this column may be split.
+    pub fn delimiters(&self) -> &[char] {
+        &self.delimiters
+    }
+
+    /// Use a regular expression to determine where the content of this column's cells may be
+    /// split.
+    ///
+    /// Content is split right after each match and the matched text is kept, so
+    /// `Regex::new(", |/")` allows splits after commas followed by a space and after slashes.
+    /// Trailing whitespace is removed from each resulting line.\
+    /// Elements that are still too long will be split mid-word as usual.
+    ///
+    /// This overwrites any [delimiter](Column::set_delimiter) and
+    /// [delimiters](Column::set_delimiters).
+    ///
+    /// ```
+    /// use comfy_table::{Table, ContentArrangement};
+    /// use regex::Regex;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(20)
+    ///     .add_row(vec!["/usr/local/share/comfy-table"]);
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_split_regex(Regex::new("/").unwrap());
+    ///
+    /// assert!(table.to_string().contains("| /usr/local/ |\n| share/      |"));
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn set_split_regex(&mut self, regex: Regex) -> &mut Self {
+        self.split_regex = Some(regex);
+
+        self
+    }
+
+    /// Get the regular expression that's used to split content of this column.
+    #[cfg(feature = "regex")]
+    pub fn split_regex(&self) -> Option<&Regex> {
+        self.split_regex.as_ref()
+    }
+
+    /// Remove the regular expression and split content at the delimiters again.
+    #[cfg(feature = "regex")]
+    pub fn remove_split_regex(&mut self) -> &mut Self {
+        self.split_regex = None;
+
+        self
+    }
+
     /// Constraints allow to influence the auto-adjustment behavior of columns.\
     /// This can be useful to counter undesired auto-adjustment of content in tables.
     pub fn set_constraint(&mut self, constraint: ColumnConstraint) -> &mut Self {
@@ -103,11 +229,290 @@ impl Column {
         matches!(self.constraint, Some(ColumnConstraint::Hidden))
     }
 
+    /// Return the smallest width this column can be arranged to, without cutting off or
+    /// overflowing any of its content in the given table.
+    ///
+    /// That's the width of the widest piece of content that cannot be split, plus the padding.
+    /// Depending on the table's [WrapPolicy](crate::WrapPolicy), that's the widest character
+    /// or the longest word. Lines of [preformatted](crate::Cell::preformatted) content are never
+    /// split. The column's constraint is respected and hidden columns have a width of `0`.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["Hello", "コンフィ"]);
+    ///
+    /// let column = table.column(1).unwrap();
+    /// assert_eq!(column.min_possible_width(&table), 4);
+    /// ```
+    ///
+    /// **Attention** This scans all cells of this column.
+    pub fn min_possible_width(&self, table: &Table) -> usize {
+        let visible_columns = count_visible_columns(&table.columns);
+
+        min_possible_column_width(table, self, visible_columns)
+    }
+
+    /// Set the truncation indicator for cells of this column that are too long to be displayed.
+    ///
+    /// This overwrites the table's indicator.
+    /// See [Table::set_truncation_indicator](crate::Table::set_truncation_indicator) for details
+    /// on which indicators are accepted.\
+    /// As the column doesn't know its table, the indicator is measured with the default
+    /// [width function](crate::Table::set_width_function).
+    pub fn set_truncation_indicator(
+        &mut self,
+        indicator: &str,
+    ) -> Result<&mut Self, TruncationIndicatorError> {
+        self.truncation_indicator = Some(validate_truncation_indicator(
+            indicator,
+            DEFAULT_WIDTH_FUNCTION,
+        )?);
+
+        Ok(self)
+    }
+
+    /// Get the truncation indicator of this column, if it overwrites the table's indicator.
+    pub fn truncation_indicator(&self) -> Option<&str> {
+        self.truncation_indicator.as_deref()
+    }
+
+    /// Remove the column's truncation indicator and use the table's indicator again.
+    pub fn remove_truncation_indicator(&mut self) -> &mut Self {
+        self.truncation_indicator = None;
+
+        self
+    }
+
+    /// Only use the content of the table's rows to determine the width of this column.
+    ///
+    /// By default, a long header can make a column a lot wider than its data needs to be.
+    /// With this option, the header is ignored instead and wraps just like any other cell,
+    /// if it doesn't fit into the column.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Amount in stock"])
+    ///     .add_row(vec!["12"]);
+    /// table.column_mut(0).unwrap().size_to_data_only(true);
+    ///
+    /// assert_eq!(table.column_max_content_widths(), vec![2]);
+    /// ```
+    pub fn size_to_data_only(&mut self, size_to_data_only: bool) -> &mut Self {
+        self.size_to_data_only = size_to_data_only;
+
+        self
+    }
+
+    /// Returns whether the header is ignored when determining the width of this column.
+    pub fn is_sized_to_data_only(&self) -> bool {
+        self.size_to_data_only
+    }
+
+    /// Mark this column as a key column, which identifies the rows of the table.
+    ///
+    /// Key columns are repeated in every chunk of [Table::split_by_width], so each chunk
+    /// can be read on its own.
+    pub fn set_key_column(&mut self, key_column: bool) -> &mut Self {
+        self.key_column = key_column;
+
+        self
+    }
+
+    /// Returns whether this column is repeated in every chunk of [Table::split_by_width].
+    pub fn is_key_column(&self) -> bool {
+        self.key_column
+    }
+
+    /// Elide the content that all cells of this column have in common, such as identical
+    /// URL bases or paths, to make the column a lot narrower.
+    ///
+    /// During rendering, the longest prefix and suffix that all lines of the column's cells
+    /// share are replaced by the given marker. The header isn't changed.
+    ///
+    /// Nothing is elided, if the column has less than two lines of content, if the shared part
+    /// isn't wider than the marker or if the content contains ANSI escape sequences.
+    /// At least a single character of each line is always kept.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Url"])
+    ///     .add_row(vec!["https://example.com/docs/install.html"])
+    ///     .add_row(vec!["https://example.com/docs/usage.html"]);
+    /// table.column_mut(0).unwrap().fit_to_pattern("…");
+    ///
+    /// let expected = "
+    /// +-----------+
+    /// | Url       |
+    /// +===========+
+    /// | …install… |
+    /// |-----------|
+    /// | …usage…   |
+    /// +-----------+";
+    /// assert_eq!(table.to_string(), expected.trim_start());
+    /// ```
+    pub fn fit_to_pattern(&mut self, marker: &str) -> &mut Self {
+        self.elision_marker = Some(marker.to_string());
+
+        self
+    }
+
+    /// Get the marker that replaces the common content of this column's cells,
+    /// if it has been set via [Column::fit_to_pattern].
+    pub fn elision_marker(&self) -> Option<&str> {
+        self.elision_marker.as_deref()
+    }
+
+    /// Stop eliding the common content of this column's cells.
+    pub fn remove_elision_marker(&mut self) -> &mut Self {
+        self.elision_marker = None;
+
+        self
+    }
+
     /// Set the alignment for content inside of cells for this column.\
     /// **Note:** Alignment on a cell will always overwrite the column's setting.
     pub fn set_cell_alignment(&mut self, alignment: CellAlignment) {
         self.cell_alignment = Some(alignment);
     }
+
+    /// Set the vertical alignment for content inside of cells for this column.\
+    /// This determines where a cell's lines are placed, if other cells of the same row have
+    /// more lines. Default is [CellVerticalAlignment::Top].\
+    /// **Note:** Vertical alignment on a cell will always overwrite the column's setting.
+    pub fn set_cell_vertical_alignment(&mut self, alignment: CellVerticalAlignment) -> &mut Self {
+        self.cell_vertical_alignment = Some(alignment);
+
+        self
+    }
+
+    /// Indent the continuation lines of wrapped content by the given amount of spaces.
+    ///
+    /// Only lines that are created by splitting content are indented.
+    /// Newlines added by the user start a new, unindented line.\
+    /// If the column is too narrow, the indentation is reduced, so that at least a single
+    /// character of content fits on each line.
+    ///
+    /// ```
+    /// use comfy_table::{Table, ColumnConstraint::Absolute, Width::Fixed};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["A long line of log output"]);
+    ///
+    /// let column = table.column_mut(0).unwrap();
+    /// column.set_constraint(Absolute(Fixed(14))).set_hanging_indent(2);
+    ///
+    /// let expected = "
+    /// +--------------+
+    /// | A long line  |
+    /// |   of log     |
+    /// |   output     |
+    /// +--------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_hanging_indent(&mut self, indent: u16) -> &mut Self {
+        self.hanging_indent = indent;
+
+        self
+    }
+
+    /// Get the hanging indent of this column's wrapped content.
+    pub fn hanging_indent(&self) -> u16 {
+        self.hanging_indent
+    }
+
+    /// Reformat the numbers in the body of this column with the given [NumberFormat].
+    ///
+    /// Cells whose content isn't a plain decimal number, e.g. `n/a`, are left untouched.
+    ///
+    /// ```
+    /// use comfy_table::{CellAlignment, NumberFormat, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Amount"])
+    ///     .add_row(vec!["1234.5"])
+    ///     .add_row(vec!["3.14159"])
+    ///     .add_row(vec!["-20"]);
+    ///
+    /// let column = table.column_mut(0).unwrap();
+    /// column.set_number_format(
+    ///     NumberFormat::new()
+    ///         .precision(2)
+    ///         .thousands_separator(',')
+    ///         .align_decimal_point(),
+    /// );
+    /// column.set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "
+    /// +----------+
+    /// |   Amount |
+    /// +==========+
+    /// | 1,234.50 |
+    /// |----------|
+    /// |     3.14 |
+    /// |----------|
+    /// |   -20.00 |
+    /// +----------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_number_format(&mut self, format: NumberFormat) -> &mut Self {
+        self.number_format = Some(format);
+
+        self
+    }
+
+    /// Get the [NumberFormat] of this column.
+    pub fn number_format(&self) -> Option<&NumberFormat> {
+        self.number_format.as_ref()
+    }
+
+    /// Display the numbers of this column as they are.
+    pub fn remove_number_format(&mut self) -> &mut Self {
+        self.number_format = None;
+
+        self
+    }
+
+    /// Give this column a name.
+    ///
+    /// The name can be used to look up the column via [Table::column_by_name] and to build the
+    /// table's header via [Table::use_column_names_as_header].
+    ///
+    /// ```
+    /// use comfy_table::{Column, Table};
+    ///
+    /// let mut column = Column::new(0);
+    /// column.set_name("Name");
+    ///
+    /// let mut table = Table::new();
+    /// table.add_column(column);
+    /// assert_eq!(table.column_index_by_name("Name"), Some(0));
+    /// ```
+    pub fn set_name<T: ToString>(&mut self, name: T) -> &mut Self {
+        self.name = Some(name.to_string());
+
+        self
+    }
+
+    /// Get the name of this column, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Remove the name of this column.
+    pub fn remove_name(&mut self) -> &mut Self {
+        self.name = None;
+
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,572 @@
+#[cfg(feature = "csv")]
+use csv::StringRecord;
+#[cfg(feature = "polars")]
+use polars::prelude::{AnyValue, DataFrame};
+#[cfg(feature = "serde")]
+use serde::ser::{self, Serialize};
+
+#[cfg(feature = "serde")]
+use crate::error::SerializeError;
+
+#[cfg(feature = "csv")]
+use crate::row::Row;
+use crate::table::Table;
+
+#[cfg(feature = "csv")]
+impl Table {
+    /// Create a new table with one row per CSV record.
+    ///
+    /// The CSV header isn't part of the records, but it can be directly passed to
+    /// [Table::set_header].
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let data = "name,amount\nApples,3\nPears,5\n";
+    /// let mut reader = csv::Reader::from_reader(data.as_bytes());
+    /// let header = reader.headers().unwrap().clone();
+    ///
+    /// let mut table = Table::from_csv_records(reader.records().map(Result::unwrap));
+    /// table.set_header(&header);
+    ///
+    /// assert_eq!(table.row_count(), 2);
+    /// ```
+    pub fn from_csv_records<I: IntoIterator<Item = StringRecord>>(records: I) -> Table {
+        let mut table = Table::new();
+        table.add_rows(records.into_iter().map(|record| Row::from(&record)));
+
+        table
+    }
+
+    /// Create a new table from CSV data, e.g. from a file or `stdin`.
+    ///
+    /// If `has_header` is set, the first record is used as the table's header.
+    /// Records may have a different amount of fields, missing cells are left empty.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let data = "name,amount\nApples,3\nPears,5\n";
+    /// let table = Table::from_csv_reader(data.as_bytes(), true).unwrap();
+    ///
+    /// let expected = "
+    /// +--------+--------+
+    /// | name   | amount |
+    /// +=================+
+    /// | Apples | 3      |
+    /// |--------+--------|
+    /// | Pears  | 5      |
+    /// +--------+--------+";
+    /// assert_eq!(table.to_string(), expected.trim_start());
+    /// ```
+    pub fn from_csv_reader<R: std::io::Read>(
+        reader: R,
+        has_header: bool,
+    ) -> Result<Table, csv::Error> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(has_header)
+            .flexible(true)
+            .from_reader(reader);
+
+        let records = reader
+            .records()
+            .collect::<Result<Vec<StringRecord>, csv::Error>>()?;
+        let mut table = Table::from_csv_records(records);
+        if has_header {
+            table.set_header(reader.headers()?);
+        }
+
+        Ok(table)
+    }
+
+    /// Get the content of the table as CSV records.
+    ///
+    /// The header is the first record, if there is one.
+    /// Multi-line cells are joined with a `\n`.
+    pub fn csv_records(&self) -> Vec<StringRecord> {
+        self.header
+            .iter()
+            .chain(self.rows.iter())
+            .map(|row| row.cell_iter().map(|cell| cell.content()).collect())
+            .collect()
+    }
+}
+
+#[cfg(feature = "polars")]
+impl From<&DataFrame> for Table {
+    /// Create a table with the column names of the dataframe as header.
+    ///
+    /// Null values are displayed as empty cells.
+    fn from(dataframe: &DataFrame) -> Self {
+        let columns = dataframe.columns();
+
+        let mut table = Table::new();
+        table.set_header(columns.iter().map(|column| column.name().as_str()));
+
+        for index in 0..dataframe.height() {
+            let row: Vec<String> = columns
+                .iter()
+                .map(|column| match column.get(index) {
+                    Ok(AnyValue::Null) | Err(_) => String::new(),
+                    Ok(value) => match value.get_str() {
+                        // Strings would be quoted by their `Display` implementation.
+                        Some(string) => string.to_string(),
+                        None => value.to_string(),
+                    },
+                })
+                .collect();
+            table.add_row(row);
+        }
+
+        table
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Table {
+    /// Add a row with the field values of a [Serialize] value, e.g. a struct.
+    ///
+    /// If the table doesn't have a header yet, the field names are used as header.
+    /// The values are added in the order of the fields.
+    ///
+    /// Nested values are displayed in a single cell.
+    /// Sequences are joined with a `, `, while maps and structs are displayed as one
+    /// `key: value` pair per line. `None` is displayed as an empty cell.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Fruit {
+    ///     name: &'static str,
+    ///     amount: u32,
+    /// }
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_serializable_row(Fruit { name: "Apples", amount: 3 })
+    ///     .unwrap();
+    ///
+    /// let expected = "
+    /// +--------+--------+
+    /// | name   | amount |
+    /// +=================+
+    /// | Apples | 3      |
+    /// +--------+--------+";
+    /// assert_eq!(table.to_string(), expected.trim_start());
+    /// ```
+    pub fn add_serializable_row<T: Serialize>(
+        &mut self,
+        row: T,
+    ) -> Result<&mut Self, SerializeError> {
+        let fields = row.serialize(FieldSerializer)?;
+        if self.header.is_none() {
+            if let Some(names) = fields.names {
+                self.set_header(names);
+            }
+        }
+        self.add_row(fields.values);
+
+        Ok(self)
+    }
+
+    /// Create a new table with one row per [Serialize] value, e.g. from a `Vec` of structs.
+    ///
+    /// The field names of the first value are used as header.
+    /// See [Table::add_serializable_row] for how the values are displayed.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Fruit {
+    ///     name: &'static str,
+    ///     amount: u32,
+    /// }
+    ///
+    /// let fruits = vec![
+    ///     Fruit { name: "Apples", amount: 3 },
+    ///     Fruit { name: "Pears", amount: 5 },
+    /// ];
+    /// let table = Table::from_serializable(&fruits).unwrap();
+    ///
+    /// assert_eq!(table.row_count(), 2);
+    /// assert_eq!(table.header().unwrap().cell_count(), 2);
+    /// ```
+    pub fn from_serializable<T: Serialize, I: IntoIterator<Item = T>>(
+        rows: I,
+    ) -> Result<Table, SerializeError> {
+        let mut table = Table::new();
+        for row in rows {
+            table.add_serializable_row(row)?;
+        }
+
+        Ok(table)
+    }
+}
+
+/// The names and display values of a serialized value's fields.
+///
+/// Plain values, such as numbers or strings, consist of a single unnamed field.
+#[cfg(feature = "serde")]
+#[derive(Default)]
+struct Fields {
+    names: Option<Vec<String>>,
+    values: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl Fields {
+    fn value(value: impl ToString) -> Self {
+        Fields {
+            names: None,
+            values: vec![value.to_string()],
+        }
+    }
+
+    fn named() -> Self {
+        Fields {
+            names: Some(Vec::new()),
+            values: Vec::new(),
+        }
+    }
+
+    /// Display all fields in a single cell.
+    fn into_cell(self) -> String {
+        match self.names {
+            Some(names) => names
+                .iter()
+                .zip(self.values.iter())
+                .map(|(name, value)| format!("{name}: {value}"))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            None => self.values.join(", "),
+        }
+    }
+
+    fn push_name<T: ?Sized + Serialize>(&mut self, name: &T) -> Result<(), SerializeError> {
+        let name = name.serialize(FieldSerializer)?.into_cell();
+        self.names.get_or_insert_with(Vec::new).push(name);
+
+        Ok(())
+    }
+
+    fn push_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.values
+            .push(value.serialize(FieldSerializer)?.into_cell());
+
+        Ok(())
+    }
+
+    fn push_field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.names
+            .get_or_insert_with(Vec::new)
+            .push(name.to_string());
+        self.push_value(value)
+    }
+
+    /// Keep an empty value for fields that are skipped, e.g. via `skip_serializing_if`.
+    /// Otherwise, all following values would end up in the wrong column.
+    fn push_skipped_field(&mut self, name: &'static str) {
+        self.names
+            .get_or_insert_with(Vec::new)
+            .push(name.to_string());
+        self.values.push(String::new());
+    }
+}
+
+/// Serializes a value into its [Fields].
+#[cfg(feature = "serde")]
+struct FieldSerializer;
+
+#[cfg(feature = "serde")]
+impl ser::Serializer for FieldSerializer {
+    type Ok = Fields;
+    type Error = SerializeError;
+    type SerializeSeq = Fields;
+    type SerializeTuple = Fields;
+    type SerializeTupleStruct = Fields;
+    type SerializeTupleVariant = Fields;
+    type SerializeMap = Fields;
+    type SerializeStruct = Fields;
+    type SerializeStructVariant = Fields;
+
+    fn serialize_bool(self, value: bool) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(value))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(String::from_utf8_lossy(value)))
+    }
+
+    fn serialize_none(self) -> Result<Fields, SerializeError> {
+        Ok(Fields::default())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Fields, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Fields, SerializeError> {
+        Ok(Fields::default())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Fields, SerializeError> {
+        Ok(Fields::default())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Fields, SerializeError> {
+        Ok(Fields::value(variant))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Fields, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<Fields, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Fields, SerializeError> {
+        Ok(Fields::default())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Fields, SerializeError> {
+        Ok(Fields::default())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Fields, SerializeError> {
+        Ok(Fields::default())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Fields, SerializeError> {
+        Ok(Fields::default())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Fields, SerializeError> {
+        Ok(Fields::named())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Fields, SerializeError> {
+        Ok(Fields::named())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Fields, SerializeError> {
+        Ok(Fields::named())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::SerializeSeq for Fields {
+    type Ok = Fields;
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_value(value)
+    }
+
+    fn end(self) -> Result<Fields, SerializeError> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::SerializeTuple for Fields {
+    type Ok = Fields;
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_value(value)
+    }
+
+    fn end(self) -> Result<Fields, SerializeError> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::SerializeTupleStruct for Fields {
+    type Ok = Fields;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_value(value)
+    }
+
+    fn end(self) -> Result<Fields, SerializeError> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::SerializeTupleVariant for Fields {
+    type Ok = Fields;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_value(value)
+    }
+
+    fn end(self) -> Result<Fields, SerializeError> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::SerializeMap for Fields {
+    type Ok = Fields;
+    type Error = SerializeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.push_name(key)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_value(value)
+    }
+
+    fn end(self) -> Result<Fields, SerializeError> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::SerializeStruct for Fields {
+    type Ok = Fields;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push_field(name, value)
+    }
+
+    fn skip_field(&mut self, name: &'static str) -> Result<(), Self::Error> {
+        self.push_skipped_field(name);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Fields, SerializeError> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::SerializeStructVariant for Fields {
+    type Ok = Fields;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push_field(name, value)
+    }
+
+    fn skip_field(&mut self, name: &'static str) -> Result<(), Self::Error> {
+        self.push_skipped_field(name);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Fields, SerializeError> {
+        Ok(self)
+    }
+}
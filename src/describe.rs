@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use crate::cell::Cell;
+use crate::table::Table;
+use crate::utils::formatting::content_split::measure_text_width;
+
+impl Table {
+    /// Create a new table with statistics about the columns of this table.
+    ///
+    /// The summary contains one column per column of this table and the following rows:
+    /// - `count`: The number of non-empty cells.
+    /// - `distinct`: The number of distinct values in non-empty cells.
+    /// - `min width`/`max width`: The width of the narrowest and widest cell.
+    /// - `min`/`mean`/`max`: Numeric statistics, if all non-empty cells contain a number.
+    ///
+    /// The header isn't part of the statistics, but is used to name the columns.
+    /// The summary uses the same border style as this table.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Fruit", "Amount"])
+    ///     .add_row(vec!["Apples", "3"])
+    ///     .add_row(vec!["Pears", "4"]);
+    ///
+    /// let summary = table.describe();
+    /// assert_eq!(summary.cell_at(1, 2).unwrap().content(), "2");
+    /// assert_eq!(summary.cell_at(6, 2).unwrap().content(), "3.5");
+    /// ```
+    pub fn describe(&self) -> Table {
+        let mut header = vec![Cell::new("")];
+        let mut count = vec![Cell::new("count")];
+        let mut distinct = vec![Cell::new("distinct")];
+        let mut min_width = vec![Cell::new("min width")];
+        let mut max_width = vec![Cell::new("max width")];
+        let mut min = vec![Cell::new("min")];
+        let mut mean = vec![Cell::new("mean")];
+        let mut max = vec![Cell::new("max")];
+
+        for column in self.columns.iter() {
+            let name = self
+                .header
+                .as_ref()
+                .and_then(|header| header.cells.get(column.index))
+                .map(Cell::content)
+                .unwrap_or_else(|| format!("Column {}", column.index + 1));
+            header.push(Cell::new(name));
+
+            let values: Vec<String> = self
+                .column_cells_iter(column.index)
+                .flatten()
+                .map(Cell::content)
+                .filter(|content| !content.trim().is_empty())
+                .collect();
+
+            count.push(Cell::new(values.len()));
+            let unique: HashSet<&String> = values.iter().collect();
+            distinct.push(Cell::new(unique.len()));
+
+            let widths = values.iter().map(|value| {
+                value
+                    .lines()
+                    .map(|line| measure_text_width(line, self.measure()))
+                    .max()
+                    .unwrap_or_default()
+            });
+            min_width.push(optional_cell(widths.clone().min()));
+            max_width.push(optional_cell(widths.max()));
+
+            // Only show numeric statistics, if the whole column is numeric.
+            let numbers: Option<Vec<f64>> = values
+                .iter()
+                .map(|value| value.trim().parse::<f64>().ok())
+                .collect();
+            match numbers {
+                Some(numbers) if !numbers.is_empty() => {
+                    let sum: f64 = numbers.iter().sum();
+                    let average = sum / numbers.len() as f64;
+                    min.push(Cell::new(numbers.iter().copied().fold(f64::MAX, f64::min)));
+                    mean.push(Cell::new((average * 100.0).round() / 100.0));
+                    max.push(Cell::new(numbers.iter().copied().fold(f64::MIN, f64::max)));
+                }
+                _ => {
+                    min.push(Cell::new(""));
+                    mean.push(Cell::new(""));
+                    max.push(Cell::new(""));
+                }
+            }
+        }
+
+        // Keep the look of the original table.
+        let mut summary = Table::new();
+        summary.style = self.style;
+        summary
+            .set_header(header)
+            .add_rows(vec![count, distinct, min_width, max_width, min, mean, max]);
+
+        summary
+    }
+}
+
+fn optional_cell(value: Option<usize>) -> Cell {
+    match value {
+        Some(value) => Cell::new(value),
+        None => Cell::new(""),
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+#[cfg(feature = "unicode-bidi")]
+use crate::style::BidiMode;
+use crate::style::{Bias, ContentArrangement, OverflowPolicy};
+use crate::table::Table;
+use crate::utils::arrangement::arrange_content;
+use crate::utils::arrangement::helper::minimum_table_width;
+
+impl Table {
+    /// Describe the configuration of the table and how its content has been arranged.
+    ///
+    /// The dump contains the table's settings, the column constraints, the measured content
+    /// widths and the resulting column widths, but none of the table's content.
+    /// This makes it possible to attach it to bug reports about the content arrangement
+    /// without sharing any data.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Description"])
+    ///     .add_row(vec!["Secret", "Some very long and secret text"])
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(30);
+    ///
+    /// let dump = table.debug_dump();
+    /// assert!(dump.contains("width: 30 (Fixed)"));
+    /// assert!(!dump.contains("Secret"));
+    /// ```
+    pub fn debug_dump(&self) -> String {
+        let mut dump = String::new();
+        // Writing to a string cannot fail.
+        let _ = self.write_debug_dump(&mut dump);
+
+        dump
+    }
+
+    fn write_debug_dump(&self, dump: &mut String) -> std::fmt::Result {
+        writeln!(dump, "comfy-table {}", env!("CARGO_PKG_VERSION"))?;
+        writeln!(dump, "arrangement: {:?}", self.arrangement)?;
+        writeln!(dump, "overflow policy: {:?}", self.overflow_policy)?;
+        match self.width() {
+            Some(width) => writeln!(dump, "width: {width} ({:?})", self.width_source())?,
+            None => writeln!(dump, "width: unknown")?,
+        }
+        if let Some(min_width) = self.min_width {
+            writeln!(dump, "min width: {min_width}")?;
+        }
+        writeln!(dump, "trim mode: {:?}", self.trim_mode)?;
+        writeln!(dump, "wrap policy: {:?}", self.wrap_policy)?;
+        #[cfg(feature = "unicode-bidi")]
+        if self.bidi_mode != BidiMode::Logical {
+            writeln!(dump, "bidi mode: {:?}", self.bidi_mode)?;
+        }
+        writeln!(dump, "delimiter: {:?}", self.delimiter)?;
+        if self.center_bias != Bias::Left {
+            writeln!(dump, "center bias: {:?}", self.center_bias)?;
+        }
+        if let Some(order) = self.column_order.as_ref() {
+            writeln!(dump, "column order: {order:?}")?;
+        }
+        if !self.header_separator {
+            writeln!(dump, "header separator: hidden")?;
+        }
+        if self.padding_collapse {
+            writeln!(dump, "padding collapse: true")?;
+        }
+        if let Some(max_height) = self.max_height {
+            writeln!(dump, "max height: {max_height}")?;
+        }
+        if let Some(strategy) = self.truncation_strategy.as_ref() {
+            writeln!(dump, "truncation strategy: {strategy:?}")?;
+        }
+
+        let preset: String = self
+            .style
+            .iter()
+            .map(|character| character.unwrap_or(' '))
+            .collect();
+        writeln!(dump, "style: {preset:?}")?;
+        if !self.style_layers.is_empty() {
+            writeln!(dump, "style layers: {}", self.style_layers.len())?;
+        }
+        writeln!(
+            dump,
+            "header: {}, rows: {}, groups: {}, footnotes: {}",
+            self.header.is_some(),
+            self.rows.len(),
+            self.groups.len(),
+            self.footnotes.len(),
+        )?;
+
+        let hidden_rows = self.rows.iter().filter(|row| row.hidden).count();
+        if hidden_rows > 0 {
+            writeln!(dump, "hidden rows: {hidden_rows}")?;
+        }
+
+        let max_heights: Vec<Option<usize>> = self.rows.iter().map(|row| row.max_height).collect();
+        if max_heights.iter().any(Option::is_some) {
+            writeln!(dump, "row max heights: {max_heights:?}")?;
+        }
+
+        let min_heights: Vec<Option<usize>> = self.rows.iter().map(|row| row.min_height).collect();
+        if min_heights.iter().any(Option::is_some) {
+            writeln!(dump, "row min heights: {min_heights:?}")?;
+        }
+
+        // Determine which path the rendering takes.
+        let minimum_width = minimum_table_width(self);
+        let overflows = !matches!(self.arrangement, ContentArrangement::Disabled)
+            && self
+                .width()
+                .map_or(false, |width| minimum_width > usize::from(width));
+        writeln!(dump, "minimum width: {minimum_width}")?;
+
+        let display_info = match self.frozen_layout.as_ref() {
+            Some(layout) if layout.matches(self) => {
+                writeln!(dump, "layout: frozen")?;
+                Some(layout.display_info(self))
+            }
+            _ if overflows && !matches!(self.overflow_policy, OverflowPolicy::Overflow) => {
+                writeln!(dump, "layout: overflow ({:?})", self.overflow_policy)?;
+                None
+            }
+            _ => {
+                writeln!(dump, "layout: arranged")?;
+                Some(arrange_content(self))
+            }
+        };
+
+        let max_content_widths = self.column_max_content_widths();
+        for column in self.columns.iter() {
+            write!(
+                dump,
+                "column {}: padding={:?} delimiter={:?} alignment={:?} constraint={:?}",
+                column.index,
+                column.padding,
+                column.delimiter,
+                column.cell_alignment,
+                column.constraint,
+            )?;
+            if !column.delimiters.is_empty() {
+                write!(dump, " delimiters={:?}", column.delimiters)?;
+            }
+            if column.size_to_data_only {
+                write!(dump, " size_to_data_only")?;
+            }
+            if let Some(format) = column.number_format.as_ref() {
+                write!(dump, " number_format={format:?}")?;
+            }
+            if let Some(name) = column.name.as_ref() {
+                write!(dump, " name={name:?}")?;
+            }
+            write!(
+                dump,
+                " max_content_width={}",
+                max_content_widths.get(column.index).copied().unwrap_or(0)
+            )?;
+
+            match display_info
+                .as_ref()
+                .and_then(|infos| infos.get(column.index))
+            {
+                Some(info) if info.is_hidden => writeln!(dump, " -> hidden")?,
+                Some(info) => writeln!(dump, " -> {}", info.content_width)?,
+                None => writeln!(dump)?,
+            }
+        }
+
+        Ok(())
+    }
+}
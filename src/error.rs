@@ -0,0 +1,283 @@
+use std::fmt;
+
+use crate::style::TableComponent;
+use crate::table::{WidthFunction, MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH};
+
+/// Returned by [Table::try_render](crate::Table::try_render), if the table cannot be
+/// arranged to fit into the requested width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooNarrowError {
+    /// The width the table has been asked to fit into.
+    pub width: u16,
+    /// The minimal width that's needed to display the table.
+    pub min_width: usize,
+}
+
+impl fmt::Display for TooNarrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Table needs at least {} characters, but only {} are available",
+            self.min_width, self.width
+        )
+    }
+}
+
+impl std::error::Error for TooNarrowError {}
+
+/// Returned when setting [ColumnConstraints](crate::ColumnConstraint) that cannot be applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstraintError {
+    /// More constraints have been passed than the table has columns.
+    TooManyConstraints { constraints: usize, columns: usize },
+    /// The column with the given index doesn't exist.
+    ColumnOutOfRange { index: usize, columns: usize },
+    /// There's no column with the given header text.
+    UnknownColumn,
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintError::TooManyConstraints {
+                constraints,
+                columns,
+            } => write!(
+                f,
+                "Got {constraints} constraints, but the table only has {columns} columns"
+            ),
+            ConstraintError::ColumnOutOfRange { index, columns } => write!(
+                f,
+                "Column {index} doesn't exist, the table only has {columns} columns"
+            ),
+            ConstraintError::UnknownColumn => {
+                write!(f, "The table doesn't have a column with this header")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConstraintError {}
+
+/// Returned when loading a [FrozenLayout](crate::FrozenLayout).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The bytes don't contain a valid layout.
+    InvalidFormat,
+    /// The layout has been serialized by an incompatible version of comfy-table.
+    UnsupportedVersion(u8),
+    /// The layout has been created for a table with a different schema.
+    SchemaMismatch,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::InvalidFormat => write!(f, "The bytes don't contain a valid layout"),
+            LayoutError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported layout version {version}")
+            }
+            LayoutError::SchemaMismatch => {
+                write!(
+                    f,
+                    "The layout has been created for a different table schema"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// Returned by [Table::parse](crate::Table::parse), if the text isn't a rendered table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text doesn't contain any lines with content.
+    Empty,
+    /// The line with the given index has neither a left border nor is it a horizontal line.
+    MissingBorder { line: usize },
+    /// The vertical lines of the line with the given index don't match the columns of the table.
+    InconsistentColumns { line: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "The table doesn't contain any content"),
+            ParseError::MissingBorder { line } => {
+                write!(f, "Line {line} isn't part of a table with the given preset")
+            }
+            ParseError::InconsistentColumns { line } => {
+                write!(
+                    f,
+                    "The cells of line {line} don't match the columns of the table"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Returned by [Table::render_verified](crate::Table::render_verified), if the rendered table
+/// is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationError {
+    /// All issues, in the order of the lines they've been found in.
+    pub issues: Vec<OutputIssue>,
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The rendered table has {} issue(s)", self.issues.len())?;
+        for issue in self.issues.iter() {
+            write!(f, "\n{issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
+/// A single problem of a rendered table, see [VerificationError].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutputIssue {
+    /// The line doesn't have the same display width as the first line of its table.
+    UnevenWidth {
+        line: usize,
+        content: String,
+        width: usize,
+        expected: usize,
+    },
+    /// The line contains a control character outside of an ANSI escape sequence.
+    ControlCharacter {
+        line: usize,
+        content: String,
+        character: char,
+    },
+}
+
+impl fmt::Display for OutputIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputIssue::UnevenWidth {
+                line,
+                content,
+                width,
+                expected,
+            } => write!(
+                f,
+                "Line {line} is {width} characters wide instead of {expected}: '{content}'"
+            ),
+            OutputIssue::ControlCharacter {
+                line,
+                content,
+                character,
+            } => write!(
+                f,
+                "Line {line} contains the control character {character:?}: {content:?}"
+            ),
+        }
+    }
+}
+
+/// Returned when setting a truncation indicator that's wider than
+/// [MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH](crate::MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH),
+/// as it wouldn't fit into the narrowest column that displays indicators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TruncationIndicatorError {
+    /// The rejected indicator.
+    pub indicator: String,
+    /// The display width of the rejected indicator.
+    pub width: usize,
+}
+
+impl fmt::Display for TruncationIndicatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Truncation indicator '{}' is {} characters wide, but at most {} are allowed",
+            self.indicator, self.width, MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH
+        )
+    }
+}
+
+impl std::error::Error for TruncationIndicatorError {}
+
+/// Make sure a truncation indicator fits into the narrowest column that displays it.
+pub(crate) fn validate_truncation_indicator(
+    indicator: &str,
+    measure: WidthFunction,
+) -> Result<String, TruncationIndicatorError> {
+    let width = measure(indicator);
+    if width > MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH {
+        return Err(TruncationIndicatorError {
+            indicator: indicator.to_string(),
+            width,
+        });
+    }
+
+    Ok(indicator.to_string())
+}
+
+/// Returned when parsing a [TableStyle](crate::TableStyle) from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TableStyleParseError {
+    /// A line isn't in the `Component=character` format.
+    MissingSeparator(String),
+    /// The name doesn't match any [TableComponent].
+    UnknownComponent(String),
+    /// The value is neither empty nor a single character.
+    InvalidValue {
+        component: TableComponent,
+        value: String,
+    },
+}
+
+impl fmt::Display for TableStyleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableStyleParseError::MissingSeparator(line) => {
+                write!(f, "Expected a 'Component=character' line, got '{line}'")
+            }
+            TableStyleParseError::UnknownComponent(name) => {
+                write!(f, "Unknown table component '{name}'")
+            }
+            TableStyleParseError::InvalidValue { component, value } => write!(
+                f,
+                "Expected a single character or nothing for {component:?}, got '{value}'"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TableStyleParseError {}
+
+/// Returned by [Table::add_serializable_row](crate::Table::add_serializable_row) and
+/// [Table::from_serializable](crate::Table::from_serializable), if a value cannot be serialized.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializeError {
+    /// The error message of the value's [Serialize](serde::Serialize) implementation.
+    pub message: String,
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to serialize row: {}", self.message)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for SerializeError {}
+
+#[cfg(feature = "serde")]
+impl serde::ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        SerializeError {
+            message: message.to_string(),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use crate::cell::Cell;
+use crate::row::Section;
+use crate::style::CellAlignment;
+use crate::table::Table;
+use crate::utils::arrangement::helper::count_visible_columns;
+use crate::utils::formatting::column_order::reorder_columns;
+use crate::utils::formatting::hidden_rows::remove_hidden_rows;
+use crate::utils::formatting::numbers::format_numbers;
+use crate::utils::formatting::templates::resolve_templates;
+
+impl Table {
+    /// Export the table as an HTML `<table>`.
+    ///
+    /// - The header is put into a `<thead>`, all rows are put into a `<tbody>`.
+    ///   Each [RowGroup](crate::RowGroup) gets its own `<tbody>`.
+    /// - [Sections](crate::Section) are rows with a single `<th>`, which spans over all columns.
+    /// - The [class](Cell::set_class) of a cell is used as its `class` attribute.
+    /// - Cell and column alignments are mapped to a `text-align` style.
+    /// - Hidden columns and [hidden rows](crate::Row::set_hidden) are skipped.
+    /// - Columns are exported in their [display order](Table::set_column_order).
+    /// - Newlines inside of cells are converted to `<br>` tags.
+    /// - Cells with a [URL](Cell::set_url) are turned into links.
+    /// - [Templates](Cell::new_template) are resolved and
+    ///   [number formats](crate::Column::set_number_format) are applied.
+    ///
+    /// Border styles, colors and the content arrangement are terminal specific and ignored.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Fruit", "Amount"])
+    ///     .add_row(vec![
+    ///         Cell::new("Apples & Pears").set_class("fruit"),
+    ///         Cell::new("3").set_alignment(CellAlignment::Right),
+    ///     ]);
+    ///
+    /// let expected = "<table>
+    ///   <thead>
+    ///     <tr>
+    ///       <th>Fruit</th>
+    ///       <th>Amount</th>
+    ///     </tr>
+    ///   </thead>
+    ///   <tbody>
+    ///     <tr>
+    ///       <td class=\"fruit\">Apples &amp; Pears</td>
+    ///       <td style=\"text-align: right\">3</td>
+    ///     </tr>
+    ///   </tbody>
+    /// </table>
+    /// ";
+    /// assert_eq!(table.to_html(), expected);
+    /// ```
+    pub fn to_html(&self) -> String {
+        // Hidden rows are removed, columns are reordered, templates are resolved and numbers
+        // are formatted just like during rendering.
+        let table = remove_hidden_rows(self);
+        let table = reorder_columns(table.as_ref());
+        let table = resolve_templates(table.as_ref());
+        let table = format_numbers(table.as_ref());
+        let table = table.as_ref();
+        let mut html = String::from("<table>\n");
+
+        if let Some(header) = table.header.as_ref() {
+            html += "  <thead>\n";
+            html_row(&mut html, table, header.cell_iter(), "th");
+            html += "  </thead>\n";
+        }
+
+        if !table.rows.is_empty() || !table.sections.is_empty() {
+            html += "  <tbody>\n";
+            let mut sections = table.sections.iter().peekable();
+            for (index, row) in table.rows.iter().enumerate() {
+                // Groups are separated by starting a new body.
+                if table.is_group_boundary(index) {
+                    html += "  </tbody>\n  <tbody>\n";
+                }
+                while let Some(section) = sections.next_if(|section| section.row_index <= index) {
+                    html_section(&mut html, table, section);
+                }
+                html_row(&mut html, table, row.cell_iter(), "td");
+            }
+            for section in sections {
+                html_section(&mut html, table, section);
+            }
+            html += "  </tbody>\n";
+        }
+
+        html += "</table>\n";
+        html
+    }
+}
+
+fn html_row<'a>(
+    html: &mut String,
+    table: &Table,
+    cells: impl Iterator<Item = &'a Cell>,
+    tag: &str,
+) {
+    *html += "    <tr>\n";
+    for (cell, column) in cells.zip(table.columns.iter()) {
+        if column.is_hidden() {
+            continue;
+        }
+
+        *html += &format!("      <{tag}{}>", attributes(cell, column.cell_alignment));
+        *html += &content(cell);
+        *html += &format!("</{tag}>\n");
+    }
+    *html += "    </tr>\n";
+}
+
+/// A section is a row with a single header cell, which spans over all visible columns.
+fn html_section(html: &mut String, table: &Table, section: &Section) {
+    let columns = std::cmp::max(1, count_visible_columns(&table.columns));
+
+    *html += "    <tr>\n";
+    *html += &format!(
+        "      <th colspan=\"{columns}\"{}>",
+        attributes(&section.title, None)
+    );
+    *html += &content(&section.title);
+    *html += "</th>\n";
+    *html += "    </tr>\n";
+}
+
+/// The escaped content of a cell. Cells with a URL are turned into links.
+fn content(cell: &Cell) -> String {
+    let lines: Vec<String> = cell.content.iter().map(|line| escape(line)).collect();
+    let content = lines.join("<br>");
+
+    match cell.url.as_ref() {
+        Some(url) => format!("<a href=\"{}\">{content}</a>", escape(url)),
+        None => content,
+    }
+}
+
+fn attributes(cell: &Cell, column_alignment: Option<CellAlignment>) -> String {
+    let mut attributes = String::new();
+    if let Some(class) = cell.class.as_ref() {
+        attributes += &format!(" class=\"{}\"", escape(class));
+    }
+
+    // Cell alignments take precedence over column alignments.
+    let alignment = match cell.alignment.or(column_alignment) {
+        Some(CellAlignment::Left) => Some("left"),
+        Some(CellAlignment::Right) => Some("right"),
+        Some(CellAlignment::Center) => Some("center"),
+        None => None,
+    };
+    if let Some(alignment) = alignment {
+        attributes += &format!(" style=\"text-align: {alignment}\"");
+    }
+
+    attributes
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            '\'' => escaped += "&#39;",
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,430 @@
+use crate::error::LayoutError;
+use crate::style::{Bias, CellAlignment};
+use crate::utils::arrangement::arrange_content;
+use crate::utils::arrangement::helper::table_width;
+use crate::utils::formatting::borders::draw_borders;
+use crate::utils::formatting::column_order::reorder_columns;
+use crate::utils::formatting::content_format::{format_content, row_kinds, RowKind};
+use crate::utils::formatting::footnotes::draw_footnotes;
+use crate::utils::formatting::prepare_content;
+use crate::utils::formatting::titles::{draw_caption, draw_title};
+use crate::utils::ColumnDisplayInfo;
+use crate::Table;
+
+/// Magic bytes at the start of every serialized layout.
+const MAGIC: &[u8; 4] = b"CTFL";
+/// The version of the binary format.
+/// Bump this whenever the format or the schema fingerprint changes.
+const VERSION: u8 = 1;
+/// Marker for columns without a custom delimiter.
+const NO_DELIMITER: u32 = u32::MAX;
+
+/// The result of the content arrangement of a table.
+///
+/// Arranging the content of large tables can be expensive, as the content of every cell needs
+/// to be measured. If you render many tables with the same schema, you can arrange the content
+/// once, serialize the layout and load it on other tables to skip the arrangement entirely.
+///
+/// A layout is only applicable to tables with the same schema, which includes the header,
+/// the number of columns, their padding, delimiters, alignment and constraints, as well as the
+/// table's width and content arrangement. The content of the rows isn't part of the schema.\
+/// If the schema of a table changes after loading a layout, the layout is ignored and the
+/// content is arranged as usual.
+///
+/// ```
+/// use comfy_table::{FrozenLayout, Table};
+///
+/// let mut table = Table::new();
+/// table.set_header(vec!["Name", "Age"]).add_row(vec!["Nils", "42"]);
+/// let bytes = table.freeze_layout().to_bytes();
+///
+/// // Somewhere else
+/// let layout = FrozenLayout::from_bytes(&bytes).unwrap();
+/// let mut other = Table::new();
+/// other
+///     .set_header(vec!["Name", "Age"])
+///     .add_row(vec!["Maximilian", "7"])
+///     .set_frozen_layout(layout)
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrozenLayout {
+    pub(crate) schema: u64,
+    pub(crate) columns: Vec<FrozenColumn>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FrozenColumn {
+    padding: (u16, u16),
+    delimiter: Option<char>,
+    content_width: u16,
+    cell_alignment: Option<CellAlignment>,
+    is_hidden: bool,
+}
+
+impl FrozenLayout {
+    /// Arrange the content of the given table and freeze the result.
+    pub(crate) fn new(table: &Table) -> Self {
+        let columns = arrange_content(&prepare_content(table))
+            .into_iter()
+            .map(|info| FrozenColumn {
+                padding: info.padding,
+                delimiter: info.delimiter,
+                content_width: info.content_width,
+                cell_alignment: info.cell_alignment,
+                is_hidden: info.is_hidden,
+            })
+            .collect();
+
+        Self {
+            schema: schema_fingerprint(table),
+            columns,
+        }
+    }
+
+    /// Check whether this layout can be used to display the given table.
+    pub fn matches(&self, table: &Table) -> bool {
+        self.schema == schema_fingerprint(table)
+    }
+
+    /// Convert this layout back into the info that's used to format the table.
+    ///
+    /// The table's schema matches the layout, so all settings except the content width are
+    /// taken from the table's columns. This includes settings that cannot be serialized.
+    pub(crate) fn display_info(&self, table: &Table) -> Vec<ColumnDisplayInfo> {
+        // The layout has been created for the columns in their display order.
+        let table = reorder_columns(table);
+        table
+            .columns
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(column, frozen)| {
+                // Paddings might have been collapsed during the arrangement.
+                let mut info = ColumnDisplayInfo::new(column, frozen.content_width);
+                info.padding = frozen.padding;
+                info
+            })
+            .collect()
+    }
+
+    /// Serialize this layout into a compact, versioned binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(17 + self.columns.len() * 12);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.schema.to_le_bytes());
+        bytes.extend_from_slice(&(self.columns.len() as u32).to_le_bytes());
+
+        for column in self.columns.iter() {
+            bytes.extend_from_slice(&column.padding.0.to_le_bytes());
+            bytes.extend_from_slice(&column.padding.1.to_le_bytes());
+            bytes.extend_from_slice(&column.content_width.to_le_bytes());
+            let delimiter = column.delimiter.map_or(NO_DELIMITER, u32::from);
+            bytes.extend_from_slice(&delimiter.to_le_bytes());
+            bytes.push(match column.cell_alignment {
+                None => 0,
+                Some(CellAlignment::Left) => 1,
+                Some(CellAlignment::Right) => 2,
+                Some(CellAlignment::Center) => 3,
+            });
+            bytes.push(u8::from(column.is_hidden));
+        }
+
+        bytes
+    }
+
+    /// Load a layout that has been serialized via [FrozenLayout::to_bytes].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, LayoutError> {
+        let mut reader = Reader { bytes };
+        if reader.take(4)? != MAGIC {
+            return Err(LayoutError::InvalidFormat);
+        }
+        let version = reader.u8()?;
+        if version != VERSION {
+            return Err(LayoutError::UnsupportedVersion(version));
+        }
+
+        let schema = u64::from_le_bytes(reader.array()?);
+        let count = u32::from_le_bytes(reader.array()?);
+        let mut columns = Vec::new();
+        for _ in 0..count {
+            let padding = (reader.u16()?, reader.u16()?);
+            let content_width = reader.u16()?;
+            let delimiter = match u32::from_le_bytes(reader.array()?) {
+                NO_DELIMITER => None,
+                delimiter => Some(char::from_u32(delimiter).ok_or(LayoutError::InvalidFormat)?),
+            };
+            let cell_alignment = match reader.u8()? {
+                0 => None,
+                1 => Some(CellAlignment::Left),
+                2 => Some(CellAlignment::Right),
+                3 => Some(CellAlignment::Center),
+                _ => return Err(LayoutError::InvalidFormat),
+            };
+            let is_hidden = match reader.u8()? {
+                0 => false,
+                1 => true,
+                _ => return Err(LayoutError::InvalidFormat),
+            };
+
+            columns.push(FrozenColumn {
+                padding,
+                delimiter,
+                content_width,
+                cell_alignment,
+                is_hidden,
+            });
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(LayoutError::InvalidFormat);
+        }
+
+        Ok(Self { schema, columns })
+    }
+}
+
+/// The planned layout of a table, as returned by [Table::plan](crate::Table::plan).
+///
+/// The plan allows to inspect the size of a table before it's rendered.
+/// If you're not happy with the result, tweak the table (e.g. its constraints) and plan again.
+/// Otherwise, pass the plan to [Table::render](crate::Table::render), which skips the content
+/// arrangement.
+///
+/// ```
+/// use comfy_table::{ColumnConstraint, Table, Width};
+///
+/// let mut table = Table::new();
+/// table
+///     .set_header(vec!["Name", "Description"])
+///     .add_row(vec!["comfy-table", "Build beautiful tables with automatic content wrapping"]);
+///
+/// let mut plan = table.plan();
+/// if plan.width() > 50 {
+///     table
+///         .column_mut(1)
+///         .unwrap()
+///         .set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(30)));
+///     plan = table.plan();
+/// }
+///
+/// assert_eq!(plan.width(), 46);
+/// assert_eq!(plan.row_heights(), &[1, 2]);
+/// println!("{}", table.render(&plan));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RenderPlan {
+    pub(crate) layout: FrozenLayout,
+    column_widths: Vec<Option<u16>>,
+    row_heights: Vec<usize>,
+    width: usize,
+    height: usize,
+}
+
+impl RenderPlan {
+    pub(crate) fn new(table: &Table) -> Self {
+        let layout = match table.frozen_layout.as_ref() {
+            Some(layout) if layout.matches(table) => layout.clone(),
+            _ => FrozenLayout::new(table),
+        };
+
+        let table = prepare_content(table);
+        let table = table.as_ref();
+        let display_info = layout.display_info(table);
+        let content = format_content(table, &display_info);
+        // Sections aren't rows of the table.
+        let row_heights = content
+            .iter()
+            .zip(row_kinds(table))
+            .filter(|(_, kind)| !matches!(kind, RowKind::Section(_)))
+            .map(|(row, _)| row.len())
+            .collect();
+        let mut lines = Vec::new();
+        draw_borders(table, &content, &display_info, &mut lines);
+        draw_title(table, &display_info, &mut lines, 0);
+        draw_caption(table, &display_info, &mut lines);
+        draw_footnotes(table, &display_info, &mut lines);
+        let height = lines.len();
+
+        Self {
+            column_widths: display_info
+                .iter()
+                .map(|info| (!info.is_hidden).then(|| info.width()))
+                .collect(),
+            row_heights,
+            width: table_width(table, &display_info),
+            height,
+            layout,
+        }
+    }
+
+    /// The width of each column, including padding.
+    /// Hidden columns are `None`.
+    pub fn column_widths(&self) -> &[Option<u16>] {
+        &self.column_widths
+    }
+
+    /// The number of lines of each row.
+    /// If the table has a header, it's the first entry. [Sections](crate::Section) aren't included.
+    pub fn row_heights(&self) -> &[usize] {
+        &self.row_heights
+    }
+
+    /// The total width of the table in characters, including borders.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The total number of lines of the table, including borders and footnotes.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The planned layout, e.g. to [serialize](FrozenLayout::to_bytes) it.
+    pub fn layout(&self) -> &FrozenLayout {
+        &self.layout
+    }
+}
+
+/// Minimal cursor over the serialized bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], LayoutError> {
+        if self.bytes.len() < count {
+            return Err(LayoutError::InvalidFormat);
+        }
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], LayoutError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8, LayoutError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, LayoutError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+}
+
+/// Compute a fingerprint of everything that influences the arrangement of a table,
+/// except for the content of its rows.
+///
+/// This uses FNV-1a instead of the std hasher, as the fingerprint needs to be stable across
+/// processes, machines and Rust versions.
+fn schema_fingerprint(table: &Table) -> u64 {
+    // Reordered columns are compared in the order they're displayed.
+    let table = reorder_columns(table);
+    let table = table.as_ref();
+    let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+
+    hasher.write(format!("{:?}", table.arrangement).as_bytes());
+    hasher.write(format!("{:?}", table.trim_mode).as_bytes());
+    hasher.write(&table.width().unwrap_or(0).to_le_bytes());
+    // Only hashed if set, which keeps the fingerprints of existing layouts valid.
+    if let Some(min_width) = table.min_width {
+        hasher.write(&min_width.to_le_bytes());
+    }
+    if table.center_bias != Bias::Left {
+        hasher.write(format!("{:?}", table.center_bias).as_bytes());
+    }
+    if table.padding_collapse {
+        hasher.write(b"padding collapse");
+    }
+    hasher.write(
+        &table
+            .delimiter
+            .map_or(NO_DELIMITER, u32::from)
+            .to_le_bytes(),
+    );
+    // Function pointers aren't stable across processes, so only their presence is considered.
+    hasher.write(&[table.width_function.is_some() as u8]);
+
+    hasher.write(&(table.columns.len() as u64).to_le_bytes());
+    for column in table.columns.iter() {
+        hasher.write(&column.padding.0.to_le_bytes());
+        hasher.write(&column.padding.1.to_le_bytes());
+        hasher.write(
+            &column
+                .delimiter
+                .map_or(NO_DELIMITER, u32::from)
+                .to_le_bytes(),
+        );
+        hasher.write(format!("{:?}", column.cell_alignment).as_bytes());
+        hasher.write(format!("{:?}", column.constraint).as_bytes());
+        hasher.write(&[column.size_to_data_only as u8]);
+        hasher.write(&column.hanging_indent.to_le_bytes());
+        for delimiter in column.delimiters.iter() {
+            hasher.write(&u32::from(*delimiter).to_le_bytes());
+        }
+        #[cfg(feature = "regex")]
+        if let Some(regex) = column.split_regex.as_ref() {
+            hasher.write(regex.as_str().as_bytes());
+        }
+    }
+
+    if let Some(header) = table.header.as_ref() {
+        for cell in header.cells.iter() {
+            for line in cell.display_lines(table.trim_mode).iter() {
+                hasher.write(line.as_bytes());
+                hasher.write(b"\n");
+            }
+            // Separate cells, so ["ab", "c"] and ["a", "bc"] don't collide.
+            hasher.write(&[0]);
+        }
+    }
+
+    hasher.0
+}
+
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut table = Table::new();
+        table
+            .set_header(vec!["head", "head"])
+            .add_row(vec!["some text", "more"]);
+        table.column_mut(1).unwrap().set_delimiter('-');
+
+        let layout = table.freeze_layout();
+        let bytes = layout.to_bytes();
+        assert_eq!(FrozenLayout::from_bytes(&bytes), Ok(layout));
+
+        // Truncated or altered input is rejected.
+        assert_eq!(
+            FrozenLayout::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(LayoutError::InvalidFormat)
+        );
+        let mut altered = bytes.clone();
+        altered[4] = VERSION + 1;
+        assert_eq!(
+            FrozenLayout::from_bytes(&altered),
+            Err(LayoutError::UnsupportedVersion(VERSION + 1))
+        );
+    }
+}
@@ -5,10 +5,30 @@
 // Had a few false-positives on v1.81. Check lateron if they're still there.
 #![allow(clippy::manual_unwrap_or)]
 
+mod builder;
 mod cell;
 mod column;
+#[cfg(any(feature = "csv", feature = "polars", feature = "serde"))]
+mod convert;
+mod describe;
+mod dump;
+mod error;
+mod html;
+mod layout;
+mod live;
+#[cfg(feature = "macros")]
+mod macros;
+mod parse;
 mod row;
-mod style;
+#[cfg(feature = "integration_test")]
+/// [Proptest](https://docs.rs/proptest) strategies to create random tables.
+/// They're used for our property tests, but can be used to test wrappers around comfy-table
+/// as well. The API inside of this isn't considered stable.
+pub mod strategies;
+/// Everything that's related to the look of tables, such as presets, constraints and colors.
+///
+/// All of its types are re-exported at the crate's root as well.
+pub mod style;
 mod table;
 #[cfg(feature = "integration_test")]
 /// We publicly expose the internal [utils] module for our integration tests.
@@ -17,9 +37,24 @@ mod table;
 pub mod utils;
 #[cfg(not(feature = "integration_test"))]
 mod utils;
+mod viewport;
 
+pub use crate::builder::TableBuilder;
 pub use crate::cell::{Cell, Cells};
 pub use crate::column::Column;
-pub use crate::row::Row;
-pub use crate::table::{ColumnCellIter, Table};
+#[cfg(feature = "serde")]
+pub use crate::error::SerializeError;
+pub use crate::error::{
+    ConstraintError, LayoutError, OutputIssue, ParseError, TableStyleParseError, TooNarrowError,
+    TruncationIndicatorError, VerificationError,
+};
+pub use crate::layout::{FrozenLayout, RenderPlan};
+pub use crate::live::LiveTable;
+pub use crate::row::{Row, RowGroup, Section, ToRow};
+pub use crate::table::{
+    ColumnCellIter, ConstraintReport, LinePatch, RenderScratch, RenderedRow, Table,
+    TruncatedContent, WidthFunction, WidthSource, BORDER_CHAR_REPLACEMENT,
+    MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH,
+};
+pub use crate::viewport::Viewport;
 pub use style::*;
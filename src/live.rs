@@ -0,0 +1,237 @@
+use std::collections::BTreeSet;
+
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::table::Table;
+use crate::utils::arrangement::arrange_content_with_cache;
+use crate::utils::arrangement::split_cache::SplitCache;
+use crate::utils::formatting::content_format::{format_content, format_row};
+use crate::utils::overflow::handle_overflow;
+use crate::utils::{
+    build_table, draw_formatted_table, draws_rows_independently, ColumnDisplayInfo,
+};
+
+/// A table that's updated in place and rendered over and over again, e.g. in a dashboard.
+///
+/// The [LiveTable] keeps track of the rows and columns that changed since the last rendering.
+/// Formatting the content of a row is the most expensive part of the rendering, so rows that
+/// didn't change are taken from a cache, as long as the widths of the columns stay the same.
+///
+/// The content is only arranged again, if a column's content or the table's width changed.
+/// Even then, the content of columns is only split again, if its hash changed.
+///
+/// ```
+/// use comfy_table::{LiveTable, Table};
+///
+/// let mut table = Table::new();
+/// table
+///     .set_header(vec!["Service", "Status"])
+///     .add_row(vec!["database", "up"])
+///     .add_row(vec!["webserver", "up"]);
+///
+/// let mut live = LiveTable::new(table);
+/// live.render();
+///
+/// // Only the second row has to be formatted again.
+/// live.update_cell(1, 1, "down");
+/// assert!(live.render().contains("| webserver | down   |"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LiveTable {
+    table: Table,
+    cache: Option<RenderCache>,
+    splits: SplitCache,
+    dirty_rows: BTreeSet<usize>,
+    dirty_columns: BTreeSet<usize>,
+}
+
+/// The intermediate results of the last rendering.
+#[derive(Clone, Debug)]
+struct RenderCache {
+    width: Option<u16>,
+    display_info: Vec<ColumnDisplayInfo>,
+    /// The formatted header (if it exists) and rows.
+    content: Vec<Vec<Vec<String>>>,
+}
+
+impl LiveTable {
+    /// Wrap a table. The first rendering formats the whole table.
+    pub fn new(table: Table) -> Self {
+        Self {
+            table,
+            cache: None,
+            splits: SplitCache::new(),
+            dirty_rows: BTreeSet::new(),
+            dirty_columns: BTreeSet::new(),
+        }
+    }
+
+    /// Get the wrapped table.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Get mutable access to the wrapped table.
+    ///
+    /// As the [LiveTable] cannot know what's going to be changed, the next rendering formats
+    /// the whole table. Use [LiveTable::update_cell], [LiveTable::update_row] and
+    /// [LiveTable::add_row] for changes that only affect parts of the table.
+    pub fn table_mut(&mut self) -> &mut Table {
+        self.invalidate();
+        self.splits = SplitCache::new();
+        self.dirty_rows.clear();
+        self.dirty_columns.clear();
+
+        &mut self.table
+    }
+
+    /// Unwrap the table.
+    pub fn into_inner(self) -> Table {
+        self.table
+    }
+
+    /// Replace the cell at the given position in the table's body.
+    ///
+    /// Returns the previous cell, or `None` if there's no cell at this position.
+    /// In that case, the table isn't changed.
+    pub fn update_cell<T: Into<Cell>>(
+        &mut self,
+        row: usize,
+        column: usize,
+        value: T,
+    ) -> Option<Cell> {
+        let cell = self.table.rows.get_mut(row)?.cells.get_mut(column)?;
+        let previous = std::mem::replace(cell, value.into());
+
+        self.dirty_rows.insert(row);
+        self.dirty_columns.insert(column);
+
+        Some(previous)
+    }
+
+    /// Replace the row at the given index of the table's body.
+    ///
+    /// Returns the previous row, or `None` if there's no row at this index.
+    /// In that case, the table isn't changed.
+    pub fn update_row<T: Into<Row>>(&mut self, index: usize, row: T) -> Option<Row> {
+        let mut row = row.into();
+        row.index = Some(index);
+        let previous = std::mem::replace(self.table.rows.get_mut(index)?, row);
+
+        let column_count = self.table.columns.len();
+        self.table.discover_columns();
+        if self.table.columns.len() != column_count {
+            self.invalidate();
+        }
+
+        self.dirty_rows.insert(index);
+        self.dirty_columns.extend(0..self.table.columns.len());
+
+        Some(previous)
+    }
+
+    /// Add a new row to the end of the table's body.
+    pub fn add_row<T: Into<Row>>(&mut self, row: T) -> &mut Self {
+        self.table.add_row(row);
+
+        self.dirty_rows.insert(self.table.rows.len() - 1);
+        self.dirty_columns.extend(0..self.table.columns.len());
+
+        self
+    }
+
+    /// Render the table. Rows that didn't change since the last rendering aren't formatted again.
+    pub fn render(&mut self) -> String {
+        self.render_lines().join("\n")
+    }
+
+    /// Drop the cached content, so the next rendering formats the whole table.
+    fn invalidate(&mut self) {
+        self.cache = None;
+    }
+
+    fn render_lines(&mut self) -> Vec<String> {
+        if !draws_rows_independently(&self.table) {
+            self.invalidate();
+            return build_table(&self.table).collect();
+        }
+
+        let table = &self.table;
+        let width = table.width();
+
+        // The previous arrangement is still valid, if neither the content of any column
+        // nor the width of the table changed.
+        let cached_info = self
+            .cache
+            .as_ref()
+            .filter(|cache| cache.width == width && self.dirty_columns.is_empty())
+            .map(|cache| cache.display_info.clone());
+
+        let display_info = match table.frozen_layout.as_ref() {
+            Some(layout) if layout.matches(table) => layout.display_info(table),
+            _ => match cached_info {
+                Some(display_info) => display_info,
+                None => {
+                    let mut lines = Vec::new();
+                    if handle_overflow(table, &mut lines).is_some() {
+                        self.invalidate();
+                        return lines;
+                    }
+
+                    self.splits.refresh(table, self.dirty_columns.iter());
+                    arrange_content_with_cache(table, &mut self.splits)
+                }
+            },
+        };
+
+        // Reuse the formatted rows, if the columns' widths didn't change.
+        // Otherwise, all rows have to be formatted again.
+        let header_offset = usize::from(table.header.is_some());
+        let content = match self.cache.take() {
+            Some(mut cache) if same_layout(&cache.display_info, &display_info) => {
+                cache
+                    .content
+                    .resize(table.rows.len() + header_offset, Vec::new());
+                for row_index in self.dirty_rows.iter() {
+                    if let Some(row) = table.rows.get(*row_index) {
+                        cache.content[row_index + header_offset] = format_row(
+                            row,
+                            Some(*row_index),
+                            &display_info,
+                            table,
+                            &mut Vec::new(),
+                        );
+                    }
+                }
+                cache.content
+            }
+            _ => format_content(table, &display_info),
+        };
+
+        let mut lines = Vec::new();
+        draw_formatted_table(table, &content, &display_info, &mut lines);
+
+        self.cache = Some(RenderCache {
+            width,
+            display_info,
+            content,
+        });
+        self.dirty_rows.clear();
+        self.dirty_columns.clear();
+
+        lines
+    }
+}
+
+/// Check whether formatted content can be reused for the other arrangement.
+fn same_layout(left: &[ColumnDisplayInfo], right: &[ColumnDisplayInfo]) -> bool {
+    left.len() == right.len()
+        && left.iter().zip(right.iter()).all(|(left, right)| {
+            left.content_width == right.content_width
+                && left.padding == right.padding
+                && left.is_hidden == right.is_hidden
+                && left.delimiter == right.delimiter
+                && left.delimiters == right.delimiters
+                && left.cell_alignment == right.cell_alignment
+        })
+}
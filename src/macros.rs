@@ -0,0 +1,88 @@
+/// Create a [Row](crate::Row) from a list of expressions.
+///
+/// Each expression is converted into a [Cell](crate::Cell), which means that anything that
+/// implements `ToString` can be used, as well as cells themselves.\
+/// Cells can be prefixed with these shorthands:
+/// - `l ->`, `c ->` and `r ->` align the cell to the left, center or right.
+/// - `b ->` makes the cell bold. This requires the `tty` feature.
+///
+/// ```
+/// use comfy_table::{row, Cell};
+///
+/// let row = row!["Name", r -> 42, Cell::new("Cell")];
+/// assert_eq!(row.cell_count(), 3);
+/// ```
+#[macro_export]
+macro_rules! row {
+    ($($content:tt)*) => {
+        $crate::Row::from($crate::__cells!([] $($content)*))
+    };
+}
+
+/// Create a [Table](crate::Table) with one row per bracketed list of expressions.
+///
+/// The rows use the same syntax as the [row!] macro.
+///
+/// ```
+/// use comfy_table::table;
+///
+/// let table = table!(
+///     ["Apples", r -> 3],
+///     ["Pears", r -> 12],
+/// );
+///
+/// let expected = "
+/// +--------+----+
+/// | Apples |  3 |
+/// |--------+----|
+/// | Pears  | 12 |
+/// +--------+----+";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+/// ```
+#[macro_export]
+macro_rules! table {
+    ($([$($row:tt)*]),* $(,)?) => {{
+        let mut table = $crate::Table::new();
+        $(
+            table.add_row($crate::row![$($row)*]);
+        )*
+        table
+    }};
+}
+
+/// Turn the content of the [row!] macro into cells, one element at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cells {
+    ([$($cells:expr,)*]) => {{
+        let cells: ::std::vec::Vec<$crate::Cell> = ::std::vec![$($cells),*];
+        cells
+    }};
+    ([$($cells:expr,)*] l -> $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__cells!(
+            [$($cells,)* $crate::Cell::from($value).set_alignment($crate::CellAlignment::Left),]
+            $($($rest)*)?
+        )
+    };
+    ([$($cells:expr,)*] c -> $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__cells!(
+            [$($cells,)* $crate::Cell::from($value).set_alignment($crate::CellAlignment::Center),]
+            $($($rest)*)?
+        )
+    };
+    ([$($cells:expr,)*] r -> $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__cells!(
+            [$($cells,)* $crate::Cell::from($value).set_alignment($crate::CellAlignment::Right),]
+            $($($rest)*)?
+        )
+    };
+    ([$($cells:expr,)*] b -> $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__cells!(
+            [$($cells,)* $crate::Cell::from($value).add_attribute($crate::CellAttribute::Bold),]
+            $($($rest)*)?
+        )
+    };
+    ([$($cells:expr,)*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__cells!([$($cells,)* $crate::Cell::from($value),] $($($rest)*)?)
+    };
+}
@@ -0,0 +1,411 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::error::ParseError;
+use crate::style::{StyleMap, TableComponent};
+use crate::table::Table;
+
+impl Table {
+    /// Read a table back in, that has been rendered with the given preset.
+    ///
+    /// This allows round-trip tests and post-processing the output of other programs that use
+    /// comfy-table. The header and all rows are restored, while the styling isn't:
+    /// - The default padding of a single space is removed from the start of each line of a cell
+    ///   and trailing whitespaces are removed. Other leading whitespaces are kept, which means
+    ///   that the alignment of centered or right aligned content isn't removed.
+    /// - Lines that don't span the whole content of a cell are lost, e.g. lines that have been
+    ///   added due to the vertical alignment.
+    /// - A single cell, that spans over all columns, is restored as [Section](crate::Section).
+    ///
+    /// There're some limitations, as the rendered output is ambiguous in a few cases:
+    /// - Rows that consist of multiple lines can only be detected, if rows are separated by
+    ///   [HorizontalLines](TableComponent::HorizontalLines).
+    ///   Otherwise, each line is restored as a row of its own.
+    /// - The header is only detected, if [HeaderLines](TableComponent::HeaderLines) are drawn
+    ///   with a different character than the lines between rows.
+    /// - Columns are detected via the intersections of the horizontal lines, such as the top
+    ///   border. Only if there're no horizontal lines with intersections, the
+    ///   [VerticalLines](TableComponent::VerticalLines) of the content are used.
+    ///   If neither is drawn, all content is put into a single column.
+    ///
+    /// If the vertical lines of a line with content don't match the detected columns, a
+    /// [ParseError::InconsistentColumns] is returned instead of guessing.
+    ///
+    /// Parsing stops at the first line after the table that doesn't have a left border, which
+    /// skips any [footnotes](Table::add_footnote).
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_FULL)
+    ///     .set_header(vec!["Name", "Description"])
+    ///     .add_row(vec!["comfy-table", "Build beautiful\ntables"]);
+    ///
+    /// let parsed = Table::parse(&table.to_string(), UTF8_FULL).unwrap();
+    /// let header: Vec<String> = parsed.header().unwrap().cell_iter().map(|cell| cell.content()).collect();
+    /// assert_eq!(header, vec!["Name", "Description"]);
+    ///
+    /// let row: Vec<String> = parsed.row(0).unwrap().cell_iter().map(|cell| cell.content()).collect();
+    /// assert_eq!(row, vec!["comfy-table", "Build beautiful\ntables"]);
+    /// ```
+    pub fn parse(rendered: &str, preset: &str) -> Result<Table, ParseError> {
+        Parser {
+            style: StyleMap::from_preset(preset),
+        }
+        .parse(rendered)
+    }
+}
+
+/// A line of a rendered table.
+enum Line<'a> {
+    /// A horizontal line, which might separate the header from the rest of the table.
+    Separator { line: &'a str, header: bool },
+    /// A line with content, without the left and right border.
+    Content { index: usize, content: &'a str },
+}
+
+struct Parser {
+    style: StyleMap,
+}
+
+impl Parser {
+    fn parse(&self, rendered: &str) -> Result<Table, ParseError> {
+        let lines = self.classify(rendered)?;
+        let boundaries = self.column_boundaries(&lines);
+
+        // Group the content lines into rows, which are separated by horizontal lines.
+        // The first row is the header, if it's followed by a header line.
+        let mut groups: Vec<Vec<(usize, &str)>> = Vec::new();
+        let mut group = Vec::new();
+        let mut has_header = false;
+        for line in lines.iter() {
+            match line {
+                Line::Separator { header, .. } => {
+                    if !group.is_empty() {
+                        has_header |= groups.is_empty() && *header;
+                        groups.push(std::mem::take(&mut group));
+                    }
+                }
+                Line::Content { index, content } => group.push((*index, *content)),
+            }
+        }
+        if !group.is_empty() {
+            groups.push(group);
+        }
+
+        let mut table = Table::new();
+        let mut groups = groups.into_iter();
+        if has_header {
+            if let Some(header) = groups.next() {
+                self.check_columns(&header, &boundaries, false)?;
+                table.set_header(split_cells(&header, &boundaries));
+            }
+        }
+
+        // Without lines between rows, each line is a row of its own.
+        let separated = self.style.style(TableComponent::HorizontalLines).is_some();
+        for group in groups {
+            let rows: Vec<Vec<(usize, &str)>> = if separated {
+                vec![group]
+            } else {
+                group.into_iter().map(|line| vec![line]).collect()
+            };
+
+            for row in rows {
+                if self.check_columns(&row, &boundaries, true)? {
+                    let title: Vec<&str> = row.iter().map(|(_, line)| line.trim()).collect();
+                    table.add_section(join_lines(&title));
+                } else {
+                    table.add_row(split_cells(&row, &boundaries));
+                }
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Determine the kind of each line and remove the left and right border of content lines.
+    fn classify<'a>(&self, rendered: &'a str) -> Result<Vec<Line<'a>>, ParseError> {
+        let left_border = self.style.style(TableComponent::LeftBorder);
+        let right_border = self.style.style(TableComponent::RightBorder);
+
+        let mut lines = Vec::new();
+        let mut has_content = false;
+        for (index, line) in rendered.lines().enumerate() {
+            // The end of the table has been reached, e.g. if footnotes follow.
+            let missing_border = left_border.map_or(false, |border| !line.starts_with(border));
+            if has_content && (line.is_empty() || (missing_border && !self.is_separator(line))) {
+                break;
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            if self.is_separator(line) {
+                lines.push(Line::Separator {
+                    line,
+                    header: self.is_header_separator(line),
+                });
+                continue;
+            }
+
+            if missing_border {
+                return Err(ParseError::MissingBorder { line: index });
+            }
+
+            let mut content = line;
+            if let Some(border) = left_border {
+                content = content.strip_prefix(border).unwrap_or(content);
+            }
+            if let Some(border) = right_border {
+                content = content.strip_suffix(border).unwrap_or(content);
+            }
+            lines.push(Line::Content { index, content });
+            has_content = true;
+        }
+
+        if !has_content {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(lines)
+    }
+
+    /// Horizontal lines only consist of characters of the style and contain at least one of the
+    /// characters that're used to draw lines.
+    fn is_separator(&self, line: &str) -> bool {
+        let mut has_line = false;
+        for character in line.chars().filter(|character| *character != ' ') {
+            if !self.style.characters.contains(&Some(character)) {
+                return false;
+            }
+            has_line |= self.is_line_character(character);
+        }
+
+        has_line
+    }
+
+    fn is_line_character(&self, character: char) -> bool {
+        [
+            TableComponent::TopBorder,
+            TableComponent::BottomBorder,
+            TableComponent::HeaderLines,
+            TableComponent::HorizontalLines,
+        ]
+        .iter()
+        .any(|component| self.style.style(*component) == Some(character))
+    }
+
+    /// Header lines can only be told apart from lines between rows, if they look different.
+    fn is_header_separator(&self, line: &str) -> bool {
+        let header = match self.style.style(TableComponent::HeaderLines) {
+            Some(header) => header,
+            None => return false,
+        };
+
+        self.style.style(TableComponent::HorizontalLines) != Some(header) && line.contains(header)
+    }
+
+    /// Get the positions of the vertical lines between columns, in columns of the content
+    /// lines.
+    ///
+    /// The content might contain the same characters as the vertical lines. Hence, the positions
+    /// are taken from the intersections of the horizontal lines, e.g. of the top border.
+    /// Horizontal lines next to rows, that span over all columns, don't have any intersections,
+    /// so the line with the most intersections is used.\
+    /// Only if there're no horizontal lines with intersections, the positions that're found in
+    /// the most content lines are used.
+    fn column_boundaries(&self, lines: &[Line]) -> Vec<usize> {
+        // The corners are located on top of the borders, which aren't part of the content.
+        let intersections: Vec<char> = [
+            TableComponent::MiddleHeaderIntersections,
+            TableComponent::MiddleIntersections,
+            TableComponent::TopBorderIntersections,
+            TableComponent::BottomBorderIntersections,
+        ]
+        .iter()
+        .filter_map(|component| self.style.style(*component))
+        .filter(|character| !self.is_line_character(*character))
+        .collect();
+        let has_left_border = self.style.style(TableComponent::LeftBorder).is_some();
+        let has_right_border = self.style.style(TableComponent::RightBorder).is_some();
+        let separators: Vec<&str> = lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Separator { line, .. } => {
+                    let mut chars = line.chars();
+                    if has_left_border {
+                        chars.next();
+                    }
+                    if has_right_border {
+                        chars.next_back();
+                    }
+                    Some(chars.as_str())
+                }
+                Line::Content { .. } => None,
+            })
+            .collect();
+
+        if !intersections.is_empty() && !separators.is_empty() {
+            // A table with a single column doesn't have any intersections at all.
+            return separators
+                .iter()
+                .map(|line| positions(line, |character| intersections.contains(&character)))
+                .max_by_key(Vec::len)
+                .unwrap_or_default();
+        }
+
+        match self.style.style(TableComponent::VerticalLines) {
+            Some(vertical) => {
+                let content = lines.iter().filter_map(|line| match line {
+                    Line::Content { content, .. } => Some(*content),
+                    Line::Separator { .. } => None,
+                });
+                most_common_positions(content, |character| character == vertical)
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Check that each line of a row has a vertical line at every column boundary.
+    ///
+    /// Returns `true`, if the row consists of a single cell that spans over all columns, i.e.
+    /// none of its lines have any vertical lines. This is only allowed, if `allow_spanning` is set.
+    /// Rows that only have some of the vertical lines result in an error.
+    fn check_columns(
+        &self,
+        row: &[(usize, &str)],
+        boundaries: &[usize],
+        allow_spanning: bool,
+    ) -> Result<bool, ParseError> {
+        if boundaries.is_empty() {
+            return Ok(false);
+        }
+        let vertical = match self.style.style(TableComponent::VerticalLines) {
+            Some(vertical) => vertical,
+            None => return Ok(false),
+        };
+
+        let mut spanning = None;
+        for (index, line) in row.iter() {
+            let positions = positions(line, |character| character == vertical);
+            let found = boundaries
+                .iter()
+                .filter(|boundary| positions.contains(boundary))
+                .count();
+            let line_spanning = if found == boundaries.len() {
+                false
+            } else if found == 0 && allow_spanning {
+                true
+            } else {
+                return Err(ParseError::InconsistentColumns { line: *index });
+            };
+
+            if *spanning.get_or_insert(line_spanning) != line_spanning {
+                return Err(ParseError::InconsistentColumns { line: *index });
+            }
+        }
+
+        Ok(spanning.unwrap_or(false))
+    }
+}
+
+/// Get the positions of all characters that match the predicate.
+/// The positions are measured in columns, which respects wide characters.
+fn positions(line: &str, predicate: impl Fn(char) -> bool) -> Vec<usize> {
+    let mut position = 0;
+    let mut positions = Vec::new();
+    for character in line.chars() {
+        if predicate(character) {
+            positions.push(position);
+        }
+        position += character.width().unwrap_or(0);
+    }
+
+    positions
+}
+
+/// Get the positions of matching characters that're found in the most lines.
+fn most_common_positions<'a>(
+    lines: impl Iterator<Item = &'a str>,
+    predicate: impl Fn(char) -> bool,
+) -> Vec<usize> {
+    let mut counts: Vec<usize> = Vec::new();
+    for line in lines {
+        for position in positions(line, &predicate) {
+            if counts.len() <= position {
+                counts.resize(position + 1, 0);
+            }
+            counts[position] += 1;
+        }
+    }
+
+    let max = counts.iter().copied().max().unwrap_or(0);
+    if max == 0 {
+        return Vec::new();
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .filter(|(_, count)| *count == max)
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// Split the lines of a row into cells at the given column boundaries.
+fn split_cells(row: &[(usize, &str)], boundaries: &[usize]) -> Vec<String> {
+    let mut cells: Vec<Vec<&str>> = vec![Vec::new(); boundaries.len() + 1];
+    for (_, line) in row {
+        for (cell, part) in cells.iter_mut().zip(split_line(line, boundaries)) {
+            cell.push(part);
+        }
+    }
+
+    cells.iter().map(|lines| join_lines(lines)).collect()
+}
+
+/// Split a single line at the given column boundaries and remove the padding of each part.
+/// Trailing whitespaces are removed as well, as they can't be told apart from the alignment.
+fn split_line<'a>(line: &'a str, boundaries: &[usize]) -> Vec<&'a str> {
+    let mut parts = Vec::with_capacity(boundaries.len() + 1);
+    let mut boundaries = boundaries.iter().peekable();
+    let mut start = 0;
+    let mut position = 0;
+    for (index, character) in line.char_indices() {
+        // Boundaries that are covered by a wide character start an empty cell.
+        while boundaries
+            .next_if(|boundary| **boundary < position)
+            .is_some()
+        {
+            parts.push(&line[start..index]);
+            start = index;
+        }
+        if boundaries
+            .next_if(|boundary| **boundary == position)
+            .is_some()
+        {
+            parts.push(&line[start..index]);
+            start = index + character.len_utf8();
+        }
+        position += character.width().unwrap_or(0);
+    }
+    parts.push(&line[start..]);
+    parts.resize(boundaries.len() + parts.len(), "");
+
+    parts
+        .into_iter()
+        .map(|part| part.strip_prefix(' ').unwrap_or(part).trim_end())
+        .collect()
+}
+
+/// Join the lines of a cell, without any empty lines at its start and end.
+fn join_lines(lines: &[&str]) -> String {
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].join("\n"),
+        _ => String::new(),
+    }
+}
@@ -1,7 +1,10 @@
+use std::ops::Range;
 use std::slice::Iter;
 
 use crate::{
     cell::{Cell, Cells},
+    style::TrimMode,
+    table::WidthFunction,
     utils::formatting::content_split::measure_text_width,
 };
 
@@ -13,6 +16,10 @@ pub struct Row {
     pub(crate) index: Option<usize>,
     pub(crate) cells: Vec<Cell>,
     pub(crate) max_height: Option<usize>,
+    pub(crate) min_height: Option<usize>,
+    pub(crate) separator_after: Option<bool>,
+    pub(crate) separator_char: Option<char>,
+    pub(crate) hidden: bool,
 }
 
 impl Row {
@@ -41,6 +48,7 @@ impl Row {
     }
 
     /// Truncate content of cells which occupies more than X lines of space.
+    /// At least a single line is always displayed.
     ///
     /// ```
     /// use comfy_table::{Row, Cell};
@@ -54,17 +62,152 @@ impl Row {
         self
     }
 
+    /// Pad the cells of this row with empty lines, until the row is at least X lines high.
+    ///
+    /// The empty lines are placed according to the
+    /// [vertical alignment](crate::Cell::set_vertical_alignment) of each cell.
+    /// If the row also has a [max height](Row::max_height), the max height takes precedence.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, CellVerticalAlignment, Row, Table};
+    ///
+    /// let mut row = Row::from(vec![
+    ///     Cell::new("Top"),
+    ///     Cell::new("Bottom").set_vertical_alignment(CellVerticalAlignment::Bottom),
+    /// ]);
+    /// row.min_height(3);
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(row);
+    ///
+    /// let expected = "
+    /// +-----+--------+
+    /// | Top |        |
+    /// |     |        |
+    /// |     | Bottom |
+    /// +-----+--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn min_height(&mut self, lines: usize) -> &mut Self {
+        self.min_height = Some(lines);
+
+        self
+    }
+
+    /// Control the horizontal line that's drawn right after this row.
+    ///
+    /// - `false` removes the line, even if the table's style draws lines between rows.
+    ///   This allows to visually group consecutive rows.
+    /// - `true` draws a line, even if the table's style doesn't have any horizontal lines.
+    ///   In that case, the header line's style is used, if it exists.
+    ///
+    /// The last row is always followed by the bottom border.
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::{Row, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.load_preset(UTF8_FULL);
+    ///
+    /// let mut row = Row::from(vec!["Apples", "3"]);
+    /// row.set_separator_after(false);
+    /// table.add_row(row);
+    /// table.add_row(vec!["Pears", "5"]);
+    /// table.add_row(vec!["Carrots", "2"]);
+    ///
+    /// let expected = "
+    /// ┌─────────┬───┐
+    /// │ Apples  ┆ 3 │
+    /// │ Pears   ┆ 5 │
+    /// ├╌╌╌╌╌╌╌╌╌┼╌╌╌┤
+    /// │ Carrots ┆ 2 │
+    /// └─────────┴───┘";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_separator_after(&mut self, separator: bool) -> &mut Self {
+        self.separator_after = Some(separator);
+
+        self
+    }
+
+    /// Get the separator setting of this row, if one has been set via
+    /// [Row::set_separator_after].
+    pub fn separator_after(&self) -> Option<bool> {
+        self.separator_after
+    }
+
+    /// Use a custom character for the horizontal line that's drawn right after this row.
+    /// The intersections with the vertical lines and borders aren't changed.
+    ///
+    /// ```
+    /// use comfy_table::Row;
+    ///
+    /// let mut row = Row::new();
+    /// row.set_separator_char('=');
+    /// ```
+    pub fn set_separator_char(&mut self, character: char) -> &mut Self {
+        self.separator_char = Some(character);
+
+        self
+    }
+
+    /// Get the custom separator character of this row.
+    pub fn separator_char(&self) -> Option<char> {
+        self.separator_char
+    }
+
+    /// Hide this row, without removing it from the [Table](crate::Table).
+    ///
+    /// Hidden rows aren't rendered and their content isn't taken into account, when
+    /// the width of the columns is determined. This is the row equivalent of the
+    /// [Hidden](crate::ColumnConstraint::Hidden) column constraint.\
+    /// The row keeps its index, which is also used for
+    /// [rendered rows](crate::Table::rendered_rows) and
+    /// [truncated content](crate::Table::truncated_content).
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["Apples"]);
+    /// table.add_row(vec!["A very long line of bananas"]);
+    ///
+    /// table.row_mut(1).unwrap().set_hidden(true);
+    ///
+    /// let expected = "
+    /// +--------+
+    /// | Apples |
+    /// +--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// assert_eq!(table.row_count(), 2);
+    /// ```
+    pub fn set_hidden(&mut self, hidden: bool) -> &mut Self {
+        self.hidden = hidden;
+
+        self
+    }
+
+    /// Check whether this row is [hidden](Row::set_hidden).
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
     /// Get the longest content width for all cells of this row
-    pub(crate) fn max_content_widths(&self) -> Vec<usize> {
+    pub(crate) fn max_content_widths(
+        &self,
+        trim_mode: TrimMode,
+        measure: WidthFunction,
+    ) -> Vec<usize> {
         // Iterate over all cells
         self.cells
             .iter()
             .map(|cell| {
                 // Iterate over all content strings and return a vector of string widths.
                 // Each entry represents the longest string width for a cell.
-                cell.content
+                cell.display_lines(trim_mode)
                     .iter()
-                    .map(|string| measure_text_width(string))
+                    .map(|string| measure_text_width(string, measure))
                     .max()
                     .unwrap_or(0)
             })
@@ -77,7 +220,7 @@ impl Row {
     }
 
     /// Returns an iterator over all cells of this row
-    pub fn cell_iter(&self) -> Iter<Cell> {
+    pub fn cell_iter(&self) -> Iter<'_, Cell> {
         self.cells.iter()
     }
 }
@@ -104,13 +247,114 @@ impl<T: Into<Cells>> From<T> for Row {
             index: None,
             cells: cells.into().0,
             max_height: None,
+            min_height: None,
+            separator_after: None,
+            separator_char: None,
+            hidden: false,
         }
     }
 }
 
+/// Convert a value into a [Row], so it can be added to a table via
+/// [Table::add](crate::Table::add) and [Table::add_items](crate::Table::add_items).
+///
+/// This keeps the conversion logic of a domain type right next to the type.
+/// As the trait is object safe, values of different types can be added to the same table.
+///
+/// ```
+/// use comfy_table::{Row, Table, ToRow};
+///
+/// struct File {
+///     name: String,
+///     size: u64,
+/// }
+///
+/// impl ToRow for File {
+///     fn to_row(&self) -> Row {
+///         Row::from(vec![self.name.clone(), format!("{} B", self.size)])
+///     }
+/// }
+///
+/// let mut table = Table::new();
+/// table.add(&File {
+///     name: "notes.txt".into(),
+///     size: 120,
+/// });
+///
+/// let expected = "
+/// +-----------+-------+
+/// | notes.txt | 120 B |
+/// +-----------+-------+";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+/// ```
+pub trait ToRow {
+    /// Create the row that represents this value.
+    fn to_row(&self) -> Row;
+}
+
+impl<T: ToRow + ?Sized> ToRow for &T {
+    fn to_row(&self) -> Row {
+        (**self).to_row()
+    }
+}
+
+impl<T: ToRow + ?Sized> ToRow for Box<T> {
+    fn to_row(&self) -> Row {
+        (**self).to_row()
+    }
+}
+
+/// A named group of consecutive rows of a [Table](crate::Table).
+///
+/// Groups are created via [Table::begin_group](crate::Table::begin_group) and
+/// [Table::end_group](crate::Table::end_group).\
+/// In the terminal output, groups are separated by a header-style line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowGroup {
+    pub(crate) name: String,
+    pub(crate) rows: Range<usize>,
+}
+
+impl RowGroup {
+    /// The name of the group.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The indices of the rows that belong to this group.
+    pub fn rows(&self) -> Range<usize> {
+        self.rows.clone()
+    }
+}
+
+/// A title, which is displayed in a row of its own that spans over all columns of a
+/// [Table](crate::Table).
+///
+/// Sections are created via [Table::add_section](crate::Table::add_section).\
+/// In the terminal output, sections are surrounded by lines in the style of the header line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section {
+    pub(crate) title: Cell,
+    pub(crate) row_index: usize,
+}
+
+impl Section {
+    /// The title of the section.
+    pub fn title(&self) -> &Cell {
+        &self.title
+    }
+
+    /// The index of the row that's displayed right after the section.
+    /// This equals the amount of rows, if no rows have been added after the section.
+    pub fn row_index(&self) -> usize {
+        self.row_index
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::formatting::content_split::DEFAULT_WIDTH_FUNCTION;
 
     #[test]
     fn test_correct_max_content_width() {
@@ -122,7 +366,7 @@ mod tests {
             "11 but with\na newline",
         ]);
 
-        let max_content_widths = row.max_content_widths();
+        let max_content_widths = row.max_content_widths(TrimMode::None, DEFAULT_WIDTH_FUNCTION);
 
         assert_eq!(max_content_widths, vec![0, 4, 5, 6, 11]);
     }
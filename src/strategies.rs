@@ -0,0 +1,162 @@
+use proptest::prelude::*;
+
+use crate::style::{CellAlignment, ColumnConstraint, ContentArrangement, Width::*};
+use crate::{Cell, Row, Table};
+
+/// Pick any of the three existing ContentArrangement types for the table.
+pub fn content_arrangement() -> impl Strategy<Value = ContentArrangement> {
+    prop_oneof![
+        Just(ContentArrangement::Disabled),
+        Just(ContentArrangement::Dynamic),
+        Just(ContentArrangement::DynamicFullWidth),
+    ]
+}
+
+/// Each cell can have any alignment.
+pub fn cell_alignment() -> impl Strategy<Value = Option<CellAlignment>> {
+    prop_oneof![
+        Just(None),
+        Just(Some(CellAlignment::Left)),
+        Just(Some(CellAlignment::Right)),
+        Just(Some(CellAlignment::Center)),
+    ]
+}
+
+/// Any Column can have any constellation of ColumnConstraints
+pub fn column_constraint() -> impl Strategy<Value = Option<ColumnConstraint>> {
+    use ColumnConstraint::*;
+
+    prop_oneof![
+        Just(None),
+        Just(Some(ContentWidth)),
+        Just(Some(Hidden)),
+        any::<u16>().prop_map(|width| { Some(Absolute(Fixed(width))) }),
+        any::<u16>().prop_map(|width| { Some(LowerBoundary(Fixed(width))) }),
+        any::<u16>().prop_map(|width| { Some(UpperBoundary(Fixed(width))) }),
+        (0u16..200u16).prop_map(|percentage| { Some(Absolute(Percentage(percentage))) }),
+        (0u16..200u16).prop_map(|percentage| { Some(LowerBoundary(Percentage(percentage))) }),
+        (0u16..200u16).prop_map(|percentage| { Some(UpperBoundary(Percentage(percentage))) }),
+    ]
+}
+
+/// We test the Row::max_height with a few values.
+pub fn max_height() -> impl Strategy<Value = Option<usize>> {
+    prop_oneof![
+        Just(None),
+        Just(Some(0)),
+        Just(Some(1)),
+        Just(Some(5)),
+        Just(Some(100))
+    ]
+}
+
+/// The content of a single cell.
+///
+/// Besides ASCII, this contains CJK characters and emojis, which are two characters wide.
+/// Spaces and newlines are included, so the content gets split into words and lines.
+pub fn cell_content() -> impl Strategy<Value = String> {
+    "[A-Za-z_ \nコンフィ表😀🦀]*"
+}
+
+prop_compose! {
+    /// Returns the dimensions of the table, i.e. the amount of rows and columns.
+    pub fn dimensions()(columns in 1u16..10u16, rows in 1u16..10u16)
+                    -> (u16, u16) {
+       (columns, rows)
+   }
+}
+
+/// Returns all data needed to build the final table.
+/// 1. A matrix of cells Row[Column[Cell]].
+/// 2. Constraints for all columns.
+/// 3. The alignment for each cell.
+/// 3. The alignment for each column.
+#[allow(clippy::type_complexity)]
+pub fn columns_and_rows() -> impl Strategy<
+    Value = (
+        Vec<Vec<String>>,
+        Vec<Option<ColumnConstraint>>,
+        Vec<Option<CellAlignment>>,
+        Vec<Option<CellAlignment>>,
+    ),
+> {
+    dimensions().prop_flat_map(|(column_count, row_count)| {
+        let mut rows = Vec::new();
+        let mut cell_alignments = Vec::new();
+        for _i in 0..row_count {
+            // Create the max amount of possibly needed cell alignments
+            for _j in 0..column_count {
+                cell_alignments.push(cell_alignment());
+            }
+            // Add a strategy that creates random cell content with a length of 0 to column_count
+            rows.push(proptest::collection::vec(
+                cell_content(),
+                0..column_count as usize,
+            ));
+        }
+        let mut constraints = Vec::new();
+        let mut column_alignments = Vec::new();
+        for _i in 0..column_count {
+            constraints.push(column_constraint());
+            column_alignments.push(cell_alignment());
+        }
+
+        (rows, constraints, cell_alignments, column_alignments)
+    })
+}
+
+/// The width of the table.
+pub fn table_width() -> impl Strategy<Value = u16> {
+    0..1000u16
+}
+
+prop_compose! {
+    /// The ultimate test
+    /// This creates a table from a combination of all "random" selectors above.
+    pub fn table()
+        (arrangement in content_arrangement(),
+        max_height in max_height(),
+        (rows, constraints, cell_alignments, column_alignments) in columns_and_rows()) -> Table {
+
+        let mut table = Table::new();
+
+        let mut cell_alignments = cell_alignments.iter();
+        for row in rows.iter() {
+            // Convert a vector of Strings to a vector of Cells and
+            // set the content alignment for each cell
+            let cells: Vec<Cell> = row.iter().map(|content| {
+                let mut cell = Cell::new(content.clone());
+                if let Some(alignment) = cell_alignments.next().unwrap() {
+                    cell = cell.set_alignment(*alignment);
+                }
+                cell
+            }).collect();
+
+            let mut row = Row::from(cells);
+            if let Some(height) = max_height {
+                row.max_height(height);
+            }
+
+            // Add the row to the table
+            table.add_row(row);
+        }
+
+        for (column_index, column) in table.column_iter_mut().enumerate() {
+            // Add the alignment for all columns
+            let alignment = column_alignments.get(column_index).unwrap();
+            if let Some(alignment) = alignment {
+                column.set_cell_alignment(*alignment);
+            }
+
+            // Set constraints for all columns
+            let constraint = constraints.get(column_index).unwrap();
+            if let Some(constraint) = constraint {
+                column.set_constraint(*constraint);
+            }
+
+        }
+
+        table.set_content_arrangement(arrangement);
+        table
+    }
+}
@@ -1,5 +1,7 @@
 /// Represents an attribute.
 ///
+/// It's part of comfy-table's own API, so updates of crossterm don't affect users of comfy-table.
+///
 /// # Platform-specific Notes
 ///
 /// * Only UNIX and Windows 10 terminals do support text attributes.
@@ -30,7 +32,7 @@
 /// Check [crate::Cell::add_attribute] on how to use it.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[non_exhaustive]
-pub enum Attribute {
+pub enum CellAttribute {
     /// Resets all the attributes.
     Reset,
     /// Increases the text intensity.
@@ -94,36 +96,78 @@ pub enum Attribute {
     NotOverLined,
 }
 
-/// Map the internal mirrored [Attribute] to the actually used [crossterm::style::Attribute]
-pub(crate) fn map_attribute(attribute: Attribute) -> crossterm::style::Attribute {
+/// Map the [CellAttribute] enum to the actually used [crossterm::style::Attribute]
+pub(crate) fn map_attribute(attribute: CellAttribute) -> crossterm::style::Attribute {
     match attribute {
-        Attribute::Reset => crossterm::style::Attribute::Reset,
-        Attribute::Bold => crossterm::style::Attribute::Bold,
-        Attribute::Dim => crossterm::style::Attribute::Dim,
-        Attribute::Italic => crossterm::style::Attribute::Italic,
-        Attribute::Underlined => crossterm::style::Attribute::Underlined,
-        Attribute::DoubleUnderlined => crossterm::style::Attribute::DoubleUnderlined,
-        Attribute::Undercurled => crossterm::style::Attribute::Undercurled,
-        Attribute::Underdotted => crossterm::style::Attribute::Underdotted,
-        Attribute::Underdashed => crossterm::style::Attribute::Underdashed,
-        Attribute::SlowBlink => crossterm::style::Attribute::SlowBlink,
-        Attribute::RapidBlink => crossterm::style::Attribute::RapidBlink,
-        Attribute::Reverse => crossterm::style::Attribute::Reverse,
-        Attribute::Hidden => crossterm::style::Attribute::Hidden,
-        Attribute::CrossedOut => crossterm::style::Attribute::CrossedOut,
-        Attribute::Fraktur => crossterm::style::Attribute::Fraktur,
-        Attribute::NoBold => crossterm::style::Attribute::NoBold,
-        Attribute::NormalIntensity => crossterm::style::Attribute::NormalIntensity,
-        Attribute::NoItalic => crossterm::style::Attribute::NoItalic,
-        Attribute::NoUnderline => crossterm::style::Attribute::NoUnderline,
-        Attribute::NoBlink => crossterm::style::Attribute::NoBlink,
-        Attribute::NoReverse => crossterm::style::Attribute::NoReverse,
-        Attribute::NoHidden => crossterm::style::Attribute::NoHidden,
-        Attribute::NotCrossedOut => crossterm::style::Attribute::NotCrossedOut,
-        Attribute::Framed => crossterm::style::Attribute::Framed,
-        Attribute::Encircled => crossterm::style::Attribute::Encircled,
-        Attribute::OverLined => crossterm::style::Attribute::OverLined,
-        Attribute::NotFramedOrEncircled => crossterm::style::Attribute::NotFramedOrEncircled,
-        Attribute::NotOverLined => crossterm::style::Attribute::NotOverLined,
+        CellAttribute::Reset => crossterm::style::Attribute::Reset,
+        CellAttribute::Bold => crossterm::style::Attribute::Bold,
+        CellAttribute::Dim => crossterm::style::Attribute::Dim,
+        CellAttribute::Italic => crossterm::style::Attribute::Italic,
+        CellAttribute::Underlined => crossterm::style::Attribute::Underlined,
+        CellAttribute::DoubleUnderlined => crossterm::style::Attribute::DoubleUnderlined,
+        CellAttribute::Undercurled => crossterm::style::Attribute::Undercurled,
+        CellAttribute::Underdotted => crossterm::style::Attribute::Underdotted,
+        CellAttribute::Underdashed => crossterm::style::Attribute::Underdashed,
+        CellAttribute::SlowBlink => crossterm::style::Attribute::SlowBlink,
+        CellAttribute::RapidBlink => crossterm::style::Attribute::RapidBlink,
+        CellAttribute::Reverse => crossterm::style::Attribute::Reverse,
+        CellAttribute::Hidden => crossterm::style::Attribute::Hidden,
+        CellAttribute::CrossedOut => crossterm::style::Attribute::CrossedOut,
+        CellAttribute::Fraktur => crossterm::style::Attribute::Fraktur,
+        CellAttribute::NoBold => crossterm::style::Attribute::NoBold,
+        CellAttribute::NormalIntensity => crossterm::style::Attribute::NormalIntensity,
+        CellAttribute::NoItalic => crossterm::style::Attribute::NoItalic,
+        CellAttribute::NoUnderline => crossterm::style::Attribute::NoUnderline,
+        CellAttribute::NoBlink => crossterm::style::Attribute::NoBlink,
+        CellAttribute::NoReverse => crossterm::style::Attribute::NoReverse,
+        CellAttribute::NoHidden => crossterm::style::Attribute::NoHidden,
+        CellAttribute::NotCrossedOut => crossterm::style::Attribute::NotCrossedOut,
+        CellAttribute::Framed => crossterm::style::Attribute::Framed,
+        CellAttribute::Encircled => crossterm::style::Attribute::Encircled,
+        CellAttribute::OverLined => crossterm::style::Attribute::OverLined,
+        CellAttribute::NotFramedOrEncircled => crossterm::style::Attribute::NotFramedOrEncircled,
+        CellAttribute::NotOverLined => crossterm::style::Attribute::NotOverLined,
+    }
+}
+
+/// Convert crossterm's attribute, so it can be passed to any function that expects a
+/// [CellAttribute].
+#[cfg(feature = "reexport_crossterm")]
+impl From<crossterm::style::Attribute> for CellAttribute {
+    fn from(attribute: crossterm::style::Attribute) -> Self {
+        match attribute {
+            crossterm::style::Attribute::Reset => CellAttribute::Reset,
+            crossterm::style::Attribute::Bold => CellAttribute::Bold,
+            crossterm::style::Attribute::Dim => CellAttribute::Dim,
+            crossterm::style::Attribute::Italic => CellAttribute::Italic,
+            crossterm::style::Attribute::Underlined => CellAttribute::Underlined,
+            crossterm::style::Attribute::DoubleUnderlined => CellAttribute::DoubleUnderlined,
+            crossterm::style::Attribute::Undercurled => CellAttribute::Undercurled,
+            crossterm::style::Attribute::Underdotted => CellAttribute::Underdotted,
+            crossterm::style::Attribute::Underdashed => CellAttribute::Underdashed,
+            crossterm::style::Attribute::SlowBlink => CellAttribute::SlowBlink,
+            crossterm::style::Attribute::RapidBlink => CellAttribute::RapidBlink,
+            crossterm::style::Attribute::Reverse => CellAttribute::Reverse,
+            crossterm::style::Attribute::Hidden => CellAttribute::Hidden,
+            crossterm::style::Attribute::CrossedOut => CellAttribute::CrossedOut,
+            crossterm::style::Attribute::Fraktur => CellAttribute::Fraktur,
+            crossterm::style::Attribute::NoBold => CellAttribute::NoBold,
+            crossterm::style::Attribute::NormalIntensity => CellAttribute::NormalIntensity,
+            crossterm::style::Attribute::NoItalic => CellAttribute::NoItalic,
+            crossterm::style::Attribute::NoUnderline => CellAttribute::NoUnderline,
+            crossterm::style::Attribute::NoBlink => CellAttribute::NoBlink,
+            crossterm::style::Attribute::NoReverse => CellAttribute::NoReverse,
+            crossterm::style::Attribute::NoHidden => CellAttribute::NoHidden,
+            crossterm::style::Attribute::NotCrossedOut => CellAttribute::NotCrossedOut,
+            crossterm::style::Attribute::Framed => CellAttribute::Framed,
+            crossterm::style::Attribute::Encircled => CellAttribute::Encircled,
+            crossterm::style::Attribute::OverLined => CellAttribute::OverLined,
+            crossterm::style::Attribute::NotFramedOrEncircled => {
+                CellAttribute::NotFramedOrEncircled
+            }
+            crossterm::style::Attribute::NotOverLined => CellAttribute::NotOverLined,
+            // Crossterm's enum is non-exhaustive, but all of its current attributes are mirrored.
+            _ => CellAttribute::Reset,
+        }
     }
 }
@@ -19,3 +19,25 @@ pub enum CellAlignment {
     Right,
     Center,
 }
+
+/// This can be set on [columns](crate::Column::set_cell_vertical_alignment) and
+/// [cells](crate::Cell::set_vertical_alignment).
+///
+/// Determines where the lines of a cell are placed, if other cells of the same row have
+/// more lines.
+///
+/// ```text
+/// +--------+-----+--------+--------+
+/// | Header | Top | Middle | Bottom |
+/// +================================+
+/// | One    | Top |        |        |
+/// | Two    |     | Middle |        |
+/// | Three  |     |        | Bottom |
+/// +--------+-----+--------+--------+
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CellVerticalAlignment {
+    Top,
+    Middle,
+    Bottom,
+}
@@ -0,0 +1,100 @@
+use crate::style::{CellAttribute, CellColor};
+use crate::Cell;
+
+/// A set of styles that can be applied to many cells at once.
+///
+/// Styles of a cell always take precedence over styles that are applied via a [CellStyle].
+/// Attributes are combined.
+///
+/// ```
+/// use comfy_table::{CellStyle, Color};
+///
+/// let style = CellStyle::new().bold().fg(Color::Cyan);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CellStyle {
+    pub(crate) fg: Option<CellColor>,
+    pub(crate) bg: Option<CellColor>,
+    pub(crate) attributes: Vec<CellAttribute>,
+}
+
+impl CellStyle {
+    /// Create a new style without any colors or attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the foreground text color.
+    #[must_use]
+    pub fn fg<C: Into<CellColor>>(mut self, color: C) -> Self {
+        self.fg = Some(color.into());
+
+        self
+    }
+
+    /// Set the background color.
+    #[must_use]
+    pub fn bg<C: Into<CellColor>>(mut self, color: C) -> Self {
+        self.bg = Some(color.into());
+
+        self
+    }
+
+    /// Add a styling attribute.
+    #[must_use]
+    pub fn add_attribute<A: Into<CellAttribute>>(mut self, attribute: A) -> Self {
+        let attribute = attribute.into();
+        if !self.attributes.contains(&attribute) {
+            self.attributes.push(attribute);
+        }
+
+        self
+    }
+
+    /// Convenience method to make the text bold.
+    #[must_use]
+    pub fn bold(self) -> Self {
+        self.add_attribute(CellAttribute::Bold)
+    }
+
+    /// Convenience method to make the text italic.
+    #[must_use]
+    pub fn italic(self) -> Self {
+        self.add_attribute(CellAttribute::Italic)
+    }
+
+    /// Convenience method to underline the text.
+    #[must_use]
+    pub fn underlined(self) -> Self {
+        self.add_attribute(CellAttribute::Underlined)
+    }
+
+    /// Returns whether this style doesn't change anything.
+    pub fn is_empty(&self) -> bool {
+        self.fg.is_none() && self.bg.is_none() && self.attributes.is_empty()
+    }
+
+    /// Combine this style with another one.
+    /// The colors of the other style take precedence, attributes are combined.
+    pub(crate) fn merge(&self, other: &CellStyle) -> CellStyle {
+        let mut style = CellStyle {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            attributes: self.attributes.clone(),
+        };
+        for attribute in other.attributes.iter() {
+            style = style.add_attribute(*attribute);
+        }
+
+        style
+    }
+
+    /// The styles that're set directly on a cell.
+    pub(crate) fn from_cell(cell: &Cell) -> CellStyle {
+        CellStyle {
+            fg: cell.fg,
+            bg: cell.bg,
+            attributes: cell.attributes.clone(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 /// Represents a color.
 ///
 /// This type is a simplified re-implementation of crossterm's Color enum.
+/// It's part of comfy-table's own API, so updates of crossterm don't affect users of comfy-table.
 /// See [crossterm::style::color](https://docs.rs/crossterm/latest/crossterm/style/enum.Color.html)
 ///
 /// # Platform-specific Notes
@@ -19,13 +20,13 @@
 /// | `White`    | `Grey`        |
 ///
 /// Most UNIX terminals and Windows 10 consoles support additional colors.
-/// See [Color::Rgb] or [Color::AnsiValue] for more info.
+/// See [CellColor::Rgb] or [CellColor::AnsiValue] for more info.
 ///
 /// Usage:
 ///
-/// Check [crate::Cell::bg], [crate::Cell::fg] and  on how to use it.
+/// Check [crate::Cell::bg] and [crate::Cell::fg] on how to use it.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
-pub enum Color {
+pub enum CellColor {
     /// Resets the terminal color.
     Reset,
 
@@ -80,37 +81,65 @@ pub enum Color {
     /// An RGB color. See [RGB color model](https://en.wikipedia.org/wiki/RGB_color_model) for more info.
     ///
     /// Most UNIX terminals and Windows 10 supported only.
-    /// See [Platform-specific notes](enum.Color.html#platform-specific-notes) for more info.
+    /// See [Platform-specific notes](enum.CellColor.html#platform-specific-notes) for more info.
     Rgb { r: u8, g: u8, b: u8 },
 
     /// An ANSI color. See [256 colors - cheat sheet](https://jonasjacek.github.io/colors/) for more info.
     ///
     /// Most UNIX terminals and Windows 10 supported only.
-    /// See [Platform-specific notes](enum.Color.html#platform-specific-notes) for more info.
+    /// See [Platform-specific notes](enum.CellColor.html#platform-specific-notes) for more info.
     AnsiValue(u8),
 }
 
-/// Map the internal mirrored [Color] enum to the actually used [crossterm::style::Color].
-pub(crate) fn map_color(color: Color) -> crossterm::style::Color {
+/// Map the [CellColor] enum to the actually used [crossterm::style::Color].
+pub(crate) fn map_color(color: CellColor) -> crossterm::style::Color {
     match color {
-        Color::Reset => crossterm::style::Color::Reset,
-        Color::Black => crossterm::style::Color::Black,
-        Color::DarkGrey => crossterm::style::Color::DarkGrey,
-        Color::Red => crossterm::style::Color::Red,
-        Color::DarkRed => crossterm::style::Color::DarkRed,
-        Color::Green => crossterm::style::Color::Green,
-        Color::DarkGreen => crossterm::style::Color::DarkGreen,
-        Color::Yellow => crossterm::style::Color::Yellow,
-        Color::DarkYellow => crossterm::style::Color::DarkYellow,
-        Color::Blue => crossterm::style::Color::Blue,
-        Color::DarkBlue => crossterm::style::Color::DarkBlue,
-        Color::Magenta => crossterm::style::Color::Magenta,
-        Color::DarkMagenta => crossterm::style::Color::DarkMagenta,
-        Color::Cyan => crossterm::style::Color::Cyan,
-        Color::DarkCyan => crossterm::style::Color::DarkCyan,
-        Color::White => crossterm::style::Color::White,
-        Color::Grey => crossterm::style::Color::Grey,
-        Color::Rgb { r, g, b } => crossterm::style::Color::Rgb { r, g, b },
-        Color::AnsiValue(value) => crossterm::style::Color::AnsiValue(value),
+        CellColor::Reset => crossterm::style::Color::Reset,
+        CellColor::Black => crossterm::style::Color::Black,
+        CellColor::DarkGrey => crossterm::style::Color::DarkGrey,
+        CellColor::Red => crossterm::style::Color::Red,
+        CellColor::DarkRed => crossterm::style::Color::DarkRed,
+        CellColor::Green => crossterm::style::Color::Green,
+        CellColor::DarkGreen => crossterm::style::Color::DarkGreen,
+        CellColor::Yellow => crossterm::style::Color::Yellow,
+        CellColor::DarkYellow => crossterm::style::Color::DarkYellow,
+        CellColor::Blue => crossterm::style::Color::Blue,
+        CellColor::DarkBlue => crossterm::style::Color::DarkBlue,
+        CellColor::Magenta => crossterm::style::Color::Magenta,
+        CellColor::DarkMagenta => crossterm::style::Color::DarkMagenta,
+        CellColor::Cyan => crossterm::style::Color::Cyan,
+        CellColor::DarkCyan => crossterm::style::Color::DarkCyan,
+        CellColor::White => crossterm::style::Color::White,
+        CellColor::Grey => crossterm::style::Color::Grey,
+        CellColor::Rgb { r, g, b } => crossterm::style::Color::Rgb { r, g, b },
+        CellColor::AnsiValue(value) => crossterm::style::Color::AnsiValue(value),
+    }
+}
+
+/// Convert crossterm's color, so it can be passed to any function that expects a [CellColor].
+#[cfg(feature = "reexport_crossterm")]
+impl From<crossterm::style::Color> for CellColor {
+    fn from(color: crossterm::style::Color) -> Self {
+        match color {
+            crossterm::style::Color::Reset => CellColor::Reset,
+            crossterm::style::Color::Black => CellColor::Black,
+            crossterm::style::Color::DarkGrey => CellColor::DarkGrey,
+            crossterm::style::Color::Red => CellColor::Red,
+            crossterm::style::Color::DarkRed => CellColor::DarkRed,
+            crossterm::style::Color::Green => CellColor::Green,
+            crossterm::style::Color::DarkGreen => CellColor::DarkGreen,
+            crossterm::style::Color::Yellow => CellColor::Yellow,
+            crossterm::style::Color::DarkYellow => CellColor::DarkYellow,
+            crossterm::style::Color::Blue => CellColor::Blue,
+            crossterm::style::Color::DarkBlue => CellColor::DarkBlue,
+            crossterm::style::Color::Magenta => CellColor::Magenta,
+            crossterm::style::Color::DarkMagenta => CellColor::DarkMagenta,
+            crossterm::style::Color::Cyan => CellColor::Cyan,
+            crossterm::style::Color::DarkCyan => CellColor::DarkCyan,
+            crossterm::style::Color::White => CellColor::White,
+            crossterm::style::Color::Grey => CellColor::Grey,
+            crossterm::style::Color::Rgb { r, g, b } => CellColor::Rgb { r, g, b },
+            crossterm::style::Color::AnsiValue(value) => CellColor::AnsiValue(value),
+        }
     }
 }
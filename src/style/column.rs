@@ -39,4 +39,16 @@ pub enum Width {
     /// - you aren't using one of ContentArrangement::{Dynamic, DynamicFullWidth}
     /// - the width of the table/terminal cannot be determined.
     Percentage(u16),
+    /// A width equivalent to a fraction `numerator / denominator` of the terminal width.
+    /// Fractions above 1 will be automatically reduced to 1.
+    ///
+    /// In contrast to [Width::Percentage], this is always resolved against the live terminal
+    /// width, even if a fixed width has been set via [Table::set_width](crate::Table::set_width).
+    /// If no terminal can be detected, the width of the table is used instead.
+    ///
+    /// **Warning:** This option will be ignored if:
+    /// - you aren't using one of ContentArrangement::{Dynamic, DynamicFullWidth}
+    /// - neither the terminal width nor the width of the table can be determined.
+    /// - the denominator is `0`.
+    TerminalFraction(u16, u16),
 }
@@ -1,51 +1,84 @@
-#[cfg(all(feature = "tty", not(feature = "reexport_crossterm")))]
+#[cfg(feature = "tty")]
 mod attribute;
 mod cell;
-#[cfg(all(feature = "tty", not(feature = "reexport_crossterm")))]
+#[cfg(feature = "tty")]
+mod cell_style;
+#[cfg(feature = "tty")]
 mod color;
 mod column;
 /// Contains modifiers, that can be used to alter certain parts of a preset.\
 /// For instance, the [UTF8_ROUND_CORNERS](modifiers::UTF8_ROUND_CORNERS) replaces all corners with round UTF8 box corners.
 pub mod modifiers;
+mod number_format;
 /// This module provides styling presets for tables.\
 /// Every preset has an example preview.
 pub mod presets;
+mod style_layer;
+mod style_map;
 mod table;
+mod table_style;
+mod theme;
+mod truncation;
 
-pub use cell::CellAlignment;
-pub use column::{ColumnConstraint, Width};
 #[cfg(feature = "tty")]
-pub(crate) use styling_enums::{map_attribute, map_color};
+pub(crate) use attribute::map_attribute;
+#[cfg(feature = "tty")]
+pub use attribute::CellAttribute;
+pub use cell::{CellAlignment, CellVerticalAlignment};
+#[cfg(feature = "tty")]
+pub use cell_style::CellStyle;
 #[cfg(feature = "tty")]
+pub(crate) use color::map_color;
+#[cfg(feature = "tty")]
+pub use color::CellColor;
+pub use column::{ColumnConstraint, Width};
+pub use number_format::NumberFormat;
+pub use style_layer::StyleLayer;
+pub use style_map::StyleMap;
+#[cfg(feature = "tty")]
+// The aliases are deprecated with the `reexport_crossterm` feature.
+#[allow(deprecated)]
 pub use styling_enums::{Attribute, Color};
-pub use table::{ContentArrangement, TableComponent};
+#[cfg(feature = "tty")]
+pub(crate) use table::is_utf8_locale;
+#[cfg(feature = "unicode-bidi")]
+pub use table::BidiMode;
+#[cfg(feature = "tty")]
+pub use table::WindowsConsoleMode;
+pub use table::{
+    Bias, ComponentMask, ContentArrangement, OverflowPolicy, TableComponent, TerminalCapabilities,
+    TrimMode, WrapPolicy,
+};
+pub use table_style::{ComponentStyle, TableStyle};
+pub use theme::Theme;
+pub use truncation::{TruncationMode, TruncationStrategy};
 
 /// Convenience module to have cleaner and "identical" conditional re-exports for style enums.
+///
+/// By default, [Color] and [Attribute] are aliases of [CellColor] and [CellAttribute].
 #[cfg(all(feature = "tty", not(feature = "reexport_crossterm")))]
 mod styling_enums {
-    pub use super::attribute::*;
-    pub use super::color::*;
+    pub use super::attribute::CellAttribute as Attribute;
+    pub use super::color::CellColor as Color;
 }
 
 /// Re-export the crossterm type directly instead of using the internal mirrored types.
 /// This result in possible ABI incompatibilities when using comfy_table and crossterm in the same
 /// project with different versions, but may also be very convenient for developers.
+///
+/// The crossterm types can be passed to all functions that expect a [CellColor] or [CellAttribute].
 #[cfg(all(feature = "tty", feature = "reexport_crossterm"))]
 mod styling_enums {
-    /// Attributes used for styling cell content. Reexport of crossterm's [Attributes](crossterm::style::Attribute) enum.
-    pub use crossterm::style::Attribute;
-    /// Colors used for styling cell content. Reexport of crossterm's [Color](crossterm::style::Color) enum.
-    pub use crossterm::style::Color;
-
-    /// Convenience function to have the same mapping code for reexported types.
-    #[inline]
-    pub(crate) fn map_attribute(attribute: Attribute) -> Attribute {
-        attribute
-    }
-
-    /// Convenience function to have the same mapping code for reexported types.
-    #[inline]
-    pub(crate) fn map_color(color: Color) -> Color {
-        color
-    }
+    /// Attributes used for styling cell content. Alias of crossterm's [Attributes](crossterm::style::Attribute) enum.
+    #[deprecated(
+        note = "The `reexport_crossterm` feature will be removed in the next major release. \
+                Use `CellAttribute` instead, which accepts crossterm's attributes as well."
+    )]
+    pub type Attribute = crossterm::style::Attribute;
+    /// Colors used for styling cell content. Alias of crossterm's [Color](crossterm::style::Color) enum.
+    #[deprecated(
+        note = "The `reexport_crossterm` feature will be removed in the next major release. \
+                Use `CellColor` instead, which accepts crossterm's colors as well."
+    )]
+    pub type Color = crossterm::style::Color;
 }
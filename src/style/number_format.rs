@@ -0,0 +1,179 @@
+/// Describes how the numbers in a column are displayed, see
+/// [Column::set_number_format](crate::Column::set_number_format).
+///
+/// Only body cells whose whole content is a plain decimal number, such as `-1234.5`,
+/// are formatted. All other cells, as well as the header, are displayed as they are.
+///
+/// ```
+/// use comfy_table::NumberFormat;
+///
+/// let format = NumberFormat::new()
+///     .precision(2)
+///     .thousands_separator(',')
+///     .align_decimal_point();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub(crate) precision: Option<usize>,
+    pub(crate) thousands_separator: Option<char>,
+    pub(crate) align_decimal_point: bool,
+}
+
+impl NumberFormat {
+    /// Create a format, which displays numbers as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round all numbers to this many decimal places, or pad them with zeros.
+    /// Halves are rounded away from zero.
+    #[must_use]
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+
+        self
+    }
+
+    /// Group the digits in front of the decimal point by thousands with this character.
+    #[must_use]
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+
+        self
+    }
+
+    /// Pad the numbers of a column with spaces, so that their decimal points line up.
+    #[must_use]
+    pub fn align_decimal_point(mut self) -> Self {
+        self.align_decimal_point = true;
+
+        self
+    }
+
+    /// Format a number and return the parts in front of and after the decimal point.
+    /// The decimal point itself is part of the second part.
+    ///
+    /// Returns `None`, if the content isn't a plain decimal number.
+    pub(crate) fn format(&self, content: &str) -> Option<(String, String)> {
+        let (negative, digits) = match content.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, content.strip_prefix('+').unwrap_or(content)),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+            return None;
+        }
+        if digits.ends_with('.') {
+            return None;
+        }
+
+        let (integer, fraction) = match self.precision {
+            Some(precision) => round(integer, fraction, precision),
+            None => (integer.to_string(), fraction.to_string()),
+        };
+
+        let mut formatted = String::new();
+        // Don't display a negative zero after rounding.
+        let is_zero = integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|byte| byte == b'0');
+        if negative && !is_zero {
+            formatted.push('-');
+        }
+        for (index, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - index;
+            if index > 0 && remaining % 3 == 0 {
+                if let Some(separator) = self.thousands_separator {
+                    formatted.push(separator);
+                }
+            }
+            formatted.push(digit);
+        }
+
+        let fraction = if fraction.is_empty() {
+            String::new()
+        } else {
+            format!(".{fraction}")
+        };
+
+        Some((formatted, fraction))
+    }
+}
+
+/// Round the decimal number `integer.fraction` to the given amount of decimal places.
+fn round(integer: &str, fraction: &str, precision: usize) -> (String, String) {
+    let mut digits: Vec<u8> = integer.bytes().chain(fraction.bytes()).collect();
+    let round_up = fraction
+        .as_bytes()
+        .get(precision)
+        .map_or(false, |digit| *digit >= b'5');
+
+    // Cut off all digits after the precision or pad the fraction with zeros.
+    digits.resize(integer.len() + precision, b'0');
+
+    if round_up {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            digits.insert(0, b'1');
+        }
+    }
+
+    let split = digits.len() - precision;
+    let digits = String::from_utf8(digits).expect("Only ASCII digits are used.");
+    let (integer, fraction) = digits.split_at(split);
+
+    (integer.to_string(), fraction.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn formatted(format: &NumberFormat, content: &str) -> Option<String> {
+        format
+            .format(content)
+            .map(|(integer, fraction)| integer + &fraction)
+    }
+
+    #[test]
+    fn rounding() {
+        let format = NumberFormat::new().precision(2);
+        assert_eq!(formatted(&format, "1.005").as_deref(), Some("1.01"));
+        assert_eq!(formatted(&format, "9.999").as_deref(), Some("10.00"));
+        assert_eq!(formatted(&format, "-0.001").as_deref(), Some("0.00"));
+        assert_eq!(formatted(&format, "42").as_deref(), Some("42.00"));
+
+        let format = NumberFormat::new().precision(0);
+        assert_eq!(formatted(&format, "99.5").as_deref(), Some("100"));
+    }
+
+    #[test]
+    fn thousands_separator() {
+        let format = NumberFormat::new().thousands_separator(',');
+        assert_eq!(
+            formatted(&format, "1234567.891").as_deref(),
+            Some("1,234,567.891")
+        );
+        assert_eq!(formatted(&format, "-123456").as_deref(), Some("-123,456"));
+        assert_eq!(formatted(&format, "123").as_deref(), Some("123"));
+    }
+
+    #[test]
+    fn no_numbers() {
+        let format = NumberFormat::new();
+        for content in ["", "-", "1.", ".5", "1e5", "1,000", "12 apples", "1.2.3"] {
+            assert_eq!(format.format(content), None, "{content}");
+        }
+    }
+}
@@ -1,3 +1,10 @@
+use std::fmt;
+
+use strum::IntoEnumIterator;
+use unicode_width::UnicodeWidthChar;
+
+use super::TableComponent;
+
 /// The default style for tables.
 ///
 /// ```text
@@ -79,7 +86,7 @@ pub const ASCII_HORIZONTAL_ONLY: &str = "  -- ==  --  --    ";
 /// | a     | b     |
 /// | c     | d     |
 /// ```
-pub const ASCII_MARKDOWN: &str = "||  |-|||           ";
+pub const ASCII_MARKDOWN: &str = "||  |-|||          ";
 
 /// The UTF8 enabled version of the default style for tables.\
 /// Quite beautiful isn't it? It's drawn with UTF8's box drawing characters.
@@ -152,3 +159,170 @@ pub const UTF8_HORIZONTAL_ONLY: &str = "  ── ══  ──  ──    ";
 ///  c      d
 /// ```
 pub const NOTHING: &str = "                   ";
+
+/// The number of characters in a full preset, one for each [TableComponent].
+pub const PRESET_LENGTH: usize = 19;
+
+/// A problem with a preset string, as reported by [validate].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PresetIssue {
+    /// The preset doesn't contain exactly one character for each [TableComponent].
+    WrongLength { expected: usize, actual: usize },
+    /// A character doesn't take up any column in the terminal, e.g. zero-width characters.
+    /// Wide characters, such as CJK characters, are fine.
+    InvalidWidth {
+        component: TableComponent,
+        character: char,
+        width: usize,
+    },
+    /// A whitespace or control character other than ` ` has been used.
+    /// Only plain spaces can be used to indicate that a component shouldn't be drawn.
+    InvalidWhitespace {
+        component: TableComponent,
+        character: char,
+    },
+}
+
+impl fmt::Display for PresetIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresetIssue::WrongLength { expected, actual } => write!(
+                f,
+                "Preset has {actual} characters, but {expected} are expected"
+            ),
+            PresetIssue::InvalidWidth {
+                component,
+                character,
+                width,
+            } => write!(
+                f,
+                "Character {character:?} for {component:?} is {width} columns wide and wouldn't be visible"
+            ),
+            PresetIssue::InvalidWhitespace {
+                component,
+                character,
+            } => write!(
+                f,
+                "Character {character:?} for {component:?} is whitespace, use ' ' to not draw it"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PresetIssue {}
+
+/// Check a preset string for common authoring errors.
+///
+/// [Table::load_preset](crate::Table::load_preset) is lenient and accepts any string,
+/// which can lead to subtly broken tables. This function makes sure, that:
+///
+/// - The preset contains exactly one character for each [TableComponent].
+/// - Each character takes up at least one column in the terminal.
+///   Wide characters are fine, as each vertical border is as wide as its widest character.
+/// - Plain spaces are the only whitespace characters used.
+///
+/// ```
+/// use comfy_table::presets::{validate, PresetIssue, UTF8_FULL};
+/// use comfy_table::TableComponent;
+///
+/// assert_eq!(validate(UTF8_FULL), Ok(()));
+///
+/// let issue = validate("││──╞═╪╡┆╌┼├┤┬┴┌┐└\t").unwrap_err();
+/// assert_eq!(
+///     issue,
+///     PresetIssue::InvalidWhitespace {
+///         component: TableComponent::BottomRightCorner,
+///         character: '\t',
+///     }
+/// );
+/// ```
+pub fn validate(preset: &str) -> Result<(), PresetIssue> {
+    let actual = preset.chars().count();
+    if actual != PRESET_LENGTH {
+        return Err(PresetIssue::WrongLength {
+            expected: PRESET_LENGTH,
+            actual,
+        });
+    }
+
+    for (character, component) in preset.chars().zip(TableComponent::iter()) {
+        if character == ' ' {
+            continue;
+        }
+
+        if character.is_whitespace() || character.is_control() {
+            return Err(PresetIssue::InvalidWhitespace {
+                component,
+                character,
+            });
+        }
+
+        let width = character.width().unwrap_or(0);
+        if width == 0 {
+            return Err(PresetIssue::InvalidWidth {
+                component,
+                character,
+                width,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::modifiers::*;
+
+    /// Make sure all presets and modifiers that ship with comfy-table are valid.
+    #[test]
+    fn test_builtin_presets() {
+        for preset in [
+            ASCII_FULL,
+            ASCII_FULL_CONDENSED,
+            ASCII_NO_BORDERS,
+            ASCII_BORDERS_ONLY,
+            ASCII_BORDERS_ONLY_CONDENSED,
+            ASCII_HORIZONTAL_ONLY,
+            ASCII_MARKDOWN,
+            UTF8_FULL,
+            UTF8_FULL_CONDENSED,
+            UTF8_NO_BORDERS,
+            UTF8_BORDERS_ONLY,
+            UTF8_HORIZONTAL_ONLY,
+            NOTHING,
+            UTF8_ROUND_CORNERS,
+            UTF8_SOLID_INNER_BORDERS,
+        ] {
+            assert_eq!(validate(preset), Ok(()), "Invalid preset {preset:?}");
+        }
+    }
+
+    #[test]
+    fn test_validate_issues() {
+        assert_eq!(
+            validate("||--"),
+            Err(PresetIssue::WrongLength {
+                expected: 19,
+                actual: 4
+            })
+        );
+        assert_eq!(validate("||--+==+|-+||+++++表"), Ok(()));
+        assert_eq!(
+            validate("||--+==+|-+||+++++\u{200b}"),
+            Err(PresetIssue::InvalidWidth {
+                component: TableComponent::BottomRightCorner,
+                character: '\u{200b}',
+                width: 0,
+            })
+        );
+        assert_eq!(
+            validate("|\u{a0}--+==+|-+||++++++"),
+            Err(PresetIssue::InvalidWhitespace {
+                component: TableComponent::RightBorder,
+                character: '\u{a0}',
+            })
+        );
+    }
+}
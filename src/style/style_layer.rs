@@ -0,0 +1,104 @@
+#[cfg(feature = "tty")]
+use super::CellStyle;
+use super::{TableComponent, TableStyle};
+
+/// A set of style overrides, which can be put on top of a table's style via
+/// [Table::push_style_layer](crate::Table::push_style_layer).
+///
+/// Layers don't change the table's own style. When the table is rendered, each component is
+/// taken from the topmost layer that sets it, falling back to the table's own style.
+/// Hence, a layer can be removed again via [Table::pop_style_layer](crate::Table::pop_style_layer),
+/// without having to restore any of the previous settings.
+///
+/// ```
+/// use comfy_table::presets::UTF8_FULL;
+/// use comfy_table::{StyleLayer, Table, TableComponent};
+///
+/// let mut table = Table::new();
+/// table.load_preset(UTF8_FULL).add_row(vec!["Disk full"]);
+///
+/// let mut error_view = StyleLayer::new();
+/// error_view
+///     .set_style(TableComponent::TopBorder, '!')
+///     .set_style(TableComponent::BottomBorder, '!');
+///
+/// table.push_style_layer(error_view);
+/// let expected = "
+/// ┌!!!!!!!!!!!┐
+/// │ Disk full │
+/// └!!!!!!!!!!!┘";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+///
+/// table.pop_style_layer();
+/// let expected = "
+/// ┌───────────┐
+/// │ Disk full │
+/// └───────────┘";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyleLayer {
+    pub(crate) border: TableStyle,
+    #[cfg(feature = "tty")]
+    pub(crate) header_style: Option<CellStyle>,
+    #[cfg(feature = "tty")]
+    pub(crate) body_style: Option<CellStyle>,
+}
+
+impl StyleLayer {
+    /// Create a new layer, which doesn't override anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw a component with the given character, while this layer is active.
+    pub fn set_style(&mut self, component: TableComponent, character: char) -> &mut Self {
+        self.border.set_style(component, character);
+
+        self
+    }
+
+    /// Don't draw a component, while this layer is active.
+    pub fn remove_style(&mut self, component: TableComponent) -> &mut Self {
+        self.border.remove_style(component);
+
+        self
+    }
+
+    /// Override all components that're set or removed in the given [TableStyle].
+    pub fn set_table_style(&mut self, style: TableStyle) -> &mut Self {
+        self.border = style;
+
+        self
+    }
+
+    /// The overridden components of this layer.
+    pub fn table_style(&self) -> &TableStyle {
+        &self.border
+    }
+
+    /// Replace the [header style](crate::Table::style_header), while this layer is active.
+    #[cfg(feature = "tty")]
+    pub fn set_header_style(&mut self, style: CellStyle) -> &mut Self {
+        self.header_style = Some(style);
+
+        self
+    }
+
+    /// Replace the [body style](crate::Table::style_body), while this layer is active.
+    #[cfg(feature = "tty")]
+    pub fn set_body_style(&mut self, style: CellStyle) -> &mut Self {
+        self.body_style = Some(style);
+
+        self
+    }
+}
+
+impl From<TableStyle> for StyleLayer {
+    fn from(style: TableStyle) -> Self {
+        let mut layer = Self::new();
+        layer.set_table_style(style);
+
+        layer
+    }
+}
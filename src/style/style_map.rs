@@ -0,0 +1,96 @@
+use strum::IntoEnumIterator;
+
+use super::TableComponent;
+
+/// The characters of all [TableComponent]s, which can be built at compile time.
+///
+/// In contrast to preset strings, which are parsed every time they're loaded, a style map is
+/// simply copied into the table via [Table::load_style_map](crate::Table::load_style_map).
+/// This makes it a good fit for applications that create many tables with the same style.\
+/// Just like for presets, components without a character aren't drawn.
+///
+/// ```
+/// use comfy_table::{StyleMap, Table, TableComponent::*};
+///
+/// const DOTTED: StyleMap = StyleMap::new()
+///     .set_style(TopLeftCorner, '.')
+///     .set_style(TopBorder, '.')
+///     .set_style(TopBorderIntersections, '.')
+///     .set_style(TopRightCorner, '.')
+///     .set_style(LeftBorder, ':')
+///     .set_style(VerticalLines, ':')
+///     .set_style(RightBorder, ':')
+///     .set_style(BottomLeftCorner, ':')
+///     .set_style(BottomBorder, '.')
+///     .set_style(BottomBorderIntersections, ':')
+///     .set_style(BottomRightCorner, ':');
+///
+/// let mut table = Table::new();
+/// table.load_style_map(&DOTTED).add_row(vec!["a", "b"]);
+///
+/// let expected = "
+/// .........
+/// : a : b :
+/// :...:...:";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StyleMap {
+    pub(crate) characters: [Option<char>; TableComponent::COUNT],
+}
+
+impl StyleMap {
+    /// Create a style map, in which no component is drawn.
+    pub const fn new() -> Self {
+        Self {
+            characters: [None; TableComponent::COUNT],
+        }
+    }
+
+    /// Create a style map from the characters of all components, in the order of the
+    /// [TableComponent] enum.
+    pub const fn from_array(characters: [Option<char>; TableComponent::COUNT]) -> Self {
+        Self { characters }
+    }
+
+    /// Create a style map from a preset string.
+    ///
+    /// Spaces and components after the end of a short preset aren't drawn.
+    pub fn from_preset(preset: &str) -> Self {
+        let mut map = Self::new();
+        for (component, character) in TableComponent::iter().zip(preset.chars()) {
+            if character != ' ' {
+                map.characters[component as usize] = Some(character);
+            }
+        }
+
+        map
+    }
+
+    /// Draw a component with the given character.
+    #[must_use]
+    pub const fn set_style(mut self, component: TableComponent, character: char) -> Self {
+        self.characters[component as usize] = Some(character);
+
+        self
+    }
+
+    /// Don't draw a component.
+    #[must_use]
+    pub const fn remove_style(mut self, component: TableComponent) -> Self {
+        self.characters[component as usize] = None;
+
+        self
+    }
+
+    /// Get the character of a specific component, if it's drawn.
+    pub const fn style(&self, component: TableComponent) -> Option<char> {
+        self.characters[component as usize]
+    }
+}
+
+impl Default for StyleMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,4 +1,8 @@
-use strum_macros::EnumIter;
+use std::ops::{BitOr, Not};
+
+use strum_macros::{EnumCount, EnumIter};
+
+use super::presets::{ASCII_FULL, NOTHING, UTF8_FULL};
 
 /// Specify how comfy_table should arrange the content in your table.
 ///
@@ -28,6 +32,230 @@ pub enum ContentArrangement {
     DynamicFullWidth,
 }
 
+/// Specify which side receives the extra space, if space cannot be split evenly.
+///
+/// This applies to [centered](crate::CellAlignment::Center) content, as well as the space that's
+/// distributed between columns by the [Dynamic](ContentArrangement::Dynamic) and
+/// [DynamicFullWidth](ContentArrangement::DynamicFullWidth) arrangements.
+///
+/// ```
+/// use comfy_table::{Bias, Table};
+///
+/// let mut table = Table::new();
+/// table.set_center_bias(Bias::Right);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Bias {
+    /// The left side of centered content and the left-most columns receive the extra space.
+    #[default]
+    Left,
+    /// The right side of centered content and the right-most columns receive the extra space.
+    Right,
+}
+
+/// Specify what should happen, if a table cannot be squeezed into the available width.
+///
+/// This only applies to the [Dynamic](ContentArrangement::Dynamic) and
+/// [DynamicFullWidth](ContentArrangement::DynamicFullWidth) arrangements, as
+/// [Disabled](ContentArrangement::Disabled) arrangement doesn't care about the available width.\
+/// A table doesn't fit, if the available width is smaller than the sum of all column's minimal
+/// widths (1 character plus padding or whatever their constraints demand) and the borders.
+///
+/// ```
+/// use comfy_table::{ContentArrangement, OverflowPolicy, Table};
+///
+/// let mut table = Table::new();
+/// table
+///     .set_content_arrangement(ContentArrangement::Dynamic)
+///     .set_overflow_policy(OverflowPolicy::HideColumns);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The table simply becomes wider than the available width.
+    #[default]
+    Overflow,
+    /// Hide columns, starting with the right-most one, until the table fits.
+    HideColumns,
+    /// Display each row as a list of `header | value` pairs.
+    /// This takes a lot more vertical space, but works with very narrow widths.
+    Vertical,
+}
+
+/// Specify how tables should deal with Windows consoles that don't support ANSI escape codes
+/// by default, such as the legacy `cmd.exe`.
+///
+/// This only has an effect on Windows.
+///
+/// ```
+/// use comfy_table::{Table, WindowsConsoleMode};
+///
+/// let mut table = Table::new();
+/// table.set_windows_console_mode(WindowsConsoleMode::Unchanged);
+/// ```
+#[cfg(feature = "tty")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WindowsConsoleMode {
+    /// Enable virtual terminal processing of the console on the first rendering.
+    /// If the console doesn't support ANSI escape codes, the table is rendered without styling.
+    #[default]
+    Auto,
+    /// Don't touch the console. Styled content is always rendered with ANSI escape codes.
+    Unchanged,
+}
+
+/// The capabilities of the terminal a table is displayed in.
+///
+/// They're used by [Table::load_preset_for](crate::Table::load_preset_for) to choose a preset,
+/// which looks good in the respective environment.
+/// [Table::terminal_capabilities](crate::Table::terminal_capabilities) detects the capabilities
+/// of the current terminal.
+///
+/// ```
+/// use comfy_table::presets::ASCII_FULL;
+/// use comfy_table::TerminalCapabilities;
+///
+/// let capabilities = TerminalCapabilities {
+///     is_tty: true,
+///     utf8: false,
+///     legacy_console: false,
+/// };
+/// assert_eq!(capabilities.preset(), ASCII_FULL);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TerminalCapabilities {
+    /// The table is displayed in a terminal and not, for instance, written to a file or a pipe.
+    pub is_tty: bool,
+    /// The locale of the terminal uses UTF-8.
+    pub utf8: bool,
+    /// The terminal is a legacy Windows console, which cannot reliably display box-drawing
+    /// characters.
+    pub legacy_console: bool,
+}
+
+impl TerminalCapabilities {
+    /// Get the preset that fits these capabilities best:
+    /// - [UTF8_FULL](crate::presets::UTF8_FULL) for modern UTF-8 terminals.
+    /// - [ASCII_FULL](crate::presets::ASCII_FULL) for terminals without UTF-8 support and
+    ///   legacy Windows consoles.
+    /// - [NOTHING](crate::presets::NOTHING) if the output isn't a terminal, which keeps the
+    ///   output easy to process for other programs.
+    pub fn preset(&self) -> &'static str {
+        if !self.is_tty {
+            return NOTHING;
+        }
+        if self.utf8 && !self.legacy_console {
+            return UTF8_FULL;
+        }
+
+        ASCII_FULL
+    }
+}
+
+/// Check whether a locale, such as `en_US.UTF-8`, uses UTF-8.
+#[cfg(feature = "tty")]
+pub(crate) fn is_utf8_locale(locale: &str) -> bool {
+    let encoding = locale
+        .split_once('.')
+        .map_or("", |(_, encoding)| encoding)
+        .split('@')
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    encoding == "utf-8" || encoding == "utf8"
+}
+
+/// Specify which whitespaces should be removed from each line of a cell's content.
+///
+/// Whitespaces are removed before the content is measured, which means they neither affect the
+/// width of a column nor the alignment of the content.\
+/// Cells that [preserve their whitespaces](crate::Cell::preserve_whitespace) and
+/// [preformatted](crate::Cell::preformatted) cells are never trimmed.
+///
+/// ```
+/// use comfy_table::{Table, TrimMode};
+///
+/// let mut table = Table::new();
+/// table.set_trim_mode(TrimMode::Both);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TrimMode {
+    /// Keep all whitespaces.
+    #[default]
+    None,
+    /// Remove whitespaces at the start of each line.
+    Leading,
+    /// Remove whitespaces at the end of each line.
+    Trailing,
+    /// Remove whitespaces at the start and the end of each line.
+    Both,
+}
+
+impl TrimMode {
+    /// Trim a single line according to this mode.
+    pub(crate) fn trim<'a>(&self, line: &'a str) -> &'a str {
+        match self {
+            TrimMode::None => line,
+            TrimMode::Leading => line.trim_start(),
+            TrimMode::Trailing => line.trim_end(),
+            TrimMode::Both => line.trim(),
+        }
+    }
+}
+
+/// Specify how words that're too long for their column are wrapped.
+///
+/// Words are separated by the [delimiter](crate::Table::set_delimiter), which is a space by
+/// default. The policy only affects words that don't fit into a line by themselves.
+///
+/// ```
+/// use comfy_table::{Table, WrapPolicy};
+///
+/// let mut table = Table::new();
+/// table.set_wrap_policy(WrapPolicy::Hyphenate);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WrapPolicy {
+    /// Break words at whichever character the line is full.
+    #[default]
+    BreakWords,
+    /// Never break words. Columns are at least as wide as their longest word instead,
+    /// even if the table then exceeds its width.\
+    /// Only a constraint that forces a column to be narrower cuts off the word.
+    KeepWords,
+    /// Break words like [WrapPolicy::BreakWords], but end the broken part with a `-`.
+    Hyphenate,
+}
+
+/// Specify how content that contains right-to-left text, e.g. Arabic or Hebrew, is displayed.
+///
+/// Content is stored in logical order, which is the order the characters are written in.
+/// Most terminals display text exactly in that order, which shows right-to-left text backwards.
+/// Terminals that reorder text by themselves reorder whole lines, including the borders and the
+/// padding of the table, which scrambles its columns.
+///
+/// ```
+/// use comfy_table::{BidiMode, Table};
+///
+/// let mut table = Table::new();
+/// table.set_bidi_mode(BidiMode::VisualRightAligned);
+/// ```
+#[cfg(feature = "unicode-bidi")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BidiMode {
+    /// Display the content in logical order.
+    #[default]
+    Logical,
+    /// Reorder each line of a cell into visual order, as described by the Unicode
+    /// Bidirectional Algorithm. Lines are wrapped before they're reordered and each line is
+    /// padded on its own, so the columns stay aligned.\
+    /// Explicit directional formatting characters are removed afterwards.
+    Visual,
+    /// Same as [BidiMode::Visual], but cells whose content is mostly right-to-left are aligned to
+    /// the right, unless the cell or its column has an explicit [alignment](crate::CellAlignment).
+    VisualRightAligned,
+}
+
 /// All configurable table components.
 /// A character can be assigned to each component via [Table::set_style](crate::table::Table::set_style).
 /// This is then used to draw character of the respective component to the commandline.
@@ -46,7 +274,7 @@ pub enum ContentArrangement {
 /// |   |   |   |    The inner "+" chars are MiddleIntersections
 /// +---+---+---+
 /// ```
-#[derive(Debug, PartialEq, Eq, Hash, EnumIter, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, EnumIter, EnumCount, Clone, Copy)]
 pub enum TableComponent {
     LeftBorder,
     RightBorder,
@@ -68,3 +296,132 @@ pub enum TableComponent {
     BottomLeftCorner,
     BottomRightCorner,
 }
+
+impl TableComponent {
+    /// The number of components.
+    pub const COUNT: usize = <Self as strum::EnumCount>::COUNT;
+}
+
+/// A set of [TableComponent]s.
+///
+/// This is used to only apply parts of a preset via [Table::load_preset_partial](crate::Table::load_preset_partial).
+/// Masks can be combined with `|` and inverted with `!`.
+///
+/// ```
+/// use comfy_table::{ComponentMask, TableComponent};
+///
+/// let mask = ComponentMask::CORNERS | ComponentMask::from(TableComponent::HeaderLines);
+/// assert!(mask.contains(TableComponent::TopLeftCorner));
+/// assert!(mask.contains(TableComponent::HeaderLines));
+/// assert!(!mask.contains(TableComponent::VerticalLines));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ComponentMask(u32);
+
+impl ComponentMask {
+    /// No components at all.
+    pub const NONE: ComponentMask = ComponentMask(0);
+    /// All components.
+    pub const ALL: ComponentMask = ComponentMask((1 << TableComponent::COUNT) - 1);
+    /// The four outer borders, without their corners and intersections.
+    pub const BORDERS: ComponentMask = ComponentMask(
+        bit(TableComponent::LeftBorder)
+            | bit(TableComponent::RightBorder)
+            | bit(TableComponent::TopBorder)
+            | bit(TableComponent::BottomBorder),
+    );
+    /// The four outer corners.
+    pub const CORNERS: ComponentMask = ComponentMask(
+        bit(TableComponent::TopLeftCorner)
+            | bit(TableComponent::TopRightCorner)
+            | bit(TableComponent::BottomLeftCorner)
+            | bit(TableComponent::BottomRightCorner),
+    );
+    /// The line between header and body, including its intersections.
+    pub const HEADER: ComponentMask = ComponentMask(
+        bit(TableComponent::LeftHeaderIntersection)
+            | bit(TableComponent::HeaderLines)
+            | bit(TableComponent::MiddleHeaderIntersections)
+            | bit(TableComponent::RightHeaderIntersection),
+    );
+    /// All horizontal lines, i.e. the top and bottom border, the header line and the lines
+    /// between rows.
+    pub const HORIZONTAL_LINES: ComponentMask = ComponentMask(
+        bit(TableComponent::TopBorder)
+            | bit(TableComponent::BottomBorder)
+            | bit(TableComponent::HeaderLines)
+            | bit(TableComponent::HorizontalLines),
+    );
+    /// All vertical lines, i.e. the left and right border and the lines between columns.
+    pub const VERTICAL_LINES: ComponentMask = ComponentMask(
+        bit(TableComponent::LeftBorder)
+            | bit(TableComponent::RightBorder)
+            | bit(TableComponent::VerticalLines),
+    );
+    /// All intersections, including the ones of the header line.
+    pub const INTERSECTIONS: ComponentMask = ComponentMask(
+        bit(TableComponent::LeftHeaderIntersection)
+            | bit(TableComponent::MiddleHeaderIntersections)
+            | bit(TableComponent::RightHeaderIntersection)
+            | bit(TableComponent::MiddleIntersections)
+            | bit(TableComponent::LeftBorderIntersections)
+            | bit(TableComponent::RightBorderIntersections)
+            | bit(TableComponent::TopBorderIntersections)
+            | bit(TableComponent::BottomBorderIntersections),
+    );
+
+    /// Check whether a component is part of this mask.
+    pub const fn contains(self, component: TableComponent) -> bool {
+        self.0 & bit(component) != 0
+    }
+}
+
+/// The bit of a component inside of a [ComponentMask].
+const fn bit(component: TableComponent) -> u32 {
+    1 << component as u32
+}
+
+impl From<TableComponent> for ComponentMask {
+    fn from(component: TableComponent) -> Self {
+        ComponentMask(bit(component))
+    }
+}
+
+impl FromIterator<TableComponent> for ComponentMask {
+    fn from_iter<T: IntoIterator<Item = TableComponent>>(iter: T) -> Self {
+        iter.into_iter()
+            .fold(ComponentMask::NONE, |mask, component| {
+                mask | ComponentMask::from(component)
+            })
+    }
+}
+
+impl BitOr for ComponentMask {
+    type Output = ComponentMask;
+
+    fn bitor(self, other: ComponentMask) -> ComponentMask {
+        ComponentMask(self.0 | other.0)
+    }
+}
+
+impl Not for ComponentMask {
+    type Output = ComponentMask;
+
+    fn not(self) -> ComponentMask {
+        ComponentMask(!self.0 & ComponentMask::ALL.0)
+    }
+}
+
+#[cfg(all(test, feature = "tty"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_utf8_locale() {
+        assert!(is_utf8_locale("en_US.UTF-8"));
+        assert!(is_utf8_locale("de_DE.utf8@euro"));
+        assert!(!is_utf8_locale("C"));
+        assert!(!is_utf8_locale("POSIX"));
+        assert!(!is_utf8_locale("en_US.ISO-8859-1"));
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+use super::TableComponent;
+use crate::error::TableStyleParseError;
+
+/// The style of a single [TableComponent] inside of a [TableStyle].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ComponentStyle {
+    /// The component has never been set. Applying the style keeps the table's current character.
+    Unset,
+    /// The component has explicitly been removed and won't be drawn.
+    Removed,
+    /// The component is drawn with this character.
+    Char(char),
+}
+
+/// A complete or partial description of a table's border style.
+///
+/// In contrast to preset strings, a [TableStyle] distinguishes between components that've been
+/// explicitly removed and components that've never been set.
+/// This allows to copy styles between tables without losing any information and to layer
+/// partial styles on top of each other.
+///
+/// A [TableStyle] can be converted to and parsed from a string with one `Component=character`
+/// line per component. Removed components have an empty value, unset components are omitted.
+///
+/// ```
+/// use comfy_table::presets::UTF8_FULL;
+/// use comfy_table::{Table, TableComponent, TableStyle};
+///
+/// let mut table = Table::new();
+/// table.load_preset(UTF8_FULL).remove_style(TableComponent::HorizontalLines);
+///
+/// let style = table.table_style().to_string();
+/// let style: TableStyle = style.parse().unwrap();
+///
+/// let mut other = Table::new();
+/// other.set_table_style(&style);
+/// assert_eq!(table.current_style_as_preset(), other.current_style_as_preset());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableStyle {
+    components: HashMap<TableComponent, ComponentStyle>,
+}
+
+impl TableStyle {
+    /// Create a new style, in which no component is set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a style from a preset string.
+    ///
+    /// Just like in [Table::load_preset](crate::Table::load_preset), spaces remove the respective
+    /// component. Components after the end of a short preset are unset.
+    pub fn from_preset(preset: &str) -> Self {
+        let mut style = Self::new();
+        for (component, character) in TableComponent::iter().zip(preset.chars()) {
+            if character == ' ' {
+                style.remove_style(component);
+            } else {
+                style.set_style(component, character);
+            }
+        }
+
+        style
+    }
+
+    /// Draw a component with the given character.
+    pub fn set_style(&mut self, component: TableComponent, character: char) -> &mut Self {
+        self.components
+            .insert(component, ComponentStyle::Char(character));
+
+        self
+    }
+
+    /// Explicitly remove a component, so it won't be drawn.
+    pub fn remove_style(&mut self, component: TableComponent) -> &mut Self {
+        self.components.insert(component, ComponentStyle::Removed);
+
+        self
+    }
+
+    /// Unset a component, so the table's current character is kept when applying this style.
+    pub fn unset_style(&mut self, component: TableComponent) -> &mut Self {
+        self.components.remove(&component);
+
+        self
+    }
+
+    /// Get the style of a specific component.
+    pub fn style(&self, component: TableComponent) -> ComponentStyle {
+        self.components
+            .get(&component)
+            .copied()
+            .unwrap_or(ComponentStyle::Unset)
+    }
+}
+
+impl fmt::Display for TableStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for component in TableComponent::iter() {
+            match self.style(component) {
+                ComponentStyle::Unset => continue,
+                ComponentStyle::Removed => writeln!(f, "{component:?}=")?,
+                ComponentStyle::Char(character) => writeln!(f, "{component:?}={character}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for TableStyle {
+    type Err = TableStyleParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut style = TableStyle::new();
+        for line in string.lines().filter(|line| !line.trim().is_empty()) {
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| TableStyleParseError::MissingSeparator(line.to_string()))?;
+
+            let name = name.trim();
+            let component = TableComponent::iter()
+                .find(|component| format!("{component:?}") == name)
+                .ok_or_else(|| TableStyleParseError::UnknownComponent(name.to_string()))?;
+
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (None, _) => style.remove_style(component),
+                (Some(character), None) => style.set_style(component, character),
+                _ => {
+                    return Err(TableStyleParseError::InvalidValue {
+                        component,
+                        value: value.to_string(),
+                    })
+                }
+            };
+        }
+
+        Ok(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets::UTF8_FULL_CONDENSED;
+
+    #[test]
+    fn round_trip() {
+        let mut style = TableStyle::from_preset(UTF8_FULL_CONDENSED);
+        style.unset_style(TableComponent::TopLeftCorner);
+
+        let parsed: TableStyle = style.to_string().parse().unwrap();
+        assert_eq!(parsed, style);
+        // The condensed preset has no horizontal lines.
+        assert_eq!(
+            parsed.style(TableComponent::HorizontalLines),
+            ComponentStyle::Removed
+        );
+        assert_eq!(
+            parsed.style(TableComponent::TopLeftCorner),
+            ComponentStyle::Unset
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "TopBorder".parse::<TableStyle>(),
+            Err(TableStyleParseError::MissingSeparator(
+                "TopBorder".to_string()
+            ))
+        );
+        assert_eq!(
+            "Top=-".parse::<TableStyle>(),
+            Err(TableStyleParseError::UnknownComponent("Top".to_string()))
+        );
+        assert_eq!(
+            "TopBorder=--".parse::<TableStyle>(),
+            Err(TableStyleParseError::InvalidValue {
+                component: TableComponent::TopBorder,
+                value: "--".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "tty")]
+use super::{CellColor, CellStyle};
+
+/// A named look and feel, which bundles a border preset, modifiers and the styling of the table.
+///
+/// Themes are applied via [Table::apply_theme](crate::Table::apply_theme).
+/// Only the parts that are set in a theme are changed, everything else is left as it is.
+/// This allows to define a theme once and share it between all tables of an application.
+///
+/// ```
+/// use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+/// use comfy_table::presets::UTF8_FULL;
+/// use comfy_table::{CellStyle, Color, Table, Theme};
+///
+/// let theme = Theme::new()
+///     .preset(UTF8_FULL)
+///     .modifier(UTF8_ROUND_CORNERS)
+///     .header_style(CellStyle::new().bold().bg(Color::DarkBlue))
+///     .zebra_stripes(Color::Reset, Color::DarkGrey);
+///
+/// let mut table = Table::new();
+/// table.apply_theme(&theme).add_row(vec!["Themed"]);
+///
+/// let expected = "
+/// ╭────────╮
+/// │ Themed │
+/// ╰────────╯";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Theme {
+    pub(crate) preset: Option<String>,
+    pub(crate) modifiers: Vec<String>,
+    #[cfg(feature = "tty")]
+    pub(crate) header_style: Option<CellStyle>,
+    #[cfg(feature = "tty")]
+    pub(crate) body_style: Option<CellStyle>,
+    #[cfg(feature = "tty")]
+    pub(crate) zebra_stripes: Option<(CellColor, CellColor)>,
+}
+
+impl Theme {
+    /// Create a theme, which doesn't change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load this [preset](crate::Table::load_preset), when the theme is applied.
+    #[must_use]
+    pub fn preset(mut self, preset: &str) -> Self {
+        self.preset = Some(preset.to_string());
+
+        self
+    }
+
+    /// Apply this [modifier](crate::Table::apply_modifier) after the preset has been loaded.
+    /// Modifiers are applied in the order they've been added.
+    #[must_use]
+    pub fn modifier(mut self, modifier: &str) -> Self {
+        self.modifiers.push(modifier.to_string());
+
+        self
+    }
+
+    /// Replace the [header style](crate::Table::style_header) of the table.
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn header_style(mut self, style: CellStyle) -> Self {
+        self.header_style = Some(style);
+
+        self
+    }
+
+    /// Replace the [body style](crate::Table::style_body) of the table.
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn body_style(mut self, style: CellStyle) -> Self {
+        self.body_style = Some(style);
+
+        self
+    }
+
+    /// Alternate the background color of the table's rows, see
+    /// [Table::set_zebra_stripes](crate::Table::set_zebra_stripes).
+    #[cfg(feature = "tty")]
+    #[must_use]
+    pub fn zebra_stripes<C: Into<CellColor>>(mut self, even: C, odd: C) -> Self {
+        self.zebra_stripes = Some((even.into(), odd.into()));
+
+        self
+    }
+}
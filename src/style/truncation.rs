@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+use std::panic::RefUnwindSafe;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::table::WidthFunction;
+use crate::utils::formatting::content_split::measure_text_width;
+
+/// Decides which part of a line is kept, if the line is too wide for its column.
+///
+/// Strategies are set via [Table::set_truncation_strategy](crate::Table::set_truncation_strategy)
+/// and apply to [truncated cells](crate::Cell::set_truncate), as well as to
+/// [preformatted](crate::Cell::preformatted) content and [kept words](crate::WrapPolicy::KeepWords)
+/// that are cut off.\
+/// Lines of cells that're capped by [Row::max_height](crate::Row::max_height) always keep their
+/// beginning, as their content continues on the hidden lines.
+///
+/// Lines that contain ANSI escape sequences are always cut at the end, as they might lose their
+/// styling otherwise.
+///
+/// Strategies are shared between clones of a table. Hence, they have to be [Send], [Sync] and
+/// [RefUnwindSafe], so the table keeps these traits.
+///
+/// ```
+/// use comfy_table::{Cell, ContentArrangement, Table, TruncationStrategy, WidthFunction};
+///
+/// /// Keep the file extension of truncated file names.
+/// #[derive(Debug)]
+/// struct KeepExtension;
+///
+/// impl TruncationStrategy for KeepExtension {
+///     fn truncate(&self, line: &str, width: usize, indicator: &str, _: WidthFunction) -> String {
+///         let (name, extension) = line.rsplit_once('.').unwrap_or((line, ""));
+///         let name_width = width.saturating_sub(indicator.len() + extension.len() + 1);
+///         let name: String = name.chars().take(name_width).collect();
+///
+///         format!("{name}{indicator}.{extension}")
+///     }
+/// }
+///
+/// let mut table = Table::new();
+/// table
+///     .set_truncation_strategy(Box::new(KeepExtension))
+///     .set_content_arrangement(ContentArrangement::Dynamic)
+///     .set_width(16)
+///     .add_row(vec![Cell::new("a_very_long_file_name.rs").set_truncate(true)]);
+///
+/// assert!(table.to_string().contains("| a_very....rs |"));
+/// ```
+pub trait TruncationStrategy: Debug + Send + Sync + RefUnwindSafe {
+    /// Shorten the `line` to at most `width` columns, including the `indicator`.
+    ///
+    /// The width of text should be measured via `measure`, which is the table's
+    /// [width function](crate::Table::set_width_function).
+    /// It's called with text that doesn't contain any ANSI escape sequences.
+    /// Results that're still too wide are cut off at the end.
+    fn truncate(&self, line: &str, width: usize, indicator: &str, measure: WidthFunction)
+        -> String;
+}
+
+/// The built-in [TruncationStrategies](TruncationStrategy).
+///
+/// Given the line `Hello world, how are you?`:
+/// - [TruncationMode::End]: `Hello world, h...`
+/// - [TruncationMode::Start]: `..., how are you?`
+/// - [TruncationMode::Middle]: `Hello w...re you?`
+/// - [TruncationMode::WordBoundary]: `Hello world,...`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruncationMode {
+    /// Keep the beginning of the line. This is the default behavior.
+    End,
+    /// Keep the end of the line, e.g. for paths.
+    Start,
+    /// Keep the beginning and the end of the line.
+    Middle,
+    /// Keep the beginning of the line, but don't cut words in half.
+    /// Words that don't fit by themselves are cut at the end.
+    WordBoundary,
+}
+
+impl TruncationStrategy for TruncationMode {
+    fn truncate(
+        &self,
+        line: &str,
+        width: usize,
+        indicator: &str,
+        measure: WidthFunction,
+    ) -> String {
+        if measure_text_width(line, measure) <= width {
+            return line.to_string();
+        }
+
+        let available = width.saturating_sub(measure_text_width(indicator, measure));
+        match self {
+            TruncationMode::End => format!("{}{indicator}", take_width(line, available, measure)),
+            TruncationMode::Start => {
+                format!("{indicator}{}", take_width_rev(line, available, measure))
+            }
+            TruncationMode::Middle => {
+                let head = take_width(line, available - available / 2, measure);
+                let tail = take_width_rev(line, available / 2, measure);
+                format!("{head}{indicator}{tail}")
+            }
+            TruncationMode::WordBoundary => {
+                let head = take_width(line, available, measure);
+                let cuts_word = line[head.len()..]
+                    .chars()
+                    .next()
+                    .map_or(false, |next| !next.is_whitespace());
+                let head = match head.rfind(char::is_whitespace) {
+                    Some(index) if cuts_word => head[..index].trim_end(),
+                    _ => head.trim_end(),
+                };
+                format!("{head}{indicator}")
+            }
+        }
+    }
+}
+
+/// Collect grapheme clusters, until they don't fit into the given width anymore.
+fn take_width(line: &str, width: usize, measure: WidthFunction) -> &str {
+    let mut current_width = 0;
+    let end = line
+        .grapheme_indices(true)
+        .take_while(|(_, grapheme)| {
+            current_width += measure(grapheme);
+            current_width <= width
+        })
+        .last()
+        .map_or(0, |(index, grapheme)| index + grapheme.len());
+
+    &line[..end]
+}
+
+/// Same as [take_width], but the grapheme clusters are taken from the end of the line.
+fn take_width_rev(line: &str, width: usize, measure: WidthFunction) -> &str {
+    let mut current_width = 0;
+    let start = line
+        .grapheme_indices(true)
+        .rev()
+        .take_while(|(_, grapheme)| {
+            current_width += measure(grapheme);
+            current_width <= width
+        })
+        .last()
+        .map_or(line.len(), |(index, _)| index);
+
+    &line[start..]
+}
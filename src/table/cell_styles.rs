@@ -0,0 +1,138 @@
+use super::Table;
+use crate::style::{CellColor, CellStyle, TableComponent};
+
+impl Table {
+    /// Style all cells of the header at once.
+    ///
+    /// The style is applied during rendering and doesn't touch the header cells,
+    /// so it survives later calls to [Table::set_header].
+    /// Styles of the cells themselves take precedence.
+    ///
+    /// The closure receives the current header style, so multiple calls can be combined.
+    ///
+    /// ```
+    /// use comfy_table::{Color, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .style_header(|style| style.bold().fg(Color::Cyan))
+    ///     .set_header(vec!["Header1", "Header2"]);
+    /// ```
+    pub fn style_header<F: FnOnce(CellStyle) -> CellStyle>(&mut self, style: F) -> &mut Self {
+        self.header_style = style(std::mem::take(&mut self.header_style));
+
+        self
+    }
+
+    /// Style all cells of the table's body (i.e. all rows, except the header) at once.
+    ///
+    /// See [Table::style_header] for details.
+    pub fn style_body<F: FnOnce(CellStyle) -> CellStyle>(&mut self, style: F) -> &mut Self {
+        self.body_style = style(std::mem::take(&mut self.body_style));
+
+        self
+    }
+
+    /// Define the style for all cells with the given [class](crate::Cell::set_class).
+    ///
+    /// Class styles take precedence over [header](Table::style_header) and
+    /// [body](Table::style_body) styles, but styles of the cells themselves always win.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, CellStyle, Color, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_class_style("warning", CellStyle::new().fg(Color::Yellow))
+    ///     .add_row(vec![Cell::new("Disk almost full").set_class("warning")]);
+    /// ```
+    pub fn set_class_style<T: ToString>(&mut self, class: T, style: CellStyle) -> &mut Self {
+        self.class_styles.insert(class.to_string(), style);
+
+        self
+    }
+
+    /// Alternate the background color of the rows of the table's body.
+    ///
+    /// The first row gets the `even` color, the second row the `odd` color and so on.
+    /// [Hidden rows](crate::Row::set_hidden) are skipped, so the stripes keep alternating.\
+    /// The stripes take precedence over the [body style](Table::style_body),
+    /// but [class styles](Table::set_class_style) and styles of the cells themselves win.
+    ///
+    /// ```
+    /// use comfy_table::{Color, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_zebra_stripes(Color::Reset, Color::DarkGrey)
+    ///     .add_row(vec!["First"])
+    ///     .add_row(vec!["Second"]);
+    /// ```
+    pub fn set_zebra_stripes<C: Into<CellColor>>(&mut self, even: C, odd: C) -> &mut Self {
+        self.zebra_stripes = Some((even.into(), odd.into()));
+
+        self
+    }
+
+    /// Remove the zebra stripes, which have been set via [Table::set_zebra_stripes].
+    pub fn remove_zebra_stripes(&mut self) -> &mut Self {
+        self.zebra_stripes = None;
+
+        self
+    }
+
+    /// Get the colors of the even and odd rows, if [zebra stripes](Table::set_zebra_stripes)
+    /// are enabled.
+    pub fn zebra_stripes(&self) -> Option<(CellColor, CellColor)> {
+        self.zebra_stripes
+    }
+
+    /// Style a component of the table's borders, e.g. to draw dim borders around bright content.
+    ///
+    /// Like all other styles, border styles are only applied, if the table
+    /// [should be styled](Table::should_style).
+    /// [Removed](Table::remove_style) components stay invisible.
+    ///
+    /// ```
+    /// use comfy_table::{CellStyle, Color, Table, TableComponent};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_border_style(TableComponent::LeftBorder, CellStyle::new().fg(Color::DarkGrey))
+    ///     .set_border_style(TableComponent::RightBorder, CellStyle::new().fg(Color::DarkGrey))
+    ///     .add_row(vec!["Content"]);
+    /// ```
+    pub fn set_border_style(&mut self, component: TableComponent, style: CellStyle) -> &mut Self {
+        self.border_styles.insert(component, style);
+
+        self
+    }
+
+    /// Remove the style of a border component, which has been set via
+    /// [Table::set_border_style] or [Table::set_style_colored].
+    pub fn remove_border_style(&mut self, component: TableComponent) -> &mut Self {
+        self.border_styles.remove(&component);
+
+        self
+    }
+
+    /// Get the style of a border component, if there is one.
+    pub fn border_style(&self, component: TableComponent) -> Option<&CellStyle> {
+        self.border_styles.get(&component)
+    }
+
+    /// Get the style for cells with the given class, if there is one.
+    pub fn class_style(&self, class: &str) -> Option<&CellStyle> {
+        self.class_styles.get(class)
+    }
+
+    /// Get the style that's applied to all header cells.
+    pub fn header_style(&self) -> &CellStyle {
+        &self.header_style
+    }
+
+    /// Get the style that's applied to all body cells.
+    pub fn body_style(&self) -> &CellStyle {
+        &self.body_style
+    }
+}
@@ -0,0 +1,99 @@
+use super::Table;
+use crate::column::Column;
+
+impl Table {
+    /// Build the header from the [names](Column::set_name) of the columns.
+    ///
+    /// Columns without a name get an empty header cell. The header is built right away, so
+    /// call this again after renaming or adding columns.
+    ///
+    /// ```
+    /// use comfy_table::{Column, Table};
+    ///
+    /// let mut name = Column::new(0);
+    /// name.set_name("Name");
+    /// let mut size = Column::new(1);
+    /// size.set_name("Size");
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_column(name)
+    ///     .add_column(size)
+    ///     .use_column_names_as_header()
+    ///     .add_row(vec!["notes.txt", "120 B"]);
+    ///
+    /// let expected = "
+    /// +-----------+-------+
+    /// | Name      | Size  |
+    /// +===================+
+    /// | notes.txt | 120 B |
+    /// +-----------+-------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn use_column_names_as_header(&mut self) -> &mut Self {
+        let names: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| column.name.clone().unwrap_or_default())
+            .collect();
+
+        self.set_header(names)
+    }
+
+    /// Get the index of the column with the given [name](Column::set_name) or header text.
+    ///
+    /// Names of the columns take precedence over the header.
+    /// The whole content of the header cell has to match. If several columns match,
+    /// the first one is used.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Size"]);
+    ///
+    /// assert_eq!(table.column_index_by_name("Size"), Some(1));
+    /// assert_eq!(table.column_index_by_name("Owner"), None);
+    /// ```
+    pub fn column_index_by_name(&self, name: &str) -> Option<usize> {
+        if let Some(index) = self
+            .columns
+            .iter()
+            .position(|column| column.name.as_deref() == Some(name))
+        {
+            return Some(index);
+        }
+
+        self.header
+            .as_ref()?
+            .cells
+            .iter()
+            .position(|cell| cell.content() == name)
+            .filter(|index| *index < self.columns.len())
+    }
+
+    /// Get a reference to the column with the given header text.
+    /// See [Table::column_index_by_name] on how columns are looked up.
+    pub fn column_by_name(&self, name: &str) -> Option<&Column> {
+        self.columns.get(self.column_index_by_name(name)?)
+    }
+
+    /// Get a mutable reference to the column with the given header text.
+    /// See [Table::column_index_by_name] on how columns are looked up.
+    ///
+    /// ```
+    /// use comfy_table::{CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Size"]);
+    ///
+    /// table
+    ///     .column_by_name_mut("Size")
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    /// ```
+    pub fn column_by_name_mut(&mut self, name: &str) -> Option<&mut Column> {
+        let index = self.column_index_by_name(name)?;
+        self.columns.get_mut(index)
+    }
+}
@@ -0,0 +1,2632 @@
+#[cfg(feature = "tty")]
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::IntoIterator;
+use std::slice::{Iter, IterMut};
+use std::sync::Arc;
+
+#[cfg(feature = "tty")]
+use crossterm::terminal;
+#[cfg(feature = "tty")]
+use crossterm::tty::IsTty;
+use strum::IntoEnumIterator;
+
+use crate::builder::TableBuilder;
+use crate::cell::Cell;
+use crate::column::Column;
+use crate::error::{
+    validate_truncation_indicator, ConstraintError, LayoutError, TruncationIndicatorError,
+};
+use crate::layout::{FrozenLayout, RenderPlan};
+use crate::row::{Row, RowGroup, Section, ToRow};
+use crate::style::presets::ASCII_FULL;
+#[cfg(feature = "unicode-bidi")]
+use crate::style::BidiMode;
+#[cfg(feature = "tty")]
+use crate::style::{is_utf8_locale, WindowsConsoleMode};
+use crate::style::{
+    Bias, CellAlignment, ColumnConstraint, ComponentMask, ContentArrangement, OverflowPolicy,
+    StyleLayer, TableComponent, TerminalCapabilities, TrimMode, TruncationStrategy, WrapPolicy,
+};
+#[cfg(feature = "tty")]
+use crate::style::{CellColor, CellStyle};
+use crate::utils::arrangement::helper::{
+    count_border_columns, count_visible_columns, min_possible_column_width,
+};
+use crate::utils::formatting::content_split::DEFAULT_WIDTH_FUNCTION;
+use crate::utils::formatting::joins::joined_style;
+use crate::utils::formatting::templates::fill_template;
+use crate::utils::{build_table, build_table_with_info, column_display_widths, constraint_report};
+
+#[cfg(feature = "tty")]
+mod cell_styles;
+mod column_names;
+mod render;
+mod sections;
+mod style_layers;
+mod titles;
+mod truncation;
+mod verify;
+
+pub use render::{LinePatch, RenderScratch, RenderedRow, TruncatedContent};
+
+/// Truncation indicators are only displayed in columns that have at least this much space for
+/// content, as they would otherwise cover up too much of the actual content.
+///
+/// Wider indicators are rejected, as they wouldn't fit into such columns.
+pub const MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH: usize = 6;
+
+/// The character that replaces box-drawing characters inside of the content,
+/// if [Table::escape_border_chars] is enabled.
+pub const BORDER_CHAR_REPLACEMENT: char = '\u{FFFD}';
+
+/// A function that measures the printed width of a piece of text.
+///
+/// See [Table::set_width_function].
+pub type WidthFunction = fn(&str) -> usize;
+
+/// This is the main interface for building a table.
+/// Each table consists of [Rows](Row), which in turn contain [Cells](crate::cell::Cell).
+///
+/// There also exists a representation of a [Column].
+/// Columns are automatically created when adding rows to a table.
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub(crate) columns: Vec<Column>,
+    /// The indices of the columns in the order they're displayed.
+    pub(crate) column_order: Option<Vec<usize>>,
+    /// The character of each component, indexed by the [TableComponent].
+    pub(crate) style: [Option<char>; TableComponent::COUNT],
+    /// Style overrides on top of the table's own style, from the bottom to the top.
+    pub(crate) style_layers: Vec<StyleLayer>,
+    pub(crate) header: Option<Row>,
+    pub(crate) rows: Vec<Row>,
+    pub(crate) groups: Vec<RowGroup>,
+    pub(crate) group_is_open: bool,
+    pub(crate) sections: Vec<Section>,
+    pub(crate) arrangement: ContentArrangement,
+    pub(crate) overflow_policy: OverflowPolicy,
+    pub(crate) delimiter: Option<char>,
+    pub(crate) trim_mode: TrimMode,
+    pub(crate) wrap_policy: WrapPolicy,
+    #[cfg(feature = "unicode-bidi")]
+    pub(crate) bidi_mode: BidiMode,
+    pub(crate) center_bias: Bias,
+    pub(crate) padding_collapse: bool,
+    pub(crate) width_function: Option<WidthFunction>,
+    pub(crate) truncation_indicator: Option<String>,
+    pub(crate) width_truncation_indicator: Option<String>,
+    pub(crate) truncation_strategy: Option<Arc<dyn TruncationStrategy>>,
+    pub(crate) truncation_markers: bool,
+    pub(crate) escape_border_chars: bool,
+    pub(crate) header_separator: bool,
+    pub(crate) footnotes: Vec<String>,
+    pub(crate) title: Option<String>,
+    pub(crate) title_alignment: CellAlignment,
+    pub(crate) title_in_border: bool,
+    pub(crate) caption: Option<String>,
+    pub(crate) caption_alignment: CellAlignment,
+    pub(crate) empty_message: Option<String>,
+    pub(crate) max_height: Option<usize>,
+    pub(crate) max_height_indicator: Option<String>,
+    hide_when_empty: bool,
+    pub(crate) frozen_layout: Option<FrozenLayout>,
+    auto_join_borders: bool,
+    #[cfg(feature = "tty")]
+    no_tty: bool,
+    #[cfg(feature = "tty")]
+    use_stderr: bool,
+    width: Option<u16>,
+    pub(crate) min_width: Option<u16>,
+    fallback_width: Option<u16>,
+    #[cfg(feature = "test-util")]
+    terminal_size_for_tests: Option<(u16, u16)>,
+    #[cfg(feature = "tty")]
+    enforce_styling: bool,
+    #[cfg(feature = "tty")]
+    windows_console_mode: WindowsConsoleMode,
+    /// Define whether everything in a cells should be styled, including whitespaces
+    /// or whether only the text should be styled.
+    #[cfg(feature = "tty")]
+    pub(crate) style_text_only: bool,
+    /// Styles that're applied to all header cells during rendering.
+    #[cfg(feature = "tty")]
+    pub(crate) header_style: CellStyle,
+    /// Styles that're applied to all body cells during rendering.
+    #[cfg(feature = "tty")]
+    pub(crate) body_style: CellStyle,
+    /// Styles for cells with a specific [class](Cell::set_class).
+    #[cfg(feature = "tty")]
+    pub(crate) class_styles: HashMap<String, CellStyle>,
+    /// Alternating background colors of the even and odd rows of the body.
+    #[cfg(feature = "tty")]
+    pub(crate) zebra_stripes: Option<(CellColor, CellColor)>,
+    /// Styles of the border components.
+    #[cfg(feature = "tty")]
+    pub(crate) border_styles: HashMap<TableComponent, CellStyle>,
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines().collect::<Vec<_>>().join("\n"))
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Table {
+    /// Create a new table with default ASCII styling.
+    pub fn new() -> Self {
+        let mut table = Self {
+            columns: Vec::new(),
+            column_order: None,
+            header: None,
+            rows: Vec::new(),
+            groups: Vec::new(),
+            group_is_open: false,
+            sections: Vec::new(),
+            arrangement: ContentArrangement::Disabled,
+            overflow_policy: OverflowPolicy::Overflow,
+            delimiter: None,
+            trim_mode: TrimMode::None,
+            wrap_policy: WrapPolicy::BreakWords,
+            #[cfg(feature = "unicode-bidi")]
+            bidi_mode: BidiMode::Logical,
+            center_bias: Bias::Left,
+            padding_collapse: false,
+            width_function: None,
+            truncation_indicator: None,
+            width_truncation_indicator: None,
+            truncation_strategy: None,
+            truncation_markers: false,
+            escape_border_chars: false,
+            header_separator: true,
+            footnotes: Vec::new(),
+            title: None,
+            title_alignment: CellAlignment::Center,
+            title_in_border: false,
+            caption: None,
+            caption_alignment: CellAlignment::Center,
+            empty_message: None,
+            max_height: None,
+            max_height_indicator: None,
+            hide_when_empty: false,
+            frozen_layout: None,
+            auto_join_borders: false,
+            #[cfg(feature = "tty")]
+            no_tty: false,
+            #[cfg(feature = "tty")]
+            use_stderr: false,
+            width: None,
+            min_width: None,
+            fallback_width: None,
+            #[cfg(feature = "test-util")]
+            terminal_size_for_tests: None,
+            style: [None; TableComponent::COUNT],
+            style_layers: Vec::new(),
+            #[cfg(feature = "tty")]
+            enforce_styling: false,
+            #[cfg(feature = "tty")]
+            windows_console_mode: WindowsConsoleMode::Auto,
+            #[cfg(feature = "tty")]
+            style_text_only: false,
+            #[cfg(feature = "tty")]
+            header_style: CellStyle::new(),
+            #[cfg(feature = "tty")]
+            body_style: CellStyle::new(),
+            #[cfg(feature = "tty")]
+            class_styles: HashMap::new(),
+            #[cfg(feature = "tty")]
+            zebra_stripes: None,
+            #[cfg(feature = "tty")]
+            border_styles: HashMap::new(),
+        };
+
+        table.load_preset(ASCII_FULL);
+
+        table
+    }
+
+    /// Build a table in a single expression, see [TableBuilder].
+    pub fn builder() -> TableBuilder {
+        TableBuilder::new()
+    }
+
+    /// Clone all settings of this table, but none of its rows, groups or sections.
+    ///
+    /// This is cheaper than cloning the whole table, if only a part of its rows is needed.
+    pub(crate) fn clone_without_rows(&self) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            column_order: self.column_order.clone(),
+            style: self.style,
+            style_layers: self.style_layers.clone(),
+            header: self.header.clone(),
+            rows: Vec::new(),
+            groups: Vec::new(),
+            group_is_open: false,
+            sections: Vec::new(),
+            arrangement: self.arrangement.clone(),
+            overflow_policy: self.overflow_policy,
+            delimiter: self.delimiter,
+            trim_mode: self.trim_mode,
+            wrap_policy: self.wrap_policy,
+            #[cfg(feature = "unicode-bidi")]
+            bidi_mode: self.bidi_mode,
+            center_bias: self.center_bias,
+            padding_collapse: self.padding_collapse,
+            width_function: self.width_function,
+            truncation_indicator: self.truncation_indicator.clone(),
+            width_truncation_indicator: self.width_truncation_indicator.clone(),
+            truncation_strategy: self.truncation_strategy.clone(),
+            truncation_markers: self.truncation_markers,
+            escape_border_chars: self.escape_border_chars,
+            header_separator: self.header_separator,
+            footnotes: self.footnotes.clone(),
+            title: self.title.clone(),
+            title_alignment: self.title_alignment,
+            title_in_border: self.title_in_border,
+            caption: self.caption.clone(),
+            caption_alignment: self.caption_alignment,
+            empty_message: self.empty_message.clone(),
+            max_height: self.max_height,
+            max_height_indicator: self.max_height_indicator.clone(),
+            hide_when_empty: self.hide_when_empty,
+            frozen_layout: self.frozen_layout.clone(),
+            auto_join_borders: self.auto_join_borders,
+            #[cfg(feature = "tty")]
+            no_tty: self.no_tty,
+            #[cfg(feature = "tty")]
+            use_stderr: self.use_stderr,
+            width: self.width,
+            min_width: self.min_width,
+            fallback_width: self.fallback_width,
+            #[cfg(feature = "test-util")]
+            terminal_size_for_tests: self.terminal_size_for_tests,
+            #[cfg(feature = "tty")]
+            enforce_styling: self.enforce_styling,
+            #[cfg(feature = "tty")]
+            windows_console_mode: self.windows_console_mode,
+            #[cfg(feature = "tty")]
+            style_text_only: self.style_text_only,
+            #[cfg(feature = "tty")]
+            header_style: self.header_style.clone(),
+            #[cfg(feature = "tty")]
+            body_style: self.body_style.clone(),
+            #[cfg(feature = "tty")]
+            class_styles: self.class_styles.clone(),
+            #[cfg(feature = "tty")]
+            zebra_stripes: self.zebra_stripes,
+            #[cfg(feature = "tty")]
+            border_styles: self.border_styles.clone(),
+        }
+    }
+
+    /// This is an alternative `fmt` function, which simply removes any trailing whitespaces.
+    /// Trailing whitespaces often occur, when using tables without a right border.
+    pub fn trim_fmt(&self) -> String {
+        self.lines()
+            .map(|line| line.trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// This is an alternative to `fmt`, but rather returns an iterator to each line, rather than
+    /// one String separated by newlines.
+    pub fn lines(&self) -> impl Iterator<Item = String> {
+        build_table(self)
+    }
+
+    /// Set the header row of the table. This is usually the title of each column.\
+    /// There'll be no header unless you explicitly set it with this function.
+    ///
+    /// ```
+    /// use comfy_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// let header = Row::from(vec!["Header One", "Header Two"]);
+    /// table.set_header(header);
+    /// ```
+    pub fn set_header<T: Into<Row>>(&mut self, row: T) -> &mut Self {
+        let row = row.into();
+        self.autogenerate_columns(&row);
+        self.header = Some(row);
+
+        self
+    }
+
+    pub fn header(&self) -> Option<&Row> {
+        self.header.as_ref()
+    }
+
+    /// Returns the number of currently present columns.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Col 1", "Col 2", "Col 3"]);
+    ///
+    /// assert_eq!(table.column_count(), 3);
+    /// ```
+    pub fn column_count(&mut self) -> usize {
+        self.discover_columns();
+        self.columns.len()
+    }
+
+    /// Add a new row to the table.
+    ///
+    /// ```
+    /// use comfy_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    /// ```
+    pub fn add_row<T: Into<Row>>(&mut self, row: T) -> &mut Self {
+        self.push_row(row.into());
+
+        self
+    }
+
+    /// Add a new row from cells that're tagged with the index of their target column.
+    ///
+    /// This is convenient for sparse data, where only some columns have a value.
+    /// Gaps between the given columns are filled with empty cells.
+    /// If the same column is given multiple times, the last cell wins.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Phone", "Mail"])
+    ///     .add_row_aligned(vec![(0, Cell::new("Nils")), (2, Cell::new("nils@example.com"))]);
+    ///
+    /// let row = table.row(0).unwrap();
+    /// assert_eq!(row.cell_count(), 3);
+    /// assert_eq!(row.cell_iter().nth(1).unwrap().content(), "");
+    /// ```
+    pub fn add_row_aligned<I: IntoIterator<Item = (usize, Cell)>>(
+        &mut self,
+        cells: I,
+    ) -> &mut Self {
+        let mut row_cells: Vec<Cell> = Vec::new();
+        for (index, cell) in cells {
+            if index >= row_cells.len() {
+                row_cells.resize(index + 1, Cell::new(""));
+            }
+            row_cells[index] = cell;
+        }
+
+        self.add_row(row_cells)
+    }
+
+    /// Add a new row to the table if the predicate evaluates to `true`.
+    ///
+    /// ```
+    /// use comfy_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row_if(|index, row| true, vec!["One", "Two"]);
+    /// ```
+    pub fn add_row_if<P, T>(&mut self, predicate: P, row: T) -> &mut Self
+    where
+        P: Fn(usize, &T) -> bool,
+        T: Into<Row>,
+    {
+        if predicate(self.rows.len(), &row) {
+            return self.add_row(row);
+        }
+
+        self
+    }
+
+    /// Add multiple rows to the table.
+    ///
+    /// ```
+    /// use comfy_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// let rows = vec![
+    ///     vec!["One", "Two"],
+    ///     vec!["Three", "Four"]
+    /// ];
+    /// table.add_rows(rows);
+    /// ```
+    pub fn add_rows<I>(&mut self, rows: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Row>,
+    {
+        for row in rows.into_iter() {
+            self.push_row(row.into());
+        }
+
+        self
+    }
+
+    /// Add a value, which knows how to convert itself into a [Row], to the table.
+    /// See [ToRow] for an example.
+    pub fn add<T: ToRow + ?Sized>(&mut self, item: &T) -> &mut Self {
+        self.push_row(item.to_row());
+
+        self
+    }
+
+    /// Add multiple values, which know how to convert themselves into [Rows](Row), to the table.
+    ///
+    /// The values don't need to be of the same type, as long as they're behind a pointer.
+    ///
+    /// ```
+    /// use comfy_table::{Row, Table, ToRow};
+    ///
+    /// struct File(&'static str);
+    /// struct Directory(&'static str, usize);
+    ///
+    /// impl ToRow for File {
+    ///     fn to_row(&self) -> Row {
+    ///         Row::from(vec![self.0, "file"])
+    ///     }
+    /// }
+    ///
+    /// impl ToRow for Directory {
+    ///     fn to_row(&self) -> Row {
+    ///         Row::from(vec![self.0.to_string(), format!("{} entries", self.1)])
+    ///     }
+    /// }
+    ///
+    /// let entries: Vec<Box<dyn ToRow>> = vec![
+    ///     Box::new(Directory("src", 12)),
+    ///     Box::new(File("README.md")),
+    /// ];
+    ///
+    /// let mut table = Table::new();
+    /// table.add_items(&entries);
+    ///
+    /// let expected = "
+    /// +-----------+------------+
+    /// | src       | 12 entries |
+    /// |-----------+------------|
+    /// | README.md | file       |
+    /// +-----------+------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn add_items<I>(&mut self, items: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: ToRow,
+    {
+        for item in items.into_iter() {
+            self.push_row(item.to_row());
+        }
+
+        self
+    }
+
+    fn push_row(&mut self, mut row: Row) {
+        self.autogenerate_columns(&row);
+        row.index = Some(self.rows.len());
+        self.rows.push(row);
+
+        // Rows that're added while a group is open belong to that group.
+        if self.group_is_open {
+            if let Some(group) = self.groups.last_mut() {
+                group.rows.end = self.rows.len();
+            }
+        }
+    }
+
+    /// Insert a row at the given index, shifting all rows after it.
+    ///
+    /// The row belongs to the same [RowGroup] as the row, that has been at this index before.\
+    /// [Sections](Section) stay in front of the row they've been anchored to. Sections at or after
+    /// the index are shifted, which is why a row that's inserted right at a section's index is
+    /// displayed above the section's title.
+    ///
+    /// **Panics** if `index > row_count`.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["One"])
+    ///     .add_row(vec!["Three"])
+    ///     .insert_row(1, vec!["Two"]);
+    ///
+    /// let second = table.row(1).unwrap();
+    /// assert_eq!(second.cell_iter().next().unwrap().content(), "Two");
+    /// ```
+    pub fn insert_row<T: Into<Row>>(&mut self, index: usize, row: T) -> &mut Self {
+        let row = row.into();
+        self.autogenerate_columns(&row);
+        let is_last = index == self.rows.len();
+        self.rows.insert(index, row);
+        self.reindex_rows(index);
+
+        let group_count = self.groups.len();
+        for (position, group) in self.groups.iter_mut().enumerate() {
+            // Appending a row behaves just like adding it.
+            let appended_to_open_group =
+                is_last && self.group_is_open && position + 1 == group_count;
+
+            if index < group.rows.start {
+                group.rows.start += 1;
+                group.rows.end += 1;
+            } else if index < group.rows.end || appended_to_open_group {
+                group.rows.end += 1;
+            }
+        }
+        for section in self.sections.iter_mut() {
+            if section.row_index >= index {
+                section.row_index += 1;
+            }
+        }
+
+        self
+    }
+
+    /// Remove the row at the given index and return it.
+    /// All rows after it are shifted. Returns `None`, if there's no such row.
+    ///
+    /// Columns are kept, even if the row has been the only one with content in them.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One"]).add_row(vec!["Two"]);
+    ///
+    /// let removed = table.remove_row(0).unwrap();
+    /// assert_eq!(removed.cell_iter().next().unwrap().content(), "One");
+    /// assert_eq!(table.row_count(), 1);
+    /// assert!(table.remove_row(1).is_none());
+    /// ```
+    pub fn remove_row(&mut self, index: usize) -> Option<Row> {
+        if index >= self.rows.len() {
+            return None;
+        }
+
+        let mut row = self.rows.remove(index);
+        row.index = None;
+        self.reindex_rows(index);
+
+        for group in self.groups.iter_mut() {
+            if index < group.rows.start {
+                group.rows.start -= 1;
+            }
+            if index < group.rows.end {
+                group.rows.end -= 1;
+            }
+        }
+        for section in self.sections.iter_mut() {
+            if section.row_index > index {
+                section.row_index -= 1;
+            }
+        }
+
+        Some(row)
+    }
+
+    /// Remove all rows, as well as all [RowGroups](RowGroup) and [Sections](Section).
+    ///
+    /// The header, the columns and all styling are kept, which allows to reuse the table.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name"]).add_row(vec!["One"]);
+    ///
+    /// table.clear_rows().add_row(vec!["Two"]);
+    /// assert_eq!(table.row_count(), 1);
+    /// assert!(table.header().is_some());
+    /// ```
+    pub fn clear_rows(&mut self) -> &mut Self {
+        self.rows.clear();
+        self.groups.clear();
+        self.group_is_open = false;
+        self.sections.clear();
+
+        self
+    }
+
+    /// Update the index of all rows, starting with the given one.
+    fn reindex_rows(&mut self, start: usize) {
+        for (index, row) in self.rows.iter_mut().enumerate().skip(start) {
+            row.index = Some(index);
+        }
+    }
+
+    /// Add multiple rows to the table if the predicate evaluates to `true`.
+    ///
+    /// ```
+    /// use comfy_table::{Table, Row};
+    ///
+    /// let mut table = Table::new();
+    /// let rows = vec![
+    ///     vec!["One", "Two"],
+    ///     vec!["Three", "Four"]
+    /// ];
+    /// table.add_rows_if(|index, rows| true, rows);
+    /// ```
+    pub fn add_rows_if<P, I>(&mut self, predicate: P, rows: I) -> &mut Self
+    where
+        P: Fn(usize, &I) -> bool,
+        I: IntoIterator,
+        I::Item: Into<Row>,
+    {
+        if predicate(self.rows.len(), &rows) {
+            return self.add_rows(rows);
+        }
+
+        self
+    }
+
+    /// Returns the number of currently present rows.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// assert_eq!(table.row_count(), 1);
+    /// ```
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns if the table is empty (contains no data rows).
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// assert!(table.is_empty());
+    ///
+    /// table.add_row(vec!["One", "Two"]);
+    /// assert!(!table.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Display a message instead of the rows, if the table [is empty](Table::is_empty).
+    ///
+    /// The message is displayed in a single row that spans over all columns.
+    /// Just like [sections](Table::add_section), the columns grow until the message fits.
+    /// It's only displayed, if the table has at least one column, e.g. due to a header.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Revenue"])
+    ///     .set_empty_message("No results");
+    ///
+    /// let expected = "
+    /// +------+---------+
+    /// | Name | Revenue |
+    /// +================+
+    /// | No results     |
+    /// +----------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_empty_message<T: ToString>(&mut self, message: T) -> &mut Self {
+        self.empty_message = Some(message.to_string());
+
+        self
+    }
+
+    /// Get the message that's displayed, if the table is empty.
+    pub fn empty_message(&self) -> Option<&str> {
+        self.empty_message.as_deref()
+    }
+
+    /// Don't display anything at all, if the table [is empty](Table::is_empty).
+    ///
+    /// This takes precedence over the [empty message](Table::set_empty_message).
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Revenue"]).hide_when_empty(true);
+    /// assert_eq!(table.to_string(), "");
+    ///
+    /// table.add_row(vec!["ACME", "1000"]);
+    /// assert!(!table.to_string().is_empty());
+    /// ```
+    pub fn hide_when_empty(&mut self, hide: bool) -> &mut Self {
+        self.hide_when_empty = hide;
+
+        self
+    }
+
+    /// Check whether nothing should be displayed, as the table is empty and should be hidden.
+    pub(crate) fn is_hidden(&self) -> bool {
+        self.hide_when_empty && self.rows.is_empty()
+    }
+
+    /// Check whether the empty message should be displayed instead of the rows.
+    pub(crate) fn shows_empty_message(&self) -> bool {
+        self.empty_message.is_some() && self.rows.is_empty()
+    }
+
+    /// Limit the rendered table to the given amount of lines, e.g. to print a preview of a
+    /// large table.
+    ///
+    /// If the table would be higher, only as many rows of its body are displayed as fit into the
+    /// limit. They're followed by a single row with the
+    /// [max height indicator](Table::set_max_height_indicator), which spans over all columns and
+    /// is displayed just like the title of a [Section](crate::Section).\
+    /// The header and the borders are always displayed, even if they exceed the limit by
+    /// themselves. The columns are arranged for all rows, just like without a limit.
+    ///
+    /// **Note:** The limit isn't applied, if an [OverflowPolicy] takes over the rendering.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Description", "Amount"]).set_max_height(9);
+    /// for index in 0..100 {
+    ///     table.add_row(vec![format!("Row {index}"), index.to_string()]);
+    /// }
+    ///
+    /// let expected = "
+    /// +-------------+--------+
+    /// | Description | Amount |
+    /// +======================+
+    /// | Row 0       | 0      |
+    /// |-------------+--------|
+    /// | Row 1       | 1      |
+    /// +======================+
+    /// | ... 98 more rows     |
+    /// +----------------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_max_height(&mut self, lines: usize) -> &mut Self {
+        self.max_height = Some(lines);
+
+        self
+    }
+
+    /// Get the max amount of lines of the rendered table, if it's limited.
+    pub fn max_height(&self) -> Option<usize> {
+        self.max_height
+    }
+
+    /// Remove the limit of the rendered table's height.
+    pub fn remove_max_height(&mut self) -> &mut Self {
+        self.max_height = None;
+
+        self
+    }
+
+    /// Set the text of the row that replaces the rows, which don't fit into the
+    /// [max height](Table::set_max_height) of the table.
+    ///
+    /// The `{rows}` placeholder is replaced by the amount of rows that aren't displayed.
+    /// Literal braces are written as `{{` and `}}`.\
+    /// The default is `… {rows} more rows` for presets with UTF-8 characters and
+    /// `... {rows} more rows` otherwise.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_max_height(5)
+    ///     .set_max_height_indicator("{rows} more")
+    ///     .add_row(vec!["Apples"])
+    ///     .add_row(vec!["Pears"])
+    ///     .add_row(vec!["Plums"]);
+    ///
+    /// let expected = "
+    /// +--------+
+    /// | Apples |
+    /// +========+
+    /// | 2 more |
+    /// +--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_max_height_indicator<T: ToString>(&mut self, indicator: T) -> &mut Self {
+        self.max_height_indicator = Some(indicator.to_string());
+
+        self
+    }
+
+    /// Get the text of the row that replaces the rows, which don't fit into the max height.
+    ///
+    /// This is `None`, if the default is used.
+    pub fn max_height_indicator(&self) -> Option<&str> {
+        self.max_height_indicator.as_deref()
+    }
+
+    /// The text of the row that replaces the given amount of rows, which don't fit into the
+    /// max height.
+    pub(crate) fn max_height_indicator_for(&self, rows: usize) -> String {
+        let template = match self.max_height_indicator.as_deref() {
+            Some(indicator) => indicator.to_string(),
+            None => format!("{} {{rows}} more rows", self.default_truncation_indicator()),
+        };
+
+        fill_template(&template, |name| (name == "rows").then(|| rows.to_string()))
+    }
+
+    /// Enforce a max width that should be used in combination with [dynamic content arrangement](ContentArrangement::Dynamic).\
+    /// This is usually not necessary, if you plan to output your table to a tty,
+    /// since the terminal width can be automatically determined.
+    pub fn set_width(&mut self, width: u16) -> &mut Self {
+        self.width = Some(width);
+
+        self
+    }
+
+    /// Expand the table to at least the given width, even if its content is narrower.
+    ///
+    /// The additional space is distributed between all visible columns, just like for
+    /// [ContentArrangement::DynamicFullWidth]. This works for all content arrangements and
+    /// allows to give several tables the same width, e.g. when they're printed below each other.\
+    /// The minimum width takes precedence over the [width](Table::set_width) of the table.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_min_width(12).add_row(vec!["a", "b"]);
+    ///
+    /// let expected = "
+    /// +-----+----+
+    /// | a   | b  |
+    /// +-----+----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_min_width(&mut self, width: u16) -> &mut Self {
+        self.min_width = Some(width);
+
+        self
+    }
+
+    /// Remove the minimum width of the table, see [Table::set_min_width].
+    pub fn remove_min_width(&mut self) -> &mut Self {
+        self.min_width = None;
+
+        self
+    }
+
+    /// Get the minimum width of the table, if one is set via [Table::set_min_width].
+    pub fn min_width(&self) -> Option<u16> {
+        self.min_width
+    }
+
+    /// Set a width that's used, if neither a width is set via [set_width](Table::set_width)
+    /// nor the terminal width can be detected.
+    ///
+    /// Without a width, [dynamic content arrangement](ContentArrangement::Dynamic) silently
+    /// falls back to [ContentArrangement::Disabled]. This is usually the case when the output is
+    /// written to a file or piped into another program, which may result in very long lines.
+    /// With a fallback width, such output gets a predictable, wrapped layout instead.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_fallback_width(80);
+    /// ```
+    pub fn set_fallback_width(&mut self, width: u16) -> &mut Self {
+        self.fallback_width = Some(width);
+
+        self
+    }
+
+    /// Simulate a terminal of the given `(width, height)`.
+    ///
+    /// The table then behaves as if it's printed to a tty of that size, regardless of the
+    /// actual environment. This allows to test code that depends on the terminal width
+    /// without controlling the terminal of the test runner.\
+    /// A width set via [set_width](Table::set_width) still takes precedence.
+    ///
+    /// This is only available with the `test-util` feature.
+    ///
+    /// ```
+    /// use comfy_table::{Table, WidthSource};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_terminal_size_for_tests((40, 20));
+    /// assert_eq!(table.width(), Some(40));
+    /// assert_eq!(table.width_source(), WidthSource::Terminal);
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn set_terminal_size_for_tests(&mut self, size: (u16, u16)) -> &mut Self {
+        self.terminal_size_for_tests = Some(size);
+
+        self
+    }
+
+    /// Get the expected width of the table.
+    ///
+    /// This will be `Some(width)`, if the terminal width can be detected, if the table width is set via [set_width](Table::set_width)
+    /// or if a [fallback width](Table::set_fallback_width) is set.
+    ///
+    /// If neither is not possible, `None` will be returned.\
+    /// This implies that both the [Dynamic](ContentArrangement::Dynamic) mode and the [Percentage](crate::style::Width::Percentage) constraint won't work.
+    pub fn width(&self) -> Option<u16> {
+        self.width_with_source().map(|(width, _)| width)
+    }
+
+    /// Get where the [width](Table::width) of the table comes from.
+    ///
+    /// This can be used to diagnose layout issues. Most notably, [WidthSource::Unknown] combined
+    /// with dynamic content arrangement means that the arrangement will silently fall back to
+    /// [ContentArrangement::Disabled].
+    ///
+    /// ```
+    /// use comfy_table::{Table, WidthSource};
+    ///
+    /// let mut table = Table::new();
+    /// table.force_no_tty();
+    /// assert_eq!(table.width_source(), WidthSource::Unknown);
+    ///
+    /// table.set_fallback_width(80);
+    /// assert_eq!(table.width_source(), WidthSource::Fallback);
+    /// ```
+    pub fn width_source(&self) -> WidthSource {
+        match self.width_with_source() {
+            Some((_, source)) => source,
+            None => WidthSource::Unknown,
+        }
+    }
+
+    fn width_with_source(&self) -> Option<(u16, WidthSource)> {
+        if let Some(width) = self.width {
+            return Some((width, WidthSource::Fixed));
+        }
+
+        if let Some(width) = self.terminal_width() {
+            return Some((width, WidthSource::Terminal));
+        }
+
+        self.fallback_width
+            .map(|width| (width, WidthSource::Fallback))
+    }
+
+    /// Get the width of the terminal, ignoring any width set via [Table::set_width].
+    pub(crate) fn terminal_width(&self) -> Option<u16> {
+        #[cfg(feature = "test-util")]
+        if let Some((width, _)) = self.terminal_size_for_tests {
+            return Some(width);
+        }
+
+        #[cfg(feature = "tty")]
+        if self.is_tty() {
+            if let Ok((width, _)) = terminal::size() {
+                return Some(width);
+            }
+        }
+
+        None
+    }
+
+    /// Specify how Comfy Table should arrange the content in your table.
+    ///
+    /// ```
+    /// use comfy_table::{Table, ContentArrangement};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_content_arrangement(ContentArrangement::Dynamic);
+    /// ```
+    pub fn set_content_arrangement(&mut self, arrangement: ContentArrangement) -> &mut Self {
+        self.arrangement = arrangement;
+
+        self
+    }
+
+    /// Get the current content arrangement of the table.
+    pub fn content_arrangement(&self) -> ContentArrangement {
+        self.arrangement.clone()
+    }
+
+    /// Specify what should happen, if the table doesn't fit into the available width.
+    ///
+    /// Check the [OverflowPolicy] docs for more information.
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) -> &mut Self {
+        self.overflow_policy = policy;
+
+        self
+    }
+
+    /// Get the current overflow policy of the table.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Return the smallest width this table can be rendered with, without cutting off or
+    /// overflowing any of its content.
+    ///
+    /// This can be used to check whether a table fits into a pane of a given size,
+    /// before actually rendering it.
+    /// It's the sum of all [Column::min_possible_width]s and the table's borders.
+    ///
+    /// In contrast to the minimum width of [Table::try_render], this takes the content into account.
+    /// Words may still be split, unless the [WrapPolicy] says otherwise.
+    ///
+    /// ```
+    /// use comfy_table::{Table, WrapPolicy};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Description"])
+    ///     .add_row(vec!["コンフィ", "A table library"]);
+    ///
+    /// // Two columns with the widest character and padding each, plus three borders.
+    /// assert_eq!(table.min_possible_width(), 10);
+    ///
+    /// table.set_wrap_policy(WrapPolicy::KeepWords);
+    /// assert_eq!(table.min_possible_width(), 26);
+    /// ```
+    ///
+    /// **Attention** This scans the whole current content of the table.
+    pub fn min_possible_width(&self) -> usize {
+        let visible_columns = count_visible_columns(&self.columns);
+
+        self.columns
+            .iter()
+            .map(|column| min_possible_column_width(self, column, visible_columns))
+            .sum::<usize>()
+            + count_border_columns(self, visible_columns)
+    }
+
+    /// Arrange the content of this table and return the width of each column, including padding.
+    /// Hidden columns are `None`.
+    ///
+    /// These are the widths that're used when the table is rendered, which allows to align other
+    /// output with the columns of the table. In contrast to [Table::plan], the content isn't
+    /// formatted.
+    ///
+    /// **Attention** This scans the whole current content of the table.\
+    /// **Note:** [OverflowPolicies](crate::OverflowPolicy) aren't applied.
+    ///
+    /// ```
+    /// use comfy_table::{ColumnConstraint, ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(30)
+    ///     .set_header(vec!["Id", "Hidden", "Description"])
+    ///     .add_row(vec!["1", "secret", "This is a longer description"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_constraint(ColumnConstraint::Hidden);
+    ///
+    /// assert_eq!(table.column_display_widths(), vec![Some(4), None, Some(18)]);
+    /// ```
+    pub fn column_display_widths(&self) -> Vec<Option<u16>> {
+        column_display_widths(self)
+    }
+
+    /// Arrange the content of this table and check for each column, whether its
+    /// [constraint](Column::set_constraint) could be satisfied.
+    ///
+    /// Constraints might be impossible to satisfy, e.g. if the table isn't wide enough for the
+    /// lower boundaries of all columns or if an absolute width is smaller than the padding.
+    /// This allows to warn users about constraints that didn't have the desired effect.
+    ///
+    /// **Attention** This scans the whole current content of the table.\
+    /// **Note:** [OverflowPolicies](crate::OverflowPolicy) aren't applied.
+    ///
+    /// ```
+    /// use comfy_table::{ColumnConstraint, Table, Width};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Id", "Description"])
+    ///     .add_row(vec!["1", "This is a longer description"]);
+    /// // The padding alone is already two characters wide.
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_constraint(ColumnConstraint::Absolute(Width::Fixed(1)));
+    ///
+    /// let report = table.constraint_report();
+    /// assert_eq!(report[0].min_width, Some(1));
+    /// assert_eq!(report[0].width, Some(3));
+    /// assert!(!report[0].honored);
+    /// assert!(report[1].honored);
+    /// ```
+    pub fn constraint_report(&self) -> Vec<ConstraintReport> {
+        constraint_report(self)
+    }
+
+    /// Arrange the content of this table and return the resulting layout.
+    ///
+    /// The layout can be serialized and loaded on other tables with the same schema via
+    /// [Table::set_frozen_layout], which then skips the content arrangement during rendering.
+    /// See [FrozenLayout] for details.
+    pub fn freeze_layout(&self) -> FrozenLayout {
+        FrozenLayout::new(self)
+    }
+
+    /// Arrange the content of this table and return the planned layout without rendering it.
+    ///
+    /// Use [Table::render] to render the table according to the plan.
+    /// See [RenderPlan] for details.
+    ///
+    /// **Note:** [OverflowPolicies](crate::OverflowPolicy) aren't applied to plans.
+    pub fn plan(&self) -> RenderPlan {
+        RenderPlan::new(self)
+    }
+
+    /// Render the table according to a plan created via [Table::plan].
+    ///
+    /// The content arrangement is skipped, unless the table's schema has changed since the plan
+    /// has been created. In that case, the table is rendered as usual.
+    pub fn render(&self, plan: &RenderPlan) -> String {
+        if !plan.layout.matches(self) {
+            return self.to_string();
+        }
+
+        build_table_with_info(self, &plan.layout.display_info(self)).join("\n")
+    }
+
+    /// Use a previously frozen layout instead of arranging the content during rendering.
+    ///
+    /// Returns an error, if the layout has been created for a table with a different schema.
+    /// Content that doesn't fit into the frozen column widths will be wrapped as usual.
+    ///
+    /// **Note:** [OverflowPolicies](crate::OverflowPolicy) aren't applied to frozen layouts.
+    pub fn set_frozen_layout(&mut self, layout: FrozenLayout) -> Result<&mut Self, LayoutError> {
+        if !layout.matches(self) {
+            return Err(LayoutError::SchemaMismatch);
+        }
+        self.frozen_layout = Some(layout);
+
+        Ok(self)
+    }
+
+    /// Remove a previously loaded layout, so the content is arranged during rendering again.
+    pub fn clear_frozen_layout(&mut self) -> &mut Self {
+        self.frozen_layout = None;
+
+        self
+    }
+
+    /// Set the delimiter used to split text in all cells.
+    ///
+    /// A custom delimiter on a cell in will overwrite the column's delimiter.\
+    /// Normal text uses spaces (` `) as delimiters. This is necessary to help comfy-table
+    /// understand the concept of _words_.
+    pub fn set_delimiter(&mut self, delimiter: char) -> &mut Self {
+        self.delimiter = Some(delimiter);
+
+        self
+    }
+
+    /// Specify which whitespaces should be removed from the content of cells.
+    ///
+    /// Check the [TrimMode] docs for more information.
+    pub fn set_trim_mode(&mut self, trim_mode: TrimMode) -> &mut Self {
+        self.trim_mode = trim_mode;
+
+        self
+    }
+
+    /// Get the current [TrimMode] of the table.
+    pub fn trim_mode(&self) -> TrimMode {
+        self.trim_mode
+    }
+
+    /// Specify how words that're too long for their column are wrapped.
+    ///
+    /// Check the [WrapPolicy] docs for more information.
+    pub fn set_wrap_policy(&mut self, wrap_policy: WrapPolicy) -> &mut Self {
+        self.wrap_policy = wrap_policy;
+
+        self
+    }
+
+    /// Get the current [WrapPolicy] of the table.
+    pub fn wrap_policy(&self) -> WrapPolicy {
+        self.wrap_policy
+    }
+
+    /// Specify how right-to-left content is displayed.
+    ///
+    /// Check the [BidiMode] docs for more information.
+    ///
+    /// ```
+    /// use comfy_table::{BidiMode, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_bidi_mode(BidiMode::VisualRightAligned)
+    ///     .set_header(vec!["Language", "Greeting"])
+    ///     .add_row(vec!["Hebrew", "\u{5e9}\u{5dc}\u{5d5}\u{5dd}"])
+    ///     .add_row(vec!["English", "Hello"]);
+    ///
+    /// // The Hebrew greeting is displayed in visual order and aligned to the right.
+    /// let expected = "
+    /// +----------+----------+
+    /// | Language | Greeting |
+    /// +=====================+
+    /// | Hebrew   |     \u{5dd}\u{5d5}\u{5dc}\u{5e9} |
+    /// |----------+----------|
+    /// | English  | Hello    |
+    /// +----------+----------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    #[cfg(feature = "unicode-bidi")]
+    pub fn set_bidi_mode(&mut self, bidi_mode: BidiMode) -> &mut Self {
+        self.bidi_mode = bidi_mode;
+
+        self
+    }
+
+    /// Get the current [BidiMode] of the table.
+    #[cfg(feature = "unicode-bidi")]
+    pub fn bidi_mode(&self) -> BidiMode {
+        self.bidi_mode
+    }
+
+    /// Specify which side receives the extra space, if it cannot be split evenly.
+    ///
+    /// This is used for [centered](CellAlignment::Center) content, titles and captions, as well
+    /// as for the space that's distributed between columns by the dynamic arrangements.
+    /// That way, both always lean in the same direction.\
+    /// The default is [Bias::Left].
+    ///
+    /// ```
+    /// use comfy_table::{Bias, Cell, CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Header"])
+    ///     .add_row(vec![Cell::new("odd").set_alignment(CellAlignment::Center)])
+    ///     .set_center_bias(Bias::Right);
+    ///
+    /// let expected = "
+    /// +--------+
+    /// | Header |
+    /// +========+
+    /// |  odd   |
+    /// +--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_center_bias(&mut self, bias: Bias) -> &mut Self {
+        self.center_bias = bias;
+
+        self
+    }
+
+    /// Get the current [Bias] of the table.
+    pub fn center_bias(&self) -> Bias {
+        self.center_bias
+    }
+
+    /// Sacrifice the padding of columns before their content, if the table doesn't fit into the
+    /// available width.
+    ///
+    /// Paddings are reduced column by column, one space at a time, until the content of all
+    /// columns fits or no padding is left. Only then, content is split.\
+    /// This only applies to the [Dynamic](ContentArrangement::Dynamic) and
+    /// [DynamicFullWidth](ContentArrangement::DynamicFullWidth) arrangements.
+    /// Columns with an [Absolute](ColumnConstraint::Absolute) width keep their padding.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Size"])
+    ///     .add_row(vec!["comfy", "12kb"])
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(13)
+    ///     .allow_padding_collapse(true);
+    ///
+    /// let expected = "
+    /// +-----+-----+
+    /// |Name |Size |
+    /// +===========+
+    /// |comfy|12kb |
+    /// +-----+-----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn allow_padding_collapse(&mut self, allow: bool) -> &mut Self {
+        self.padding_collapse = allow;
+
+        self
+    }
+
+    /// Returns whether paddings may be reduced to fit the table into the available width,
+    /// see [Table::allow_padding_collapse].
+    pub fn allows_padding_collapse(&self) -> bool {
+        self.padding_collapse
+    }
+
+    /// Replace the function that's used to measure the printed width of text.
+    ///
+    /// By default, the width is determined via the Unicode standard.
+    /// Some terminals and fonts disagree with it, for instance on emojis or ambiguous-width
+    /// East Asian characters, which results in misaligned borders.
+    /// The function is called with runs of text that don't contain any ANSI escape sequences and
+    /// is used for all measurements, including the splitting of long words.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_width_function(|text| text.chars().count())
+    ///     .add_row(vec!["✅"]);
+    ///
+    /// let expected = "
+    /// +---+
+    /// | ✅ |
+    /// +---+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_width_function(&mut self, width_function: WidthFunction) -> &mut Self {
+        self.width_function = Some(width_function);
+
+        self
+    }
+
+    /// Go back to measuring text via the Unicode standard.
+    pub fn remove_width_function(&mut self) -> &mut Self {
+        self.width_function = None;
+
+        self
+    }
+
+    /// Get the custom width function of the table, if one is set.
+    pub fn width_function(&self) -> Option<WidthFunction> {
+        self.width_function
+    }
+
+    /// The function that's used to measure the content of this table.
+    pub(crate) fn measure(&self) -> WidthFunction {
+        self.width_function.unwrap_or(DEFAULT_WIDTH_FUNCTION)
+    }
+
+    /// Set the indicator for cells that have more lines than allowed by
+    /// [Row::max_height](crate::Row::max_height).
+    ///
+    /// By default, "…" is used if the table's style contains UTF-8 characters and "..."
+    /// otherwise. The default is thereby picked automatically from the loaded preset.\
+    /// The indicator is only displayed in columns that are at least
+    /// [MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH] characters wide, which is why wider indicators are
+    /// rejected. Indicators that don't leave space for any content next to them are left out
+    /// as well, e.g. an indicator with the full width in a column that's just as wide.
+    ///
+    /// A column's indicator will overwrite the table's indicator.
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// assert_eq!(table.truncation_indicator(), "...");
+    /// table.load_preset(UTF8_FULL);
+    /// assert_eq!(table.truncation_indicator(), "…");
+    ///
+    /// table.set_truncation_indicator("~").unwrap();
+    /// assert_eq!(table.truncation_indicator(), "~");
+    ///
+    /// assert!(table.set_truncation_indicator("[truncated]").is_err());
+    /// ```
+    pub fn set_truncation_indicator(
+        &mut self,
+        indicator: &str,
+    ) -> Result<&mut Self, TruncationIndicatorError> {
+        self.truncation_indicator = Some(validate_truncation_indicator(indicator, self.measure())?);
+
+        Ok(self)
+    }
+
+    /// Get the indicator for cells that have more lines than allowed by
+    /// [Row::max_height](crate::Row::max_height).
+    pub fn truncation_indicator(&self) -> &str {
+        self.truncation_indicator
+            .as_deref()
+            .unwrap_or_else(|| self.default_truncation_indicator())
+    }
+
+    /// Set the indicator for lines that're cut off, as they're too wide for their column.
+    ///
+    /// This happens for [preformatted](crate::Cell::preformatted) cells in columns that are
+    /// too narrow for their content.\
+    /// The same defaults and restrictions as for
+    /// [Table::set_truncation_indicator] apply.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, ColumnConstraint, Table, Width};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec![Cell::new("a long line").preformatted(true)])
+    ///     .set_constraints(vec![ColumnConstraint::UpperBoundary(Width::Fixed(10))])
+    ///     .set_width_truncation_indicator(">")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| a long > |");
+    /// ```
+    pub fn set_width_truncation_indicator(
+        &mut self,
+        indicator: &str,
+    ) -> Result<&mut Self, TruncationIndicatorError> {
+        self.width_truncation_indicator =
+            Some(validate_truncation_indicator(indicator, self.measure())?);
+
+        Ok(self)
+    }
+
+    /// Get the indicator for lines that're cut off, as they're too wide for their column.
+    pub fn width_truncation_indicator(&self) -> &str {
+        self.width_truncation_indicator
+            .as_deref()
+            .unwrap_or_else(|| self.default_truncation_indicator())
+    }
+
+    /// Replace all box-drawing characters inside of the content with [BORDER_CHAR_REPLACEMENT].
+    ///
+    /// This ensures that the borders of the table are the only box-drawing characters in the
+    /// output, so they can't be confused with the content by programs that parse it.\
+    /// The replacement is just as wide, which is why the layout of the table doesn't change.
+    /// The content of the cells itself isn't modified.
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_FULL)
+    ///     .escape_border_chars(true)
+    ///     .add_row(vec!["a│b"]);
+    ///
+    /// let expected = "
+    /// ┌─────┐
+    /// │ a\u{FFFD}b │
+    /// └─────┘";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn escape_border_chars(&mut self, escape: bool) -> &mut Self {
+        self.escape_border_chars = escape;
+
+        self
+    }
+
+    /// Returns whether box-drawing characters inside of the content are replaced,
+    /// see [Table::escape_border_chars].
+    pub fn escapes_border_chars(&self) -> bool {
+        self.escape_border_chars
+    }
+
+    /// Use an ellipsis, if the current style already uses UTF-8 characters.
+    fn default_truncation_indicator(&self) -> &'static str {
+        if self
+            .style
+            .iter()
+            .flatten()
+            .any(|character| !character.is_ascii())
+        {
+            "…"
+        } else {
+            "..."
+        }
+    }
+
+    /// In case you are sure you don't want export tables to a tty or you experience
+    /// problems with tty specific code, you can enforce a non_tty mode.
+    ///
+    /// This disables:
+    ///
+    /// - width lookup from the current tty
+    /// - Styling and attributes on cells (unless you use [Table::enforce_styling])
+    ///
+    /// If you use the [dynamic content arrangement](ContentArrangement::Dynamic),
+    /// you need to set the width of your desired table manually with [set_width](Table::set_width).
+    #[cfg(feature = "tty")]
+    pub fn force_no_tty(&mut self) -> &mut Self {
+        self.no_tty = true;
+
+        self
+    }
+
+    /// Use this function to check whether `stderr` is a tty.
+    ///
+    /// The default is `stdout`.
+    #[cfg(feature = "tty")]
+    pub fn use_stderr(&mut self) -> &mut Self {
+        self.use_stderr = true;
+
+        self
+    }
+
+    /// Returns whether the table will be handled as if it's printed to a tty.
+    ///
+    /// By default, comfy-table looks at `stdout` and checks whether it's a tty.
+    /// This behavior can be changed via [Table::force_no_tty] and [Table::use_stderr].
+    #[cfg(feature = "tty")]
+    pub fn is_tty(&self) -> bool {
+        if self.no_tty {
+            return false;
+        }
+
+        if self.use_stderr {
+            ::std::io::stderr().is_tty()
+        } else {
+            ::std::io::stdout().is_tty()
+        }
+    }
+
+    /// Enforce terminal styling.
+    ///
+    /// Only useful if you forcefully disabled tty, but still want those fancy terminal styles.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.force_no_tty()
+    ///     .enforce_styling();
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn enforce_styling(&mut self) -> &mut Self {
+        self.enforce_styling = true;
+
+        self
+    }
+
+    /// Returns whether the content of this table should be styled with the current settings and
+    /// environment.
+    #[cfg(feature = "tty")]
+    pub fn should_style(&self) -> bool {
+        if self.enforce_styling {
+            return true;
+        }
+        self.is_tty() && self.console_supports_ansi()
+    }
+
+    /// Specify how to deal with Windows consoles that don't support ANSI escape codes by default.
+    ///
+    /// Check the [WindowsConsoleMode] docs for more information.
+    #[cfg(feature = "tty")]
+    pub fn set_windows_console_mode(&mut self, mode: WindowsConsoleMode) -> &mut Self {
+        self.windows_console_mode = mode;
+
+        self
+    }
+
+    /// Detect the [TerminalCapabilities] of the terminal this table is displayed in.
+    ///
+    /// - The output is a terminal, if [Table::is_tty] says so.
+    /// - UTF-8 support is detected via the first non-empty variable of `LC_ALL`, `LC_CTYPE` and
+    ///   `LANG`. Windows consoles don't use these variables and are assumed to support UTF-8.
+    /// - Windows consoles, that don't support ANSI escape codes, are considered legacy consoles.
+    ///   Check the [WindowsConsoleMode] docs on how this is detected.
+    #[cfg(feature = "tty")]
+    pub fn terminal_capabilities(&self) -> TerminalCapabilities {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty());
+        let utf8 = match locale {
+            Some(locale) => is_utf8_locale(&locale),
+            None => cfg!(windows),
+        };
+
+        TerminalCapabilities {
+            is_tty: self.is_tty(),
+            utf8,
+            legacy_console: cfg!(windows) && !self.console_supports_ansi(),
+        }
+    }
+
+    /// Check whether the console is able to display ANSI escape codes.
+    ///
+    /// On Windows, this enables virtual terminal processing of the console, which only happens
+    /// once per process.
+    #[cfg(feature = "tty")]
+    fn console_supports_ansi(&self) -> bool {
+        #[cfg(windows)]
+        if self.windows_console_mode == WindowsConsoleMode::Auto {
+            return crossterm::ansi_support::supports_ansi();
+        }
+
+        true
+    }
+
+    /// By default, the whole content of a cells will be styled.
+    /// Calling this function disables this behavior for all cells, resulting in
+    /// only the text of cells being styled.
+    #[cfg(feature = "tty")]
+    pub fn style_text_only(&mut self) {
+        self.style_text_only = true;
+    }
+
+    /// Convenience method to set a [ColumnConstraint] for all columns at once.
+    /// Constraints are used to influence the way the columns will be arranged.
+    /// Check out their docs for more information.
+    ///
+    /// **Attention:**
+    /// This function should be called after at least one row (or the headers) has been added to the table.
+    /// Before that, the columns won't initialized.
+    ///
+    /// If more constraints are passed than there are columns, any superfluous constraints will be ignored.
+    /// Use [Table::try_set_constraints] to get an error instead.
+    /// ```
+    /// use comfy_table::{Width::*, CellAlignment, ColumnConstraint::*, ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(&vec!["one", "two", "three"])
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_constraints(vec![
+    ///         UpperBoundary(Fixed(15)),
+    ///         LowerBoundary(Fixed(20)),
+    /// ]);
+    /// ```
+    pub fn set_constraints<T: IntoIterator<Item = ColumnConstraint>>(
+        &mut self,
+        constraints: T,
+    ) -> &mut Self {
+        let mut constraints = constraints.into_iter();
+        for column in self.column_iter_mut() {
+            if let Some(constraint) = constraints.next() {
+                column.set_constraint(constraint);
+            } else {
+                break;
+            }
+        }
+
+        self
+    }
+
+    /// Like [Table::set_constraints], but returns an error if more constraints are passed than
+    /// there are columns. In that case, no constraints are applied at all.
+    ///
+    /// Returns the number of applied constraints otherwise.
+    ///
+    /// ```
+    /// use comfy_table::{ColumnConstraint::*, ConstraintError, Table, Width::*};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["one", "two"]);
+    ///
+    /// assert_eq!(table.try_set_constraints(vec![UpperBoundary(Fixed(15))]), Ok(1));
+    /// assert_eq!(
+    ///     table.try_set_constraints(vec![Hidden, Hidden, Hidden]),
+    ///     Err(ConstraintError::TooManyConstraints {
+    ///         constraints: 3,
+    ///         columns: 2
+    ///     })
+    /// );
+    /// ```
+    pub fn try_set_constraints<T: IntoIterator<Item = ColumnConstraint>>(
+        &mut self,
+        constraints: T,
+    ) -> Result<usize, ConstraintError> {
+        let constraints: Vec<ColumnConstraint> = constraints.into_iter().collect();
+        if constraints.len() > self.columns.len() {
+            return Err(ConstraintError::TooManyConstraints {
+                constraints: constraints.len(),
+                columns: self.columns.len(),
+            });
+        }
+
+        let count = constraints.len();
+        self.set_constraints(constraints);
+
+        Ok(count)
+    }
+
+    /// Set a [ColumnConstraint] for the column with the given index.
+    ///
+    /// Returns an error, if the column doesn't exist.
+    ///
+    /// ```
+    /// use comfy_table::{ColumnConstraint::*, Table, Width::*};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["one", "two"]);
+    ///
+    /// table.set_constraint(1, UpperBoundary(Fixed(15))).unwrap();
+    /// assert!(table.set_constraint(2, Hidden).is_err());
+    /// ```
+    pub fn set_constraint(
+        &mut self,
+        index: usize,
+        constraint: ColumnConstraint,
+    ) -> Result<&mut Self, ConstraintError> {
+        let columns = self.columns.len();
+        match self.columns.get_mut(index) {
+            Some(column) => {
+                column.set_constraint(constraint);
+            }
+            None => return Err(ConstraintError::ColumnOutOfRange { index, columns }),
+        }
+
+        Ok(self)
+    }
+
+    /// Set a [ColumnConstraint] for the column with the given header text.
+    ///
+    /// This is handy, if the columns of a table are assembled dynamically and their indices
+    /// aren't known in advance. See [Table::column_index_by_name] on how columns are looked up.\
+    /// Returns an error, if there's no such column.
+    ///
+    /// ```
+    /// use comfy_table::{ColumnConstraint::*, ConstraintError, Table, Width::*};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Description"]);
+    ///
+    /// table.set_constraint_for("Description", UpperBoundary(Fixed(40))).unwrap();
+    /// assert_eq!(
+    ///     table.column(1).unwrap().constraint(),
+    ///     Some(&UpperBoundary(Fixed(40)))
+    /// );
+    /// assert_eq!(
+    ///     table.set_constraint_for("Size", Hidden).err(),
+    ///     Some(ConstraintError::UnknownColumn)
+    /// );
+    /// ```
+    pub fn set_constraint_for(
+        &mut self,
+        name: &str,
+        constraint: ColumnConstraint,
+    ) -> Result<&mut Self, ConstraintError> {
+        match self.column_index_by_name(name) {
+            Some(index) => self.set_constraint(index, constraint),
+            None => Err(ConstraintError::UnknownColumn),
+        }
+    }
+
+    /// This function creates a TableStyle from a given preset string.\
+    /// Preset strings can be found in `styling::presets::*`.
+    ///
+    /// You can also write your own preset strings and use them with this function.
+    /// There's the convenience method [Table::current_style_as_preset], which prints you a preset
+    /// string from your current style configuration. \
+    /// The function expects the to-be-drawn characters to be in the same order as in the [TableComponent] enum.
+    ///
+    /// If the string isn't long enough, the default [ASCII_FULL] style will be used for all remaining components.
+    ///
+    /// If the string is too long, remaining charaacters will be simply ignored.
+    ///
+    /// Use [presets::validate](crate::presets::validate) to check custom presets for common
+    /// mistakes.
+    pub fn load_preset(&mut self, preset: &str) -> &mut Self {
+        let mut components = TableComponent::iter();
+
+        for character in preset.chars() {
+            if let Some(component) = components.next() {
+                // White spaces mean "don't draw this" in presets
+                // If we want to override the default preset, we need to remove
+                // this component from the HashMap in case we find a whitespace.
+                if character == ' ' {
+                    self.remove_style(component);
+                    continue;
+                }
+
+                self.set_style(component, character);
+            } else {
+                break;
+            }
+        }
+
+        self
+    }
+
+    /// Load the preset that fits the given [TerminalCapabilities] best,
+    /// see [TerminalCapabilities::preset].
+    ///
+    /// This is the hook behind [Table::auto_preset], which allows to use custom detection logic.
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::{Table, TerminalCapabilities};
+    ///
+    /// let mut table = Table::new();
+    /// table.load_preset_for(TerminalCapabilities {
+    ///     is_tty: true,
+    ///     utf8: true,
+    ///     legacy_console: false,
+    /// });
+    /// assert_eq!(table.current_style_as_preset(), UTF8_FULL);
+    /// ```
+    pub fn load_preset_for(&mut self, capabilities: TerminalCapabilities) -> &mut Self {
+        self.load_preset(capabilities.preset())
+    }
+
+    /// Load the preset that fits the capabilities of the current terminal best.
+    ///
+    /// This is a shortcut for passing [Table::terminal_capabilities] to [Table::load_preset_for].
+    #[cfg(feature = "tty")]
+    pub fn auto_preset(&mut self) -> &mut Self {
+        self.load_preset_for(self.terminal_capabilities())
+    }
+
+    /// Like [Table::load_preset], but only the components that're part of the given
+    /// [ComponentMask] are taken from the preset. All other components are kept as they are.
+    ///
+    /// This allows to mix presets, independent of the order in which they're loaded.
+    ///
+    /// ```
+    /// use comfy_table::presets::{ASCII_FULL, UTF8_FULL};
+    /// use comfy_table::{ComponentMask, Table, TableComponent};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(ASCII_FULL)
+    ///     .load_preset_partial(UTF8_FULL, ComponentMask::CORNERS);
+    ///
+    /// assert_eq!(table.style(TableComponent::TopLeftCorner), Some('┌'));
+    /// assert_eq!(table.style(TableComponent::TopBorder), Some('-'));
+    /// ```
+    pub fn load_preset_partial(&mut self, preset: &str, mask: ComponentMask) -> &mut Self {
+        for (component, character) in TableComponent::iter().zip(preset.chars()) {
+            if !mask.contains(component) {
+                continue;
+            }
+
+            if character == ' ' {
+                self.remove_style(component);
+            } else {
+                self.set_style(component, character);
+            }
+        }
+
+        self
+    }
+
+    /// Returns the current style as a preset string.
+    ///
+    /// A pure convenience method, so you're not force to fiddle with those preset strings yourself.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// use comfy_table::presets::UTF8_FULL;
+    ///
+    /// let mut table = Table::new();
+    /// table.load_preset(UTF8_FULL);
+    ///
+    /// assert_eq!(UTF8_FULL, table.current_style_as_preset())
+    /// ```
+    pub fn current_style_as_preset(&mut self) -> String {
+        self.style
+            .iter()
+            .map(|character| character.unwrap_or(' '))
+            .collect()
+    }
+
+    /// Define the char that will be used to draw a specific component.\
+    /// Look at [TableComponent] to see all stylable components
+    ///
+    /// If `None` is supplied, the element won't be displayed.\
+    /// In case of a e.g. *BorderIntersection a whitespace will be used as placeholder,
+    /// unless related borders and and corners are set to `None` as well.
+    ///
+    /// For example, if `TopBorderIntersections` is `None` the first row would look like this:
+    ///
+    /// ```text
+    /// +------ ------+
+    /// | this | test |
+    /// ```
+    ///
+    /// If in addition `TopLeftCorner`,`TopBorder` and `TopRightCorner` would be `None` as well,
+    /// the first line wouldn't be displayed at all.
+    ///
+    /// Characters that're wider than a single column, such as emojis, are supported.
+    /// All characters of the same vertical border are padded to the width of the widest one.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::TableComponent::*;
+    ///
+    /// let mut table = Table::new();
+    /// // Load the UTF8_FULL preset
+    /// table.load_preset(UTF8_FULL);
+    /// // Set all outer corners to round UTF8 corners
+    /// // This is basically the same as the UTF8_ROUND_CORNERS modifier
+    /// table.set_style(TopLeftCorner, '╭');
+    /// table.set_style(TopRightCorner, '╮');
+    /// table.set_style(BottomLeftCorner, '╰');
+    /// table.set_style(BottomRightCorner, '╯');
+    /// ```
+    pub fn set_style(&mut self, component: TableComponent, character: char) -> &mut Self {
+        self.style[component as usize] = Some(character);
+
+        self
+    }
+
+    /// Set the character of a component and draw it in the given color.
+    ///
+    /// This is a shortcut for [Table::set_style] and [Table::set_border_style] with a
+    /// foreground color.
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::{CellStyle, Color, Table, TableComponent};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_FULL)
+    ///     .set_style_colored(TableComponent::HeaderLines, '═', Color::DarkGrey)
+    ///     .set_header(vec!["Header"])
+    ///     .add_row(vec!["Content"]);
+    ///
+    /// assert_eq!(
+    ///     table.border_style(TableComponent::HeaderLines),
+    ///     Some(&CellStyle::new().fg(Color::DarkGrey))
+    /// );
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_style_colored<C: Into<CellColor>>(
+        &mut self,
+        component: TableComponent,
+        character: char,
+        color: C,
+    ) -> &mut Self {
+        self.set_style(component, character);
+        let style = self
+            .border_styles
+            .remove(&component)
+            .unwrap_or_default()
+            .fg(color);
+        self.set_border_style(component, style)
+    }
+
+    /// Automatically pick corner and intersection characters that visually join the lines
+    /// around them.
+    ///
+    /// When mixing presets, modifiers and custom styles, box-drawing characters often don't
+    /// match up, e.g. a light corner meets a heavy border.
+    /// With this option enabled, all corners and intersections that use box-drawing characters
+    /// are replaced with the character that correctly joins the adjacent lines during rendering.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::TableComponent::*;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_FULL)
+    ///     .set_style(TopBorder, '━')
+    ///     .auto_join_borders(true)
+    ///     .add_row(vec!["One", "Two"]);
+    ///
+    /// assert!(table.to_string().starts_with("┍━━━━━┯━━━━━┑"));
+    /// ```
+    pub fn auto_join_borders(&mut self, auto_join: bool) -> &mut Self {
+        self.auto_join_borders = auto_join;
+
+        self
+    }
+
+    /// Get a copy of the char that's currently used for drawing this component.
+    /// ```
+    /// use comfy_table::Table;
+    /// use comfy_table::TableComponent::*;
+    ///
+    /// let mut table = Table::new();
+    /// assert_eq!(table.style(TopLeftCorner), Some('+'));
+    /// ```
+    pub fn style(&mut self, component: TableComponent) -> Option<char> {
+        self.style[component as usize]
+    }
+
+    /// Remove the style for a specific component of the table.\
+    /// By default, a space will be used as a placeholder instead.\
+    /// Though, if for instance all components of the left border are removed, the left border won't be displayed.
+    pub fn remove_style(&mut self, component: TableComponent) -> &mut Self {
+        self.style[component as usize] = None;
+
+        self
+    }
+
+    /// Don't draw the line between the header and the body.
+    ///
+    /// In contrast to removing the header components via [Table::remove_style], the preset
+    /// is left intact. Lines between [groups](Table::begin_group) and around
+    /// [sections](Table::add_section) are still drawn in the style of the header line.\
+    /// Use [Table::show_header_separator] to draw the line again.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Amount"])
+    ///     .add_row(vec!["Apples", "3"])
+    ///     .hide_header_separator();
+    ///
+    /// let expected = "
+    /// +--------+--------+
+    /// | Name   | Amount |
+    /// | Apples | 3      |
+    /// +--------+--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// assert!(!table.shows_header_separator());
+    /// ```
+    pub fn hide_header_separator(&mut self) -> &mut Self {
+        self.header_separator = false;
+
+        self
+    }
+
+    /// Draw the line between the header and the body again, after it has been hidden via
+    /// [Table::hide_header_separator]. This is the default.
+    ///
+    /// The line is only drawn, if the current style has any of the header components.
+    pub fn show_header_separator(&mut self) -> &mut Self {
+        self.header_separator = true;
+
+        self
+    }
+
+    /// Check whether the line between the header and the body is drawn, as far as the table's
+    /// settings are concerned. See [Table::hide_header_separator].
+    pub fn shows_header_separator(&self) -> bool {
+        self.header_separator
+    }
+
+    /// Add a column to the table.
+    ///
+    /// This allows to configure the columns up front, while the rows are added later on.
+    /// The column is appended after all existing columns and its index is set accordingly.\
+    /// If the header or rows have already been added, they're padded with empty cells, so
+    /// they line up with the new column.
+    ///
+    /// ```
+    /// use comfy_table::{CellAlignment, Column, Table};
+    ///
+    /// let mut column = Column::new(0);
+    /// column.set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let mut table = Table::new();
+    /// table.add_column(column);
+    /// table.add_row(vec!["1"]);
+    ///
+    /// assert_eq!(table.column_count(), 1);
+    /// ```
+    pub fn add_column(&mut self, mut column: Column) -> &mut Self {
+        column.index = self.columns.len();
+        self.columns.push(column);
+
+        let column_count = self.columns.len();
+        for row in self.header.iter_mut().chain(self.rows.iter_mut()) {
+            if row.cells.len() < column_count {
+                row.cells.resize_with(column_count, || Cell::new(""));
+            }
+        }
+
+        self
+    }
+
+    /// Get a reference to a specific column.
+    pub fn column(&self, index: usize) -> Option<&Column> {
+        self.columns.get(index)
+    }
+
+    /// Get a mutable reference to a specific column.
+    pub fn column_mut(&mut self, index: usize) -> Option<&mut Column> {
+        self.columns.get_mut(index)
+    }
+
+    /// Iterator over all columns
+    pub fn column_iter(&self) -> Iter<'_, Column> {
+        self.columns.iter()
+    }
+
+    /// Get a mutable iterator over all columns.
+    ///
+    /// ```
+    /// use comfy_table::{Width::*, ColumnConstraint::*, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(&vec!["First", "Second", "Third"]);
+    ///
+    /// // Add a ColumnConstraint to each column (left->right)
+    /// // first -> min width of 10
+    /// // second -> max width of 8
+    /// // third -> fixed width of 10
+    /// let constraints = vec![
+    ///     LowerBoundary(Fixed(10)),
+    ///     UpperBoundary(Fixed(8)),
+    ///     Absolute(Fixed(10)),
+    /// ];
+    ///
+    /// // Add the constraints to their respective column
+    /// for (column_index, column) in table.column_iter_mut().enumerate() {
+    ///     let constraint = constraints.get(column_index).unwrap();
+    ///     column.set_constraint(*constraint);
+    /// }
+    /// ```
+    pub fn column_iter_mut(&mut self) -> IterMut<'_, Column> {
+        self.columns.iter_mut()
+    }
+
+    /// Display the columns in the given order, without touching the rows of the table.
+    ///
+    /// The order consists of the indices of the columns, in which they've been added to the table.
+    /// Columns that aren't part of the order aren't displayed at all.
+    /// Indices of columns that don't exist and repeated indices are ignored.
+    ///
+    /// All settings of the columns, as well as [Table::column], refer to the original indices.
+    /// The same goes for [Table::column_display_widths] and [Table::truncated_content].
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Size", "Owner", "Modified"])
+    ///     .add_row(vec!["notes.txt", "12K", "root", "2024-01-01"]);
+    ///
+    /// table.set_column_order(&[0, 3, 1]);
+    ///
+    /// let expected = "
+    /// +-----------+------------+------+
+    /// | Name      | Modified   | Size |
+    /// +===============================+
+    /// | notes.txt | 2024-01-01 | 12K  |
+    /// +-----------+------------+------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_column_order(&mut self, order: &[usize]) -> &mut Self {
+        self.column_order = Some(order.to_vec());
+
+        self
+    }
+
+    /// Get the column order, if one has been set via [Table::set_column_order].
+    pub fn column_order(&self) -> Option<&[usize]> {
+        self.column_order.as_deref()
+    }
+
+    /// Display all columns in the order they've been added to the table again.
+    pub fn remove_column_order(&mut self) -> &mut Self {
+        self.column_order = None;
+
+        self
+    }
+
+    /// Get a mutable iterator over cells of a column.
+    /// The iterator returns a nested `Option<Option<Cell>>`, since there might be
+    /// rows that are missing this specific Cell.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// let mut table = Table::new();
+    /// table.add_row(&vec!["First", "Second"]);
+    /// table.add_row(&vec!["Third"]);
+    /// table.add_row(&vec!["Fourth", "Fifth"]);
+    ///
+    /// // Create an iterator over the second column
+    /// let mut cell_iter = table.column_cells_iter(1);
+    /// assert_eq!(cell_iter.next().unwrap().unwrap().content(), "Second");
+    /// assert!(cell_iter.next().unwrap().is_none());
+    /// assert_eq!(cell_iter.next().unwrap().unwrap().content(), "Fifth");
+    /// assert!(cell_iter.next().is_none());
+    /// ```
+    pub fn column_cells_iter(&self, column_index: usize) -> ColumnCellIter<'_> {
+        ColumnCellIter {
+            rows: &self.rows,
+            column_index,
+            row_index: 0,
+        }
+    }
+
+    /// Get a mutable iterator over cells of a column, including the header cell.
+    /// The header cell will be the very first cell returned.
+    /// The iterator returns a nested `Option<Option<Cell>>`, since there might be
+    /// rows that are missing this specific Cell.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// let mut table = Table::new();
+    /// table.set_header(&vec!["A", "B"]);
+    /// table.add_row(&vec!["First", "Second"]);
+    /// table.add_row(&vec!["Third"]);
+    /// table.add_row(&vec!["Fourth", "Fifth"]);
+    ///
+    /// // Create an iterator over the second column
+    /// let mut cell_iter = table.column_cells_with_header_iter(1);
+    /// assert_eq!(cell_iter.next().unwrap().unwrap().content(), "B");
+    /// assert_eq!(cell_iter.next().unwrap().unwrap().content(), "Second");
+    /// assert!(cell_iter.next().unwrap().is_none());
+    /// assert_eq!(cell_iter.next().unwrap().unwrap().content(), "Fifth");
+    /// assert!(cell_iter.next().is_none());
+    /// ```
+    pub fn column_cells_with_header_iter(
+        &self,
+        column_index: usize,
+    ) -> ColumnCellsWithHeaderIter<'_> {
+        ColumnCellsWithHeaderIter {
+            header_checked: false,
+            header: &self.header,
+            rows: &self.rows,
+            column_index,
+            row_index: 0,
+        }
+    }
+
+    /// Get the cell at the given position of the table's grid.
+    ///
+    /// The grid includes the header: If the table has a header, row `0` is the header and the
+    /// table's rows start at row `1`. Otherwise, row `0` is the table's first row.
+    ///
+    /// Returns `None`, if the row doesn't exist or is missing a cell at this position.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    /// assert_eq!(table.cell_at(0, 1).unwrap().content(), "Two");
+    ///
+    /// table.set_header(vec!["Header1", "Header2"]);
+    /// assert_eq!(table.cell_at(0, 1).unwrap().content(), "Header2");
+    /// assert_eq!(table.cell_at(1, 0).unwrap().content(), "One");
+    /// assert!(table.cell_at(2, 0).is_none());
+    /// ```
+    pub fn cell_at(&self, row: usize, column: usize) -> Option<&Cell> {
+        let row = match (&self.header, row) {
+            (Some(header), 0) => header,
+            (Some(_), row) => self.rows.get(row - 1)?,
+            (None, row) => self.rows.get(row)?,
+        };
+
+        row.cells.get(column)
+    }
+
+    /// Get a mutable reference to the cell at the given position of the table's grid.
+    ///
+    /// See [Table::cell_at] for how positions are addressed.\
+    /// The layout of the next rendering takes changes of the cell into account.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["Old"]);
+    /// *table.cell_at_mut(0, 0).unwrap() = Cell::new("Longer");
+    /// assert_eq!(table.to_string(), "+--------+\n| Longer |\n+--------+");
+    /// ```
+    pub fn cell_at_mut(&mut self, row: usize, column: usize) -> Option<&mut Cell> {
+        let row = match (&mut self.header, row) {
+            (Some(header), 0) => header,
+            (Some(_), row) => self.rows.get_mut(row - 1)?,
+            (None, row) => self.rows.get_mut(row)?,
+        };
+
+        row.cells.get_mut(column)
+    }
+
+    /// Reference to a specific row
+    pub fn row(&self, index: usize) -> Option<&Row> {
+        self.rows.get(index)
+    }
+
+    /// Mutable reference to a specific row
+    pub fn row_mut(&mut self, index: usize) -> Option<&mut Row> {
+        self.rows.get_mut(index)
+    }
+
+    /// Iterator over all rows
+    pub fn row_iter(&self) -> Iter<'_, Row> {
+        self.rows.iter()
+    }
+
+    /// Get a mutable iterator over all rows.
+    ///
+    /// Column widths are derived from the current content on each rendering, which means that
+    /// rows can be changed freely. Only call [Table::discover_columns] after adding cells.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// let mut table = Table::new();
+    /// table.add_row(&vec!["First", "Second", "Third"]);
+    ///
+    /// // Add the constraints to their respective row
+    /// for row in table.row_iter_mut() {
+    ///     row.max_height(5);
+    /// }
+    /// assert!(table.row_iter_mut().len() == 1);
+    /// ```
+    pub fn row_iter_mut(&mut self) -> IterMut<'_, Row> {
+        self.rows.iter_mut()
+    }
+
+    /// Return a vector representing the maximum amount of characters in any line of this column.\
+    /// The header is ignored for columns that are [sized to their data](Column::size_to_data_only).
+    /// [Hidden rows](Row::set_hidden) are ignored as well.
+    ///
+    /// **Attention** This scans the whole current content of the table.
+    pub fn column_max_content_widths(&self) -> Vec<u16> {
+        fn set_max_content_widths(max_widths: &mut [u16], row: &Row, table: &Table) {
+            // Get the max width for each cell of the row
+            let row_max_widths = row.max_content_widths(table.trim_mode, table.measure());
+            for (index, width) in row_max_widths.iter().enumerate() {
+                let mut width = (*width).try_into().unwrap_or(u16::MAX);
+                // Cells with their own padding need more or less space than the column's padding.
+                if let (Some(padding), Some(column)) =
+                    (row.cells[index].padding, table.columns.get(index))
+                {
+                    width = width
+                        .saturating_add(padding.0)
+                        .saturating_add(padding.1)
+                        .saturating_sub(column.padding_width());
+                }
+                // A column's content is at least 1 char wide.
+                width = std::cmp::max(1, width);
+
+                // Set a new max, if the current cell is the longest for that column.
+                let current_max = max_widths[index];
+                if current_max < width {
+                    max_widths[index] = width;
+                }
+            }
+        }
+        // The vector that'll contain the max widths per column.
+        let mut max_widths = vec![0; self.columns.len()];
+
+        if let Some(header) = &self.header {
+            set_max_content_widths(&mut max_widths, header, self);
+            // Forget the header's widths for columns that should only fit their data.
+            for column in self
+                .columns
+                .iter()
+                .filter(|column| column.size_to_data_only)
+            {
+                max_widths[column.index] = 1;
+            }
+        }
+        // Iterate through all visible rows of the table.
+        for row in self.rows.iter().filter(|row| !row.hidden) {
+            set_max_content_widths(&mut max_widths, row, self);
+        }
+
+        max_widths
+    }
+
+    pub(crate) fn style_or_default(&self, component: TableComponent) -> String {
+        let character = if self.auto_join_borders {
+            joined_style(self, component)
+        } else {
+            self.style[component as usize]
+        };
+
+        match character {
+            None => " ".to_string(),
+            Some(character) => character.to_string(),
+        }
+    }
+
+    pub(crate) fn style_exists(&self, component: TableComponent) -> bool {
+        self.style[component as usize].is_some()
+    }
+
+    /// Autogenerate new columns, if a row is added with more cells than existing columns.
+    fn autogenerate_columns(&mut self, row: &Row) {
+        if row.cell_count() > self.columns.len() {
+            for index in self.columns.len()..row.cell_count() {
+                self.columns.push(Column::new(index));
+            }
+        }
+    }
+
+    /// Calling this might be necessary if you add new cells to rows that're already added to the
+    /// table.
+    ///
+    /// If more cells than're currently know to the table are added to that row,
+    /// the table cannot know about these, since new [Column]s are only
+    /// automatically detected when a new row is added.
+    ///
+    /// To make sure everything works as expected, just call this function if you're adding cells
+    /// to rows that're already added to the table.
+    pub fn discover_columns(&mut self) {
+        for row in self.rows.iter() {
+            if row.cell_count() > self.columns.len() {
+                for index in self.columns.len()..row.cell_count() {
+                    self.columns.push(Column::new(index));
+                }
+            }
+        }
+    }
+}
+
+/// How the [constraint](Column::set_constraint) of a column has been applied, as returned by
+/// [Table::constraint_report].
+///
+/// All widths include the column's padding, just like the widths of the constraints.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintReport {
+    /// The index of the column.
+    pub column_index: usize,
+    /// The constraint of the column, if it has one.
+    pub constraint: Option<ColumnConstraint>,
+    /// The smallest width the constraint asks for, resolved for the current table width.
+    /// For [Absolute](ColumnConstraint::Absolute) constraints, this is the absolute width.
+    pub min_width: Option<u16>,
+    /// The largest width the constraint allows, resolved for the current table width.
+    /// For [Absolute](ColumnConstraint::Absolute) constraints, this is the absolute width.
+    pub max_width: Option<u16>,
+    /// The width the column has been arranged to. `None`, if the column isn't displayed.
+    pub width: Option<u16>,
+    /// Whether the arranged width satisfies the constraint.
+    /// Constraints that cannot be resolved, e.g. percentages of an unknown table width,
+    /// aren't honored.
+    pub honored: bool,
+}
+
+/// Where the [width](Table::width) of a table comes from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WidthSource {
+    /// The width has been set via [Table::set_width].
+    Fixed,
+    /// The width of the terminal has been detected.
+    Terminal,
+    /// The width has been set via [Table::set_fallback_width].
+    Fallback,
+    /// The width is unknown.
+    Unknown,
+}
+
+/// An iterator over cells of a specific column.
+/// A dedicated struct is necessary, as data is usually handled by rows and thereby stored in
+/// `Table::rows`. This type is returned by [Table::column_cells_iter].
+pub struct ColumnCellIter<'a> {
+    rows: &'a [Row],
+    column_index: usize,
+    row_index: usize,
+}
+
+impl<'a> Iterator for ColumnCellIter<'a> {
+    type Item = Option<&'a Cell>;
+    fn next(&mut self) -> Option<Option<&'a Cell>> {
+        // Check if there's a next row
+        if let Some(row) = self.rows.get(self.row_index) {
+            self.row_index += 1;
+
+            // Return the cell (if it exists).
+            return Some(row.cells.get(self.column_index));
+        }
+
+        None
+    }
+}
+
+/// An iterator over cells of a specific column.
+/// A dedicated struct is necessary, as data is usually handled by rows and thereby stored in
+/// `Table::rows`. This type is returned by [Table::column_cells_iter].
+pub struct ColumnCellsWithHeaderIter<'a> {
+    header_checked: bool,
+    header: &'a Option<Row>,
+    rows: &'a [Row],
+    column_index: usize,
+    row_index: usize,
+}
+
+impl<'a> Iterator for ColumnCellsWithHeaderIter<'a> {
+    type Item = Option<&'a Cell>;
+    fn next(&mut self) -> Option<Option<&'a Cell>> {
+        // Get the header as the first cell
+        if !self.header_checked {
+            self.header_checked = true;
+
+            return match self.header {
+                Some(header) => {
+                    // Return the cell (if it exists).
+                    Some(header.cells.get(self.column_index))
+                }
+                None => Some(None),
+            };
+        }
+
+        // Check if there's a next row
+        if let Some(row) = self.rows.get(self.row_index) {
+            self.row_index += 1;
+
+            // Return the cell (if it exists).
+            return Some(row.cells.get(self.column_index));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{RefUnwindSafe, UnwindSafe};
+
+    use super::*;
+
+    /// Tables can be shared between threads and used across panic boundaries.
+    /// Custom strategies mustn't take these traits away.
+    #[test]
+    fn auto_traits() {
+        fn assert_auto_traits<T: Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>() {}
+
+        assert_auto_traits::<Table>();
+    }
+
+    #[test]
+    fn test_column_generation() {
+        let mut table = Table::new();
+        table.set_header(vec!["thr", "four", "fivef"]);
+
+        // When adding a new row, columns are automatically generated
+        assert_eq!(table.columns.len(), 3);
+        // The max content width is also correctly set for each column
+        assert_eq!(table.column_max_content_widths(), vec![3, 4, 5]);
+
+        // When adding a new row, the max content width is updated accordingly
+        table.add_row(vec!["four", "fivef", "very long text with 23"]);
+        assert_eq!(table.column_max_content_widths(), vec![4, 5, 22]);
+
+        // Now add a row that has column lines. The max content width shouldn't change
+        table.add_row(vec!["", "", "shorter"]);
+        assert_eq!(table.column_max_content_widths(), vec![4, 5, 22]);
+
+        println!("{table}");
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let mut table = Table::new();
+        table.add_row(vec!["one"]).add_row(vec!["two"]);
+        let previous = table.to_string();
+
+        // Nothing changed
+        assert_eq!(table.render_diff(&previous), vec![]);
+
+        // The table got shorter
+        let mut shorter = Table::new();
+        shorter.add_row(vec!["one"]);
+        assert_eq!(
+            shorter.render_diff(&previous),
+            vec![
+                LinePatch::Set {
+                    index: 2,
+                    line: "+-----+".to_string()
+                },
+                LinePatch::Clear { index: 3 },
+                LinePatch::Clear { index: 4 },
+            ]
+        );
+
+        // The table got longer
+        assert_eq!(
+            table.render_diff(&shorter.to_string()),
+            vec![
+                LinePatch::Set {
+                    index: 2,
+                    line: "|-----|".to_string()
+                },
+                LinePatch::Set {
+                    index: 3,
+                    line: "| two |".to_string()
+                },
+                LinePatch::Set {
+                    index: 4,
+                    line: "+-----+".to_string()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use super::Table;
+use crate::error::TooNarrowError;
+use crate::style::OverflowPolicy;
+use crate::utils::arrangement::helper::minimum_table_width;
+use crate::utils::{build_table_into, rendered_rows, truncated_content};
+
+impl Table {
+    /// Render the table into an existing buffer.
+    ///
+    /// The buffer is cleared beforehand, but keeps its capacity. This avoids allocating a new
+    /// string each time, if the same table is rendered over and over again, e.g. in a TUI.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["CPU", "12%"]);
+    ///
+    /// let mut buffer = String::new();
+    /// table.render_into(&mut buffer);
+    /// assert_eq!(buffer, table.to_string());
+    /// ```
+    pub fn render_into(&self, buffer: &mut String) {
+        self.render_into_with_scratch(buffer, &mut RenderScratch::new());
+    }
+
+    /// Same as [Table::render_into], but the rendered lines are collected in a [RenderScratch],
+    /// whose list of lines can be reused for the next rendering.
+    ///
+    /// ```
+    /// use comfy_table::{RenderScratch, Table};
+    ///
+    /// let mut table = Table::new();
+    /// let mut buffer = String::new();
+    /// let mut scratch = RenderScratch::new();
+    ///
+    /// for usage in [12, 42] {
+    ///     table = Table::new();
+    ///     table.add_row(vec!["CPU".to_string(), format!("{usage}%")]);
+    ///     table.render_into_with_scratch(&mut buffer, &mut scratch);
+    /// }
+    /// assert_eq!(buffer, table.to_string());
+    /// ```
+    pub fn render_into_with_scratch(&self, buffer: &mut String, scratch: &mut RenderScratch) {
+        build_table_into(self, &mut scratch.lines);
+
+        buffer.clear();
+        for (index, line) in scratch.lines.iter().enumerate() {
+            if index != 0 {
+                buffer.push('\n');
+            }
+            buffer.push_str(line);
+        }
+    }
+
+    /// Render the table and compare it line by line with a previous rendering.
+    ///
+    /// Only lines that differ are returned, which allows full-screen applications to only
+    /// repaint the regions of the screen that actually changed.\
+    /// If the table got shorter, a [LinePatch::Clear] is returned for each line that no longer
+    /// exists.
+    ///
+    /// ```
+    /// use comfy_table::{LinePatch, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["CPU", "12%"]);
+    /// let previous = table.to_string();
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["CPU", "42%"]);
+    /// assert_eq!(
+    ///     table.render_diff(&previous),
+    ///     vec![LinePatch::Set {
+    ///         index: 1,
+    ///         line: "| CPU | 42% |".to_string()
+    ///     }]
+    /// );
+    /// ```
+    pub fn render_diff(&self, previous_render: &str) -> Vec<LinePatch> {
+        let mut previous_lines = previous_render.lines();
+        let mut patches = Vec::new();
+        let mut line_count = 0;
+
+        for (index, line) in self.lines().enumerate() {
+            line_count += 1;
+            if previous_lines.next() != Some(line.as_str()) {
+                patches.push(LinePatch::Set { index, line });
+            }
+        }
+
+        // Clear all lines that don't exist anymore.
+        for (offset, _) in previous_lines.enumerate() {
+            patches.push(LinePatch::Clear {
+                index: line_count + offset,
+            });
+        }
+
+        patches
+    }
+
+    /// Render the table for the given width.
+    ///
+    /// Contrary to the [OverflowPolicy], this returns an error instead of an overflowing or
+    /// otherwise degraded table, if the table cannot be arranged to fit into the given width.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .add_row(vec!["One", "Two", "Three"]);
+    ///
+    /// assert!(table.try_render(40).is_ok());
+    /// // Three columns with 1 char and padding each, plus four borders.
+    /// let error = table.try_render(12).unwrap_err();
+    /// assert_eq!(error.min_width, 13);
+    /// ```
+    pub fn try_render(&self, width: u16) -> Result<String, TooNarrowError> {
+        let mut table = self.clone();
+        table.set_width(width);
+        table.set_overflow_policy(OverflowPolicy::Overflow);
+
+        let min_width = minimum_table_width(&table);
+        if min_width > usize::from(width) {
+            return Err(TooNarrowError { width, min_width });
+        }
+
+        Ok(table.to_string())
+    }
+
+    /// Get the full content of all cells that're truncated due to
+    /// [Row::max_height](crate::Row::max_height), in the order they're displayed.
+    ///
+    /// The number of the [marker](Table::set_truncation_markers) of each cell is part of the
+    /// result. Truncated header cells aren't included.
+    ///
+    /// **Attention** This arranges and formats the whole table, just like rendering does.\
+    /// **Note:** [OverflowPolicies](crate::OverflowPolicy) aren't applied.
+    ///
+    /// ```
+    /// use comfy_table::{Row, Table};
+    ///
+    /// let mut table = Table::new();
+    /// let mut row = Row::from(vec!["Short", "First line\nSecond line"]);
+    /// row.max_height(1);
+    /// table.add_row(row);
+    ///
+    /// let truncated = table.truncated_content();
+    /// assert_eq!(truncated.len(), 1);
+    /// assert_eq!(truncated[0].marker, 1);
+    /// assert_eq!(truncated[0].column_index, 1);
+    /// assert_eq!(truncated[0].content, "First line\nSecond line");
+    /// ```
+    pub fn truncated_content(&self) -> Vec<TruncatedContent> {
+        truncated_content(self)
+    }
+
+    /// Render the table and group its lines by the rows they belong to.
+    ///
+    /// This allows pagers and other interactive applications to scroll, select or highlight
+    /// whole rows, without having to parse the rendered table.\
+    /// Each horizontal line is attached to exactly one row. The top border belongs to the first
+    /// row, all other lines belong to the row above them. Hence, joining the lines of all rows
+    /// in order results in the same lines as [Table::lines], minus the [title](Table::set_title)
+    /// above the table, the [caption](Table::set_caption) and the footnotes.
+    ///
+    /// **Attention** This arranges and formats the whole table, just like rendering does.\
+    /// **Note:** [OverflowPolicies](crate::OverflowPolicy) aren't applied.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name"])
+    ///     .add_row(vec!["First\nline"]);
+    ///
+    /// let rows = table.rendered_rows();
+    /// assert_eq!(rows.len(), 2);
+    ///
+    /// assert!(rows[0].is_header);
+    /// assert_eq!(rows[0].separator_above.as_deref(), Some("+-------+"));
+    /// assert_eq!(rows[0].physical_lines, vec!["| Name  |"]);
+    /// assert_eq!(rows[0].separator_below.as_deref(), Some("+=======+"));
+    ///
+    /// assert_eq!(rows[1].row_index, Some(0));
+    /// assert_eq!(rows[1].physical_lines, vec!["| First |", "| line  |"]);
+    /// assert_eq!(rows[1].separator_below.as_deref(), Some("+-------+"));
+    /// ```
+    pub fn rendered_rows(&self) -> Vec<RenderedRow> {
+        rendered_rows(self)
+    }
+}
+
+/// A reusable list of rendered lines for [Table::render_into_with_scratch].
+///
+/// Keep it around between renderings, so the list itself isn't allocated again.
+/// The lines in it are still allocated anew for each rendering.
+#[derive(Clone, Debug, Default)]
+pub struct RenderScratch {
+    lines: Vec<String>,
+}
+
+impl RenderScratch {
+    /// Create an empty scratch, which doesn't allocate until it's used for the first rendering.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The full content of a cell that's truncated due to its row's
+/// [max height](crate::Row::max_height), as returned by [Table::truncated_content].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TruncatedContent {
+    /// The number of the cell's [marker](Table::set_truncation_markers), e.g. `1` for `[+1]`.
+    pub marker: usize,
+    /// The index of the cell's row in the table's body.
+    pub row_index: usize,
+    /// The index of the cell's column.
+    pub column_index: usize,
+    /// The full content of the cell.
+    pub content: String,
+}
+
+/// The rendered lines of a single row, as returned by [Table::rendered_rows].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderedRow {
+    /// The index of the row in the table's body.
+    /// This is `None` for the header, [sections](crate::Section) and the
+    /// [empty message](Table::set_empty_message).
+    pub row_index: Option<usize>,
+    /// Whether this row is the table's header.
+    pub is_header: bool,
+    /// The lines of the row's content, including the vertical borders.
+    pub physical_lines: Vec<String>,
+    /// The horizontal line above the row.
+    /// This is only ever set for the first row, which owns the top border.
+    pub separator_above: Option<String>,
+    /// The horizontal line below the row, e.g. a row separator or the bottom border.
+    pub separator_below: Option<String>,
+}
+
+/// A changed line, as returned by [Table::render_diff].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinePatch {
+    /// The line at this index changed or has been added.
+    Set { index: usize, line: String },
+    /// The line at this index doesn't exist anymore and should be cleared.
+    Clear { index: usize },
+}
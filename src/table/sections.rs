@@ -0,0 +1,112 @@
+use super::Table;
+use crate::cell::Cell;
+use crate::row::{RowGroup, Section};
+
+impl Table {
+    /// Start a new [RowGroup]. All rows that're added afterwards belong to this group,
+    /// until [Table::end_group] is called or another group is started.
+    ///
+    /// Groups cannot be nested.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Fruit", "Amount"])
+    ///     .begin_group("Local")
+    ///     .add_row(vec!["Apples", "3"])
+    ///     .add_row(vec!["Pears", "5"])
+    ///     .begin_group("Imported")
+    ///     .add_row(vec!["Bananas", "4"])
+    ///     .end_group();
+    ///
+    /// let groups = table.groups();
+    /// assert_eq!(groups[0].name(), "Local");
+    /// assert_eq!(groups[0].rows(), 0..2);
+    /// assert_eq!(groups[1].rows(), 2..3);
+    /// ```
+    pub fn begin_group<T: ToString>(&mut self, name: T) -> &mut Self {
+        let start = self.rows.len();
+        self.groups.push(RowGroup {
+            name: name.to_string(),
+            rows: start..start,
+        });
+        self.group_is_open = true;
+
+        self
+    }
+
+    /// End the current [RowGroup]. Rows that're added afterwards don't belong to any group.
+    pub fn end_group(&mut self) -> &mut Self {
+        self.group_is_open = false;
+
+        self
+    }
+
+    /// Get all [RowGroups](RowGroup) of this table.
+    pub fn groups(&self) -> &[RowGroup] {
+        &self.groups
+    }
+
+    /// Add a [Section] with the given title. It's displayed right before the row that's added next.
+    ///
+    /// The title is displayed in a row of its own, which spans over all columns.
+    /// Just like any other cell, it can be styled and aligned.\
+    /// The columns grow until the title fits into a single line. It's only wrapped, if that
+    /// would exceed the [width](Table::set_width) of a dynamically arranged table.
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL_CONDENSED;
+    /// use comfy_table::{Cell, CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_FULL_CONDENSED)
+    ///     .set_header(vec!["Name", "Amount"])
+    ///     .add_section("Fruit")
+    ///     .add_row(vec!["Apples", "3"])
+    ///     .add_row(vec!["Pears", "5"])
+    ///     .add_section(Cell::new("Vegetables").set_alignment(CellAlignment::Center))
+    ///     .add_row(vec!["Carrots", "2"]);
+    ///
+    /// let expected = "
+    /// ┌─────────┬────────┐
+    /// │ Name    ┆ Amount │
+    /// ╞═════════╪════════╡
+    /// │ Fruit            │
+    /// ╞═════════╪════════╡
+    /// │ Apples  ┆ 3      │
+    /// │ Pears   ┆ 5      │
+    /// ╞═════════╪════════╡
+    /// │    Vegetables    │
+    /// ╞═════════╪════════╡
+    /// │ Carrots ┆ 2      │
+    /// └─────────┴────────┘";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn add_section<T: Into<Cell>>(&mut self, title: T) -> &mut Self {
+        self.sections.push(Section {
+            title: title.into(),
+            row_index: self.rows.len(),
+        });
+
+        self
+    }
+
+    /// Get all [Sections](Section) of this table.
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    /// Check whether a group separator should be drawn right before the row with the given index.
+    pub(crate) fn is_group_boundary(&self, row_index: usize) -> bool {
+        if row_index == 0 || row_index >= self.rows.len() {
+            return false;
+        }
+
+        self.groups.iter().any(|group| {
+            !group.rows.is_empty() && (group.rows.start == row_index || group.rows.end == row_index)
+        })
+    }
+}
@@ -0,0 +1,154 @@
+use strum::IntoEnumIterator;
+
+use super::Table;
+use crate::style::{ComponentStyle, StyleLayer, StyleMap, TableComponent, TableStyle, Theme};
+
+impl Table {
+    /// Get the current style of all components as a [TableStyle].
+    ///
+    /// Components that aren't drawn are marked as [removed](ComponentStyle::Removed).
+    pub fn table_style(&self) -> TableStyle {
+        let mut style = TableStyle::new();
+        for component in TableComponent::iter() {
+            match self.style[component as usize] {
+                Some(character) => style.set_style(component, character),
+                None => style.remove_style(component),
+            };
+        }
+
+        style
+    }
+
+    /// Apply a [TableStyle] to this table.
+    ///
+    /// Components that're [unset](ComponentStyle::Unset) in the style are kept as they are.
+    pub fn set_table_style(&mut self, style: &TableStyle) -> &mut Self {
+        for component in TableComponent::iter() {
+            match style.style(component) {
+                ComponentStyle::Unset => {}
+                ComponentStyle::Removed => {
+                    self.remove_style(component);
+                }
+                ComponentStyle::Char(character) => {
+                    self.set_style(component, character);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Put a [StyleLayer] on top of the table's style.
+    ///
+    /// The table's own style isn't changed. During rendering, each component is taken from the
+    /// topmost layer that sets it. Remove the layer via [Table::pop_style_layer] to get back
+    /// to the previous style. See [StyleLayer] for an example.
+    pub fn push_style_layer(&mut self, layer: StyleLayer) -> &mut Self {
+        self.style_layers.push(layer);
+
+        self
+    }
+
+    /// Remove the topmost [StyleLayer], which has been added via [Table::push_style_layer].
+    pub fn pop_style_layer(&mut self) -> Option<StyleLayer> {
+        self.style_layers.pop()
+    }
+
+    /// Get all [StyleLayers](StyleLayer) of this table, from the bottom to the top.
+    pub fn style_layers(&self) -> &[StyleLayer] {
+        &self.style_layers
+    }
+
+    /// Load a [StyleMap], which replaces the style of all components.
+    ///
+    /// This is equivalent to [Table::load_preset] with a full preset, but the characters
+    /// don't need to be parsed.
+    pub fn load_style_map(&mut self, map: &StyleMap) -> &mut Self {
+        self.style = map.characters;
+
+        self
+    }
+
+    /// Get the current style of all components as a [StyleMap].
+    pub fn style_map(&self) -> StyleMap {
+        StyleMap::from_array(self.style)
+    }
+
+    /// Modify a preset with a modifier string from [modifiers](crate::style::modifiers).
+    ///
+    /// For instance, the [UTF8_ROUND_CORNERS](crate::style::modifiers::UTF8_ROUND_CORNERS) modifies all corners to be round UTF8 box corners.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+    ///
+    /// let mut table = Table::new();
+    /// table.load_preset(UTF8_FULL);
+    /// table.apply_modifier(UTF8_ROUND_CORNERS);
+    /// ```
+    pub fn apply_modifier(&mut self, modifier: &str) -> &mut Self {
+        let mut components = TableComponent::iter();
+
+        for character in modifier.chars() {
+            // Skip spaces while applying modifiers.
+            if character == ' ' {
+                components.next();
+                continue;
+            }
+            if let Some(component) = components.next() {
+                self.set_style(component, character);
+            } else {
+                break;
+            }
+        }
+
+        self
+    }
+
+    /// Apply a [Theme], i.e. load its preset, apply its modifiers and take over its styling.
+    ///
+    /// Everything that isn't set in the theme is left as it is.
+    ///
+    /// ```
+    /// use comfy_table::presets::ASCII_MARKDOWN;
+    /// use comfy_table::{Table, Theme};
+    ///
+    /// let markdown = Theme::new().preset(ASCII_MARKDOWN);
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .apply_theme(&markdown)
+    ///     .set_header(vec!["Name", "Amount"])
+    ///     .add_row(vec!["Apples", "3"]);
+    ///
+    /// let expected = "
+    /// | Name   | Amount |
+    /// |--------|--------|
+    /// | Apples | 3      |";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn apply_theme(&mut self, theme: &Theme) -> &mut Self {
+        if let Some(preset) = theme.preset.as_deref() {
+            self.load_preset(preset);
+        }
+        for modifier in theme.modifiers.iter() {
+            self.apply_modifier(modifier);
+        }
+
+        #[cfg(feature = "tty")]
+        {
+            if let Some(style) = theme.header_style.as_ref() {
+                self.header_style = style.clone();
+            }
+            if let Some(style) = theme.body_style.as_ref() {
+                self.body_style = style.clone();
+            }
+            if let Some((even, odd)) = theme.zebra_stripes {
+                self.set_zebra_stripes(even, odd);
+            }
+        }
+
+        self
+    }
+}
@@ -0,0 +1,160 @@
+use super::Table;
+use crate::style::CellAlignment;
+
+impl Table {
+    /// Add a footnote, which will be displayed below the table.
+    ///
+    /// Footnotes are numbered in the order they're added, starting at `1`.
+    /// Each footnote is prefixed with its reference marker (e.g. `[1]`) and wrapped to the
+    /// width of the table.\
+    /// Cells can refer to a footnote via [Cell::set_footnote_ref](crate::Cell::set_footnote_ref).
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Revenue"])
+    ///     .add_row(vec![Cell::new("ACME"), Cell::new("1000").set_footnote_ref(1)])
+    ///     .add_footnote("Numbers are preliminary.");
+    ///
+    /// let expected = "
+    /// +------+---------+
+    /// | Name | Revenue |
+    /// +================+
+    /// | ACME | 1000[1] |
+    /// +------+---------+
+    /// [1] Numbers are
+    ///     preliminary.";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn add_footnote<T: ToString>(&mut self, footnote: T) -> &mut Self {
+        self.footnotes.push(footnote.to_string());
+
+        self
+    }
+
+    /// Get all footnotes of this table in the order they've been added.
+    pub fn footnotes(&self) -> &[String] {
+        &self.footnotes
+    }
+
+    /// Display a title above the table.
+    ///
+    /// The title spans the full width of the table. Just like [sections](Table::add_section),
+    /// the columns grow until the title fits. It's only wrapped, if that would exceed the
+    /// [width](Table::set_width) of a dynamically arranged table.
+    /// It's centered by default, see [Table::set_title_alignment].
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_title("Revenue")
+    ///     .set_caption("Numbers are preliminary")
+    ///     .set_header(vec!["Company", "Q1", "Q2"])
+    ///     .add_row(vec!["ACME", "1000", "2000"]);
+    ///
+    /// let expected = "
+    ///          Revenue
+    /// +---------+------+------+
+    /// | Company | Q1   | Q2   |
+    /// +=======================+
+    /// | ACME    | 1000 | 2000 |
+    /// +---------+------+------+
+    ///  Numbers are preliminary";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_title<T: ToString>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.to_string());
+
+        self
+    }
+
+    /// Remove the title of the table, see [Table::set_title].
+    pub fn remove_title(&mut self) -> &mut Self {
+        self.title = None;
+
+        self
+    }
+
+    /// Get the title of the table.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Set the alignment of the [title](Table::set_title). The default is [CellAlignment::Center].
+    pub fn set_title_alignment(&mut self, alignment: CellAlignment) -> &mut Self {
+        self.title_alignment = alignment;
+
+        self
+    }
+
+    /// Display the [title](Table::set_title) inside of the top border.
+    ///
+    /// The columns grow until the title fits into the border.
+    /// The title is still displayed above the table, if there's no top border, if the title
+    /// has multiple lines or if it doesn't fit into the [width](Table::set_width) of a
+    /// dynamically arranged table.
+    ///
+    /// ```
+    /// use comfy_table::presets::UTF8_FULL;
+    /// use comfy_table::{CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_FULL)
+    ///     .set_title("Revenue")
+    ///     .set_title_alignment(CellAlignment::Left)
+    ///     .set_title_in_border(true)
+    ///     .add_row(vec!["ACME", "1000", "2000"]);
+    ///
+    /// let expected = "
+    /// ┌─ Revenue ───┬──────┐
+    /// │ ACME ┆ 1000 ┆ 2000 │
+    /// └──────┴──────┴──────┘";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_title_in_border(&mut self, in_border: bool) -> &mut Self {
+        self.title_in_border = in_border;
+
+        self
+    }
+
+    /// Returns whether the title is displayed inside of the top border,
+    /// see [Table::set_title_in_border].
+    pub fn title_in_border(&self) -> bool {
+        self.title_in_border
+    }
+
+    /// Display a caption below the table.
+    ///
+    /// Just like the [title](Table::set_title), the caption spans the full width of the table.
+    /// It's displayed right below the table, before any [footnotes](Table::add_footnote).
+    pub fn set_caption<T: ToString>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.to_string());
+
+        self
+    }
+
+    /// Remove the caption of the table, see [Table::set_caption].
+    pub fn remove_caption(&mut self) -> &mut Self {
+        self.caption = None;
+
+        self
+    }
+
+    /// Get the caption of the table.
+    pub fn caption(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+
+    /// Set the alignment of the [caption](Table::set_caption). The default is
+    /// [CellAlignment::Center].
+    pub fn set_caption_alignment(&mut self, alignment: CellAlignment) -> &mut Self {
+        self.caption_alignment = alignment;
+
+        self
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use super::Table;
+use crate::style::TruncationStrategy;
+
+impl Table {
+    /// Decide which part of lines that're too wide for their column is kept.
+    ///
+    /// By default, lines are cut off at the end.
+    /// Use one of the built-in [TruncationModes](crate::TruncationMode) or implement your own
+    /// [TruncationStrategy].
+    ///
+    /// ```
+    /// use comfy_table::{Cell, ContentArrangement, Table, TruncationMode};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_truncation_strategy(Box::new(TruncationMode::Start))
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(16)
+    ///     .add_row(vec![Cell::new("/usr/share/comfy-table").set_truncate(true)]);
+    ///
+    /// assert_eq!(table.lines().nth(1).unwrap(), "| ...mfy-table |");
+    /// ```
+    pub fn set_truncation_strategy(&mut self, strategy: Box<dyn TruncationStrategy>) -> &mut Self {
+        self.truncation_strategy = Some(Arc::from(strategy));
+
+        self
+    }
+
+    /// Go back to cutting off lines at the end.
+    pub fn remove_truncation_strategy(&mut self) -> &mut Self {
+        self.truncation_strategy = None;
+
+        self
+    }
+
+    /// Get the custom truncation strategy of the table, if one is set.
+    pub fn truncation_strategy(&self) -> Option<&dyn TruncationStrategy> {
+        self.truncation_strategy.as_deref()
+    }
+
+    /// Number the cells that're truncated due to [Row::max_height](crate::Row::max_height).
+    ///
+    /// The number is displayed as marker, e.g. `[+1]`, right after the truncation indicator.
+    /// Use it to look up the full content of the cell via [Table::truncated_content],
+    /// e.g. to offer a way to expand the cell in an interactive application.
+    /// The marker is only displayed, if the column is wide enough for both the indicator
+    /// and the marker.
+    ///
+    /// ```
+    /// use comfy_table::{Row, Table};
+    ///
+    /// let mut table = Table::new();
+    /// let mut row = Row::from(vec!["Some very long\ncell content"]);
+    /// row.max_height(1);
+    /// table.add_row(row).set_truncation_markers(true);
+    ///
+    /// let expected = "
+    /// +----------------+
+    /// | Some ve...[+1] |
+    /// +----------------+";
+    /// assert_eq!(table.to_string(), expected.trim_start());
+    /// ```
+    pub fn set_truncation_markers(&mut self, enabled: bool) -> &mut Self {
+        self.truncation_markers = enabled;
+
+        self
+    }
+
+    /// Returns whether truncated cells are numbered, see [Table::set_truncation_markers].
+    pub fn shows_truncation_markers(&self) -> bool {
+        self.truncation_markers
+    }
+}
@@ -0,0 +1,47 @@
+use super::Table;
+use crate::error::{OutputIssue, VerificationError};
+use crate::utils::build_table_into;
+use crate::utils::formatting::verify::find_issues;
+
+impl Table {
+    /// Render the table and make sure that the output is well-formed.
+    ///
+    /// This is a debug mode, which makes sure that all lines of the table have the same display
+    /// width and that no control characters leaked through from the content.
+    /// Titles, captions and footnotes aren't part of the check, as they aren't padded.
+    /// ANSI escape sequences are ignored.
+    ///
+    /// If the output is malformed, a report of all problems is returned instead.
+    /// The line numbers of the [OutputIssue](crate::OutputIssue)s refer to the rendered output.
+    ///
+    /// ```
+    /// use comfy_table::{OutputIssue, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["Bell\u{7}"]);
+    ///
+    /// let error = table.render_verified().unwrap_err();
+    /// assert_eq!(
+    ///     error.issues,
+    ///     vec![OutputIssue::ControlCharacter {
+    ///         line: 1,
+    ///         content: "| Bell\u{7} |".to_string(),
+    ///         character: '\u{7}',
+    ///     }]
+    /// );
+    /// ```
+    pub fn render_verified(&self) -> Result<String, VerificationError> {
+        let mut lines = Vec::new();
+        let ranges = build_table_into(self, &mut lines);
+
+        let issues: Vec<OutputIssue> = ranges
+            .into_iter()
+            .flat_map(|range| find_issues(&lines, range, self.measure()))
+            .collect();
+        if !issues.is_empty() {
+            return Err(VerificationError { issues });
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
@@ -9,7 +9,7 @@ use crate::{Column, Table};
 /// For example:
 /// - We get an absolute width.
 /// - MinWidth constraints on columns, whose content is garantueed to be smaller than the specified
-///     minimal width.
+///   minimal width.
 /// - The Column is supposed to be hidden.
 pub fn evaluate(
     table: &Table,
@@ -42,7 +42,7 @@ pub fn evaluate(
     if let Some(min_width) = min(table, &column.constraint, visible_columns) {
         // In case a min_width is specified, we may already fix the size of the column.
         // We do this, if we know that the content is smaller than the min size.
-        let max_width = max_content_width + column.padding_width();
+        let max_width = max_content_width.saturating_add(column.padding_width());
         if max_width <= min_width {
             let width = absolute_width_with_padding(column, min_width);
             let info = ColumnDisplayInfo::new(column, width);
@@ -58,6 +58,7 @@ pub fn evaluate(
 /// Lower boundaries with [Width::Fixed] just return their internal value. \
 /// Lower boundaries with [Width::Percentage] return the percental amount of the current table
 /// width.
+/// Lower boundaries with [Width::TerminalFraction] return the fraction of the terminal width.
 pub fn min(
     table: &Table,
     constraint: &Option<ColumnConstraint>,
@@ -84,6 +85,7 @@ pub fn min(
 /// Upper boundaries with [Width::Fixed] just return their internal value. \
 /// Upper boundaries with [Width::Percentage] return the percental amount of the current table
 /// width.
+/// Upper boundaries with [Width::TerminalFraction] return the fraction of the terminal width.
 pub fn max(
     table: &Table,
     constraint: &Option<ColumnConstraint>,
@@ -127,5 +129,22 @@ pub fn absolute_value_from_width(
                 .unwrap_or(u16::MAX);
             Some(width)
         }
+        Width::TerminalFraction(numerator, denominator) => {
+            if *denominator == 0 {
+                return None;
+            }
+
+            // Prefer the live terminal width over any fixed table width.
+            let screen_width = table.terminal_width().or_else(|| table.width())?;
+
+            // Enforce at most the whole screen.
+            let numerator = std::cmp::min(*numerator, *denominator);
+
+            let width = (usize::from(screen_width) * usize::from(numerator)
+                / usize::from(*denominator))
+            .try_into()
+            .unwrap_or(u16::MAX);
+            Some(width)
+        }
     }
 }
@@ -1,4 +1,5 @@
 use super::constraint;
+use super::dynamic::grow_for_spanning_content;
 use super::helper::*;
 use super::{ColumnDisplayInfo, DisplayInfos};
 use crate::Table;
@@ -6,6 +7,9 @@ use crate::Table;
 /// Dynamic arrangement is disabled.
 /// Apply all non-relative constraints, and set the width of all remaining columns to the
 /// respective max content width.
+///
+/// As there's no width limit, the columns then grow until all content that spans over them,
+/// such as section titles, fits without being wrapped.
 pub fn arrange(
     table: &Table,
     infos: &mut DisplayInfos,
@@ -29,4 +33,6 @@ pub fn arrange(
         let info = ColumnDisplayInfo::new(column, width);
         infos.insert(column.index, info);
     }
+
+    grow_for_spanning_content(table, infos, usize::MAX);
 }
@@ -1,11 +1,11 @@
-use unicode_width::UnicodeWidthStr;
-
 use super::constraint;
 use super::helper::*;
+use super::split_cache::SplitCache;
 use super::{ColumnDisplayInfo, DisplayInfos};
 use crate::style::*;
-use crate::utils::formatting::content_split::split_line;
-use crate::{Column, Table};
+use crate::utils::formatting::content_split::{measure_text_width, split_line, split_long_word};
+use crate::utils::formatting::titles::title_width;
+use crate::{Cell, Column, Table};
 
 /// Try to find the best fit for a given content and table_width
 ///
@@ -31,6 +31,7 @@ pub fn arrange(
     infos: &mut DisplayInfos,
     table_width: usize,
     max_content_widths: &[u16],
+    splits: Option<&mut SplitCache>,
 ) {
     let visible_columns = count_visible_columns(&table.columns);
 
@@ -92,6 +93,7 @@ pub fn arrange(
             infos,
             remaining_width,
             remaining_columns,
+            splits,
         );
     }
 
@@ -109,9 +111,13 @@ pub fn arrange(
     if remaining_columns == 0 {
         if remaining_width > 0 && matches!(table.arrangement, ContentArrangement::DynamicFullWidth)
         {
-            use_full_width(infos, remaining_width);
+            use_full_width(infos, remaining_width, table.center_bias);
             #[cfg(feature = "debug")]
             println!("dynamic::arrange: After full width: {infos:#?}");
+        } else if remaining_width > 0 {
+            grow_for_spanning_content(table, infos, remaining_width);
+            #[cfg(feature = "debug")]
+            println!("dynamic::arrange: After spanning content: {infos:#?}");
         }
         return;
     }
@@ -122,7 +128,7 @@ pub fn arrange(
         remaining_width = remaining_columns;
     }
 
-    distribute_remaining_space(&table.columns, infos, remaining_width, remaining_columns);
+    distribute_remaining_space(table, infos, remaining_width, remaining_columns);
 
     #[cfg(feature = "debug")]
     println!("dynamic::arrange: After distribute: {infos:#?}");
@@ -155,8 +161,7 @@ fn available_content_width(
             continue;
         }
         // Remove the fixed padding for each column
-        let (left, right) = column.padding;
-        width = width.saturating_sub((left + right).into());
+        width = width.saturating_sub(column.padding_width().into());
     }
 
     // Remove all already fixed sizes from the remaining_width.
@@ -178,17 +183,17 @@ fn available_content_width(
 /// The algorithm is a while loop with a nested for loop.
 /// 1. We iterate over all columns and check if there are columns that take less space.
 /// 2. If we find one or more such columns, we fix their width and add the surplus space to the
-///     remaining space. Due to this step, the average space per column increased. Now some other
-///     column might be fixed in width as well.
+///    remaining space. Due to this step, the average space per column increased. Now some other
+///    column might be fixed in width as well.
 /// 3. Do step 1 and 2, as long as there are columns left and as long as we find columns
-///     that take up less space than the current remaining average.
+///    that take up less space than the current remaining average.
 ///
 /// Parameters:
 /// - `table_width`: The absolute amount of available space.
 /// - `remaining_width`: This is the amount of space that isn't yet reserved by any other column.
-///                      We need this to determine the average space each column has left.
-///                      Any columns that needs less than this average receives a fixed width.
-///                      The leftover space can then be used for the other columns.
+///   We need this to determine the average space each column has left.
+///   Any columns that needs less than this average receives a fixed width.
+///   The leftover space can then be used for the other columns.
 /// - `visible_columns`: All visible columns that should be displayed.
 ///
 /// Returns:
@@ -235,7 +240,7 @@ fn find_columns_that_fit_into_average(
                 let average_space_with_padding =
                     average_space + usize::from(column.padding_width());
 
-                let width_with_padding = max_column_width + column.padding_width();
+                let width_with_padding = max_column_width.saturating_add(column.padding_width());
                 // Check that both conditions mentioned above are met.
                 if usize::from(max_width) <= average_space_with_padding
                     && width_with_padding >= max_width
@@ -308,51 +313,86 @@ fn enforce_lower_boundary_constraints(
     visible_columns: usize,
 ) -> (usize, usize) {
     let mut average_space = remaining_width / remaining_columns;
-    for column in table.columns.iter() {
-        // Ignore hidden columns
-        // We already checked this column, skip it
-        if infos.contains_key(&column.index) {
-            continue;
-        }
+    // Fixing a column reduces the average space of all other columns.
+    // Columns that have already been checked might not fit anymore, so check them again.
+    let mut found_smaller = true;
+    while found_smaller {
+        found_smaller = false;
+        for column in table.columns.iter() {
+            // Ignore hidden columns
+            // We already checked this column, skip it
+            if infos.contains_key(&column.index) {
+                continue;
+            }
 
-        // Check whether the column has a LowerBoundary constraint.
-        let min_width =
-            if let Some(min_width) = constraint::min(table, &column.constraint, visible_columns) {
+            // Check whether the column has a LowerBoundary constraint or content that cannot be split.
+            let min_width = if let Some(min_width) = min_width(table, column, visible_columns) {
                 min_width
             } else {
                 continue;
             };
 
-        // Only proceed if the average spaces is smaller than the specified lower boundary.
-        if average_space >= min_width.into() {
-            continue;
-        }
+            // Only proceed if the average spaces is smaller than the specified lower boundary.
+            if average_space >= min_width.into() {
+                continue;
+            }
 
-        // This column would get smaller than the specified lower boundary.
-        // Fix its width!!!
-        let width = absolute_width_with_padding(column, min_width);
-        let info = ColumnDisplayInfo::new(column, width);
-        infos.insert(column.index, info);
+            // This column would get smaller than the specified lower boundary.
+            // Fix its width!!!
+            let width = absolute_width_with_padding(column, min_width);
+            let info = ColumnDisplayInfo::new(column, width);
+            infos.insert(column.index, info);
 
-        #[cfg(feature = "debug")]
-        println!(
-            "dynamic::enforce_lower_boundary_constraints: Fixed column {} to min constraint width {}",
-            column.index, width
-        );
+            #[cfg(feature = "debug")]
+            println!(
+                "dynamic::enforce_lower_boundary_constraints: Fixed column {} to min constraint width {}",
+                column.index, width
+            );
 
-        // Continue with new recalculated width
-        remaining_width = remaining_width.saturating_sub(width.into());
-        remaining_columns -= 1;
-        if remaining_columns == 0 {
-            break;
+            // Continue with new recalculated width
+            remaining_width = remaining_width.saturating_sub(width.into());
+            remaining_columns -= 1;
+            if remaining_columns == 0 {
+                return (remaining_width, remaining_columns);
+            }
+            average_space = remaining_width / remaining_columns;
+            found_smaller = true;
         }
-        average_space = remaining_width / remaining_columns;
-        continue;
     }
 
     (remaining_width, remaining_columns)
 }
 
+/// Determine the minimum width of a column, including its padding.
+///
+/// This is the maximum of a [LowerBoundary] constraint and the width of [preformatted](crate::Cell::preformatted)
+/// content in this column, as preformatted content cannot be split.\
+/// The same goes for the longest word, if the table's [WrapPolicy] keeps words as a whole.\
+/// The width of such content never exceeds an upper boundary constraint.
+fn min_width(table: &Table, column: &Column, visible_columns: usize) -> Option<u16> {
+    let lower_boundary = constraint::min(table, &column.constraint, visible_columns);
+
+    let mut unsplittable = match (
+        preformatted_width(table, column),
+        longest_word_width(table, column),
+    ) {
+        (Some(preformatted), Some(word)) => Some(std::cmp::max(preformatted, word)),
+        (preformatted, word) => preformatted.or(word),
+    }
+    .map(|width| width.saturating_add(column.padding_width()));
+    if let (Some(width), Some(max_width)) = (
+        unsplittable,
+        constraint::max(table, &column.constraint, visible_columns),
+    ) {
+        unsplittable = Some(std::cmp::min(width, max_width));
+    }
+
+    match (lower_boundary, unsplittable) {
+        (Some(lower), Some(unsplittable)) => Some(std::cmp::max(lower, unsplittable)),
+        (lower, unsplittable) => lower.or(unsplittable),
+    }
+}
+
 /// Step 5.
 ///
 /// Some Column's are too big and need to be split.
@@ -377,6 +417,7 @@ fn optimize_space_after_split(
     infos: &mut DisplayInfos,
     mut remaining_width: usize,
     mut remaining_columns: usize,
+    mut splits: Option<&mut SplitCache>,
 ) -> (usize, usize) {
     let mut found_smaller = true;
     // Calculate the average space that remains for each column.
@@ -397,7 +438,13 @@ fn optimize_space_after_split(
                 continue;
             }
 
-            let longest_line = longest_line_after_split(average_space, column, table);
+            // Reuse the result of a previous arrangement, if the content didn't change.
+            let longest_line = match splits.as_deref_mut() {
+                Some(splits) => splits.longest_line(column.index, average_space, || {
+                    longest_line_after_split(average_space, column, table)
+                }),
+                None => longest_line_after_split(average_space, column, table),
+            };
 
             #[cfg(feature = "debug")]
             println!(
@@ -440,9 +487,7 @@ fn optimize_space_after_split(
 ///
 /// A lot of this logic is duplicated from the [utils::format::format_row] function.
 fn longest_line_after_split(average_space: usize, column: &Column, table: &Table) -> usize {
-    // Collect all resulting lines of the column in a single vector.
-    // That way we can easily determine the longest line afterwards.
-    let mut column_lines = Vec::new();
+    let mut longest_line = 0;
 
     // Iterate
     for cell in table.column_cells_with_header_iter(column.index) {
@@ -457,32 +502,54 @@ fn longest_line_after_split(average_space: usize, column: &Column, table: &Table
         // Create a temporary ColumnDisplayInfo with the average space as width.
         // That way we can simulate how the split text will look like.
         let info = ColumnDisplayInfo::new(column, average_space.try_into().unwrap_or(u16::MAX));
+        // Cells with their own padding have a different amount of space for their content.
+        let info = info.for_cell(cell);
+        let space = usize::from(info.content_width);
+
+        // Collect all resulting lines of the cell in a single vector.
+        // That way we can easily determine the longest line afterwards.
+        let mut cell_lines = Vec::new();
 
         // Iterate over each line and split it into multiple lines, if necessary.
         // Newlines added by the user will be preserved.
-        for line in cell.content.iter() {
-            if line.width() > average_space {
-                let mut parts = split_line(line, &info, delimiter);
+        for line in cell.display_lines(table.trim_mode).iter() {
+            if cell.truncate {
+                // Truncated content is cut off and never takes up more than the available space.
+                let (kept, _) = split_long_word(space, line, table.measure());
+                cell_lines.push(kept);
+            } else if cell.preformatted {
+                // Preformatted content is never split.
+                cell_lines.push(line.into());
+            } else if measure_text_width(line, table.measure()) > space {
+                let mut parts =
+                    split_line(line, &info, delimiter, table.wrap_policy, table.measure());
 
                 #[cfg(feature = "debug")]
                 println!(
                     "dynamic::longest_line_after_split: Splitting line with width {}. Original:\n    {}\nSplitted:\n    {:?}",
-                    line.width(), line, parts
+                    measure_text_width(line, table.measure()), line, parts
                 );
 
-                column_lines.append(&mut parts);
+                cell_lines.append(&mut parts);
             } else {
-                column_lines.push(line.into());
+                cell_lines.push(line.into());
             }
         }
+
+        let cell_longest_line = cell_lines
+            .iter()
+            .map(|line| measure_text_width(line, table.measure()))
+            .max()
+            .unwrap_or(0);
+        // Translate the width back into the content width of the column.
+        let cell_longest_line =
+            (cell_longest_line + usize::from(info.padding.0) + usize::from(info.padding.1))
+                .saturating_sub(usize::from(column.padding_width()));
+        longest_line = longest_line.max(cell_longest_line);
     }
 
-    // Get the longest line, default to length 0 if no lines exist.
-    column_lines
-        .iter()
-        .map(|line| line.width())
-        .max()
-        .unwrap_or(0)
+    // Default to length 0 if no lines exist.
+    longest_line
 }
 
 /// Step 6 - First branch
@@ -491,7 +558,7 @@ fn longest_line_after_split(average_space: usize, column: &Column, table: &Table
 /// The user wants to utilize the full width of the terminal and there's space left.
 ///
 /// Equally distribute the remaining space between all columns.
-fn use_full_width(infos: &mut DisplayInfos, remaining_width: usize) {
+pub(super) fn use_full_width(infos: &mut DisplayInfos, remaining_width: usize, bias: Bias) {
     let visible_columns = infos.iter().filter(|(_, info)| !info.is_hidden).count();
 
     if visible_columns == 0 {
@@ -500,26 +567,99 @@ fn use_full_width(infos: &mut DisplayInfos, remaining_width: usize) {
 
     // Calculate the amount of average remaining space per column.
     // Since we do integer division, there is most likely a little bit of non equally-divisible space.
-    // We then try to distribute it as fair as possible (from left to right).
+    // We then try to distribute it as fair as possible, starting on the side of the bias.
     let average_space = remaining_width / visible_columns;
-    let mut excess = remaining_width - (average_space * visible_columns);
+    let excess = remaining_width - (average_space * visible_columns);
 
-    for (_, info) in infos.iter_mut() {
-        // Ignore hidden columns
-        if info.is_hidden {
-            continue;
+    let visible_infos = infos.iter_mut().filter(|(_, info)| !info.is_hidden);
+    for (position, (_, info)) in visible_infos.enumerate() {
+        let mut width = average_space;
+        if receives_excess(position, visible_columns, excess, bias) {
+            width += 1;
         }
 
-        // Distribute the non-divisible excess from left-to right until nothing is left.
-        let width = if excess > 0 {
-            excess -= 1;
-            (average_space + 1).try_into().unwrap_or(u16::MAX)
-        } else {
-            average_space.try_into().unwrap_or(u16::MAX)
-        };
+        info.content_width = info
+            .content_width
+            .saturating_add(width.try_into().unwrap_or(u16::MAX));
+    }
+}
 
-        info.content_width += width;
+/// Step 6 - First branch, continued
+///
+/// Section titles and the empty message span over all visible columns, but they aren't part of
+/// any column. The same goes for the title and the caption of the table, which span over the
+/// whole table. Hence, they aren't considered when the columns' widths are determined.
+///
+/// If the spanning content doesn't fit into the combined width of the columns, the spare width
+/// is distributed across all columns, until the content fits or there's no space left.
+/// This prevents words of the spanning content from being split, while the table could still grow.
+///
+/// This is used by the disabled arrangement as well, which doesn't have any width limit.
+pub(super) fn grow_for_spanning_content(
+    table: &Table,
+    infos: &mut DisplayInfos,
+    remaining_width: usize,
+) {
+    let needed = std::cmp::max(
+        spanning_content_width(table).saturating_sub(spanning_width(table, infos)),
+        title_width(table).saturating_sub(arranged_table_width(table, infos)),
+    );
+    if needed == 0 {
+        return;
     }
+
+    let extra = std::cmp::min(needed, remaining_width);
+    use_full_width(infos, extra, table.center_bias);
+}
+
+/// The width of the whole table with the columns arranged so far.
+fn arranged_table_width(table: &Table, infos: &DisplayInfos) -> usize {
+    let visible: Vec<&ColumnDisplayInfo> = infos.values().filter(|info| !info.is_hidden).collect();
+    let columns: usize = visible.iter().map(|info| usize::from(info.width())).sum();
+
+    columns + count_border_columns(table, visible.len())
+}
+
+/// The display width of the widest line of all section titles and the empty message.
+fn spanning_content_width(table: &Table) -> usize {
+    let message = table
+        .empty_message
+        .as_ref()
+        .filter(|_| table.shows_empty_message())
+        .map(Cell::new);
+
+    table
+        .sections
+        .iter()
+        .map(|section| &section.title)
+        .chain(message.iter())
+        .flat_map(|cell| {
+            cell.display_lines(table.trim_mode)
+                .iter()
+                .map(|line| measure_text_width(line, table.measure()))
+                .collect::<Vec<usize>>()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// The width that's available to the content of a cell that spans over all visible columns.
+///
+/// That's the width of all columns and the vertical lines in between, minus the outer paddings.
+fn spanning_width(table: &Table, infos: &DisplayInfos) -> usize {
+    let visible: Vec<&ColumnDisplayInfo> = infos.values().filter(|info| !info.is_hidden).collect();
+    let (first, last) = match (visible.first(), visible.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return 0,
+    };
+
+    let columns: usize = visible.iter().map(|info| usize::from(info.width())).sum();
+    let borders = count_border_columns(table, visible.len());
+    let outer_borders = count_border_columns(table, 1);
+
+    (columns + borders - outer_borders)
+        .saturating_sub(usize::from(first.padding.0))
+        .saturating_sub(usize::from(last.padding.1))
 }
 
 /// Step 6 - Second branch
@@ -529,32 +669,40 @@ fn use_full_width(infos: &mut DisplayInfos, remaining_width: usize) {
 ///
 /// This function now equally distributes the remaining width between the remaining columns.
 fn distribute_remaining_space(
-    columns: &[Column],
+    table: &Table,
     infos: &mut DisplayInfos,
     remaining_width: usize,
     remaining_columns: usize,
 ) {
     // Calculate the amount of average remaining space per column.
     // Since we do integer division, there is most likely a little bit of non equally-divisible space.
-    // We then try to distribute it as fair as possible (from left to right).
+    // We then try to distribute it as fair as possible, starting on the side of the bias.
     let average_space = remaining_width / remaining_columns;
-    let mut excess = remaining_width - (average_space * remaining_columns);
+    let excess = remaining_width - (average_space * remaining_columns);
 
-    for column in columns.iter() {
-        // Ignore hidden columns
+    let mut position = 0;
+    for column in table.columns.iter() {
+        // Ignore columns whose width has already been determined and hidden columns
         if infos.contains_key(&column.index) {
             continue;
         }
 
-        // Distribute the non-divisible excess from left-to right until nothing is left.
-        let width = if excess > 0 {
-            excess -= 1;
-            (average_space + 1).try_into().unwrap_or(u16::MAX)
-        } else {
-            average_space.try_into().unwrap_or(u16::MAX)
-        };
+        let mut width = average_space;
+        if receives_excess(position, remaining_columns, excess, table.center_bias) {
+            width += 1;
+        }
+        position += 1;
 
-        let info = ColumnDisplayInfo::new(column, width);
+        let info = ColumnDisplayInfo::new(column, width.try_into().unwrap_or(u16::MAX));
         infos.insert(column.index, info);
     }
 }
+
+/// Space that cannot be split evenly between columns is handed out one character per column,
+/// starting with the left-most or right-most column, depending on the bias.
+fn receives_excess(position: usize, columns: usize, excess: usize, bias: Bias) -> bool {
+    match bias {
+        Bias::Left => position < excess,
+        Bias::Right => position + excess >= columns,
+    }
+}
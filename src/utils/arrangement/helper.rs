@@ -1,7 +1,15 @@
-use super::DisplayInfos;
+use super::{constraint, DisplayInfos};
+use crate::style::{ColumnConstraint, ContentArrangement, WrapPolicy};
 use crate::utils::formatting::borders::{
-    should_draw_left_border, should_draw_right_border, should_draw_vertical_lines,
+    should_draw_left_border, should_draw_right_border, should_draw_vertical_lines, BorderWidths,
 };
+#[cfg(feature = "regex")]
+use crate::utils::formatting::content_split::split_line_by_regex;
+use crate::utils::formatting::content_split::{
+    measure_text_width, split_line_after_delimiters, split_line_by_delimiter,
+    widest_character_width,
+};
+use crate::utils::ColumnDisplayInfo;
 use crate::{Cell, Column, Table};
 
 /// The ColumnDisplayInfo works with a fixed value for content width.
@@ -35,21 +43,145 @@ pub fn count_remaining_columns(column_count: usize, infos: &DisplayInfos) -> usi
     column_count - infos.iter().filter(|(_, info)| !info.is_hidden).count()
 }
 
-/// Return the amount of border columns, that will be visible in the final table output.
+/// Return the width of all border columns, that will be visible in the final table output.
+///
+/// Border characters might be wider than a single column, see [BorderWidths].
 pub fn count_border_columns(table: &Table, visible_columns: usize) -> usize {
-    let mut lines = 0;
+    let widths = BorderWidths::new(table);
+    let mut width = 0;
     // Remove space occupied by borders from remaining_width
     if should_draw_left_border(table) {
-        lines += 1;
+        width += widths.left;
     }
     if should_draw_right_border(table) {
-        lines += 1;
+        width += widths.right;
     }
     if should_draw_vertical_lines(table) {
-        lines += visible_columns.saturating_sub(1);
+        width += widths.vertical * visible_columns.saturating_sub(1);
+    }
+
+    width
+}
+
+/// Return the smallest width the table can possibly be arranged to.
+///
+/// Every visible column needs at least a single character of content plus its padding.
+/// Constraints that enforce a minimum width on a column are respected as well.
+/// Paddings aren't included, if they [may collapse](Table::allow_padding_collapse).
+pub fn minimum_table_width(table: &Table) -> usize {
+    let visible_columns = count_visible_columns(&table.columns);
+    let max_content_widths = table.column_max_content_widths();
+
+    let collapses_padding =
+        table.padding_collapse && !matches!(table.arrangement, ContentArrangement::Disabled);
+
+    let mut width = count_border_columns(table, visible_columns);
+    for column in table.columns.iter() {
+        // Paddings may collapse entirely, unless the column has an absolute width.
+        let padding_collapses =
+            collapses_padding && !matches!(column.constraint, Some(ColumnConstraint::Absolute(_)));
+
+        let content_width = match &column.constraint {
+            Some(ColumnConstraint::Hidden) => continue,
+            Some(ColumnConstraint::ContentWidth) => max_content_widths[column.index],
+            Some(ColumnConstraint::Absolute(absolute)) => {
+                match constraint::absolute_value_from_width(table, absolute, visible_columns) {
+                    Some(absolute) => absolute_width_with_padding(column, absolute),
+                    None => 1,
+                }
+            }
+            constraint => match constraint::min(table, constraint, visible_columns) {
+                Some(min) if padding_collapses => min,
+                Some(min) => absolute_width_with_padding(column, min),
+                None => 1,
+            },
+        };
+
+        width += usize::from(std::cmp::max(1, content_width));
+        if !padding_collapses {
+            width += usize::from(column.padding_width());
+        }
     }
 
-    lines
+    width
+}
+
+/// Return the smallest width a column can be arranged to without cutting off or
+/// overflowing any of its content.
+///
+/// In contrast to [minimum_table_width], this takes the column's content into account.
+/// See [unsplittable_width] for the content that cannot be split any further.
+/// Hidden columns have a width of `0`.
+pub fn min_possible_column_width(table: &Table, column: &Column, visible_columns: usize) -> usize {
+    let content_width = match &column.constraint {
+        Some(ColumnConstraint::Hidden) => return 0,
+        Some(ColumnConstraint::ContentWidth) => table.column_max_content_widths()[column.index],
+        Some(ColumnConstraint::Absolute(absolute)) => {
+            match constraint::absolute_value_from_width(table, absolute, visible_columns) {
+                Some(absolute) => absolute_width_with_padding(column, absolute),
+                None => unsplittable_width(table, column),
+            }
+        }
+        constraint => {
+            let mut width = unsplittable_width(table, column);
+            if let Some(max) = constraint::max(table, constraint, visible_columns) {
+                width = std::cmp::min(width, absolute_width_with_padding(column, max));
+            }
+            if let Some(min) = constraint::min(table, constraint, visible_columns) {
+                width = std::cmp::max(width, absolute_width_with_padding(column, min));
+            }
+            width
+        }
+    };
+
+    usize::from(std::cmp::max(1, content_width)) + usize::from(column.padding_width())
+}
+
+/// Return the width of the widest piece of content in a column that cannot be split any further.
+///
+/// Depending on the table's [WrapPolicy], that's either the widest character or the longest word.
+/// As these might end up on an indented continuation line, the column's hanging indent is added.
+/// Lines of preformatted content cannot be split at all, see [preformatted_width].
+/// [Truncated](Cell::set_truncate) cells are ignored, as they're cut off anyway.
+pub fn unsplittable_width(table: &Table, column: &Column) -> u16 {
+    let widest_character = table
+        .column_cells_with_header_iter(column.index)
+        .flatten()
+        .filter(|cell| !cell.preformatted && !cell.truncate)
+        .flat_map(|cell| {
+            cell.display_lines(table.trim_mode)
+                .iter()
+                .map(|line| {
+                    widest_character_width(line, table.measure())
+                        .try_into()
+                        .unwrap_or(u16::MAX)
+                })
+                .collect::<Vec<u16>>()
+        })
+        .max()
+        .map(|width| width.saturating_add(column.hanging_indent));
+
+    [
+        widest_character,
+        longest_word_width(table, column),
+        preformatted_width(table, column),
+    ]
+    .into_iter()
+    .flatten()
+    .max()
+    .unwrap_or(1)
+}
+
+/// Return the width of the final table, given the arranged columns.
+pub fn table_width(table: &Table, display_info: &[ColumnDisplayInfo]) -> usize {
+    let visible_columns = display_info.iter().filter(|info| !info.is_hidden).count();
+
+    display_info
+        .iter()
+        .filter(|info| !info.is_hidden)
+        .map(|info| usize::from(info.width()))
+        .sum::<usize>()
+        + count_border_columns(table, visible_columns)
 }
 
 /// Get the delimiter for a Cell.
@@ -66,3 +198,77 @@ pub fn delimiter(table: &Table, column: &Column, cell: &Cell) -> char {
         ' '
     }
 }
+
+/// Return the width of the widest line of [preformatted](Cell::preformatted) content in a column.
+///
+/// Preformatted content cannot be split, which is why the column should be at least this wide.
+/// Returns `None`, if there's no preformatted content in this column.
+/// [Truncated](Cell::set_truncate) cells are ignored, as they're cut off anyway.
+pub fn preformatted_width(table: &Table, column: &Column) -> Option<u16> {
+    table
+        .column_cells_with_header_iter(column.index)
+        .flatten()
+        .filter(|cell| cell.preformatted && !cell.truncate)
+        .flat_map(|cell| {
+            cell.display_lines(table.trim_mode)
+                .iter()
+                .map(|line| {
+                    measure_text_width(line, table.measure())
+                        .try_into()
+                        .unwrap_or(u16::MAX)
+                })
+                .collect::<Vec<u16>>()
+        })
+        .max()
+}
+
+/// Return the width of the longest word in a column, if words may not be split.
+/// The column's hanging indent is included, as the word might end up on a continuation line.
+///
+/// Returns `None`, if the table's [WrapPolicy] allows to split words.
+/// Preformatted content is ignored, see [preformatted_width].
+/// [Truncated](Cell::set_truncate) cells are ignored, as they're cut off anyway.
+pub fn longest_word_width(table: &Table, column: &Column) -> Option<u16> {
+    if table.wrap_policy != WrapPolicy::KeepWords {
+        return None;
+    }
+
+    table
+        .column_cells_with_header_iter(column.index)
+        .flatten()
+        .filter(|cell| !cell.preformatted && !cell.truncate)
+        .flat_map(|cell| {
+            let delimiter = delimiter(table, column, cell);
+            cell.display_lines(table.trim_mode)
+                .iter()
+                .flat_map(|line| words(line, column, delimiter))
+                .map(|word| {
+                    measure_text_width(&word, table.measure())
+                        .try_into()
+                        .unwrap_or(u16::MAX)
+                })
+                .collect::<Vec<u16>>()
+        })
+        .max()
+        .map(|width| width.saturating_add(column.hanging_indent))
+}
+
+/// Split a line into the words, which are kept as a whole.
+fn words(line: &str, column: &Column, delimiter: char) -> Vec<String> {
+    #[cfg(feature = "regex")]
+    if let Some(regex) = column.split_regex.as_ref() {
+        return split_line_by_regex(line, regex)
+            .into_iter()
+            .map(|word| word.trim_end().to_string())
+            .collect();
+    }
+
+    if !column.delimiters.is_empty() {
+        return split_line_after_delimiters(line, &column.delimiters)
+            .into_iter()
+            .map(|word| word.trim_end().to_string())
+            .collect();
+    }
+
+    split_line_by_delimiter(line, delimiter)
+}
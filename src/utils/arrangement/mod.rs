@@ -8,12 +8,29 @@ pub mod constraint;
 mod disabled;
 mod dynamic;
 pub mod helper;
+mod padding;
+pub(crate) mod split_cache;
+
+use split_cache::SplitCache;
 
 type DisplayInfos = BTreeMap<usize, ColumnDisplayInfo>;
 
 /// Determine the width of each column depending on the content of the given table.
 /// The results uses Option<usize>, since users can choose to hide columns.
 pub fn arrange_content(table: &Table) -> Vec<ColumnDisplayInfo> {
+    arrange(table, None)
+}
+
+/// Same as [arrange_content], but results of simulated splits are taken from and stored in
+/// the given cache.
+pub(crate) fn arrange_content_with_cache(
+    table: &Table,
+    splits: &mut SplitCache,
+) -> Vec<ColumnDisplayInfo> {
+    arrange(table, Some(splits))
+}
+
+fn arrange(table: &Table, splits: Option<&mut SplitCache>) -> Vec<ColumnDisplayInfo> {
     let table_width = table.width().map(usize::from);
     let mut infos = BTreeMap::new();
 
@@ -41,16 +58,49 @@ pub fn arrange_content(table: &Table) -> Vec<ColumnDisplayInfo> {
     let table_width = if let Some(table_width) = table_width {
         table_width
     } else {
+        #[cfg(feature = "debug")]
+        if !matches!(table.arrangement, ContentArrangement::Disabled) {
+            println!(
+                "Warning: The table width is unknown, falling back to ContentArrangement::Disabled. \
+                Use Table::set_fallback_width for a predictable layout."
+            );
+        }
+
         disabled::arrange(table, &mut infos, visible_columns, &max_content_widths);
-        return infos.into_values().collect();
+        return enforce_min_width(table, infos);
     };
 
+    let table = padding::collapse_padding(table, table_width);
+    let table = table.as_ref();
     match &table.arrangement {
         ContentArrangement::Disabled => {
             disabled::arrange(table, &mut infos, visible_columns, &max_content_widths)
         }
         ContentArrangement::Dynamic | ContentArrangement::DynamicFullWidth => {
-            dynamic::arrange(table, &mut infos, table_width, &max_content_widths);
+            dynamic::arrange(table, &mut infos, table_width, &max_content_widths, splits);
+        }
+    }
+
+    enforce_min_width(table, infos)
+}
+
+/// Expand the columns, if the arranged table is narrower than its [minimum width](Table::set_min_width).
+fn enforce_min_width(table: &Table, mut infos: DisplayInfos) -> Vec<ColumnDisplayInfo> {
+    if let Some(min_width) = table.min_width {
+        let visible_columns = infos.values().filter(|info| !info.is_hidden).count();
+        let width = infos
+            .values()
+            .filter(|info| !info.is_hidden)
+            .map(|info| usize::from(info.width()))
+            .sum::<usize>()
+            + helper::count_border_columns(table, visible_columns);
+
+        if width < usize::from(min_width) {
+            dynamic::use_full_width(
+                &mut infos,
+                usize::from(min_width) - width,
+                table.center_bias,
+            );
         }
     }
 
@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use super::constraint;
+use super::helper::{count_border_columns, count_visible_columns};
+use crate::style::{ColumnConstraint, ContentArrangement};
+use crate::Table;
+
+/// Return the table with reduced paddings, if its content doesn't fit into the given width
+/// otherwise, see [Table::allow_padding_collapse](crate::Table::allow_padding_collapse).
+///
+/// Paddings are reduced column by column, one space at a time, until the table fits or all
+/// paddings are gone. The larger side of each padding is reduced first, the left one on a tie.\
+/// The table is only cloned, if any padding needs to be reduced.
+pub(crate) fn collapse_padding(table: &Table, table_width: usize) -> Cow<'_, Table> {
+    if !table.padding_collapse || matches!(table.arrangement, ContentArrangement::Disabled) {
+        return Cow::Borrowed(table);
+    }
+
+    let visible_columns = count_visible_columns(&table.columns);
+    let max_content_widths = table.column_max_content_widths();
+
+    // The width the table would take, if no content had to be split.
+    // Only columns whose width depends on their padding can be shrunk.
+    let mut natural_width = count_border_columns(table, visible_columns);
+    let mut collapsible = Vec::new();
+    for column in table.columns.iter() {
+        if column.is_hidden() {
+            continue;
+        }
+
+        if let Some(ColumnConstraint::Absolute(absolute)) = &column.constraint {
+            let width = constraint::absolute_value_from_width(table, absolute, visible_columns);
+            natural_width += usize::from(width.unwrap_or(1));
+            continue;
+        }
+
+        let width = usize::from(std::cmp::max(1, max_content_widths[column.index]))
+            + usize::from(column.padding_width());
+        match constraint::max(table, &column.constraint, visible_columns) {
+            Some(max) if usize::from(max) < width => natural_width += usize::from(max),
+            _ => {
+                natural_width += width;
+                collapsible.push(column.index);
+            }
+        }
+    }
+
+    let mut excess = natural_width.saturating_sub(table_width);
+    if excess == 0 || collapsible.is_empty() {
+        return Cow::Borrowed(table);
+    }
+
+    let mut table = table.clone();
+    let mut reduced = true;
+    while excess > 0 && reduced {
+        reduced = false;
+        for index in collapsible.iter() {
+            if excess == 0 {
+                break;
+            }
+
+            let padding = &mut table.columns[*index].padding;
+            if padding.0 >= padding.1 && padding.0 > 0 {
+                padding.0 -= 1;
+            } else if padding.1 > 0 {
+                padding.1 -= 1;
+            } else {
+                continue;
+            }
+            excess -= 1;
+            reduced = true;
+        }
+    }
+
+    Cow::Owned(table)
+}
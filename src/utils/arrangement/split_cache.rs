@@ -0,0 +1,157 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::table::Table;
+
+/// Results of simulated splits from previous arrangements of the same table.
+///
+/// Simulating how the content of a column is split is the most expensive part of the dynamic
+/// arrangement. The results only depend on the content of the column and the width that's
+/// allotted to it, so they can be reused as long as the content's hash stays the same.
+///
+/// The cache assumes that the settings of the table and its columns don't change.
+/// Create a new cache, if they do.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SplitCache {
+    columns: HashMap<usize, ColumnSplits>,
+}
+
+#[derive(Clone, Debug)]
+struct ColumnSplits {
+    /// The hash of the column's content when the splits were simulated.
+    hash: u64,
+    /// The allotted width mapped to the longest line after splitting the content.
+    longest_lines: HashMap<usize, usize>,
+}
+
+impl SplitCache {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash the content of the given columns and forget all results of columns whose content
+    /// changed.
+    ///
+    /// Columns that aren't known to the cache yet are always hashed.
+    pub(crate) fn refresh<'a>(&mut self, table: &Table, columns: impl Iterator<Item = &'a usize>) {
+        let mut columns: Vec<usize> = columns.copied().collect();
+        columns.extend(
+            table
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .filter(|index| !self.columns.contains_key(index)),
+        );
+        self.columns.retain(|index, _| *index < table.columns.len());
+
+        for index in columns {
+            let hash = column_hash(table, index);
+            match self.columns.get_mut(&index) {
+                Some(splits) if splits.hash == hash => {}
+                _ => {
+                    self.columns.insert(
+                        index,
+                        ColumnSplits {
+                            hash,
+                            longest_lines: HashMap::new(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Get the longest line of a column after splitting its content to the given width.
+    /// The result is only computed, if it isn't known yet.
+    pub(crate) fn longest_line(
+        &mut self,
+        column: usize,
+        width: usize,
+        compute: impl FnOnce() -> usize,
+    ) -> usize {
+        match self.columns.get_mut(&column) {
+            Some(splits) => *splits.longest_lines.entry(width).or_insert_with(compute),
+            // The content of this column hasn't been hashed, so the result cannot be cached.
+            None => compute(),
+        }
+    }
+}
+
+/// Hash everything about the cells of a column that has an influence on how it's split.
+fn column_hash(table: &Table, index: usize) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for cell in table.column_cells_with_header_iter(index) {
+        match cell {
+            Some(cell) => {
+                cell.content.hash(&mut hasher);
+                cell.delimiter.hash(&mut hasher);
+                cell.padding.hash(&mut hasher);
+                cell.preformatted.hash(&mut hasher);
+                cell.preserve_whitespace.hash(&mut hasher);
+                cell.raw_ansi.hash(&mut hasher);
+                cell.truncate.hash(&mut hasher);
+            }
+            None => 0u8.hash(&mut hasher),
+        }
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::arrangement::{arrange_content, arrange_content_with_cache};
+    use crate::ContentArrangement;
+
+    #[test]
+    fn results_are_reused_until_content_changes() {
+        let mut table = Table::new();
+        table.add_row(vec!["Some long text", "More text"]);
+
+        let mut cache = SplitCache::new();
+        cache.refresh(&table, [].iter());
+        assert_eq!(cache.longest_line(0, 5, || 4), 4);
+        // The cached result is used.
+        assert_eq!(cache.longest_line(0, 5, || unreachable!()), 4);
+
+        // Setting the same content keeps the cached results.
+        table.row_mut(0).unwrap().cells[0] = "Some long text".into();
+        cache.refresh(&table, [0].iter());
+        assert_eq!(cache.longest_line(0, 5, || unreachable!()), 4);
+
+        // New content invalidates the column's results.
+        table.row_mut(0).unwrap().cells[0] = "Other text".into();
+        cache.refresh(&table, [0].iter());
+        assert_eq!(cache.longest_line(0, 5, || 5), 5);
+    }
+
+    #[test]
+    fn cached_arrangement_matches_arrangement() {
+        let mut table = Table::new();
+        table
+            .set_header(vec!["Header1", "Header2", "Header3"])
+            .add_row(vec![
+                "This is a very long line of text",
+                "short",
+                "And another pretty long line of text",
+            ])
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_width(50);
+
+        let mut cache = SplitCache::new();
+        for _ in 0..2 {
+            cache.refresh(&table, [].iter());
+            let cached: Vec<u16> = arrange_content_with_cache(&table, &mut cache)
+                .iter()
+                .map(|info| info.content_width)
+                .collect();
+            let expected: Vec<u16> = arrange_content(&table)
+                .iter()
+                .map(|info| info.content_width)
+                .collect();
+            assert_eq!(cached, expected);
+        }
+    }
+}
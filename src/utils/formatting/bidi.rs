@@ -0,0 +1,94 @@
+use unicode_bidi::{
+    bidi_class, get_base_direction, BidiClass, Direction, ParagraphBidiInfo, LTR_LEVEL, RTL_LEVEL,
+};
+
+/// Reorder the lines that a single line of content has been split into from logical into
+/// visual order.
+///
+/// The direction of the paragraph is determined by the first strong character of the whole
+/// content, so all of its lines are displayed in the same direction.
+pub(crate) fn reorder_lines(content: &str, lines: &mut [String]) {
+    let level = match get_base_direction(content) {
+        Direction::Rtl => RTL_LEVEL,
+        _ => LTR_LEVEL,
+    };
+
+    for line in lines.iter_mut() {
+        // Escape sequences would be torn apart.
+        if line.contains('\x1b') {
+            continue;
+        }
+
+        let info = ParagraphBidiInfo::new(line, Some(level));
+        if !info.has_rtl() {
+            continue;
+        }
+        let reordered: String = info
+            .reorder_line(0..line.len())
+            .chars()
+            .filter(|character| !is_formatting_character(*character))
+            .collect();
+        *line = reordered;
+    }
+}
+
+/// Check whether the majority of strong characters of the lines are right-to-left.
+pub(crate) fn is_rtl_dominant(lines: &[String]) -> bool {
+    let (rtl, ltr) =
+        lines
+            .iter()
+            .flat_map(|line| line.chars())
+            .fold((0, 0), |(rtl, ltr), character| {
+                match bidi_class(character) {
+                    BidiClass::R | BidiClass::AL => (rtl + 1, ltr),
+                    BidiClass::L => (rtl, ltr + 1),
+                    _ => (rtl, ltr),
+                }
+            });
+
+    rtl > ltr
+}
+
+/// Explicit directional formatting characters have no meaning after the content has been
+/// reordered. Some terminals display them, which would break the alignment of the columns.
+fn is_formatting_character(character: char) -> bool {
+    matches!(
+        character,
+        '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reorder_mixed_lines() {
+        // "abc אבג" followed by a wrapped line, which only consists of RTL characters.
+        let content = "abc \u{5d0}\u{5d1}\u{5d2} \u{5d3}\u{5d4}";
+        let mut lines = vec![
+            "abc \u{5d0}\u{5d1}\u{5d2}".to_string(),
+            "\u{5d3}\u{5d4}".to_string(),
+        ];
+        reorder_lines(content, &mut lines);
+
+        assert_eq!(lines[0], "abc \u{5d2}\u{5d1}\u{5d0}");
+        assert_eq!(lines[1], "\u{5d4}\u{5d3}");
+    }
+
+    #[test]
+    fn remove_formatting_characters() {
+        let content = "\u{2067}\u{5d0}\u{5d1}\u{2069}";
+        let mut lines = vec![content.to_string()];
+        reorder_lines(content, &mut lines);
+
+        assert_eq!(lines[0], "\u{5d1}\u{5d0}");
+    }
+
+    #[test]
+    fn rtl_dominance() {
+        assert!(is_rtl_dominant(&["\u{5d0}\u{5d1} a".to_string()]));
+        assert!(!is_rtl_dominant(&["\u{5d0} ab".to_string()]));
+        assert!(!is_rtl_dominant(&["123".to_string()]));
+    }
+}
@@ -1,40 +1,214 @@
+use std::ops::Range;
+
+#[cfg(feature = "tty")]
+use super::content_format::style_line;
+use super::content_format::{row_kinds, RowKind};
+use super::content_split::measure_text_width;
 use crate::style::TableComponent;
 use crate::table::Table;
 use crate::utils::ColumnDisplayInfo;
 
+/// All components that are drawn in the left border column of the table.
+const LEFT_BORDER: [TableComponent; 5] = [
+    TableComponent::TopLeftCorner,
+    TableComponent::LeftBorder,
+    TableComponent::LeftBorderIntersections,
+    TableComponent::LeftHeaderIntersection,
+    TableComponent::BottomLeftCorner,
+];
+
+/// All components that are drawn in the right border column of the table.
+const RIGHT_BORDER: [TableComponent; 5] = [
+    TableComponent::TopRightCorner,
+    TableComponent::RightBorder,
+    TableComponent::RightBorderIntersections,
+    TableComponent::RightHeaderIntersection,
+    TableComponent::BottomRightCorner,
+];
+
+/// All components that are drawn in the vertical lines between columns.
+const VERTICAL_LINES: [TableComponent; 5] = [
+    TableComponent::TopBorderIntersections,
+    TableComponent::MiddleHeaderIntersections,
+    TableComponent::VerticalLines,
+    TableComponent::MiddleIntersections,
+    TableComponent::BottomBorderIntersections,
+];
+
+/// The widths of the vertical borders of a table.
+///
+/// Border characters aren't necessarily a single column wide, e.g. when CJK characters or
+/// emojis are used. Each vertical border is as wide as its widest character.
+/// Narrower characters of the same border are padded to that width.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BorderWidths {
+    pub(crate) left: usize,
+    pub(crate) vertical: usize,
+    pub(crate) right: usize,
+}
+
+impl BorderWidths {
+    pub(crate) fn new(table: &Table) -> Self {
+        Self {
+            left: border_width(table, &LEFT_BORDER),
+            vertical: border_width(table, &VERTICAL_LINES),
+            right: border_width(table, &RIGHT_BORDER),
+        }
+    }
+}
+
+/// The width of the widest character of a vertical border, but at least 1.
+fn border_width(table: &Table, components: &[TableComponent]) -> usize {
+    components
+        .iter()
+        .filter(|component| table.style_exists(**component))
+        .map(|component| measure_text_width(&table.style_or_default(*component), table.measure()))
+        .fold(1, std::cmp::max)
+}
+
+/// Apply the [style](Table::set_border_style) of a component to a part of the border.
+/// Parts without a component, such as the whitespace padding of vertical borders, aren't styled.
+#[cfg_attr(not(feature = "tty"), allow(unused_variables))]
+fn styled(table: &Table, component: Option<TableComponent>, part: String) -> String {
+    #[cfg(feature = "tty")]
+    if let Some(style) = component.and_then(|component| table.border_styles.get(&component)) {
+        if table.should_style() {
+            return style_line(part, style);
+        }
+    }
+
+    part
+}
+
+/// Repeat the character of a border component until it fills the given width.
+/// Space that's too narrow for another wide character is filled with whitespaces.
+fn fill(table: &Table, component: Option<TableComponent>, character: &str, width: usize) -> String {
+    if width == 0 {
+        return String::new();
+    }
+    let character_width = std::cmp::max(1, measure_text_width(character, table.measure()));
+    let mut line = character.repeat(width / character_width);
+    line += &" ".repeat(width % character_width);
+
+    styled(table, component, line)
+}
+
+/// Pad the character of a border component with the filler to the width of its border.
+/// The character is kept on the side that faces the columns of the table.
+fn pad(
+    table: &Table,
+    component: TableComponent,
+    width: usize,
+    filler: (Option<TableComponent>, &str),
+    right_side: bool,
+) -> String {
+    let character = table.style_or_default(component);
+    let (filler_component, filler) = filler;
+    let padding = fill(
+        table,
+        filler_component,
+        filler,
+        width.saturating_sub(measure_text_width(&character, table.measure())),
+    );
+    let character = styled(table, Some(component), character);
+    if right_side {
+        padding + &character
+    } else {
+        character + &padding
+    }
+}
+
+/// The position of the lines of a single row inside of the drawn table.
+#[derive(Clone, Debug)]
+pub(crate) struct RowLines {
+    /// The lines of the row's content.
+    pub(crate) content: Range<usize>,
+    /// The horizontal line that's drawn after the row, if any.
+    pub(crate) separator: Option<usize>,
+}
+
 pub(crate) fn draw_borders(
     table: &Table,
     rows: &[Vec<Vec<String>>],
     display_info: &[ColumnDisplayInfo],
-) -> Vec<String> {
-    // We know how many lines there should be. Initialize the vector with the rough correct amount.
+    lines: &mut Vec<String>,
+) {
+    draw_borders_with_rows(table, rows, display_info, lines, None);
+}
+
+/// Same as [draw_borders], but the position of each row's lines is recorded as well.
+pub(crate) fn draw_borders_with_rows(
+    table: &Table,
+    rows: &[Vec<Vec<String>>],
+    display_info: &[ColumnDisplayInfo],
+    lines: &mut Vec<String>,
+    row_lines: Option<&mut Vec<RowLines>>,
+) {
+    // We know how many lines there should be. Reserve the rough correct amount.
     // We might over allocate a bit, but that's better than under allocating.
-    let mut lines = if let Some(capacity) = rows.first().map(|lines| lines.len()) {
+    if let Some(capacity) = rows.first().map(|lines| lines.len()) {
         // Lines * 2 -> Lines + delimiters
         // + 5 -> header delimiters + header + bottom/top borders
-        Vec::with_capacity(capacity * 2 + 5)
-    } else {
-        Vec::new()
-    };
+        lines.reserve(capacity * 2 + 5);
+    }
+
+    let widths = BorderWidths::new(table);
+    let kinds = row_kinds(table);
+
+    // Rows that span over all columns don't have any intersections with the borders.
+    let first_spanning = kinds.first().map_or(false, RowKind::is_spanning);
+    let last_spanning = kinds.last().map_or(false, RowKind::is_spanning);
 
     if should_draw_top_border(table) {
-        lines.push(draw_top_border(table, display_info));
+        lines.push(draw_top_border(table, display_info, widths, first_spanning));
     }
 
-    draw_rows(&mut lines, rows, table, display_info);
+    draw_rows(lines, rows, &kinds, table, display_info, widths, row_lines);
 
     if should_draw_bottom_border(table) {
-        lines.push(draw_bottom_border(table, display_info));
+        lines.push(draw_bottom_border(
+            table,
+            display_info,
+            widths,
+            last_spanning,
+        ));
     }
-
-    lines
 }
 
-fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String {
-    let left_corner = table.style_or_default(TableComponent::TopLeftCorner);
+// The top border. If the first row spans over all columns, there're no intersections.
+fn draw_top_border(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    widths: BorderWidths,
+    spanning_row: bool,
+) -> String {
     let top_border = table.style_or_default(TableComponent::TopBorder);
-    let intersection = table.style_or_default(TableComponent::TopBorderIntersections);
-    let right_corner = table.style_or_default(TableComponent::TopRightCorner);
+    let filler = (Some(TableComponent::TopBorder), top_border.as_str());
+    let left_corner = pad(
+        table,
+        TableComponent::TopLeftCorner,
+        widths.left,
+        filler,
+        false,
+    );
+    let intersection = if spanning_row {
+        fill(table, filler.0, &top_border, widths.vertical)
+    } else {
+        pad(
+            table,
+            TableComponent::TopBorderIntersections,
+            widths.vertical,
+            filler,
+            false,
+        )
+    };
+    let right_corner = pad(
+        table,
+        TableComponent::TopRightCorner,
+        widths.right,
+        filler,
+        true,
+    );
 
     let mut line = String::new();
     // We only need the top left corner, if we need to draw a left border
@@ -44,14 +218,16 @@ fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String
 
     // Build the top border line depending on the columns' width.
     // Also add the border intersections.
+    // Intersections are only drawn, if the lines of the content have vertical lines as well.
+    let vertical_lines = should_draw_vertical_lines(table);
     let mut first = true;
     for info in display_info.iter() {
         // Only add something, if the column isn't hidden
         if !info.is_hidden {
-            if !first {
+            if !first && vertical_lines {
                 line += &intersection;
             }
-            line += &top_border.repeat(info.width().into());
+            line += &fill(table, filler.0, &top_border, info.width().into());
             first = false;
         }
     }
@@ -67,37 +243,105 @@ fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String
 fn draw_rows(
     lines: &mut Vec<String>,
     rows: &[Vec<Vec<String>>],
+    kinds: &[RowKind],
     table: &Table,
     display_info: &[ColumnDisplayInfo],
+    widths: BorderWidths,
+    mut row_lines: Option<&mut Vec<RowLines>>,
 ) {
     // Iterate over all rows
-    let mut row_iter = rows.iter().enumerate().peekable();
-    while let Some((row_index, row)) = row_iter.next() {
+    let mut row_iter = rows.iter().zip(kinds.iter()).peekable();
+    while let Some((row, kind)) = row_iter.next() {
+        let start = lines.len();
         // Concatenate the line parts and insert the vertical borders if needed
         for line_parts in row.iter() {
-            lines.push(embed_line(line_parts, table));
+            lines.push(embed_line(line_parts, table, widths));
         }
+        let end = lines.len();
+
+        let next = row_iter.peek().map(|(_, kind)| *kind);
 
-        // Draw the horizontal header line if desired, otherwise continue to the next iteration
-        if row_index == 0 && table.header.is_some() {
-            if should_draw_header(table) {
-                lines.push(draw_horizontal_lines(table, display_info, true));
+        // Determine the line that's drawn after this row.
+        // `Some(true)` is a line in the style of the header line.
+        let mut separator = match (kind, next) {
+            // The horizontal header line, if desired.
+            (RowKind::Header(_), _) => {
+                (table.header_separator && should_draw_header(table)).then_some(true)
             }
-            continue;
+            // There's no line after the last row, as the bottom border follows.
+            (_, None) => None,
+            // Sections are surrounded by lines in the style of the header line.
+            // Fall back to the normal horizontal line, if no header line is drawn.
+            (RowKind::Section(_), _) | (_, Some(RowKind::Section(_))) => {
+                if should_draw_header(table) {
+                    Some(true)
+                } else {
+                    should_draw_horizontal_lines(table).then_some(false)
+                }
+            }
+            // Groups of rows are separated by a line in the style of the header line.
+            // Fall back to the normal horizontal line, if no header line is drawn.
+            (_, Some(RowKind::Body(index, _)))
+                if table.is_group_boundary(*index) && should_draw_header(table) =>
+            {
+                Some(true)
+            }
+            // A horizontal line, if desired.
+            _ => should_draw_horizontal_lines(table).then_some(false),
+        };
+
+        // Rows may enforce or suppress the line after them.
+        let settings = kind.row();
+        match settings.and_then(|settings| settings.separator_after) {
+            Some(false) => separator = None,
+            Some(true) if separator.is_none() && next.is_some() => {
+                separator = Some(!should_draw_horizontal_lines(table) && should_draw_header(table));
+            }
+            _ => (),
+        }
+
+        if let Some(header) = separator {
+            lines.push(draw_horizontal_lines(
+                table,
+                display_info,
+                widths,
+                header,
+                settings.and_then(|settings| settings.separator_char),
+            ));
         }
 
-        // Draw a horizontal line, if we desired and if we aren't in the last row of the table.
-        if row_iter.peek().is_some() && should_draw_horizontal_lines(table) {
-            lines.push(draw_horizontal_lines(table, display_info, false));
+        if let Some(row_lines) = row_lines.as_mut() {
+            row_lines.push(RowLines {
+                content: start..end,
+                separator: separator.map(|_| end),
+            });
         }
     }
 }
 
 // Takes the parts of a single line, surrounds them with borders and adds vertical lines.
-fn embed_line(line_parts: &[String], table: &Table) -> String {
-    let vertical_lines = table.style_or_default(TableComponent::VerticalLines);
-    let left_border = table.style_or_default(TableComponent::LeftBorder);
-    let right_border = table.style_or_default(TableComponent::RightBorder);
+fn embed_line(line_parts: &[String], table: &Table, widths: BorderWidths) -> String {
+    let vertical_lines = pad(
+        table,
+        TableComponent::VerticalLines,
+        widths.vertical,
+        (None, " "),
+        false,
+    );
+    let left_border = pad(
+        table,
+        TableComponent::LeftBorder,
+        widths.left,
+        (None, " "),
+        false,
+    );
+    let right_border = pad(
+        table,
+        TableComponent::RightBorder,
+        widths.right,
+        (None, " "),
+        true,
+    );
 
     let mut line = String::new();
     if should_draw_left_border(table) {
@@ -121,25 +365,38 @@ fn embed_line(line_parts: &[String], table: &Table) -> String {
 fn draw_horizontal_lines(
     table: &Table,
     display_info: &[ColumnDisplayInfo],
+    widths: BorderWidths,
     header: bool,
+    fill_char: Option<char>,
 ) -> String {
     // Styling depends on whether we're currently on the header line or not.
-    let (left_intersection, horizontal_lines, middle_intersection, right_intersection) = if header {
+    let (left_intersection, lines_component, middle_intersection, right_intersection) = if header {
         (
-            table.style_or_default(TableComponent::LeftHeaderIntersection),
-            table.style_or_default(TableComponent::HeaderLines),
-            table.style_or_default(TableComponent::MiddleHeaderIntersections),
-            table.style_or_default(TableComponent::RightHeaderIntersection),
+            TableComponent::LeftHeaderIntersection,
+            TableComponent::HeaderLines,
+            TableComponent::MiddleHeaderIntersections,
+            TableComponent::RightHeaderIntersection,
         )
     } else {
         (
-            table.style_or_default(TableComponent::LeftBorderIntersections),
-            table.style_or_default(TableComponent::HorizontalLines),
-            table.style_or_default(TableComponent::MiddleIntersections),
-            table.style_or_default(TableComponent::RightBorderIntersections),
+            TableComponent::LeftBorderIntersections,
+            TableComponent::HorizontalLines,
+            TableComponent::MiddleIntersections,
+            TableComponent::RightBorderIntersections,
         )
     };
 
+    // Rows may use a custom character for the line after them.
+    let horizontal_lines = match fill_char {
+        Some(character) => character.to_string(),
+        None => table.style_or_default(lines_component),
+    };
+    let filler = (Some(lines_component), horizontal_lines.as_str());
+
+    let left_intersection = pad(table, left_intersection, widths.left, filler, false);
+    let middle_intersection = pad(table, middle_intersection, widths.vertical, filler, false);
+    let right_intersection = pad(table, right_intersection, widths.right, filler, true);
+
     let mut line = String::new();
     // We only need the bottom left corner, if we need to draw a left border
     if should_draw_left_border(table) {
@@ -148,14 +405,16 @@ fn draw_horizontal_lines(
 
     // Append the middle lines depending on the columns' widths.
     // Also add the middle intersections.
+    // Intersections are only drawn, if the lines of the content have vertical lines as well.
+    let vertical_lines = should_draw_vertical_lines(table);
     let mut first = true;
     for info in display_info.iter() {
         // Only add something, if the column isn't hidden
         if !info.is_hidden {
-            if !first {
+            if !first && vertical_lines {
                 line += &middle_intersection;
             }
-            line += &horizontal_lines.repeat(info.width().into());
+            line += &fill(table, filler.0, &horizontal_lines, info.width().into());
             first = false;
         }
     }
@@ -168,11 +427,40 @@ fn draw_horizontal_lines(
     line
 }
 
-fn draw_bottom_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String {
-    let left_corner = table.style_or_default(TableComponent::BottomLeftCorner);
+// The bottom border. If the last row spans over all columns, there're no intersections.
+fn draw_bottom_border(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    widths: BorderWidths,
+    spanning_row: bool,
+) -> String {
     let bottom_border = table.style_or_default(TableComponent::BottomBorder);
-    let middle_intersection = table.style_or_default(TableComponent::BottomBorderIntersections);
-    let right_corner = table.style_or_default(TableComponent::BottomRightCorner);
+    let filler = (Some(TableComponent::BottomBorder), bottom_border.as_str());
+    let left_corner = pad(
+        table,
+        TableComponent::BottomLeftCorner,
+        widths.left,
+        filler,
+        false,
+    );
+    let middle_intersection = if spanning_row {
+        fill(table, filler.0, &bottom_border, widths.vertical)
+    } else {
+        pad(
+            table,
+            TableComponent::BottomBorderIntersections,
+            widths.vertical,
+            filler,
+            false,
+        )
+    };
+    let right_corner = pad(
+        table,
+        TableComponent::BottomRightCorner,
+        widths.right,
+        filler,
+        true,
+    );
 
     let mut line = String::new();
     // We only need the bottom left corner, if we need to draw a left border
@@ -182,14 +470,16 @@ fn draw_bottom_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> Stri
 
     // Add the bottom border lines depending on column width
     // Also add the border intersections.
+    // Intersections are only drawn, if the lines of the content have vertical lines as well.
+    let vertical_lines = should_draw_vertical_lines(table);
     let mut first = true;
     for info in display_info.iter() {
         // Only add something, if the column isn't hidden
         if !info.is_hidden {
-            if !first {
+            if !first && vertical_lines {
                 line += &middle_intersection;
             }
-            line += &bottom_border.repeat(info.width().into());
+            line += &fill(table, filler.0, &bottom_border, info.width().into());
             first = false;
         }
     }
@@ -202,7 +492,7 @@ fn draw_bottom_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> Stri
     line
 }
 
-fn should_draw_top_border(table: &Table) -> bool {
+pub(crate) fn should_draw_top_border(table: &Table) -> bool {
     if table.style_exists(TableComponent::TopLeftCorner)
         || table.style_exists(TableComponent::TopBorder)
         || table.style_exists(TableComponent::TopBorderIntersections)
@@ -227,29 +517,15 @@ fn should_draw_bottom_border(table: &Table) -> bool {
 }
 
 pub fn should_draw_left_border(table: &Table) -> bool {
-    if table.style_exists(TableComponent::TopLeftCorner)
-        || table.style_exists(TableComponent::LeftBorder)
-        || table.style_exists(TableComponent::LeftBorderIntersections)
-        || table.style_exists(TableComponent::LeftHeaderIntersection)
-        || table.style_exists(TableComponent::BottomLeftCorner)
-    {
-        return true;
-    }
-
-    false
+    LEFT_BORDER
+        .iter()
+        .any(|component| table.style_exists(*component))
 }
 
 pub fn should_draw_right_border(table: &Table) -> bool {
-    if table.style_exists(TableComponent::TopRightCorner)
-        || table.style_exists(TableComponent::RightBorder)
-        || table.style_exists(TableComponent::RightBorderIntersections)
-        || table.style_exists(TableComponent::RightHeaderIntersection)
-        || table.style_exists(TableComponent::BottomRightCorner)
-    {
-        return true;
-    }
-
-    false
+    RIGHT_BORDER
+        .iter()
+        .any(|component| table.style_exists(*component))
 }
 
 fn should_draw_horizontal_lines(table: &Table) -> bool {
@@ -265,16 +541,9 @@ fn should_draw_horizontal_lines(table: &Table) -> bool {
 }
 
 pub fn should_draw_vertical_lines(table: &Table) -> bool {
-    if table.style_exists(TableComponent::TopBorderIntersections)
-        || table.style_exists(TableComponent::MiddleHeaderIntersections)
-        || table.style_exists(TableComponent::VerticalLines)
-        || table.style_exists(TableComponent::MiddleIntersections)
-        || table.style_exists(TableComponent::BottomBorderIntersections)
-    {
-        return true;
-    }
-
-    false
+    VERTICAL_LINES
+        .iter()
+        .any(|component| table.style_exists(*component))
 }
 
 fn should_draw_header(table: &Table) -> bool {
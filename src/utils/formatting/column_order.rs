@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use crate::{Cell, Row, Table};
+
+/// Return the indices of the columns in the order they're displayed,
+/// see [Table::set_column_order](crate::Table::set_column_order).
+///
+/// Indices of columns that don't exist and repeated indices are skipped.
+pub(crate) fn displayed_columns(table: &Table) -> Vec<usize> {
+    let order = match table.column_order.as_ref() {
+        Some(order) => order,
+        None => return (0..table.columns.len()).collect(),
+    };
+
+    let mut seen = BTreeSet::new();
+    order
+        .iter()
+        .copied()
+        .filter(|index| *index < table.columns.len() && seen.insert(*index))
+        .collect()
+}
+
+/// Return the table with its columns and cells in the order they're displayed.
+/// Columns that aren't part of the order are removed.
+///
+/// The table is only cloned, if a column order has been set.
+pub(crate) fn reorder_columns(table: &Table) -> Cow<'_, Table> {
+    if table.column_order.is_none() {
+        return Cow::Borrowed(table);
+    }
+
+    let order = displayed_columns(table);
+    let mut reordered = table.clone();
+    reordered.column_order = None;
+    reordered.columns = order
+        .iter()
+        .enumerate()
+        .map(|(index, source)| {
+            let mut column = table.columns[*source].clone();
+            column.index = index;
+            column
+        })
+        .collect();
+
+    if let Some(header) = reordered.header.as_mut() {
+        reorder_cells(header, &order);
+    }
+    for row in reordered.rows.iter_mut() {
+        reorder_cells(row, &order);
+    }
+
+    Cow::Owned(reordered)
+}
+
+/// Move the cells of a row into the given order.
+/// Missing cells are filled up with empty cells, unless they're at the end of the row.
+fn reorder_cells(row: &mut Row, order: &[usize]) {
+    let mut cells: Vec<Option<Cell>> = std::mem::take(&mut row.cells)
+        .into_iter()
+        .map(Some)
+        .collect();
+
+    let mut reordered: Vec<Option<Cell>> = order
+        .iter()
+        .map(|source| cells.get_mut(*source).and_then(Option::take))
+        .collect();
+    while matches!(reordered.last(), Some(None)) {
+        reordered.pop();
+    }
+
+    row.cells = reordered
+        .into_iter()
+        .map(|cell| cell.unwrap_or_else(|| Cell::new("")))
+        .collect();
+}
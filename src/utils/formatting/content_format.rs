@@ -1,16 +1,23 @@
 #[cfg(feature = "tty")]
 use crossterm::style::{style, Stylize};
-use unicode_width::UnicodeWidthStr;
 
 use super::content_split::measure_text_width;
 use super::content_split::split_line;
+use super::content_split::{split_long_word, split_long_word_raw};
 
 use crate::cell::Cell;
-use crate::row::Row;
-use crate::style::CellAlignment;
+use crate::row::{Row, Section};
+#[cfg(feature = "unicode-bidi")]
+use crate::style::BidiMode;
 #[cfg(feature = "tty")]
-use crate::style::{map_attribute, map_color};
-use crate::table::Table;
+use crate::style::{map_attribute, map_color, CellStyle};
+use crate::style::{Bias, CellAlignment, CellVerticalAlignment, WrapPolicy};
+use crate::table::{
+    Table, TruncatedContent, BORDER_CHAR_REPLACEMENT, MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH,
+};
+#[cfg(feature = "unicode-bidi")]
+use crate::utils::formatting::bidi;
+use crate::utils::formatting::borders::{should_draw_vertical_lines, BorderWidths};
 use crate::utils::ColumnDisplayInfo;
 
 pub fn delimiter(cell: &Cell, info: &ColumnDisplayInfo, table: &Table) -> char {
@@ -43,31 +50,181 @@ pub fn delimiter(cell: &Cell, info: &ColumnDisplayInfo, table: &Table) -> char {
 ///
 /// The strings for each row will be padded and aligned according to their respective column.
 pub fn format_content(table: &Table, display_info: &[ColumnDisplayInfo]) -> Vec<Vec<Vec<String>>> {
+    let kinds = row_kinds(table);
     // The content of the whole table
-    let mut table_content = Vec::with_capacity(table.rows.len() + 1);
+    let mut table_content = Vec::with_capacity(kinds.len());
+
+    // The content of truncated cells isn't needed, but numbers their markers.
+    let mut truncated = Vec::new();
+
+    for kind in kinds {
+        match kind {
+            RowKind::Header(header) => table_content.push(format_row(
+                header,
+                None,
+                display_info,
+                table,
+                &mut truncated,
+            )),
+            RowKind::Body(index, row) => table_content.push(format_row(
+                row,
+                Some(index),
+                display_info,
+                table,
+                &mut truncated,
+            )),
+            // Sections are always added, even if they cannot be displayed.
+            // Otherwise, the content wouldn't line up with the kinds of rows anymore.
+            RowKind::Section(section) => table_content.push(
+                format_spanning_cell(&section.title, table, display_info).unwrap_or_default(),
+            ),
+            RowKind::EmptyMessage => {
+                let message = table
+                    .empty_message
+                    .as_ref()
+                    .and_then(|message| format_spanning_cell(&message.into(), table, display_info));
+                if let Some(message) = message {
+                    table_content.push(message);
+                }
+            }
+        }
+    }
+
+    table_content
+}
 
-    // Format table header if it exists
+/// The different kinds of rows that're displayed in a table.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum RowKind<'a> {
+    Header(&'a Row),
+    /// A row of the table's body and its index.
+    Body(usize, &'a Row),
+    Section(&'a Section),
+    EmptyMessage,
+}
+
+impl RowKind<'_> {
+    /// Sections and the empty message consist of a single cell, which spans over all columns.
+    pub(crate) fn is_spanning(&self) -> bool {
+        matches!(self, RowKind::Section(_) | RowKind::EmptyMessage)
+    }
+
+    /// The actual row, if this isn't a section or the empty message.
+    pub(crate) fn row(&self) -> Option<&Row> {
+        match self {
+            RowKind::Header(row) | RowKind::Body(_, row) => Some(row),
+            RowKind::Section(_) | RowKind::EmptyMessage => None,
+        }
+    }
+}
+
+/// Get the kinds of all rows of the table in the order they're displayed in.
+/// This is the same order as the one of [format_content].
+pub(crate) fn row_kinds(table: &Table) -> Vec<RowKind<'_>> {
+    let mut kinds = Vec::with_capacity(table.rows.len() + table.sections.len() + 2);
     if let Some(header) = table.header() {
-        table_content.push(format_row(header, display_info, table));
+        kinds.push(RowKind::Header(header));
     }
 
-    for row in table.rows.iter() {
-        table_content.push(format_row(row, display_info, table));
+    // Sections are displayed right before the row that's been added after them.
+    let mut sections = table.sections.iter().peekable();
+    for (index, row) in table.rows.iter().enumerate() {
+        while let Some(section) = sections.next_if(|section| section.row_index <= index) {
+            kinds.push(RowKind::Section(section));
+        }
+        kinds.push(RowKind::Body(index, row));
     }
-    table_content
+    kinds.extend(sections.map(RowKind::Section));
+
+    if table.shows_empty_message() {
+        kinds.push(RowKind::EmptyMessage);
+    }
+
+    kinds
 }
 
+/// Format a single cell, which spans over all visible columns, e.g. the empty message.
+fn format_spanning_cell(
+    cell: &Cell,
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+) -> Option<Vec<Vec<String>>> {
+    let visible: Vec<&ColumnDisplayInfo> =
+        display_info.iter().filter(|info| !info.is_hidden).collect();
+    let (first, last) = (visible.first()?, visible.last()?);
+
+    // The message takes up the space of all columns and the vertical lines in between.
+    let mut width = visible
+        .iter()
+        .fold(0u16, |width, info| width.saturating_add(info.width()));
+    if should_draw_vertical_lines(table) {
+        let vertical_lines = BorderWidths::new(table).vertical * (visible.len() - 1);
+        width = width.saturating_add(vertical_lines.try_into().unwrap_or(u16::MAX));
+    }
+
+    let mut info = (*first).clone();
+    info.padding = (first.padding.0, last.padding.1);
+    info.content_width = std::cmp::max(
+        1,
+        width.saturating_sub(info.padding.0.saturating_add(info.padding.1)),
+    );
+    info.cell_alignment = None;
+    info.hanging_indent = 0;
+
+    Some(format_row(
+        &Row::from(vec![cell.clone()]),
+        None,
+        &[info],
+        table,
+        &mut Vec::new(),
+    ))
+}
+
+/// Replace all characters of the Unicode box-drawing block with [BORDER_CHAR_REPLACEMENT].
+fn escape_border_chars(lines: &mut [String]) {
+    for line in lines.iter_mut() {
+        if line.contains(is_box_drawing) {
+            *line = line
+                .chars()
+                .map(|character| {
+                    if is_box_drawing(character) {
+                        BORDER_CHAR_REPLACEMENT
+                    } else {
+                        character
+                    }
+                })
+                .collect();
+        }
+    }
+}
+
+fn is_box_drawing(character: char) -> bool {
+    ('\u{2500}'..='\u{257F}').contains(&character)
+}
+
+/// Format a single row of the table.
+///
+/// The `row_index` is the position of the row in the table's body, or `None` for the header.
+/// It determines the row's [zebra stripe](Table::set_zebra_stripes).
+/// The full content of body cells that're truncated due to the row's max height is added to
+/// `truncated`, which also determines the numbers of their markers.
+/// Truncated content refers to the row's own index, in case hidden rows have been removed.
+#[allow(unused_variables)]
 pub fn format_row(
     row: &Row,
+    row_index: Option<usize>,
     display_infos: &[ColumnDisplayInfo],
     table: &Table,
+    truncated: &mut Vec<TruncatedContent>,
 ) -> Vec<Vec<String>> {
     // The content of this specific row
     let mut temp_row_content = Vec::with_capacity(display_infos.len());
+    // The vertical alignment of each visible cell of this row
+    let mut vertical_alignments = Vec::with_capacity(display_infos.len());
 
     let mut cell_iter = row.cells.iter();
     // Now iterate over all cells and handle them according to their alignment
-    for info in display_infos.iter() {
+    for (column_index, info) in display_infos.iter().enumerate() {
         if info.is_hidden {
             cell_iter.next();
             continue;
@@ -83,20 +240,86 @@ pub fn format_row(
         } else {
             cell_lines.push(" ".repeat(info.width().into()));
             temp_row_content.push(cell_lines);
+            vertical_alignments.push(CellVerticalAlignment::Top);
             continue;
         };
 
+        // The cell might have its own padding, which changes the width of its content.
+        let info = info.for_cell(cell);
+        let info = info.as_ref();
+
         // The delimiter is configurable, determine which one should be used for this cell.
         let delimiter = delimiter(cell, info, table);
 
-        // Iterate over each line and split it into multiple lines if necessary.
-        // Newlines added by the user will be preserved.
-        for line in cell.content.iter() {
-            if measure_text_width(line) > info.content_width.into() {
-                let mut parts = split_line(line, info, delimiter);
-                cell_lines.append(&mut parts);
-            } else {
-                cell_lines.push(line.into());
+        let mut display_lines = cell.display_lines(table.trim_mode);
+        if table.escape_border_chars
+            && display_lines
+                .iter()
+                .any(|line| line.contains(is_box_drawing))
+        {
+            escape_border_chars(display_lines.to_mut());
+        }
+        let content_width = usize::from(info.content_width);
+        // The range of lines that each line of the content has been split into.
+        // Right-to-left content is reordered once the lines are final.
+        #[cfg(feature = "unicode-bidi")]
+        let mut paragraphs = Vec::new();
+        if cell.truncate {
+            // Truncated cells are never split and only display their first line.
+            let mut line = display_lines.first().cloned().unwrap_or_default();
+            if display_lines.len() > 1 {
+                truncate_line(&mut line, info, column_index, table, None);
+            } else if measure_text_width(&line, table.measure()) > content_width {
+                // The whole content is visible, except for the part that's too wide.
+                let indicator = truncation_indicator(column_index, table);
+                line = cut_line(&line, info, table, indicator);
+            }
+            cell_lines.push(line);
+            #[cfg(feature = "unicode-bidi")]
+            paragraphs.push(0..1);
+        } else {
+            // Iterate over each line and split it into multiple lines if necessary.
+            // Newlines added by the user will be preserved.
+            for line in display_lines.iter() {
+                #[cfg(feature = "unicode-bidi")]
+                let start = cell_lines.len();
+                if cell.preformatted {
+                    // Preformatted content is never split.
+                    // If the column is too narrow, the line gets cut off instead.
+                    if measure_text_width(line, table.measure()) > content_width {
+                        cell_lines.push(cut_line(
+                            line,
+                            info,
+                            table,
+                            table.width_truncation_indicator(),
+                        ));
+                    } else {
+                        cell_lines.push(line.into());
+                    }
+                } else if measure_text_width(line, table.measure()) > content_width {
+                    let parts =
+                        split_line(line, info, delimiter, table.wrap_policy, table.measure());
+                    for part in parts {
+                        // Words that are kept as a whole might still be too long, if the column's
+                        // width is constrained. These are cut off just like preformatted content.
+                        if table.wrap_policy == WrapPolicy::KeepWords
+                            && measure_text_width(&part, table.measure()) > content_width
+                        {
+                            cell_lines.push(cut_line(
+                                &part,
+                                info,
+                                table,
+                                table.width_truncation_indicator(),
+                            ));
+                        } else {
+                            cell_lines.push(part);
+                        }
+                    }
+                } else {
+                    cell_lines.push(line.into());
+                }
+                #[cfg(feature = "unicode-bidi")]
+                paragraphs.push(start..cell_lines.len());
             }
         }
 
@@ -104,6 +327,8 @@ pub fn format_row(
         // amount of lines and there're too many lines in this cell.
         // This then inserts a '...' string at the end to indicate that the cell has been truncated.
         if let Some(lines) = row.max_height {
+            // Always keep at least a single line.
+            let lines = std::cmp::max(lines, 1);
             if cell_lines.len() > lines {
                 let _ = cell_lines.split_off(lines);
                 // Direct access.
@@ -111,39 +336,101 @@ pub fn format_row(
                     .get_mut(lines - 1)
                     .expect("We know it's this long.");
 
-                // Truncate any ansi codes, as the following cutoff might break an ansi code
-                // otherwise. This could be handled smarter, but works for now.
-                #[cfg(feature = "custom_styling")]
-                {
-                    let stripped = console::strip_ansi_codes(last_line).to_string();
-                    *last_line = stripped;
-                }
+                // Body cells are numbered, so their full content can be looked up.
+                let marker = row_index.map(|row_index| {
+                    truncated.push(TruncatedContent {
+                        marker: truncated.len() + 1,
+                        row_index: row.index.unwrap_or(row_index),
+                        column_index,
+                        content: cell.content(),
+                    });
+                    truncated.len()
+                });
+                let marker = marker.filter(|_| table.truncation_markers);
+                truncate_line(last_line, info, column_index, table, marker);
+            }
+        }
+
+        #[cfg(feature = "unicode-bidi")]
+        let rtl_info;
+        #[cfg(feature = "unicode-bidi")]
+        let info = if table.bidi_mode != BidiMode::Logical && !cell.raw_ansi {
+            for (index, lines) in paragraphs.into_iter().enumerate() {
+                let end = lines.end.min(cell_lines.len());
+                let start = lines.start.min(end);
+                bidi::reorder_lines(&display_lines[index], &mut cell_lines[start..end]);
+            }
 
-                // Only show the `...` indicator if the column is smaller then 6 characters.
-                // Otherwise it feels like it doesn't make a lot of sense to show it, as it
-                // might cover up too much important content on such a small column.
-                //
-                // That's questionable though, should we really keep that limitation as users
-                // won't have an indicator that truncation is taking place?
-                let width: usize = info.content_width.into();
-                if width >= 6 {
-                    let indicator_width = table.truncation_indicator.width();
-                    // Truncate the line if indicator doesn't fit
-                    if last_line.width() >= width - indicator_width {
-                        let surplus = (last_line.width() + indicator_width) - width;
-                        last_line.truncate(last_line.width() - surplus);
+            // Cells that are mostly right-to-left lean to the right, unless they're aligned.
+            if table.bidi_mode == BidiMode::VisualRightAligned
+                && cell.alignment.is_none()
+                && info.cell_alignment.is_none()
+                && bidi::is_rtl_dominant(&cell_lines)
+            {
+                rtl_info = ColumnDisplayInfo {
+                    cell_alignment: Some(CellAlignment::Right),
+                    ..info.clone()
+                };
+                &rtl_info
+            } else {
+                info
+            }
+        } else {
+            info
+        };
+
+        // Combine the styles that apply to the whole row with the styles of the cell's class
+        // and the styles of the cell itself. The most specific style takes precedence.
+        // Raw cells aren't styled at all, as their content manages its own styles.
+        #[cfg(feature = "tty")]
+        let cell_style = if cell.raw_ansi {
+            CellStyle::new()
+        } else {
+            let mut cell_style = match row_index {
+                None => table.header_style.clone(),
+                Some(row_index) => match table.zebra_stripes.as_ref() {
+                    Some((even, odd)) => {
+                        let stripe = if row_index % 2 == 0 { even } else { odd };
+                        table.body_style.clone().bg(*stripe)
                     }
-                    last_line.push_str(&table.truncation_indicator);
-                }
+                    None => table.body_style.clone(),
+                },
+            };
+            if let Some(class_style) = cell
+                .class
+                .as_ref()
+                .and_then(|class| table.class_styles.get(class))
+            {
+                cell_style = cell_style.merge(class_style);
             }
-        }
+            cell_style.merge(&CellStyle::from_cell(cell))
+        };
 
         // Iterate over all generated lines of this cell and align them
-        let cell_lines = cell_lines
-            .iter()
-            .map(|line| align_line(table, info, cell, line.to_string()));
+        let cell_lines = cell_lines.iter().map(|line| {
+            align_line(
+                table,
+                info,
+                cell,
+                #[cfg(feature = "tty")]
+                &cell_style,
+                line.to_string(),
+            )
+        });
 
         temp_row_content.push(cell_lines.collect());
+
+        // Cell settings overwrite the column's vertical alignment. Default is Top.
+        let vertical_alignment = cell
+            .vertical_alignment
+            .or_else(|| {
+                table
+                    .columns
+                    .get(column_index)
+                    .and_then(|column| column.cell_vertical_alignment)
+            })
+            .unwrap_or(CellVerticalAlignment::Top);
+        vertical_alignments.push(vertical_alignment);
     }
 
     // Right now, we have a different structure than desired.
@@ -161,21 +448,36 @@ pub fn format_row(
     //  tc[0][0][0]     tc[0][0][1]
     //  tc[0][1][0]     tc[0][1][1]
     //  tc[0][2][0]     tc[0][2][1] <- Now filled with placeholder (spaces)
-    let max_lines = temp_row_content.iter().map(Vec::len).max().unwrap_or(0);
+    let mut max_lines = temp_row_content.iter().map(Vec::len).max().unwrap_or(0);
+    // Rows with a minimum height are topped up as well, as long as any cell is visible.
+    // The max height of the row takes precedence.
+    if let (Some(min_height), false) = (row.min_height, temp_row_content.is_empty()) {
+        let min_height = match row.max_height {
+            Some(max_height) => min_height.min(max_height.max(1)),
+            None => min_height,
+        };
+        max_lines = max_lines.max(min_height);
+    }
     let mut row_content = Vec::with_capacity(max_lines * display_infos.len());
 
     // Each column should have `max_lines` for this row.
     // Cells content with fewer lines will simply be topped up with empty strings.
+    // Depending on the vertical alignment, the empty strings are placed above or below the content.
     for index in 0..max_lines {
         let mut line = Vec::with_capacity(display_infos.len());
-        let mut cell_iter = temp_row_content.iter();
+        let mut cell_iter = temp_row_content.iter().zip(vertical_alignments.iter());
 
         for info in display_infos.iter() {
             if info.is_hidden {
                 continue;
             }
-            let cell = cell_iter.next().unwrap();
-            match cell.get(index) {
+            let (cell, vertical_alignment) = cell_iter.next().unwrap();
+            let offset = match vertical_alignment {
+                CellVerticalAlignment::Top => 0,
+                CellVerticalAlignment::Middle => (max_lines - cell.len()) / 2,
+                CellVerticalAlignment::Bottom => max_lines - cell.len(),
+            };
+            match index.checked_sub(offset).and_then(|index| cell.get(index)) {
                 // The current cell has content for this line. Append it
                 Some(content) => line.push(content.clone()),
                 // The current cell doesn't have content for this line.
@@ -189,26 +491,164 @@ pub fn format_row(
     row_content
 }
 
+/// Mark the last visible line of a truncated cell by appending the truncation indicator.
+///
+/// The line is cut off, if the indicator doesn't fit into the column otherwise.
+/// The numbered `marker` of the cell is appended to the indicator, if there's enough space.
+fn truncate_line(
+    line: &mut String,
+    info: &ColumnDisplayInfo,
+    column_index: usize,
+    table: &Table,
+    marker: Option<usize>,
+) {
+    // Truncate any ansi codes, as the following cutoff might break an ansi code
+    // otherwise. This could be handled smarter, but works for now.
+    // Raw content is kept as it is and cut without breaking any ansi code.
+    #[cfg(feature = "custom_styling")]
+    if !info.raw_ansi {
+        let stripped = console::strip_ansi_codes(line).to_string();
+        *line = stripped;
+    }
+    let split_long_word = if info.raw_ansi {
+        split_long_word_raw
+    } else {
+        split_long_word
+    };
+
+    // Only show the `...` indicator if the column is at least 6 characters wide.
+    // Otherwise it feels like it doesn't make a lot of sense to show it, as it
+    // might cover up too much important content on such a small column.
+    //
+    // That's questionable though, should we really keep that limitation as users
+    // won't have an indicator that truncation is taking place?
+    let width: usize = info.content_width.into();
+    let indicator = truncation_indicator(column_index, table);
+    let indicator = match marker.map(|marker| format!("{indicator}[+{marker}]")) {
+        Some(marked) if measure_text_width(&marked, table.measure()) < width => marked,
+        _ => indicator.to_string(),
+    };
+    let indicator_width = measure_text_width(&indicator, table.measure());
+    // Indicators that don't leave space for any content are left out as well.
+    if width < MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH || indicator_width >= width {
+        if measure_text_width(line, table.measure()) > width {
+            *line = split_long_word(width, line, table.measure()).0;
+        }
+        return;
+    }
+
+    // Truncate the line if indicator doesn't fit
+    // The line is cut at a character boundary, as multi-byte characters
+    // would otherwise be split.
+    if measure_text_width(line, table.measure()) >= width - indicator_width {
+        *line = split_long_word(width - indicator_width, line, table.measure()).0;
+    }
+    line.push_str(&indicator);
+}
+
+/// Get the indicator for truncated cells of a column.
+/// The column's indicator overwrites the table's indicator.
+fn truncation_indicator(column_index: usize, table: &Table) -> &str {
+    table
+        .columns
+        .get(column_index)
+        .and_then(|column| column.truncation_indicator.as_deref())
+        .unwrap_or_else(|| table.truncation_indicator())
+}
+
+/// Cut off a line that's too wide for its column.
+///
+/// The table's [TruncationStrategy](crate::TruncationStrategy) decides which part of the line is
+/// kept. Without a strategy or if the line contains ANSI escape sequences, its end is cut off.
+///
+/// Just like for truncated cells, the indicator is only shown if the column is at least
+/// [MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH] characters wide and has space for some content next to
+/// the indicator.
+fn cut_line(line: &str, info: &ColumnDisplayInfo, table: &Table, indicator: &str) -> String {
+    let width = usize::from(info.content_width);
+    let split_long_word = if info.raw_ansi {
+        split_long_word_raw
+    } else {
+        split_long_word
+    };
+
+    if width < MIN_TRUNCATION_INDICATOR_COLUMN_WIDTH
+        || measure_text_width(indicator, table.measure()) >= width
+    {
+        return split_long_word(width, line, table.measure()).0;
+    }
+
+    if let Some(strategy) = table.truncation_strategy.as_ref() {
+        if !line.contains('\x1b') {
+            let truncated = strategy.truncate(line, width, indicator, table.measure());
+            // Make sure that custom strategies don't break the table's borders.
+            if measure_text_width(&truncated, table.measure()) > width {
+                return split_long_word(width, &truncated, table.measure()).0;
+            }
+
+            return truncated;
+        }
+    }
+
+    let (mut head, _) = split_long_word(
+        width - measure_text_width(indicator, table.measure()),
+        line,
+        table.measure(),
+    );
+    head.push_str(indicator);
+    head
+}
+
 /// Apply the alignment for a column. Alignment can be either Left/Right/Center.
 /// In every case all lines will be exactly the same character length `info.width - padding long`
 /// This is needed, so we can simply insert it into the border frame later on.
 /// Padding is applied in this function as well.
 #[allow(unused_variables)]
-fn align_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell, mut line: String) -> String {
+fn align_line(
+    table: &Table,
+    info: &ColumnDisplayInfo,
+    cell: &Cell,
+    #[cfg(feature = "tty")] style: &CellStyle,
+    mut line: String,
+) -> String {
     let content_width = info.content_width;
-    let remaining: usize = usize::from(content_width).saturating_sub(measure_text_width(&line));
+
+    // A line might still be too wide, if a single character is wider than the whole column,
+    // e.g. a CJK character in a column with a content width of 1.
+    // Such characters cannot be displayed and are cut off, so the borders stay aligned.
+    if measure_text_width(&line, table.measure()) > usize::from(content_width) {
+        let split_long_word = if info.raw_ansi {
+            split_long_word_raw
+        } else {
+            split_long_word
+        };
+        line = split_long_word(content_width.into(), &line, table.measure()).0;
+    }
+    let remaining: usize =
+        usize::from(content_width).saturating_sub(measure_text_width(&line, table.measure()));
+
+    // Only the text itself is turned into a hyperlink, the whitespace around it isn't.
+    #[cfg(feature = "tty")]
+    if let (true, Some(url)) = (table.should_style(), cell.url.as_ref()) {
+        if !line.trim().is_empty() {
+            line = hyperlink(&line, url);
+        }
+    }
 
     // Apply the styling before aligning the line, if the user requests it.
     // That way non-delimiter whitespaces won't have stuff like underlines.
     #[cfg(feature = "tty")]
     if table.should_style() && table.style_text_only {
-        line = style_line(line, cell);
+        line = style_line(line, style);
     }
 
     // Determine the alignment of the column cells.
     // Cell settings overwrite the columns Alignment settings.
     // Default is Left
-    let alignment = if let Some(alignment) = cell.alignment {
+    // Preformatted content is never aligned.
+    let alignment = if cell.preformatted {
+        CellAlignment::Left
+    } else if let Some(alignment) = cell.alignment {
         alignment
     } else if let Some(alignment) = info.cell_alignment {
         alignment
@@ -225,8 +665,8 @@ fn align_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell, mut line: St
             line = " ".repeat(remaining) + &line;
         }
         CellAlignment::Center => {
-            let left_padding = (remaining as f32 / 2f32).ceil() as usize;
-            let right_padding = (remaining as f32 / 2f32).floor() as usize;
+            let left_padding = center_offset(remaining, table.center_bias);
+            let right_padding = remaining - left_padding;
             line = " ".repeat(left_padding) + &line + &" ".repeat(right_padding);
         }
     }
@@ -235,12 +675,21 @@ fn align_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell, mut line: St
 
     #[cfg(feature = "tty")]
     if table.should_style() && !table.style_text_only {
-        return style_line(line, cell);
+        return style_line(line, style);
     }
 
     line
 }
 
+/// Get the amount of space left of centered content.
+/// If the space cannot be split evenly, the bias decides which side receives the extra space.
+pub(crate) fn center_offset(remaining: usize, bias: Bias) -> usize {
+    match bias {
+        Bias::Left => remaining - remaining / 2,
+        Bias::Right => remaining / 2,
+    }
+}
+
 /// Apply the column's padding to this line
 fn pad_line(line: &str, info: &ColumnDisplayInfo) -> String {
     let mut padded_line = String::new();
@@ -252,26 +701,32 @@ fn pad_line(line: &str, info: &ColumnDisplayInfo) -> String {
     padded_line
 }
 
+/// Wrap the text in OSC 8 escape sequences, which turn it into a hyperlink.
+#[cfg(feature = "tty")]
+fn hyperlink(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
 #[cfg(feature = "tty")]
-fn style_line(line: String, cell: &Cell) -> String {
+pub(crate) fn style_line(line: String, cell_style: &CellStyle) -> String {
     // Just return the line, if there's no need to style.
-    if cell.fg.is_none() && cell.bg.is_none() && cell.attributes.is_empty() {
+    if cell_style.is_empty() {
         return line;
     }
 
     let mut content = style(line);
 
     // Apply text color
-    if let Some(color) = cell.fg {
+    if let Some(color) = cell_style.fg {
         content = content.with(map_color(color));
     }
 
     // Apply background color
-    if let Some(color) = cell.bg {
+    if let Some(color) = cell_style.bg {
         content = content.on(map_color(color));
     }
 
-    for attribute in cell.attributes.iter() {
+    for attribute in cell_style.attributes.iter() {
         content = content.attribute(map_attribute(*attribute));
     }
 
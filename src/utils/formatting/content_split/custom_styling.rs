@@ -1,16 +1,19 @@
-use ansi_str::AnsiStr;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use unicode_segmentation::UnicodeSegmentation;
 
-const ANSI_RESET: &str = "\u{1b}[0m";
+use super::measure_text_width;
+use crate::table::WidthFunction;
 
-/// Returns printed length of string, takes into account escape codes
-#[inline(always)]
-pub fn measure_text_width(s: &str) -> usize {
-    s.ansi_strip().width()
-}
+const ANSI_RESET: &str = "\u{1b}[0m";
 
 /// Split the line by the given deliminator without breaking ansi codes that contain the delimiter
 pub fn split_line_by_delimiter(line: &str, delimiter: char) -> Vec<String> {
+    let mut lines = split_line_by_delimiter_raw(line, delimiter);
+    fix_style_in_split_str(lines.as_mut());
+    lines
+}
+
+/// Same as [split_line_by_delimiter], but styles aren't terminated and resumed around the splits.
+pub fn split_line_by_delimiter_raw(line: &str, delimiter: char) -> Vec<String> {
     let mut lines: Vec<String> = Vec::new();
     let mut current_line = String::default();
 
@@ -36,12 +39,94 @@ pub fn split_line_by_delimiter(line: &str, delimiter: char) -> Vec<String> {
         }
     }
     lines.push(current_line);
-    fix_style_in_split_str(lines.as_mut());
     lines
 }
 
+/// Split a line into its individual parts right after each of the given delimiters.
+/// The delimiters stay part of the preceding element.
+///
+/// ANSI escape codes are never split and styles are preserved across elements.
+pub fn split_line_after_delimiters(line: &str, delimiters: &[char]) -> Vec<String> {
+    let mut elements = split_line_after_delimiters_raw(line, delimiters);
+    fix_style_in_split_str(elements.as_mut());
+    elements
+}
+
+/// Same as [split_line_after_delimiters], but styles aren't terminated and resumed around the
+/// splits.
+pub fn split_line_after_delimiters_raw(line: &str, delimiters: &[char]) -> Vec<String> {
+    let mut elements = Vec::new();
+    let mut current_element = String::new();
+
+    let iter = console::AnsiCodeIterator::new(line);
+    for (str_slice, is_esc) in iter {
+        if is_esc {
+            current_element.push_str(str_slice);
+            continue;
+        }
+
+        for part in str_slice.split_inclusive(delimiters) {
+            current_element.push_str(part);
+            if part.ends_with(delimiters) {
+                elements.push(std::mem::take(&mut current_element));
+            }
+        }
+    }
+
+    if !current_element.is_empty() || elements.is_empty() {
+        elements.push(current_element);
+    }
+    elements
+}
+
+/// Split a line into its individual parts right after each match of the regex.
+/// The matched text stays part of the preceding element.
+///
+/// ANSI escape codes are never matched and styles are preserved across elements.
+#[cfg(feature = "regex")]
+pub fn split_line_by_regex(line: &str, regex: &regex::Regex) -> Vec<String> {
+    let mut elements = split_line_by_regex_raw(line, regex);
+    fix_style_in_split_str(elements.as_mut());
+    elements
+}
+
+/// Same as [split_line_by_regex], but styles aren't terminated and resumed around the splits.
+#[cfg(feature = "regex")]
+pub fn split_line_by_regex_raw(line: &str, regex: &regex::Regex) -> Vec<String> {
+    let mut elements = Vec::new();
+    let mut current_element = String::new();
+
+    let iter = console::AnsiCodeIterator::new(line);
+    for (str_slice, is_esc) in iter {
+        if is_esc {
+            current_element.push_str(str_slice);
+            continue;
+        }
+
+        let mut start = 0;
+        for found in regex.find_iter(str_slice) {
+            // Skip empty matches at the start of a slice, they would produce empty elements.
+            if found.end() > start {
+                current_element.push_str(&str_slice[start..found.end()]);
+                elements.push(std::mem::take(&mut current_element));
+                start = found.end();
+            }
+        }
+        current_element.push_str(&str_slice[start..]);
+    }
+
+    if !current_element.is_empty() || elements.is_empty() {
+        elements.push(current_element);
+    }
+    elements
+}
+
 /// Splits a long word at a given character width. Inserting the needed ansi codes to preserve style.
-pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
+pub fn split_long_word(
+    allowed_width: usize,
+    word: &str,
+    measure: WidthFunction,
+) -> (String, String) {
     // A buffer for the first half of the split str, which will take up at most `allowed_len` characters when printed to the terminal.
     let mut head = String::with_capacity(word.len());
     // A buffer for the second half of the split str
@@ -70,7 +155,7 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
 
         let slice_len = match is_esc {
             true => 0,
-            false => str_slice.width(),
+            false => measure_text_width(str_slice, measure),
         };
 
         if head_len + slice_len <= allowed_width {
@@ -84,18 +169,19 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
             }
         } else {
             assert!(!is_esc);
-            let mut char_iter = str_slice.chars().peekable();
-            while let Some(c) = char_iter.peek() {
-                let character_width = c.width().unwrap_or(0);
-                if allowed_width < head_len + character_width {
+            // The byte index of the slice right after the last grapheme that fits.
+            let mut end = 0;
+            for (index, grapheme) in str_slice.grapheme_indices(true) {
+                let grapheme_width = measure(grapheme);
+                if allowed_width < head_len + grapheme_width {
                     break;
                 }
 
-                head_len += character_width;
-                let c = char_iter.next().unwrap();
-                head.push(c);
+                head_len += grapheme_width;
+                head.push_str(grapheme);
+                end = index + grapheme.len();
 
-                // c is not escape code
+                // The grapheme is not an escape code
                 head_len_last = head.len();
                 escape_count_last = escapes.len();
             }
@@ -109,8 +195,7 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
             for esc in escapes {
                 tail.push_str(esc);
             }
-            let remaining: String = char_iter.collect();
-            tail.push_str(&remaining);
+            tail.push_str(&str_slice[end..]);
             break;
         }
     }
@@ -119,6 +204,37 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
     (head, tail)
 }
 
+/// Same as [split_long_word], but no ansi codes are inserted to preserve the style.
+/// Escape codes right at the split stay in front of the text that follows them.
+pub fn split_long_word_raw(
+    allowed_width: usize,
+    word: &str,
+    measure: WidthFunction,
+) -> (String, String) {
+    let mut width = 0;
+    // The byte index right after the last character that fits.
+    let mut end = 0;
+    // The byte index of the current segment.
+    let mut position = 0;
+
+    'segments: for (str_slice, is_esc) in console::AnsiCodeIterator::new(word) {
+        if !is_esc {
+            for (index, grapheme) in str_slice.grapheme_indices(true) {
+                let grapheme_width = measure(grapheme);
+                if width + grapheme_width > allowed_width {
+                    break 'segments;
+                }
+
+                width += grapheme_width;
+                end = position + index + grapheme.len();
+            }
+        }
+        position += str_slice.len();
+    }
+
+    (word[..end].to_string(), word[end..].to_string())
+}
+
 /// Fixes ansi escape codes in a split string
 /// 1. Adds reset code to the end of each substring if needed.
 /// 2. Keeps track of previous substring's escape codes and inserts them in later substrings to continue style
@@ -176,18 +292,21 @@ mod test {
             ]
         )
     }
-
-    // TODO: Figure out why this fails with the custom_styling feature enabled.
     #[test]
-    #[cfg(not(feature = "custom_styling"))]
-    fn measure_text_width_osc8_test() {
-        use super::measure_text_width;
-        use unicode_width::UnicodeWidthStr;
+    fn ansi_aware_split_after_delimiters_test() {
+        use super::split_line_after_delimiters;
 
-        let text = "\x1b]8;;https://github.com\x1b\\This is a link\x1b]8;;\x1b";
-        let width = measure_text_width(text);
+        let text = "\u{1b}[1mhead::tail\u{1b}[0m, after";
+        let split = split_line_after_delimiters(text, &[':', ',']);
 
-        assert_eq!(text.width(), 41);
-        assert_eq!(width, 14);
+        assert_eq!(
+            split,
+            [
+                "\u{1b}[1mhead:\u{1b}[0m",
+                "\u{1b}[1m:\u{1b}[0m",
+                "\u{1b}[1mtail\u{1b}[0m,",
+                " after"
+            ]
+        )
     }
 }
@@ -1,3 +1,8 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::style::WrapPolicy;
+use crate::table::WidthFunction;
 use crate::utils::ColumnDisplayInfo;
 
 #[cfg(feature = "custom_styling")]
@@ -10,6 +15,93 @@ pub use custom_styling::*;
 #[cfg(not(feature = "custom_styling"))]
 pub use normal::*;
 
+/// Measures text by the unicode width of its characters.
+///
+/// This is used, unless the table has a custom [width function](crate::Table::set_width_function).
+pub const DEFAULT_WIDTH_FUNCTION: WidthFunction = UnicodeWidthStr::width;
+
+/// Returns the printed width of a string.
+///
+/// ANSI escape sequences, such as CSI sequences used for colors (`\x1b[31m`) and OSC sequences
+/// used for hyperlinks (`\x1b]8;;https://example.com\x1b\\`), don't take up any space.
+///
+/// This is intentionally independent of any feature flags. Otherwise, enabling a feature in an
+/// unrelated crate of the same workspace could change the layout of tables.
+/// The printable text is measured with the given width function, which is usually the table's
+/// [width function](crate::Table::set_width_function).
+pub fn measure_text_width(text: &str, measure: WidthFunction) -> usize {
+    // Fast path for the common case of text without any escape sequences.
+    if !text.contains('\x1b') {
+        return measure(text);
+    }
+
+    let mut width = 0;
+    for_each_printable_run(text, |run| width += measure(run));
+
+    width
+}
+
+/// Returns the printed width of the widest character of a string.
+///
+/// Characters are grapheme clusters, i.e. what's perceived as a single character, e.g. an emoji
+/// that consists of several emojis joined by zero width joiners (`👩‍👩‍👧`) or a character followed
+/// by a variation selector. Such clusters must never be split, as their parts have different
+/// widths.\
+/// Just like for [measure_text_width], ANSI escape sequences are ignored.
+pub fn widest_character_width(text: &str, measure: WidthFunction) -> usize {
+    let mut width = 0;
+    for_each_printable_run(text, |run| {
+        let widest = run.graphemes(true).map(measure).max();
+        width = width.max(widest.unwrap_or(0));
+    });
+
+    width
+}
+
+/// Call the function with each run of printable text, skipping all ANSI escape sequences.
+pub(crate) fn for_each_printable_run(text: &str, mut function: impl FnMut(&str)) {
+    // The byte index at which the current run of printable text started.
+    let mut text_start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, character)) = chars.next() {
+        if character != '\x1b' {
+            continue;
+        }
+        function(&text[text_start..index]);
+
+        match chars.next().map(|(_, character)| character) {
+            // CSI: ends with a byte in the range `@` to `~`.
+            Some('[') => {
+                for (_, character) in chars.by_ref() {
+                    if ('@'..='~').contains(&character) {
+                        break;
+                    }
+                }
+            }
+            // OSC: ends with BEL or ST (`ESC \`).
+            // A lone ESC is accepted as terminator as well, as some tools emit it that way.
+            Some(']') => {
+                while let Some((_, character)) = chars.next() {
+                    if character == '\x07' {
+                        break;
+                    }
+                    if character == '\x1b' {
+                        if let Some((_, '\\')) = chars.peek() {
+                            chars.next();
+                        }
+                        break;
+                    }
+                }
+            }
+            // Any other escape sequence consists of ESC and a single character.
+            _ => {}
+        }
+
+        text_start = chars.peek().map_or(text.len(), |(index, _)| *index);
+    }
+    function(&text[text_start..]);
+}
+
 /// Split a line if it's longer than the allowed columns (width - padding).
 ///
 /// This function tries to do this in a smart way, by splitting the content
@@ -20,46 +112,200 @@ pub use normal::*;
 /// This is repeated until there're no more "elements".
 ///
 /// Mid-element splits only occurs if a element doesn't fit in a single line by itself.
-pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<String> {
-    let mut lines = Vec::new();
+/// How these elements are split is determined by the [WrapPolicy].
+///
+/// If the column has a split regex or a set of delimiters, the line is split right after each
+/// match or delimiter instead.
+pub fn split_line(
+    line: &str,
+    info: &ColumnDisplayInfo,
+    delimiter: char,
+    wrap_policy: WrapPolicy,
+    measure: WidthFunction,
+) -> Vec<String> {
     let content_width = usize::from(info.content_width);
+    // Keep space for at least a single character on indented lines.
+    let indent = std::cmp::min(
+        usize::from(info.hanging_indent),
+        content_width.saturating_sub(1),
+    );
+
+    #[cfg(feature = "regex")]
+    if let Some(regex) = info.split_regex.as_ref() {
+        #[cfg(feature = "custom_styling")]
+        let elements = if info.raw_ansi {
+            split_line_by_regex_raw(line, regex)
+        } else {
+            split_line_by_regex(line, regex)
+        };
+        #[cfg(not(feature = "custom_styling"))]
+        let elements = split_line_by_regex(line, regex);
+
+        return wrap_inclusive_elements(
+            elements,
+            info,
+            content_width,
+            indent,
+            wrap_policy,
+            false,
+            measure,
+        );
+    }
+
+    if !info.delimiters.is_empty() {
+        #[cfg(feature = "custom_styling")]
+        let elements = if info.raw_ansi {
+            split_line_after_delimiters_raw(line, &info.delimiters)
+        } else {
+            split_line_after_delimiters(line, &info.delimiters)
+        };
+        #[cfg(not(feature = "custom_styling"))]
+        let elements = split_line_after_delimiters(line, &info.delimiters);
+
+        return wrap_inclusive_elements(
+            elements,
+            info,
+            content_width,
+            indent,
+            wrap_policy,
+            true,
+            measure,
+        );
+    }
 
     // Split the line by the given deliminator and turn the content into a stack.
     // Also clone it and convert it into a Vec<String>. Otherwise we get some burrowing problems
     // due to early drops of borrowed values that need to be inserted into `Vec<&str>`
-    let mut elements = split_line_by_delimiter(line, delimiter);
+    #[cfg(feature = "custom_styling")]
+    let elements = if info.raw_ansi {
+        split_line_by_delimiter_raw(line, delimiter)
+    } else {
+        split_line_by_delimiter(line, delimiter)
+    };
+    #[cfg(not(feature = "custom_styling"))]
+    let elements = split_line_by_delimiter(line, delimiter);
+
+    let lines = wrap_elements(
+        elements,
+        content_width,
+        indent,
+        Some(delimiter),
+        wrap_policy,
+        info.raw_ansi,
+        measure,
+    );
+
+    indent_lines(lines, indent)
+}
+
+/// Wrap elements that still contain the text they were split at.
+///
+/// Hence, they're joined without delimiter and trailing whitespace is removed from each line.
+/// If `trim_start` is set, leading whitespace is removed from all lines but the first one, e.g.
+/// the space of `a, b` that follows the `,` delimiter.
+fn wrap_inclusive_elements(
+    elements: Vec<String>,
+    info: &ColumnDisplayInfo,
+    content_width: usize,
+    indent: usize,
+    wrap_policy: WrapPolicy,
+    trim_start: bool,
+    measure: WidthFunction,
+) -> Vec<String> {
+    let mut lines = wrap_elements(
+        elements,
+        content_width,
+        indent,
+        None,
+        wrap_policy,
+        info.raw_ansi,
+        measure,
+    );
+    for (index, line) in lines.iter_mut().enumerate() {
+        line.truncate(line.trim_end().len());
+        if trim_start && index > 0 {
+            line.drain(..line.len() - line.trim_start().len());
+        }
+    }
+
+    indent_lines(lines, indent)
+}
+
+/// Prepend the hanging indent to all lines, except for the first one.
+fn indent_lines(mut lines: Vec<String>, indent: usize) -> Vec<String> {
+    if indent == 0 {
+        return lines;
+    }
+
+    for line in lines.iter_mut().skip(1) {
+        line.insert_str(0, &" ".repeat(indent));
+    }
+
+    lines
+}
+
+/// Add the elements one-by-one to lines of the given width.
+///
+/// If a delimiter is given, it's inserted between elements on the same line.\
+/// All lines except for the first one are narrower by the hanging `indent`.
+/// The indentation itself isn't added to the lines.
+fn wrap_elements(
+    mut elements: Vec<String>,
+    content_width: usize,
+    indent: usize,
+    delimiter: Option<char>,
+    wrap_policy: WrapPolicy,
+    raw_ansi: bool,
+    measure: WidthFunction,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    let delimiter_width = usize::from(delimiter.is_some());
 
     // Reverse it, since we want to push/pop without reversing the text.
     elements.reverse();
 
+    let continuation_width = content_width.saturating_sub(indent);
+
     let mut current_line = String::new();
     while let Some(next) = elements.pop() {
-        let current_length = measure_text_width(&current_line);
-        let next_length = measure_text_width(&next);
+        // The first line is the only one that isn't indented.
+        let content_width = if lines.is_empty() {
+            content_width
+        } else {
+            continuation_width
+        };
+        let current_length = measure_text_width(&current_line, measure);
+        // Without delimiter, elements may end with whitespace, e.g. when splitting after `, `.
+        // That whitespace is removed at the end of a line, so it may exceed the content width.
+        let next_length = if delimiter.is_none() {
+            measure_text_width(next.trim_end(), measure)
+        } else {
+            measure_text_width(&next, measure)
+        };
 
         // Some helper variables
         // The length of the current line when combining it with the next element
         // Add 1 for the delimiter if we are on a non-empty line.
         let mut added_length = next_length + current_length;
         if !current_line.is_empty() {
-            added_length += 1;
+            added_length += delimiter_width;
         }
         // The remaining width for this column. If we are on a non-empty line, subtract 1 for the delimiter.
-        let mut remaining_width = content_width - current_length;
+        let mut remaining_width = content_width.saturating_sub(current_length);
         if !current_line.is_empty() {
-            remaining_width = remaining_width.saturating_sub(1);
+            remaining_width = remaining_width.saturating_sub(delimiter_width);
         }
 
         // The next element fits into the current line
         if added_length <= content_width {
             // Only add delimiter, if we're not on a fresh line
-            if !current_line.is_empty() {
+            if let (false, Some(delimiter)) = (current_line.is_empty(), delimiter) {
                 current_line.push(delimiter);
             }
             current_line += &next;
 
             // Already complete the current line, if there isn't space for more than two chars
-            current_line = check_if_full(&mut lines, content_width, current_line);
+            current_line = check_if_full(&mut lines, content_width, current_line, measure);
             continue;
         }
 
@@ -90,28 +336,40 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
         // The element is longer than the specified content_width
         // Split the word, push the remaining string back on the stack
         if next_length > content_width {
+            // Words are never split. The element gets a line of its own, which is too long.
+            if wrap_policy == WrapPolicy::KeepWords {
+                if !current_line.is_empty() {
+                    lines.push(current_line);
+                }
+                lines.push(next);
+                current_line = String::new();
+
+                continue;
+            }
+
             let new_line = current_line.is_empty();
 
             // Only add delimiter, if we're not on a fresh line
-            if !new_line {
+            if let (false, Some(delimiter)) = (new_line, delimiter) {
                 current_line.push(delimiter);
             }
 
-            let (mut next, mut remaining) = split_long_word(remaining_width, &next);
+            let (mut next, mut remaining) =
+                split_word(remaining_width, &next, wrap_policy, raw_ansi, measure);
 
             // This is a ugly hack, but it's needed for now.
             //
             // Scenario: The current column has to have a width of 1 and we work with a new line.
-            // However, the next char is a multi-character UTF-8 symbol.
+            // However, the next grapheme is a multi-character UTF-8 symbol.
             //
             // Since a multi-character wide symbol doesn't fit into a 1-character column,
             // this code would loop endlessly. (There's no legitimate way to split that character.)
-            // Hence, we have to live with the fact, that this line will look broken, as we put a
-            // two-character wide symbol into it, despite the line being formatted for 1 character.
+            // Hence, the symbol gets a line of its own. It's cut off when the line is aligned,
+            // as it cannot be displayed in a column that narrow.
             if new_line && next.is_empty() {
-                let mut chars = remaining.chars();
-                next.push(chars.next().unwrap());
-                remaining = chars.collect();
+                let grapheme = remaining.graphemes(true).next().unwrap_or_default();
+                next.push_str(grapheme);
+                remaining.drain(..grapheme.len());
             }
 
             current_line += &next;
@@ -129,7 +387,7 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
         // Push the current line and initialize the next line with the element.
         lines.push(current_line);
         current_line = next.to_string();
-        current_line = check_if_full(&mut lines, content_width, current_line);
+        current_line = check_if_full(&mut lines, continuation_width, current_line, measure);
     }
 
     if !current_line.is_empty() {
@@ -139,6 +397,71 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
     lines
 }
 
+/// Split a word that's too long for the remaining width of a line.
+///
+/// When hyphenating, a `-` is added to the first part, if there's space for it.\
+/// Raw content is split without inserting any ansi codes.
+fn split_word(
+    allowed_width: usize,
+    word: &str,
+    wrap_policy: WrapPolicy,
+    raw_ansi: bool,
+    measure: WidthFunction,
+) -> (String, String) {
+    let split_long_word = if raw_ansi {
+        split_long_word_raw
+    } else {
+        split_long_word
+    };
+
+    if wrap_policy == WrapPolicy::Hyphenate && allowed_width >= 2 {
+        let (mut part, remaining) = split_long_word(allowed_width - 1, word, measure);
+        if !part.is_empty() && !part.ends_with('-') {
+            part.push('-');
+        }
+
+        return (part, remaining);
+    }
+
+    split_long_word(allowed_width, word, measure)
+}
+
+/// Wrap some free-standing text, that isn't part of any cell, to the given width.
+///
+/// Newlines in the text are preserved and words are split along spaces.
+/// This is used for text that's displayed around the actual table, such as footnotes.
+pub fn wrap_text(text: &str, width: usize, measure: WidthFunction) -> Vec<String> {
+    let info = ColumnDisplayInfo {
+        padding: (0, 0),
+        delimiter: None,
+        content_width: std::cmp::max(1, width).try_into().unwrap_or(u16::MAX),
+        cell_alignment: None,
+        is_hidden: false,
+        raw_ansi: false,
+        hanging_indent: 0,
+        delimiters: Vec::new(),
+        #[cfg(feature = "regex")]
+        split_regex: None,
+    };
+
+    let mut lines = Vec::new();
+    for line in text.split('\n') {
+        if measure_text_width(line, measure) > usize::from(info.content_width) {
+            lines.append(&mut split_line(
+                line,
+                &info,
+                ' ',
+                WrapPolicy::BreakWords,
+                measure,
+            ));
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+
+    lines
+}
+
 /// This is the minimum of available characters per line.
 /// It's used to check, whether another element can be added to the current line.
 /// Otherwise the line will simply be left as it is and we start with a new one.
@@ -149,12 +472,80 @@ const MIN_FREE_CHARS: usize = 2;
 /// Check if the current line is too long and whether we should start a new one
 /// If it's too long, we add the current line to the list of lines and return a new [String].
 /// Otherwise, we simply return the current line and basically don't do anything.
-fn check_if_full(lines: &mut Vec<String>, content_width: usize, current_line: String) -> String {
+fn check_if_full(
+    lines: &mut Vec<String>,
+    content_width: usize,
+    current_line: String,
+    measure: WidthFunction,
+) -> String {
     // Already complete the current line, if there isn't space for more than two chars
-    if measure_text_width(&current_line) > content_width.saturating_sub(MIN_FREE_CHARS) {
+    if measure_text_width(&current_line, measure) > content_width.saturating_sub(MIN_FREE_CHARS) {
         lines.push(current_line);
         return String::new();
     }
 
     current_line
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: WidthFunction = DEFAULT_WIDTH_FUNCTION;
+
+    #[test]
+    fn measure_plain_text() {
+        assert_eq!(measure_text_width("Hello", WIDTH), 5);
+        assert_eq!(measure_text_width("コンフィ", WIDTH), 8);
+    }
+
+    #[test]
+    fn widest_character() {
+        assert_eq!(widest_character_width("Hello", WIDTH), 1);
+        assert_eq!(widest_character_width("abcコ", WIDTH), 2);
+        assert_eq!(widest_character_width("\x1b[1mBold\x1b[0m", WIDTH), 1);
+        assert_eq!(widest_character_width("", WIDTH), 0);
+    }
+
+    #[test]
+    fn grapheme_clusters() {
+        let family = "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+        assert_eq!(WIDTH(family), 2);
+        assert_eq!(widest_character_width(family, WIDTH), 2);
+        assert_eq!(WIDTH("\u{2764}\u{fe0f}"), 2);
+
+        // Clusters are never split, even if parts of them would fit.
+        let word = format!("a{family}b");
+        assert_eq!(
+            split_long_word(2, &word, WIDTH),
+            ("a".to_string(), format!("{family}b"))
+        );
+        assert_eq!(
+            split_long_word(3, &word, WIDTH),
+            (format!("a{family}"), "b".to_string())
+        );
+    }
+
+    #[test]
+    fn measure_csi() {
+        assert_eq!(
+            measure_text_width("\x1b[1m\x1b[38;5;10mBold\x1b[0m", WIDTH),
+            4
+        );
+    }
+
+    #[test]
+    fn measure_osc8() {
+        // Terminated with ST
+        let text = "\x1b]8;;https://github.com\x1b\\This is a link\x1b]8;;\x1b\\";
+        assert_eq!(measure_text_width(text, WIDTH), 14);
+
+        // Terminated with BEL
+        let text = "\x1b]8;;https://github.com\x07This is a link\x1b]8;;\x07";
+        assert_eq!(measure_text_width(text, WIDTH), 14);
+
+        // Terminated with a lone ESC
+        let text = "\x1b]8;;https://github.com\x1b\\This is a link\x1b]8;;\x1b";
+        assert_eq!(measure_text_width(text, WIDTH), 14);
+    }
+}
@@ -1,11 +1,6 @@
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use unicode_segmentation::UnicodeSegmentation;
 
-/// returns printed length of string
-/// if ansi feature enabled, takes into account escape codes
-#[inline(always)]
-pub fn measure_text_width(s: &str) -> usize {
-    s.width()
-}
+use crate::table::WidthFunction;
 
 /// Split a line into its individual parts along the given delimiter.
 pub fn split_line_by_delimiter(line: &str, delimiter: char) -> Vec<String> {
@@ -14,37 +9,78 @@ pub fn split_line_by_delimiter(line: &str, delimiter: char) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
+/// Split a line into its individual parts right after each of the given delimiters.
+/// The delimiters stay part of the preceding element.
+pub fn split_line_after_delimiters(line: &str, delimiters: &[char]) -> Vec<String> {
+    let mut elements: Vec<String> = line
+        .split_inclusive(delimiters)
+        .map(ToString::to_string)
+        .collect();
+    if elements.is_empty() {
+        elements.push(String::new());
+    }
+
+    elements
+}
+
+/// Split a line into its individual parts right after each match of the regex.
+/// The matched text stays part of the preceding element.
+#[cfg(feature = "regex")]
+pub fn split_line_by_regex(line: &str, regex: &regex::Regex) -> Vec<String> {
+    let mut elements = Vec::new();
+    let mut start = 0;
+    for found in regex.find_iter(line) {
+        // Skip empty matches at the start of an element, they would produce empty elements.
+        if found.end() > start {
+            elements.push(line[start..found.end()].to_string());
+            start = found.end();
+        }
+    }
+
+    if start < line.len() || elements.is_empty() {
+        elements.push(line[start..].to_string());
+    }
+
+    elements
+}
+
+/// Without the `custom_styling` feature, ansi codes are never inserted.
+/// This is the same as [split_long_word].
+pub fn split_long_word_raw(
+    allowed_width: usize,
+    word: &str,
+    measure: WidthFunction,
+) -> (String, String) {
+    split_long_word(allowed_width, word, measure)
+}
+
 /// Splits a long word at a given character width.
 /// This needs some special logic, as we have to take multi-character UTF-8 symbols into account.
 /// When simply splitting at a certain char position, we might end up with a string that's has a
 /// wider display width than allowed.
-pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
+///
+/// The word is split between grapheme clusters, so symbols that consist of several characters,
+/// such as emojis joined by zero width joiners, are never torn apart.
+pub fn split_long_word(
+    allowed_width: usize,
+    word: &str,
+    measure: WidthFunction,
+) -> (String, String) {
     let mut current_width = 0;
-    let mut parts = String::new();
-
-    let mut char_iter = word.chars().peekable();
-    // Check if the string might be too long, one character at a time.
-    // Peek into the next char and check the exit condition.
-    // That is, pushing the next character would result in the string being too long.
-    while let Some(c) = char_iter.peek() {
-        if (current_width + c.width().unwrap_or(1)) > allowed_width {
+    // The byte index right after the last grapheme that fits.
+    let mut end = 0;
+
+    // Check if the string might be too long, one grapheme at a time.
+    // That is, pushing the next grapheme would result in the string being too long.
+    for (index, grapheme) in word.grapheme_indices(true) {
+        let grapheme_width = measure(grapheme);
+        if current_width + grapheme_width > allowed_width {
             break;
         }
 
-        // We can unwrap, as we just checked that a suitable character is next in line.
-        let c = char_iter.next().unwrap();
-
-        // We default to 1 char, if the character length cannot be determined.
-        // The user has to live with this, if they decide to add control characters or some fancy
-        // stuff into their tables. This is considered undefined behavior and we try to handle this
-        // to the best of our capabilities.
-        let character_width = c.width().unwrap_or(1);
-
-        current_width += character_width;
-        parts.push(c);
+        current_width += grapheme_width;
+        end = index + grapheme.len();
     }
 
-    // Collect the remaining characters.
-    let remaining = char_iter.collect();
-    (parts, remaining)
+    (word[..end].to_string(), word[end..].to_string())
 }
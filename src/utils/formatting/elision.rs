@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+
+use super::content_split::measure_text_width;
+use crate::Table;
+
+/// Return the table with the content that all cells of a column have in common replaced by
+/// the column's marker, see [Column::fit_to_pattern](crate::Column::fit_to_pattern).
+///
+/// The table is only cloned, if there's anything to elide.
+pub(crate) fn elide_common_content(table: &Table) -> Cow<'_, Table> {
+    let mut elided: Option<Table> = None;
+    for column in table.columns.iter() {
+        let marker = match column.elision_marker.as_deref() {
+            Some(marker) => marker,
+            None => continue,
+        };
+
+        let (prefix, suffix) = common_affixes(table, column.index, marker);
+        if prefix == 0 && suffix == 0 {
+            continue;
+        }
+
+        let elided = elided.get_or_insert_with(|| table.clone());
+        for row in elided.rows.iter_mut() {
+            let cell = match row.cells.get_mut(column.index) {
+                Some(cell) => cell,
+                None => continue,
+            };
+            for line in cell.content.iter_mut().filter(|line| !line.is_empty()) {
+                *line = elide_line(line, prefix, suffix, marker);
+            }
+        }
+    }
+
+    match elided {
+        Some(table) => Cow::Owned(table),
+        None => Cow::Borrowed(table),
+    }
+}
+
+/// Check whether any column of the table elides its common content.
+pub(crate) fn has_elided_columns(table: &Table) -> bool {
+    table
+        .columns
+        .iter()
+        .any(|column| column.elision_marker.is_some())
+}
+
+/// Return the byte lengths of the prefix and suffix that all non-empty lines of a column share.
+///
+/// Affixes that aren't wider than the marker are ignored, as eliding them wouldn't save space.
+fn common_affixes(table: &Table, column_index: usize, marker: &str) -> (usize, usize) {
+    let lines: Vec<&str> = table
+        .rows
+        .iter()
+        .filter_map(|row| row.cells.get(column_index))
+        .flat_map(|cell| cell.content.iter())
+        .map(String::as_str)
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    // There's no pattern without at least two lines.
+    // Escape sequences could be cut in half, which is why such content is never elided.
+    if lines.len() < 2 || lines.iter().any(|line| line.contains('\x1b')) {
+        return (0, 0);
+    }
+
+    let mut prefix = lines[0].len();
+    for line in lines.iter().skip(1) {
+        prefix = common_prefix_length(&lines[0][..prefix], line);
+    }
+    // Keep at least one character of the shortest line.
+    if lines.iter().any(|line| line.len() == prefix) {
+        prefix -= last_char_length(&lines[0][..prefix]);
+    }
+
+    let remainders: Vec<&str> = lines.iter().map(|line| &line[prefix..]).collect();
+    let mut suffix = remainders[0].len();
+    for remainder in remainders.iter().skip(1) {
+        let first = remainders[0];
+        suffix = common_suffix_length(&first[first.len() - suffix..], remainder);
+    }
+    if remainders.iter().any(|remainder| remainder.len() == suffix) {
+        let first = remainders[0];
+        suffix -= first[first.len() - suffix..]
+            .chars()
+            .next()
+            .map_or(0, char::len_utf8);
+    }
+
+    let marker_width = measure_text_width(marker, table.measure());
+    if measure_text_width(&lines[0][..prefix], table.measure()) <= marker_width {
+        prefix = 0;
+    }
+    let first = remainders[0];
+    if measure_text_width(&first[first.len() - suffix..], table.measure()) <= marker_width {
+        suffix = 0;
+    }
+
+    (prefix, suffix)
+}
+
+/// The byte length of the longest prefix both strings share.
+fn common_prefix_length(first: &str, second: &str) -> usize {
+    first
+        .char_indices()
+        .zip(second.chars())
+        .find(|((_, left), right)| left != right)
+        .map_or(
+            std::cmp::min(first.len(), second.len()),
+            |((index, _), _)| index,
+        )
+}
+
+/// The byte length of the longest suffix both strings share.
+fn common_suffix_length(first: &str, second: &str) -> usize {
+    first
+        .char_indices()
+        .rev()
+        .zip(second.chars().rev())
+        .find(|((_, left), right)| left != right)
+        .map_or(
+            std::cmp::min(first.len(), second.len()),
+            |((index, character), _)| first.len() - index - character.len_utf8(),
+        )
+}
+
+fn last_char_length(text: &str) -> usize {
+    text.chars().last().map_or(0, char::len_utf8)
+}
+
+/// Replace the prefix and suffix with the given byte lengths by the marker.
+fn elide_line(line: &str, prefix: usize, suffix: usize, marker: &str) -> String {
+    let mut elided = String::new();
+    if prefix > 0 {
+        elided.push_str(marker);
+    }
+    elided.push_str(&line[prefix..line.len() - suffix]);
+    if suffix > 0 {
+        elided.push_str(marker);
+    }
+
+    elided
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn affixes() {
+        assert_eq!(common_prefix_length("/usr/bin", "/usr/lib"), 5);
+        assert_eq!(common_prefix_length("/usr", "/usr/lib"), 4);
+        assert_eq!(common_suffix_length("a.html", "bb.html"), 5);
+        assert_eq!(common_suffix_length("コンフィ", "ンフィ"), 9);
+    }
+}
@@ -0,0 +1,42 @@
+use super::content_split::{measure_text_width, wrap_text};
+use crate::cell::footnote_marker;
+use crate::table::Table;
+use crate::utils::arrangement::helper::table_width;
+use crate::utils::ColumnDisplayInfo;
+
+/// Draw all footnotes of the table, which are displayed below the table.
+///
+/// Each footnote is prefixed with its marker and wrapped to the table's width.
+/// Wrapped lines are indented, so they align with the text of the first line:
+/// ```text
+/// [1] This is a long footnote
+///     that has been wrapped.
+/// ```
+pub(crate) fn draw_footnotes(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    lines: &mut Vec<String>,
+) {
+    if table.footnotes.is_empty() {
+        return;
+    }
+
+    let width = table_width(table, display_info);
+    for (index, footnote) in table.footnotes.iter().enumerate() {
+        let prefix = format!("{} ", footnote_marker(index + 1));
+        let prefix_width = measure_text_width(&prefix, table.measure());
+
+        let wrapped = wrap_text(
+            footnote,
+            width.saturating_sub(prefix_width),
+            table.measure(),
+        );
+        for (line_index, line) in wrapped.into_iter().enumerate() {
+            if line_index == 0 {
+                lines.push(format!("{prefix}{line}"));
+            } else {
+                lines.push(format!("{}{line}", " ".repeat(prefix_width)));
+            }
+        }
+    }
+}
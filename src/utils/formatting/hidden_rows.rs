@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+
+use crate::Table;
+
+/// Return the table without its [hidden rows](crate::Row::set_hidden).
+///
+/// Groups and sections are moved along with the remaining rows.
+/// The rows keep their original index, so the rendered rows can be mapped back to the table.
+///
+/// The table is only cloned, if there's any hidden row.
+pub(crate) fn remove_hidden_rows(table: &Table) -> Cow<'_, Table> {
+    if !has_hidden_rows(table) {
+        return Cow::Borrowed(table);
+    }
+
+    // The new index of each row, i.e. the number of visible rows in front of it.
+    // The extra entry at the end is the index for groups and sections at the end of the table.
+    let mut new_indices = Vec::with_capacity(table.rows.len() + 1);
+    let mut visible = 0;
+    for row in table.rows.iter() {
+        new_indices.push(visible);
+        if !row.hidden {
+            visible += 1;
+        }
+    }
+    new_indices.push(visible);
+    let new_index = |index: usize| new_indices[index.min(table.rows.len())];
+
+    let mut filtered = table.clone_without_rows();
+    filtered.rows = table
+        .rows
+        .iter()
+        .filter(|row| !row.hidden)
+        .cloned()
+        .collect();
+    filtered.groups = table
+        .groups
+        .iter()
+        .cloned()
+        .map(|mut group| {
+            group.rows = new_index(group.rows.start)..new_index(group.rows.end);
+            group
+        })
+        .collect();
+    filtered.group_is_open = table.group_is_open;
+    filtered.sections = table
+        .sections
+        .iter()
+        .cloned()
+        .map(|mut section| {
+            section.row_index = new_index(section.row_index);
+            section
+        })
+        .collect();
+
+    Cow::Owned(filtered)
+}
+
+/// Check whether any row of the table is hidden.
+pub(crate) fn has_hidden_rows(table: &Table) -> bool {
+    table.rows.iter().any(|row| row.hidden)
+}
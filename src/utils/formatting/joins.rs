@@ -0,0 +1,197 @@
+use crate::style::TableComponent;
+use crate::style::TableComponent::*;
+use crate::table::Table;
+
+/// The weight of a line going into one direction of a box-drawing character.
+const NONE: u8 = 0;
+const LIGHT: u8 = 1;
+const HEAVY: u8 = 2;
+const DOUBLE: u8 = 3;
+
+/// All box-drawing characters that can be used to join lines.
+///
+/// The weights are in the order `[up, down, left, right]`.
+#[rustfmt::skip]
+const JOINS: &[(char, [u8; 4])] = &[
+    ('─', [NONE, NONE, LIGHT, LIGHT]), ('━', [NONE, NONE, HEAVY, HEAVY]),
+    ('│', [LIGHT, LIGHT, NONE, NONE]), ('┃', [HEAVY, HEAVY, NONE, NONE]),
+    ('═', [NONE, NONE, DOUBLE, DOUBLE]), ('║', [DOUBLE, DOUBLE, NONE, NONE]),
+    // Corners
+    ('┌', [NONE, LIGHT, NONE, LIGHT]), ('┍', [NONE, LIGHT, NONE, HEAVY]),
+    ('┎', [NONE, HEAVY, NONE, LIGHT]), ('┏', [NONE, HEAVY, NONE, HEAVY]),
+    ('┐', [NONE, LIGHT, LIGHT, NONE]), ('┑', [NONE, LIGHT, HEAVY, NONE]),
+    ('┒', [NONE, HEAVY, LIGHT, NONE]), ('┓', [NONE, HEAVY, HEAVY, NONE]),
+    ('└', [LIGHT, NONE, NONE, LIGHT]), ('┕', [LIGHT, NONE, NONE, HEAVY]),
+    ('┖', [HEAVY, NONE, NONE, LIGHT]), ('┗', [HEAVY, NONE, NONE, HEAVY]),
+    ('┘', [LIGHT, NONE, LIGHT, NONE]), ('┙', [LIGHT, NONE, HEAVY, NONE]),
+    ('┚', [HEAVY, NONE, LIGHT, NONE]), ('┛', [HEAVY, NONE, HEAVY, NONE]),
+    ('╭', [NONE, LIGHT, NONE, LIGHT]), ('╮', [NONE, LIGHT, LIGHT, NONE]),
+    ('╰', [LIGHT, NONE, NONE, LIGHT]), ('╯', [LIGHT, NONE, LIGHT, NONE]),
+    ('╒', [NONE, LIGHT, NONE, DOUBLE]), ('╓', [NONE, DOUBLE, NONE, LIGHT]),
+    ('╔', [NONE, DOUBLE, NONE, DOUBLE]), ('╕', [NONE, LIGHT, DOUBLE, NONE]),
+    ('╖', [NONE, DOUBLE, LIGHT, NONE]), ('╗', [NONE, DOUBLE, DOUBLE, NONE]),
+    ('╘', [LIGHT, NONE, NONE, DOUBLE]), ('╙', [DOUBLE, NONE, NONE, LIGHT]),
+    ('╚', [DOUBLE, NONE, NONE, DOUBLE]), ('╛', [LIGHT, NONE, DOUBLE, NONE]),
+    ('╜', [DOUBLE, NONE, LIGHT, NONE]), ('╝', [DOUBLE, NONE, DOUBLE, NONE]),
+    // Left/right intersections
+    ('├', [LIGHT, LIGHT, NONE, LIGHT]), ('┝', [LIGHT, LIGHT, NONE, HEAVY]),
+    ('┞', [HEAVY, LIGHT, NONE, LIGHT]), ('┟', [LIGHT, HEAVY, NONE, LIGHT]),
+    ('┠', [HEAVY, HEAVY, NONE, LIGHT]), ('┡', [HEAVY, LIGHT, NONE, HEAVY]),
+    ('┢', [LIGHT, HEAVY, NONE, HEAVY]), ('┣', [HEAVY, HEAVY, NONE, HEAVY]),
+    ('┤', [LIGHT, LIGHT, LIGHT, NONE]), ('┥', [LIGHT, LIGHT, HEAVY, NONE]),
+    ('┦', [HEAVY, LIGHT, LIGHT, NONE]), ('┧', [LIGHT, HEAVY, LIGHT, NONE]),
+    ('┨', [HEAVY, HEAVY, LIGHT, NONE]), ('┩', [HEAVY, LIGHT, HEAVY, NONE]),
+    ('┪', [LIGHT, HEAVY, HEAVY, NONE]), ('┫', [HEAVY, HEAVY, HEAVY, NONE]),
+    ('╞', [LIGHT, LIGHT, NONE, DOUBLE]), ('╟', [DOUBLE, DOUBLE, NONE, LIGHT]),
+    ('╠', [DOUBLE, DOUBLE, NONE, DOUBLE]), ('╡', [LIGHT, LIGHT, DOUBLE, NONE]),
+    ('╢', [DOUBLE, DOUBLE, LIGHT, NONE]), ('╣', [DOUBLE, DOUBLE, DOUBLE, NONE]),
+    // Top/bottom intersections
+    ('┬', [NONE, LIGHT, LIGHT, LIGHT]), ('┭', [NONE, LIGHT, HEAVY, LIGHT]),
+    ('┮', [NONE, LIGHT, LIGHT, HEAVY]), ('┯', [NONE, LIGHT, HEAVY, HEAVY]),
+    ('┰', [NONE, HEAVY, LIGHT, LIGHT]), ('┱', [NONE, HEAVY, HEAVY, LIGHT]),
+    ('┲', [NONE, HEAVY, LIGHT, HEAVY]), ('┳', [NONE, HEAVY, HEAVY, HEAVY]),
+    ('┴', [LIGHT, NONE, LIGHT, LIGHT]), ('┵', [LIGHT, NONE, HEAVY, LIGHT]),
+    ('┶', [LIGHT, NONE, LIGHT, HEAVY]), ('┷', [LIGHT, NONE, HEAVY, HEAVY]),
+    ('┸', [HEAVY, NONE, LIGHT, LIGHT]), ('┹', [HEAVY, NONE, HEAVY, LIGHT]),
+    ('┺', [HEAVY, NONE, LIGHT, HEAVY]), ('┻', [HEAVY, NONE, HEAVY, HEAVY]),
+    ('╤', [NONE, LIGHT, DOUBLE, DOUBLE]), ('╥', [NONE, DOUBLE, LIGHT, LIGHT]),
+    ('╦', [NONE, DOUBLE, DOUBLE, DOUBLE]), ('╧', [LIGHT, NONE, DOUBLE, DOUBLE]),
+    ('╨', [DOUBLE, NONE, LIGHT, LIGHT]), ('╩', [DOUBLE, NONE, DOUBLE, DOUBLE]),
+    // Middle intersections
+    ('┼', [LIGHT, LIGHT, LIGHT, LIGHT]), ('┽', [LIGHT, LIGHT, HEAVY, LIGHT]),
+    ('┾', [LIGHT, LIGHT, LIGHT, HEAVY]), ('┿', [LIGHT, LIGHT, HEAVY, HEAVY]),
+    ('╀', [HEAVY, LIGHT, LIGHT, LIGHT]), ('╁', [LIGHT, HEAVY, LIGHT, LIGHT]),
+    ('╂', [HEAVY, HEAVY, LIGHT, LIGHT]), ('╃', [HEAVY, LIGHT, HEAVY, LIGHT]),
+    ('╄', [HEAVY, LIGHT, LIGHT, HEAVY]), ('╅', [LIGHT, HEAVY, HEAVY, LIGHT]),
+    ('╆', [LIGHT, HEAVY, LIGHT, HEAVY]), ('╇', [HEAVY, LIGHT, HEAVY, HEAVY]),
+    ('╈', [LIGHT, HEAVY, HEAVY, HEAVY]), ('╉', [HEAVY, HEAVY, HEAVY, LIGHT]),
+    ('╊', [HEAVY, HEAVY, LIGHT, HEAVY]), ('╋', [HEAVY, HEAVY, HEAVY, HEAVY]),
+    ('╪', [LIGHT, LIGHT, DOUBLE, DOUBLE]), ('╫', [DOUBLE, DOUBLE, LIGHT, LIGHT]),
+    ('╬', [DOUBLE, DOUBLE, DOUBLE, DOUBLE]),
+];
+
+/// Get the line weight of a horizontal line character.
+fn horizontal_weight(character: char) -> Option<u8> {
+    match character {
+        '─' | '┄' | '┈' | '╌' => Some(LIGHT),
+        '━' | '┅' | '┉' | '╍' => Some(HEAVY),
+        '═' => Some(DOUBLE),
+        _ => None,
+    }
+}
+
+/// Get the line weight of a vertical line character.
+fn vertical_weight(character: char) -> Option<u8> {
+    match character {
+        '│' | '┆' | '┊' | '╎' => Some(LIGHT),
+        '┃' | '┇' | '┋' | '╏' => Some(HEAVY),
+        '║' => Some(DOUBLE),
+        _ => None,
+    }
+}
+
+/// Return the components whose lines meet at the given component.
+///
+/// The components are in the order `[up, down, left, right]`.
+/// Returns `None` for components that are lines themselves.
+fn neighbors(component: TableComponent) -> Option<[Option<TableComponent>; 4]> {
+    let neighbors = match component {
+        TopLeftCorner => [None, Some(LeftBorder), None, Some(TopBorder)],
+        TopRightCorner => [None, Some(RightBorder), Some(TopBorder), None],
+        BottomLeftCorner => [Some(LeftBorder), None, None, Some(BottomBorder)],
+        BottomRightCorner => [Some(RightBorder), None, Some(BottomBorder), None],
+        TopBorderIntersections => [None, Some(VerticalLines), Some(TopBorder), Some(TopBorder)],
+        BottomBorderIntersections => [
+            Some(VerticalLines),
+            None,
+            Some(BottomBorder),
+            Some(BottomBorder),
+        ],
+        LeftHeaderIntersection => [Some(LeftBorder), Some(LeftBorder), None, Some(HeaderLines)],
+        RightHeaderIntersection => [
+            Some(RightBorder),
+            Some(RightBorder),
+            Some(HeaderLines),
+            None,
+        ],
+        MiddleHeaderIntersections => [
+            Some(VerticalLines),
+            Some(VerticalLines),
+            Some(HeaderLines),
+            Some(HeaderLines),
+        ],
+        LeftBorderIntersections => [
+            Some(LeftBorder),
+            Some(LeftBorder),
+            None,
+            Some(HorizontalLines),
+        ],
+        RightBorderIntersections => [
+            Some(RightBorder),
+            Some(RightBorder),
+            Some(HorizontalLines),
+            None,
+        ],
+        MiddleIntersections => [
+            Some(VerticalLines),
+            Some(VerticalLines),
+            Some(HorizontalLines),
+            Some(HorizontalLines),
+        ],
+        LeftBorder | RightBorder | TopBorder | BottomBorder | HeaderLines | VerticalLines
+        | HorizontalLines => return None,
+    };
+
+    Some(neighbors)
+}
+
+/// Determine the character of a component, so it visually joins the lines around it.
+///
+/// This only touches components that are drawn with box-drawing characters.
+/// If no suitable character exists (e.g. there's no box-drawing character that joins heavy and
+/// double lines) or if any of the adjacent lines isn't a box-drawing line, the configured
+/// character is returned as is.
+pub(crate) fn joined_style(table: &Table, component: TableComponent) -> Option<char> {
+    let current = table.style[component as usize]?;
+    let neighbors = match neighbors(component) {
+        Some(neighbors) => neighbors,
+        None => return Some(current),
+    };
+
+    // Only touch box-drawing characters.
+    if !('\u{2500}'..='\u{257F}').contains(&current) {
+        return Some(current);
+    }
+
+    let mut weights = [NONE; 4];
+    for (index, neighbor) in neighbors.iter().enumerate() {
+        let character = match neighbor.and_then(|neighbor| table.style[neighbor as usize]) {
+            Some(character) => character,
+            None => continue,
+        };
+
+        // Index 0 and 1 are up and down, which are vertical lines.
+        let weight = if index < 2 {
+            vertical_weight(character)
+        } else {
+            horizontal_weight(character)
+        };
+        match weight {
+            Some(weight) => weights[index] = weight,
+            None => return Some(current),
+        }
+    }
+
+    // Keep the current character, if it already joins correctly.
+    // This is for instance the case for round corners.
+    if JOINS.contains(&(current, weights)) {
+        return Some(current);
+    }
+
+    let joined = JOINS
+        .iter()
+        .find(|(_, join_weights)| *join_weights == weights)
+        .map(|(character, _)| *character);
+
+    Some(joined.unwrap_or(current))
+}
@@ -1,3 +1,78 @@
+#[cfg(feature = "unicode-bidi")]
+pub(crate) mod bidi;
 pub mod borders;
+pub(crate) mod column_order;
 pub mod content_format;
 pub mod content_split;
+pub(crate) mod elision;
+pub mod footnotes;
+pub(crate) mod hidden_rows;
+pub(crate) mod joins;
+pub(crate) mod numbers;
+pub(crate) mod style_layers;
+pub(crate) mod templates;
+pub(crate) mod titles;
+pub(crate) mod verify;
+
+use std::borrow::Cow;
+
+use crate::Table;
+use column_order::reorder_columns;
+use elision::{elide_common_content, has_elided_columns};
+use hidden_rows::{has_hidden_rows, remove_hidden_rows};
+use numbers::{format_numbers, has_number_formats};
+use style_layers::apply_style_layers;
+use templates::{has_templates, resolve_templates};
+
+/// Apply all [style layers](Table::push_style_layer), remove all
+/// [hidden rows](crate::Row::set_hidden), bring the columns into their
+/// [display order](Table::set_column_order), resolve all [templates](crate::Cell::new_template),
+/// format all [numbers](crate::Column::set_number_format) and elide common content afterwards.
+///
+/// This has to happen before the table is measured, as it changes the content of cells.
+pub(crate) fn prepare_content(table: &Table) -> Cow<'_, Table> {
+    if !needs_preparation(table) {
+        return Cow::Borrowed(table);
+    }
+
+    let table = apply_style_layers(table);
+    let table = replace_if_owned(table, remove_hidden_rows);
+    let table = replace_if_owned(table, reorder_columns);
+    let table = replace_if_owned(table, resolve_templates);
+    let table = replace_if_owned(table, format_numbers);
+
+    replace_if_owned(table, elide_common_content)
+}
+
+/// Check whether [prepare_content] might change the table.
+///
+/// If it doesn't, the rows of the prepared table line up with the rows of the table and the
+/// content of each row doesn't depend on any other row.
+/// - Style layers are only applied to a copy of the table.
+/// - Hidden rows are removed.
+/// - Reordered columns don't line up with the cells of the rows.
+/// - Templates, aligned decimal points and elided content depend on all rows.
+pub(crate) fn needs_preparation(table: &Table) -> bool {
+    !table.style_layers.is_empty()
+        || has_hidden_rows(table)
+        || table.column_order.is_some()
+        || has_templates(table)
+        || has_number_formats(table)
+        || has_elided_columns(table)
+}
+
+/// Apply a step of the preparation and only keep its result, if the table had to be changed.
+fn replace_if_owned<'a>(
+    table: Cow<'a, Table>,
+    step: impl for<'b> Fn(&'b Table) -> Cow<'b, Table>,
+) -> Cow<'a, Table> {
+    let changed = match step(table.as_ref()) {
+        Cow::Owned(changed) => Some(changed),
+        Cow::Borrowed(_) => None,
+    };
+
+    match changed {
+        Some(changed) => Cow::Owned(changed),
+        None => table,
+    }
+}
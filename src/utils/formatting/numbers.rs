@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use crate::Table;
+
+/// Check whether any column of the table has a [number format](crate::Column::set_number_format).
+pub(crate) fn has_number_formats(table: &Table) -> bool {
+    table
+        .columns
+        .iter()
+        .any(|column| column.number_format.is_some())
+}
+
+/// Return the table with the numbers of all columns with a
+/// [number format](crate::Column::set_number_format) formatted accordingly.
+///
+/// Only single-line body cells, whose content is a plain decimal number, are touched.
+/// If the decimal points are aligned, the numbers are padded with spaces, so that the
+/// parts in front of and after the decimal point have the same width in the whole column.
+///
+/// The table is only cloned, if any column has a number format.
+pub(crate) fn format_numbers(table: &Table) -> Cow<'_, Table> {
+    if !has_number_formats(table) {
+        return Cow::Borrowed(table);
+    }
+
+    let mut formatted = table.clone();
+    for column in table.columns.iter() {
+        let format = match column.number_format.as_ref() {
+            Some(format) => format,
+            None => continue,
+        };
+
+        // Format all numbers and remember the cells they belong to.
+        let numbers: Vec<(usize, String, String)> = table
+            .rows
+            .iter()
+            .enumerate()
+            .filter_map(|(row_index, row)| {
+                let cell = row.cells.get(column.index)?;
+                if cell.template.is_some() || cell.content.len() != 1 {
+                    return None;
+                }
+                let (integer, fraction) = format.format(cell.content[0].trim())?;
+
+                Some((row_index, integer, fraction))
+            })
+            .collect();
+
+        let (integer_width, fraction_width) = if format.align_decimal_point {
+            numbers.iter().fold(
+                (0, 0),
+                |(integer_width, fraction_width), (_, integer, fraction)| {
+                    (
+                        integer_width.max(integer.chars().count()),
+                        fraction_width.max(fraction.chars().count()),
+                    )
+                },
+            )
+        } else {
+            (0, 0)
+        };
+
+        for (row_index, integer, fraction) in numbers {
+            let cell = &mut formatted.rows[row_index].cells[column.index];
+            cell.content = vec![format!(
+                "{integer:>integer_width$}{fraction:<fraction_width$}"
+            )];
+            // Trimming would remove the padding that aligns the decimal points.
+            if format.align_decimal_point {
+                cell.preserve_whitespace = true;
+            }
+        }
+    }
+
+    Cow::Owned(formatted)
+}
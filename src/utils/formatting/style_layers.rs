@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+use crate::Table;
+
+/// Return the table with all of its [style layers](Table::push_style_layer) applied to its
+/// own style, from the bottom to the top.
+///
+/// The table is only cloned, if there's any layer.
+pub(crate) fn apply_style_layers(table: &Table) -> Cow<'_, Table> {
+    if table.style_layers.is_empty() {
+        return Cow::Borrowed(table);
+    }
+
+    let mut flattened = table.clone();
+    let layers = std::mem::take(&mut flattened.style_layers);
+    for layer in layers.iter() {
+        flattened.set_table_style(&layer.border);
+        #[cfg(feature = "tty")]
+        if let Some(style) = layer.header_style.as_ref() {
+            flattened.header_style = style.clone();
+        }
+        #[cfg(feature = "tty")]
+        if let Some(style) = layer.body_style.as_ref() {
+            flattened.body_style = style.clone();
+        }
+    }
+
+    Cow::Owned(flattened)
+}
@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use crate::cell::Cell;
+use crate::Table;
+
+/// Replace all `{name}` placeholders of a template with the value returned by the lookup.
+///
+/// Placeholders without a value are kept as they are.
+/// Literal braces are written as `{{` and `}}`.
+pub(crate) fn fill_template(template: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find(['{', '}']) {
+        filled.push_str(&rest[..start]);
+        let tail = &rest[start..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            filled.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+
+        if let Some(name) = tail.strip_prefix('{') {
+            if let Some(end) = name.find('}') {
+                match lookup(&name[..end]) {
+                    Some(value) => filled.push_str(&value),
+                    None => filled.push_str(&tail[..end + 2]),
+                }
+                rest = &name[end + 1..];
+                continue;
+            }
+        }
+
+        // A lone brace without a counterpart.
+        filled.push_str(&tail[..1]);
+        rest = &tail[1..];
+    }
+    filled.push_str(rest);
+
+    filled
+}
+
+/// Check whether any cell of the table is a [template](Cell::new_template).
+pub(crate) fn has_templates(table: &Table) -> bool {
+    table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .flat_map(|row| row.cells.iter())
+        .any(|cell| cell.template.is_some())
+}
+
+/// Return the table with the placeholders of all template cells resolved.
+///
+/// Besides the values of each cell, the following placeholders are available:
+/// - `total`: The sum of the numeric values of all body cells in the same column.
+/// - `percent`: The cell's `value` in percent of `total`, rounded to an integer.
+///
+/// The table is only cloned, if there are any templates.
+pub(crate) fn resolve_templates(table: &Table) -> Cow<'_, Table> {
+    if !has_templates(table) {
+        return Cow::Borrowed(table);
+    }
+
+    let totals: Vec<f64> = table
+        .columns
+        .iter()
+        .map(|column| {
+            table
+                .rows
+                .iter()
+                .filter_map(|row| row.cells.get(column.index))
+                .filter_map(numeric_value)
+                .sum()
+        })
+        .collect();
+
+    let mut resolved = table.clone();
+    let rows = resolved.header.iter_mut().chain(resolved.rows.iter_mut());
+    for row in rows {
+        for (index, cell) in row.cells.iter_mut().enumerate() {
+            let template = match cell.template.as_ref() {
+                Some(template) => template,
+                None => continue,
+            };
+            let total = totals.get(index).copied();
+            let value = numeric_value(cell);
+
+            let content = fill_template(&template.template, |name| {
+                if let Some(value) = template.values.get(name) {
+                    return Some(value.clone());
+                }
+
+                match name {
+                    "total" => total.map(|total| total.to_string()),
+                    "percent" => match (value, total) {
+                        (Some(value), Some(total)) if total != 0.0 => {
+                            Some(format!("{:.0}", value / total * 100.0))
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            });
+            cell.content = content.split('\n').map(ToString::to_string).collect();
+        }
+    }
+
+    Cow::Owned(resolved)
+}
+
+/// The numeric value of a cell, which is used to calculate column totals.
+///
+/// That's the `value` of template cells and the content of all other cells.
+fn numeric_value(cell: &Cell) -> Option<f64> {
+    let value = match cell.template.as_ref() {
+        Some(template) => template.values.get("value")?.clone(),
+        None => cell.content(),
+    };
+
+    value.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_template() {
+        let lookup = |name: &str| (name == "value").then(|| "42".to_string());
+
+        assert_eq!(fill_template("{value} ({pct}%)", lookup), "42 ({pct}%)");
+        assert_eq!(fill_template("{{value}} {value}", lookup), "{value} 42");
+        assert_eq!(
+            fill_template("open { and close }", lookup),
+            "open { and close }"
+        );
+        assert_eq!(fill_template("{value", lookup), "{value");
+    }
+}
@@ -0,0 +1,126 @@
+use super::borders::should_draw_top_border;
+use super::content_format::center_offset;
+use super::content_split::{measure_text_width, wrap_text};
+use crate::style::CellAlignment;
+use crate::table::Table;
+use crate::utils::arrangement::helper::table_width;
+use crate::utils::ColumnDisplayInfo;
+
+/// Draw the title of the table.
+///
+/// `start` is the index of the table's first line. The title is either embedded into the top
+/// border at this index or inserted right before it.
+pub(crate) fn draw_title(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    lines: &mut Vec<String>,
+    start: usize,
+) {
+    let title = match table.title.as_ref() {
+        Some(title) => title,
+        None => return,
+    };
+
+    if table.title_in_border && should_draw_top_border(table) {
+        if let Some(border) = lines.get_mut(start) {
+            if let Some(embedded) = embed_title(table, border, title) {
+                *border = embedded;
+                return;
+            }
+        }
+    }
+
+    let width = table_width(table, display_info);
+    let title = aligned_lines(table, title, width, table.title_alignment);
+    lines.splice(start..start, title);
+}
+
+/// Draw the caption of the table, right below the table.
+pub(crate) fn draw_caption(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    lines: &mut Vec<String>,
+) {
+    if let Some(caption) = table.caption.as_ref() {
+        let width = table_width(table, display_info);
+        lines.extend(aligned_lines(
+            table,
+            caption,
+            width,
+            table.caption_alignment,
+        ));
+    }
+}
+
+/// The width of the table that's needed to display the title and the caption without wrapping.
+///
+/// A title in the top border needs a space on each side and keeps at least one character of the
+/// border next to each corner.
+pub(crate) fn title_width(table: &Table) -> usize {
+    let widest_line = |text: &str| {
+        text.lines()
+            .map(|line| measure_text_width(line, table.measure()))
+            .max()
+            .unwrap_or(0)
+    };
+
+    let title = table.title.as_deref().map_or(0, |title| {
+        if table.title_in_border && should_draw_top_border(table) && !title.contains('\n') {
+            measure_text_width(title, table.measure()) + 6
+        } else {
+            widest_line(title)
+        }
+    });
+    let caption = table.caption.as_deref().map_or(0, widest_line);
+
+    std::cmp::max(title, caption)
+}
+
+/// Wrap the text to the given width and align each line.
+/// Lines aren't padded on the right side.
+fn aligned_lines(table: &Table, text: &str, width: usize, alignment: CellAlignment) -> Vec<String> {
+    wrap_text(text, width, table.measure())
+        .into_iter()
+        .map(|line| {
+            let remaining = width.saturating_sub(measure_text_width(&line, table.measure()));
+            let padding = match alignment {
+                CellAlignment::Left => 0,
+                CellAlignment::Center => center_offset(remaining, table.center_bias),
+                CellAlignment::Right => remaining,
+            };
+            format!("{}{line}", " ".repeat(padding))
+        })
+        .collect()
+}
+
+/// Replace a part of the top border with the title, surrounded by a space on each side.
+///
+/// At least one character of the border is kept next to each corner.
+/// Returns `None`, if the title doesn't fit or the border is styled.
+fn embed_title(table: &Table, border: &str, title: &str) -> Option<String> {
+    if title.contains('\n') || border.contains('\x1b') {
+        return None;
+    }
+
+    let border: Vec<char> = border.chars().collect();
+    let title = format!(" {title} ");
+    let title_width = measure_text_width(&title, table.measure());
+    // The corners and a single border character next to them.
+    let available = border.len().checked_sub(4)?;
+    if title_width > available {
+        return None;
+    }
+
+    let offset = match table.title_alignment {
+        CellAlignment::Left => 0,
+        CellAlignment::Center => center_offset(available - title_width, table.center_bias),
+        CellAlignment::Right => available - title_width,
+    };
+    let start = 2 + offset;
+
+    let mut embedded: String = border[..start].iter().collect();
+    embedded.push_str(&title);
+    embedded.extend(border[start + title_width..].iter());
+
+    Some(embedded)
+}
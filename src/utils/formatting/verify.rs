@@ -0,0 +1,51 @@
+use std::ops::Range;
+
+use super::content_split::{for_each_printable_run, measure_text_width};
+use crate::error::OutputIssue;
+use crate::table::WidthFunction;
+
+/// Check the lines of a single table and return all issues.
+///
+/// All lines of a table must have the same display width as its first line and must not contain
+/// any control characters, except for those of ANSI escape sequences.
+/// The line numbers of the issues are the indices in `lines`.
+pub(crate) fn find_issues(
+    lines: &[String],
+    range: Range<usize>,
+    measure: WidthFunction,
+) -> Vec<OutputIssue> {
+    let mut issues = Vec::new();
+    let expected = match lines.get(range.start) {
+        Some(line) => measure_text_width(line, measure),
+        None => return issues,
+    };
+
+    for (index, line) in lines[range.clone()].iter().enumerate() {
+        let index = range.start + index;
+        let width = measure_text_width(line, measure);
+        if width != expected {
+            issues.push(OutputIssue::UnevenWidth {
+                line: index,
+                content: line.clone(),
+                width,
+                expected,
+            });
+        }
+
+        let mut control_character = None;
+        for_each_printable_run(line, |run| {
+            if control_character.is_none() {
+                control_character = run.chars().find(|character| character.is_control());
+            }
+        });
+        if let Some(character) = control_character {
+            issues.push(OutputIssue::ControlCharacter {
+                line: index,
+                content: line.clone(),
+                character,
+            });
+        }
+    }
+
+    issues
+}
@@ -1,12 +1,25 @@
 pub mod arrangement;
 pub mod formatting;
+pub(crate) mod overflow;
 
+use std::borrow::Cow;
+use std::cmp::{max, min};
+use std::ops::Range;
+
+use crate::row::Section;
 use crate::style::{CellAlignment, ColumnConstraint};
-use crate::{Column, Table};
+use crate::{Cell, Column, ConstraintReport, RenderedRow, Table, TruncatedContent, Viewport};
 
 use arrangement::arrange_content;
-use formatting::borders::draw_borders;
-use formatting::content_format::format_content;
+use arrangement::constraint;
+use arrangement::helper::count_visible_columns;
+use formatting::borders::{draw_borders, draw_borders_with_rows};
+use formatting::column_order::displayed_columns;
+use formatting::content_format::{format_content, format_row, row_kinds, RowKind};
+use formatting::footnotes::draw_footnotes;
+use formatting::prepare_content;
+use formatting::titles::{draw_caption, draw_title};
+use overflow::handle_overflow;
 
 /// This struct is ONLY used when table.to_string() is called.
 /// It's purpose is to store intermediate results, information on how to
@@ -14,7 +27,7 @@ use formatting::content_format::format_content;
 ///
 /// The idea is to have a place for all this intermediate stuff, without
 /// actually touching the Column struct.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ColumnDisplayInfo {
     pub padding: (u16, u16),
     pub delimiter: Option<char>,
@@ -22,7 +35,17 @@ pub struct ColumnDisplayInfo {
     pub content_width: u16,
     /// The content alignment of cells in this column
     pub cell_alignment: Option<CellAlignment>,
-    is_hidden: bool,
+    pub(crate) is_hidden: bool,
+    /// ANSI escape sequences of the content are passed through as they are.
+    /// This is only ever set for a specific cell, see [ColumnDisplayInfo::for_cell].
+    pub(crate) raw_ansi: bool,
+    /// Indentation of the continuation lines of wrapped content
+    pub(crate) hanging_indent: u16,
+    /// Split content right after each of these characters instead of at the delimiter
+    pub(crate) delimiters: Vec<char>,
+    /// Split content at the end of each match instead of at the delimiter
+    #[cfg(feature = "regex")]
+    pub(crate) split_regex: Option<regex::Regex>,
 }
 
 impl ColumnDisplayInfo {
@@ -37,6 +60,11 @@ impl ColumnDisplayInfo {
             content_width,
             cell_alignment: column.cell_alignment,
             is_hidden: matches!(column.constraint, Some(ColumnConstraint::Hidden)),
+            raw_ansi: false,
+            hanging_indent: column.hanging_indent,
+            delimiters: column.delimiters.clone(),
+            #[cfg(feature = "regex")]
+            split_regex: column.split_regex.clone(),
         }
     }
 
@@ -45,10 +73,376 @@ impl ColumnDisplayInfo {
             .saturating_add(self.padding.0)
             .saturating_add(self.padding.1)
     }
+
+    /// Get the display info that's used for a specific cell of this column.
+    ///
+    /// A cell with its own [padding](Cell::set_padding) keeps the total width of the column,
+    /// but the width of its content changes accordingly.
+    /// The content is always left with at least a single character.
+    pub(crate) fn for_cell(&self, cell: &Cell) -> Cow<'_, Self> {
+        let padding = cell.padding.filter(|padding| *padding != self.padding);
+        if padding.is_none() && !cell.raw_ansi {
+            return Cow::Borrowed(self);
+        }
+
+        let mut info = self.clone();
+        info.raw_ansi = cell.raw_ansi;
+        if let Some((left, right)) = padding {
+            let width = self.width();
+            let left = min(left, width.saturating_sub(1));
+            let right = min(right, width.saturating_sub(1).saturating_sub(left));
+
+            info.padding = (left, right);
+            info.content_width = max(1, width.saturating_sub(left).saturating_sub(right));
+        }
+
+        Cow::Owned(info)
+    }
 }
 
 pub fn build_table(table: &Table) -> impl Iterator<Item = String> {
-    let display_info = arrange_content(table);
+    let mut lines = Vec::new();
+    build_table_into(table, &mut lines);
+
+    lines.into_iter()
+}
+
+/// Same as [build_table], but the lines are written into an existing vector.
+/// The vector is cleared beforehand, but keeps its capacity.
+///
+/// Returns the ranges of the lines that belong to the table itself, without its title, caption
+/// and footnotes. Tables that overflow might be displayed as several tables.
+pub(crate) fn build_table_into(table: &Table, lines: &mut Vec<String>) -> Vec<Range<usize>> {
+    lines.clear();
+
+    if table.is_hidden() {
+        return Vec::new();
+    }
+
+    let table = prepare_content(table);
+    let table = table.as_ref();
+    let display_info = match table.frozen_layout.as_ref() {
+        // Skip the arrangement, if a layout has been loaded that still fits the table's schema.
+        Some(layout) if layout.matches(table) => layout.display_info(table),
+        _ => {
+            // The table doesn't fit and needs to be displayed differently.
+            if let Some(ranges) = handle_overflow(table, lines) {
+                return ranges;
+            }
+
+            arrange_content(table)
+        }
+    };
+
+    vec![draw_table_with_max_height(table, &display_info, lines)]
+}
+
+/// Check whether each row of the table's body is formatted and drawn independently of all other
+/// rows, which allows to reuse the formatted content of rows that didn't change.
+///
+/// Otherwise, the table has to be rendered as a whole:
+/// - Hidden tables and tables that show their empty message don't have any rows.
+/// - Sections don't line up with the rows of the table.
+/// - Truncation markers are numbered across all rows.
+/// - The rows that fit into the max height depend on all rows.
+/// - The content might be [prepared](formatting::needs_preparation) across all rows.
+pub(crate) fn draws_rows_independently(table: &Table) -> bool {
+    !table.is_hidden()
+        && !table.shows_empty_message()
+        && table.sections.is_empty()
+        && !table.truncation_markers
+        && table.max_height.is_none()
+        && !formatting::needs_preparation(table)
+}
+
+/// Arrange the content of the table, unless a frozen layout that fits the table is loaded.
+///
+/// In contrast to rendering, overflow policies aren't applied.
+fn arranged_display_info(table: &Table) -> Vec<ColumnDisplayInfo> {
+    match table.frozen_layout.as_ref() {
+        Some(layout) if layout.matches(table) => layout.display_info(table),
+        _ => arrange_content(table),
+    }
+}
+
+/// Get the width of each column, including padding, as it's used during rendering.
+/// Hidden columns and columns that aren't part of the [column order](Table::set_column_order)
+/// are `None`.
+pub(crate) fn column_display_widths(table: &Table) -> Vec<Option<u16>> {
+    let order = displayed_columns(table);
+    let prepared = prepare_content(table);
+
+    let mut widths = vec![None; table.columns.len()];
+    for (info, source) in arranged_display_info(prepared.as_ref()).iter().zip(order) {
+        widths[source] = (!info.is_hidden).then(|| info.width());
+    }
+
+    widths
+}
+
+/// Check for each column, whether its constraint is satisfied by the arranged width.
+pub(crate) fn constraint_report(table: &Table) -> Vec<ConstraintReport> {
+    let widths = column_display_widths(table);
+    let max_content_widths = table.column_max_content_widths();
+    let visible_columns = count_visible_columns(&prepare_content(table).columns);
+
+    table
+        .columns
+        .iter()
+        .zip(widths)
+        .map(|(column, width)| {
+            let (min_width, max_width) = match &column.constraint {
+                Some(ColumnConstraint::Absolute(absolute)) => {
+                    let absolute =
+                        constraint::absolute_value_from_width(table, absolute, visible_columns);
+                    (absolute, absolute)
+                }
+                constraint => (
+                    constraint::min(table, constraint, visible_columns),
+                    constraint::max(table, constraint, visible_columns),
+                ),
+            };
+
+            let honored = match (&column.constraint, width) {
+                (None, _) => true,
+                (Some(ColumnConstraint::Hidden), width) => width.is_none(),
+                // Columns that aren't displayed cannot satisfy any other constraint.
+                (_, None) => false,
+                (Some(ColumnConstraint::ContentWidth), Some(width)) => {
+                    let content_width = max_content_widths[column.index];
+                    width >= content_width.saturating_add(column.padding_width())
+                }
+                (Some(ColumnConstraint::Absolute(_)), Some(width)) => max_width == Some(width),
+                (Some(ColumnConstraint::LowerBoundary(_)), Some(width)) => {
+                    min_width.map_or(false, |min| width >= min)
+                }
+                (Some(ColumnConstraint::UpperBoundary(_)), Some(width)) => {
+                    max_width.map_or(false, |max| width <= max)
+                }
+                (Some(ColumnConstraint::Boundaries { .. }), Some(width)) => {
+                    min_width.map_or(false, |min| width >= min)
+                        && max_width.map_or(false, |max| width <= max)
+                }
+            };
+
+            ConstraintReport {
+                column_index: column.index,
+                constraint: column.constraint,
+                min_width,
+                max_width,
+                width,
+                honored,
+            }
+        })
+        .collect()
+}
+
+/// Collect the full content of all body cells that're truncated due to their row's max height.
+///
+/// The content is arranged just like during rendering, but overflow policies aren't applied.
+pub(crate) fn truncated_content(table: &Table) -> Vec<TruncatedContent> {
+    let mut truncated = Vec::new();
+    if table.is_hidden() {
+        return truncated;
+    }
+
+    let order = displayed_columns(table);
+    let table = prepare_content(table);
+    let table = table.as_ref();
+    let display_info = arranged_display_info(table);
+
+    for (index, row) in table.rows.iter().enumerate() {
+        format_row(row, Some(index), &display_info, table, &mut truncated);
+    }
+
+    // Refer to the columns by their original index, in case they've been reordered.
+    for content in truncated.iter_mut() {
+        content.column_index = order[content.column_index];
+    }
+
+    truncated
+}
+
+/// Draw the table and split its lines up into the rows they belong to.
+///
+/// The content is arranged just like during rendering, but overflow policies aren't applied.
+pub(crate) fn rendered_rows(table: &Table) -> Vec<RenderedRow> {
+    if table.is_hidden() {
+        return Vec::new();
+    }
+
+    let table = prepare_content(table);
+    let table = table.as_ref();
+    let display_info = arranged_display_info(table);
+
     let content = format_content(table, &display_info);
-    draw_borders(table, &content, &display_info).into_iter()
+    let mut lines = Vec::new();
+    let mut row_lines = Vec::new();
+    draw_borders_with_rows(
+        table,
+        &content,
+        &display_info,
+        &mut lines,
+        Some(&mut row_lines),
+    );
+
+    // The title is part of the top border or above the table, which shifts all rows.
+    let before = lines.len();
+    draw_title(table, &display_info, &mut lines, 0);
+    let offset = lines.len() - before;
+
+    let rows_end = row_lines.last().map_or(0, |last| {
+        last.separator.map_or(last.content.end, |index| index + 1)
+    });
+    let mut rows: Vec<RenderedRow> = row_kinds(table)
+        .iter()
+        .zip(row_lines.iter())
+        .map(|(kind, row)| RenderedRow {
+            row_index: match kind {
+                // Hidden rows have been removed, but the rows still know their original index.
+                RowKind::Body(index, row) => Some(row.index.unwrap_or(*index)),
+                _ => None,
+            },
+            is_header: matches!(kind, RowKind::Header(_)),
+            physical_lines: lines[row.content.start + offset..row.content.end + offset].to_vec(),
+            separator_above: None,
+            separator_below: row.separator.map(|index| lines[index + offset].clone()),
+        })
+        .collect();
+
+    // The borders are attached to the first and the last row.
+    if let Some(first) = rows.first_mut() {
+        if row_lines[0].content.start > 0 {
+            first.separator_above = Some(lines[offset].clone());
+        }
+    }
+    if let Some(last) = rows.last_mut() {
+        if rows_end + offset < lines.len() {
+            last.separator_below = Some(lines[rows_end + offset].clone());
+        }
+    }
+
+    rows
+}
+
+/// Draw the given rows of the table's body, while the columns are arranged for all rows.
+///
+/// Overflow policies aren't applied.
+pub(crate) fn build_table_page(table: &Table, rows: Range<usize>) -> Vec<String> {
+    let mut lines = Vec::new();
+    if table.is_hidden() {
+        return lines;
+    }
+
+    let table = prepare_content(table);
+    let table = table.as_ref();
+    let display_info = arranged_display_info(table);
+
+    let page = table.view(&Viewport::new(rows, 0..table.columns.len()));
+    draw_table(&page, &display_info, &mut lines);
+
+    lines
+}
+
+/// Format and draw the table with an already arranged layout.
+pub(crate) fn build_table_with_info(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    draw_table(&prepare_content(table), display_info, &mut lines);
+
+    lines
+}
+
+/// Draw the table, but only as many rows of its body as fit into its
+/// [max height](Table::set_max_height).
+///
+/// The remaining rows are replaced by a single row with the max height indicator.
+/// At least the header and the indicator are displayed, even if they don't fit.
+///
+/// Returns the range of the table's own lines, see [draw_table].
+fn draw_table_with_max_height(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    lines: &mut Vec<String>,
+) -> Range<usize> {
+    let max_height = match table.max_height {
+        Some(max_height) if !table.rows.is_empty() => max_height,
+        _ => return draw_table(table, display_info, lines),
+    };
+
+    // Each row takes up at least one line, so the whole table can only fit, if it doesn't have
+    // more rows than lines are allowed. Otherwise, there's no need to draw it at all.
+    let start = lines.len();
+    if table.rows.len() <= max_height {
+        let range = draw_table(table, display_info, lines);
+        if lines.len() - start <= max_height {
+            return range;
+        }
+        lines.truncate(start);
+    }
+
+    // Draw the table with the first `rows` rows of its body, followed by the indicator.
+    let draw_rows = |rows: usize| {
+        let mut preview = table.view(&Viewport::new(0..rows, 0..table.columns.len()));
+        preview.sections.push(Section {
+            title: Cell::new(table.max_height_indicator_for(table.rows.len() - rows)),
+            row_index: rows,
+        });
+
+        let mut lines = Vec::new();
+        let range = draw_table(&preview, display_info, &mut lines);
+        (lines, range)
+    };
+
+    // More rows never result in less lines, so search for the most rows that still fit.
+    // For the same reason as above, there's no need to try more rows than lines are allowed.
+    let (mut low, mut high) = (0, std::cmp::min(table.rows.len() - 1, max_height));
+    while low < high {
+        let middle = (low + high + 1) / 2;
+        if draw_rows(middle).0.len() <= max_height {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    let (mut rows, range) = draw_rows(low);
+    lines.append(&mut rows);
+
+    range.start + start..range.end + start
+}
+
+/// Draw the table and append its lines.
+///
+/// Returns the range of the table's own lines, which excludes the title, the caption and the
+/// footnotes.
+fn draw_table(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    lines: &mut Vec<String>,
+) -> Range<usize> {
+    let content = format_content(table, display_info);
+    draw_formatted_table(table, &content, display_info, lines)
+}
+
+/// Same as [draw_table], but the content of the header and rows has already been formatted.
+pub(crate) fn draw_formatted_table(
+    table: &Table,
+    content: &[Vec<Vec<String>>],
+    display_info: &[ColumnDisplayInfo],
+    lines: &mut Vec<String>,
+) -> Range<usize> {
+    let start = lines.len();
+    draw_borders(table, content, display_info, lines);
+    let end = lines.len();
+
+    // The title might be inserted above the table.
+    draw_title(table, display_info, lines, start);
+    let title_height = lines.len() - end;
+    draw_caption(table, display_info, lines);
+    draw_footnotes(table, display_info, lines);
+
+    start + title_height..end + title_height
 }
@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use super::arrangement::helper::{count_visible_columns, minimum_table_width};
+use super::build_table_into;
+use crate::style::{ColumnConstraint, ContentArrangement, OverflowPolicy, Width};
+use crate::utils::formatting::content_split::measure_text_width;
+use crate::{Cell, Column, Table};
+
+/// Check whether the table fits into the available width and apply the table's
+/// [OverflowPolicy] if it doesn't.
+///
+/// The lines of the table are appended to `lines`.
+/// Returns the ranges of the lines of all displayed tables, see [build_table_into].
+/// Returns `None`, if the table should be displayed as usual.
+pub fn handle_overflow(table: &Table, lines: &mut Vec<String>) -> Option<Vec<Range<usize>>> {
+    if matches!(table.arrangement, ContentArrangement::Disabled) {
+        return None;
+    }
+
+    let width = table.width()?;
+    if minimum_table_width(table) <= usize::from(width) {
+        return None;
+    }
+
+    match table.overflow_policy {
+        OverflowPolicy::Overflow => None,
+        OverflowPolicy::HideColumns => Some(hide_columns(table, width, lines)),
+        OverflowPolicy::Vertical => Some(vertical_layout(table, width, lines)),
+    }
+}
+
+/// Hide the right-most visible column, until the table fits into the given width.
+/// At least one column will always stay visible.
+fn hide_columns(table: &Table, width: u16, lines: &mut Vec<String>) -> Vec<Range<usize>> {
+    let mut table = table.clone();
+    table.set_overflow_policy(OverflowPolicy::Overflow);
+
+    while minimum_table_width(&table) > usize::from(width)
+        && count_visible_columns(&table.columns) > 1
+    {
+        if let Some(column) = table
+            .columns
+            .iter_mut()
+            .rev()
+            .find(|column| !column.is_hidden())
+        {
+            column.set_constraint(ColumnConstraint::Hidden);
+        }
+    }
+
+    append_table(&table, lines)
+}
+
+/// Display each row as a separate table with two columns.
+/// The left column contains the name of each column, the right one the respective value.
+///
+/// ```text
+/// +-------+-----+
+/// | Name  | Joe |
+/// |-------+-----|
+/// | Age   | 42  |
+/// +-------+-----+
+/// ```
+fn vertical_layout(table: &Table, width: u16, lines: &mut Vec<String>) -> Vec<Range<usize>> {
+    let visible_columns: Vec<&Column> = table
+        .columns
+        .iter()
+        .filter(|column| !column.is_hidden())
+        .collect();
+
+    // Use the header as column names or fall back to the position of each column.
+    let names: Vec<Cell> = visible_columns
+        .iter()
+        .map(|column| {
+            table
+                .header
+                .as_ref()
+                .and_then(|header| header.cells.get(column.index))
+                .cloned()
+                .unwrap_or_else(|| Cell::new(format!("Column {}", column.index + 1)))
+        })
+        .collect();
+
+    // All records should have the same layout, which is why the name column gets a fixed width.
+    let name_width = names
+        .iter()
+        .flat_map(|cell| cell.display_lines(table.trim_mode).to_vec())
+        .map(|line| measure_text_width(&line, table.measure()))
+        .max()
+        .unwrap_or(1)
+        + 2;
+
+    // Use the original table as a template, so we keep all styling and tty settings.
+    let mut template = table.clone();
+    template.header = None;
+    template.rows = Vec::new();
+    template.columns = Vec::new();
+    template.footnotes = Vec::new();
+    template.title = None;
+    template.caption = None;
+    template.groups = Vec::new();
+    template.group_is_open = false;
+    template
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_overflow_policy(OverflowPolicy::Overflow)
+        .set_width(width);
+
+    let start = lines.len();
+    let mut ranges = Vec::new();
+    let mut row_iter = table.rows.iter().peekable();
+    while let Some(row) = row_iter.next() {
+        let mut record = template.clone();
+        for (column, name) in visible_columns.iter().zip(names.iter()) {
+            let value = row
+                .cells
+                .get(column.index)
+                .cloned()
+                .unwrap_or_else(|| Cell::new(""));
+            record.add_row(vec![name.clone(), value]);
+        }
+        record.set_constraints(vec![ColumnConstraint::Absolute(Width::Fixed(
+            name_width.try_into().unwrap_or(u16::MAX),
+        ))]);
+
+        // Display the title above the very first record and the caption and footnotes below the
+        // very last one.
+        if lines.len() == start {
+            record.title = table.title.clone();
+        }
+        if row_iter.peek().is_none() {
+            record.caption = table.caption.clone();
+            record.footnotes = table.footnotes.clone();
+        }
+
+        ranges.append(&mut append_table(&record, lines));
+    }
+
+    ranges
+}
+
+/// Build the table and append its lines.
+/// Returns the ranges of the table's own lines in `lines`.
+fn append_table(table: &Table, lines: &mut Vec<String>) -> Vec<Range<usize>> {
+    let mut table_lines = Vec::new();
+    let offset = lines.len();
+    let ranges = build_table_into(table, &mut table_lines);
+    lines.append(&mut table_lines);
+
+    ranges
+        .into_iter()
+        .map(|range| range.start + offset..range.end + offset)
+        .collect()
+}
@@ -0,0 +1,300 @@
+use std::ops::Range;
+
+use crate::column::Column;
+use crate::row::{RowGroup, Section};
+use crate::style::{ColumnConstraint, ContentArrangement};
+use crate::table::Table;
+use crate::utils::arrangement::arrange_content;
+use crate::utils::arrangement::helper::count_border_columns;
+use crate::utils::build_table_page;
+use crate::utils::formatting::column_order::displayed_columns;
+use crate::utils::formatting::prepare_content;
+
+/// A window into a table, which is rendered via [Table::render_scrollable].
+///
+/// The viewport consists of a range of rows of the table's body and a range of columns.
+/// The first `frozen_columns` columns are always displayed, regardless of the column range,
+/// just like the header is always displayed, regardless of the row range.
+///
+/// ```
+/// use comfy_table::Viewport;
+///
+/// // Show rows 100 to 119 and columns 5 to 9, plus the first column.
+/// let view = Viewport::new(100..120, 5..10).set_frozen_columns(1);
+/// assert_eq!(view.rows(), 100..120);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Viewport {
+    pub(crate) rows: Range<usize>,
+    pub(crate) columns: Range<usize>,
+    pub(crate) frozen_columns: usize,
+}
+
+impl Viewport {
+    /// Create a new viewport for the given rows of the table's body and the given columns.
+    pub fn new(rows: Range<usize>, columns: Range<usize>) -> Self {
+        Self {
+            rows,
+            columns,
+            frozen_columns: 0,
+        }
+    }
+
+    /// Always display the given amount of leading columns, e.g. a column with row labels.
+    #[must_use]
+    pub fn set_frozen_columns(mut self, frozen_columns: usize) -> Self {
+        self.frozen_columns = frozen_columns;
+
+        self
+    }
+
+    /// The indices of the rows of the table's body that're displayed.
+    pub fn rows(&self) -> Range<usize> {
+        self.rows.clone()
+    }
+
+    /// The indices of the columns that're displayed in addition to the frozen columns.
+    pub fn columns(&self) -> Range<usize> {
+        self.columns.clone()
+    }
+
+    /// The amount of leading columns that're always displayed.
+    pub fn frozen_columns(&self) -> usize {
+        self.frozen_columns
+    }
+
+    /// Check whether the column with the given index is part of this viewport.
+    fn contains_column(&self, index: usize) -> bool {
+        index < self.frozen_columns || self.columns.contains(&index)
+    }
+}
+
+impl Table {
+    /// Render a window of the table, e.g. for scrolling through huge tables in a TUI.
+    ///
+    /// Only the rows and columns of the [Viewport] are rendered.
+    /// The header and the frozen columns of the viewport are always included.
+    /// All other settings of the table, such as its style, width and row groups, are respected.
+    ///
+    /// The columns are arranged for the content that's displayed in the viewport.
+    /// Use a [FrozenLayout](crate::FrozenLayout) for column widths that don't change while
+    /// scrolling.
+    ///
+    /// ```
+    /// use comfy_table::{Table, Viewport};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Q1", "Q2", "Q3"]);
+    /// for index in 0..100 {
+    ///     table.add_row(vec![format!("Row {index}"), "1".into(), "2".into(), "3".into()]);
+    /// }
+    ///
+    /// let view = Viewport::new(50..52, 2..4).set_frozen_columns(1);
+    /// let expected = "
+    /// +--------+----+----+
+    /// | Name   | Q2 | Q3 |
+    /// +==================+
+    /// | Row 50 | 2  | 3  |
+    /// |--------+----+----|
+    /// | Row 51 | 2  | 3  |
+    /// +--------+----+----+";
+    /// assert_eq!(table.render_scrollable(view), expected.trim_start());
+    /// ```
+    pub fn render_scrollable(&self, view: Viewport) -> String {
+        self.view(&view).to_string()
+    }
+
+    /// Render a page of the table, which consists of `limit` rows starting at row `offset`.
+    ///
+    /// In contrast to [Table::render_scrollable], the columns are arranged for the content of
+    /// all rows. Hence, the columns have the same widths on every page and don't jump around
+    /// while paging through the table.\
+    /// The header, title, caption and footnotes are part of every page.
+    /// [Hidden rows](crate::Row::set_hidden) don't count towards the offset and the limit.
+    ///
+    /// **Attention** This arranges the whole table, just like rendering does.\
+    /// **Note:** [OverflowPolicies](crate::OverflowPolicy) aren't applied.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Amount"])
+    ///     .add_row(vec!["Apples", "3"])
+    ///     .add_row(vec!["Pears", "5"])
+    ///     .add_row(vec!["Strawberries", "120"]);
+    ///
+    /// let expected = "
+    /// +--------------+--------+
+    /// | Name         | Amount |
+    /// +=======================+
+    /// | Apples       | 3      |
+    /// |--------------+--------|
+    /// | Pears        | 5      |
+    /// +--------------+--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.render_page(0, 2));
+    ///
+    /// let expected = "
+    /// +--------------+--------+
+    /// | Name         | Amount |
+    /// +=======================+
+    /// | Strawberries | 120    |
+    /// +--------------+--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.render_page(2, 2));
+    /// ```
+    pub fn render_page(&self, offset: usize, limit: usize) -> String {
+        build_table_page(self, offset..offset.saturating_add(limit)).join("\n")
+    }
+
+    /// Split a table that's too wide into multiple tables, which each fit into the given width.
+    ///
+    /// The columns are distributed in order, as many per table as fit into the width without
+    /// wrapping any content. [Key columns](crate::Column::set_key_column) are repeated in every
+    /// table. A single column that's too wide by itself gets a table of its own.\
+    /// Each table keeps all rows and settings of the original table and its width is set to
+    /// the given width. Dynamic arrangements wrap the content of such columns to that width.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "January", "February", "March"])
+    ///     .add_row(vec!["Rent", "800", "800", "820"]);
+    /// table.column_mut(0).unwrap().set_key_column(true);
+    ///
+    /// let chunks = table.split_by_width(30);
+    /// assert_eq!(chunks.len(), 2);
+    ///
+    /// let expected = "
+    /// +------+---------+----------+
+    /// | Name | January | February |
+    /// +===========================+
+    /// | Rent | 800     | 800      |
+    /// +------+---------+----------+";
+    /// assert_eq!(expected, "\n".to_string() + &chunks[0].to_string());
+    ///
+    /// let expected = "
+    /// +------+-------+
+    /// | Name | March |
+    /// +==============+
+    /// | Rent | 820   |
+    /// +------+-------+";
+    /// assert_eq!(expected, "\n".to_string() + &chunks[1].to_string());
+    /// ```
+    pub fn split_by_width(&self, max_width: u16) -> Vec<Table> {
+        // Measure the width of the columns without wrapping any content.
+        let mut natural = self.clone();
+        natural
+            .set_content_arrangement(ContentArrangement::Disabled)
+            .remove_min_width();
+        let natural = prepare_content(&natural);
+        let display_info = arrange_content(natural.as_ref());
+        // The columns have been arranged in the order they're displayed.
+        let order = displayed_columns(self);
+        let width = |index: usize| {
+            order
+                .iter()
+                .position(|source| *source == index)
+                .map_or(0, |position| usize::from(display_info[position].width()))
+        };
+
+        let visible: Vec<&Column> = order
+            .iter()
+            .map(|index| &self.columns[*index])
+            .filter(|column| !column.is_hidden())
+            .collect();
+        let key_columns = visible.iter().filter(|column| column.key_column).count();
+        let key_width: usize = visible
+            .iter()
+            .filter(|column| column.key_column)
+            .map(|column| width(column.index))
+            .sum();
+
+        // Fill the chunks with as many columns as possible.
+        let mut chunks = Vec::new();
+        let mut chunk: Vec<usize> = Vec::new();
+        let mut chunk_width = key_width;
+        for column in visible.iter().filter(|column| !column.key_column) {
+            let columns = key_columns + chunk.len() + 1;
+            let total_width =
+                chunk_width + width(column.index) + count_border_columns(self, columns);
+            if total_width > usize::from(max_width) && !chunk.is_empty() {
+                chunks.push(std::mem::take(&mut chunk));
+                chunk_width = key_width;
+            }
+            chunk.push(column.index);
+            chunk_width += width(column.index);
+        }
+        if !chunk.is_empty() || chunks.is_empty() {
+            chunks.push(chunk);
+        }
+
+        chunks
+            .into_iter()
+            .map(|chunk| {
+                let mut table = self.clone();
+                table.set_width(max_width);
+                for column in table.columns.iter_mut() {
+                    if !column.key_column && !chunk.contains(&column.index) {
+                        column.set_constraint(ColumnConstraint::Hidden);
+                    }
+                }
+
+                table
+            })
+            .collect()
+    }
+
+    /// Create a table that only consists of the rows and columns of the viewport.
+    pub(crate) fn view(&self, view: &Viewport) -> Table {
+        let start = view.rows.start.min(self.rows.len());
+        let end = view.rows.end.clamp(start, self.rows.len());
+
+        let mut table = self.clone_without_rows();
+        for (index, row) in self.rows[start..end].iter().enumerate() {
+            let mut row = row.clone();
+            row.index = Some(index);
+            table.rows.push(row);
+        }
+
+        // Only keep the parts of groups that're inside the viewport.
+        for group in self.groups.iter() {
+            let group_start = group.rows.start.clamp(start, end);
+            let group_end = group.rows.end.clamp(start, end);
+            if group_start < group_end {
+                table.groups.push(RowGroup {
+                    name: group.name.clone(),
+                    rows: group_start - start..group_end - start,
+                });
+            }
+        }
+
+        // Only keep sections that precede a row inside the viewport.
+        // Sections after the last row are kept, if the viewport reaches the end of the table.
+        for section in self.sections.iter() {
+            let trailing = section.row_index >= self.rows.len() && end == self.rows.len();
+            if (start..end).contains(&section.row_index) || trailing {
+                table.sections.push(Section {
+                    title: section.title.clone(),
+                    row_index: section.row_index.min(end) - start,
+                });
+            }
+        }
+
+        for column in table.columns.iter_mut() {
+            if !view.contains_column(column.index) {
+                column.set_constraint(ColumnConstraint::Hidden);
+            }
+        }
+
+        // The table isn't empty, only the viewport is.
+        if !self.rows.is_empty() {
+            table.empty_message = None;
+            table.hide_when_empty(false);
+        }
+
+        table
+    }
+}
@@ -46,3 +46,161 @@ fn cell_alignment() {
 +---------------------+---------------------+---------------------+";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// Trimmed whitespaces neither affect the column width nor the alignment.
+/// Cells that preserve their whitespaces aren't trimmed.
+#[test]
+fn trim_mode() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header"])
+        .add_row(vec![
+            Cell::new("  right   ").set_alignment(CellAlignment::Right)
+        ])
+        .add_row(vec![Cell::new("  kept  ").preserve_whitespace(true)]);
+
+    table.set_trim_mode(TrimMode::Both);
+    println!("{table}");
+    let expected = "
++----------+
+| Header   |
++==========+
+|    right |
+|----------|
+|   kept   |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_trim_mode(TrimMode::Leading);
+    println!("{table}");
+    let expected = "
++----------+
+| Header   |
++==========+
+| right    |
+|----------|
+|   kept   |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Cells with fewer lines than the rest of their row are placed according to their
+/// vertical alignment. The cell's setting overwrites the column's setting.
+fn vertical_alignment() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header", "Top", "Middle", "Bottom"])
+        .add_row(vec![
+            Cell::new("One\nTwo\nThree"),
+            Cell::new("Top"),
+            Cell::new("Middle"),
+            Cell::new("Bottom"),
+        ])
+        .add_row(vec![
+            Cell::new("One\nTwo\nThree\nFour"),
+            Cell::new("Top"),
+            Cell::new("Middle"),
+            Cell::new("Top").set_vertical_alignment(CellVerticalAlignment::Top),
+        ]);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_cell_vertical_alignment(CellVerticalAlignment::Middle);
+    table
+        .column_mut(3)
+        .unwrap()
+        .set_cell_vertical_alignment(CellVerticalAlignment::Bottom);
+
+    println!("{table}");
+    let expected = "
++--------+-----+--------+--------+
+| Header | Top | Middle | Bottom |
++================================+
+| One    | Top |        |        |
+| Two    |     | Middle |        |
+| Three  |     |        | Bottom |
+|--------+-----+--------+--------|
+| One    | Top |        | Top    |
+| Two    |     | Middle |        |
+| Three  |     |        |        |
+| Four   |     |        |        |
++--------+-----+--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The bias decides which side receives the extra space of centered content.
+/// Surplus space of the full width arrangement leans in the same direction.
+#[test]
+fn center_bias() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Left", "Right"])
+        .add_row(vec![Cell::new("ab"), Cell::new("ab")])
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_width(17);
+    for column in table.column_iter_mut() {
+        column.set_cell_alignment(CellAlignment::Center);
+    }
+
+    println!("{table}");
+    let expected = "
++-------+-------+
+|  Left | Right |
++===============+
+|   ab  |   ab  |
++-------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.center_bias(), Bias::Left);
+
+    table.set_center_bias(Bias::Right);
+    println!("{table}");
+    let expected = "
++------+--------+
+| Left | Right  |
++===============+
+|  ab  |   ab   |
++------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Rows with a minimum height are padded according to the vertical alignment of their cells.
+#[test]
+fn row_min_height() {
+    let mut table = Table::new();
+    table.set_header(vec!["Header", "Middle"]);
+
+    let mut row = Row::from(vec![
+        Cell::new("Top"),
+        Cell::new("Middle").set_vertical_alignment(CellVerticalAlignment::Middle),
+    ]);
+    row.min_height(3);
+    table.add_row(row);
+
+    // Rows that are already high enough aren't changed.
+    let mut row = Row::from(vec!["One\nTwo", "Three"]);
+    row.min_height(1);
+    table.add_row(row);
+
+    // The max height takes precedence.
+    let mut row = Row::from(vec!["Capped", "Row"]);
+    row.min_height(4).max_height(2);
+    table.add_row(row);
+
+    println!("{table}");
+    let expected = "
++--------+--------+
+| Header | Middle |
++=================+
+| Top    |        |
+|        | Middle |
+|        |        |
+|--------+--------|
+| One    | Three  |
+| Two    |        |
+|--------+--------|
+| Capped | Row    |
+|        |        |
++--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
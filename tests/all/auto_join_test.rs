@@ -0,0 +1,90 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::*;
+use comfy_table::TableComponent::*;
+use comfy_table::*;
+
+#[test]
+fn auto_join_heavy_outer_border() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_row(vec!["Three", "Four"]);
+
+    table
+        .load_preset(UTF8_FULL)
+        .set_style(TopBorder, '━')
+        .set_style(BottomBorder, '━')
+        .set_style(LeftBorder, '┃')
+        .set_style(RightBorder, '┃')
+        .auto_join_borders(true);
+
+    println!("{table}");
+    // There's no box-drawing character that joins heavy and double lines,
+    // so the header intersections are left untouched.
+    let expected = "
+┏━━━━━━━━━┯━━━━━━━━━┓
+┃ Header1 ┆ Header2 ┃
+╞═════════╪═════════╡
+┃ One     ┆ Two     ┃
+┠╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┨
+┃ Three   ┆ Four    ┃
+┗━━━━━━━━━┷━━━━━━━━━┛";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn auto_join_keeps_round_corners() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_row(vec!["Three", "Four"]);
+
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(modifiers::UTF8_ROUND_CORNERS)
+        .auto_join_borders(true);
+
+    println!("{table}");
+    let expected = "
+╭─────────┬─────────╮
+│ Header1 ┆ Header2 │
+╞═════════╪═════════╡
+│ One     ┆ Two     │
+├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+│ Three   ┆ Four    │
+╰─────────┴─────────╯";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Non box-drawing characters are never touched.
+#[test]
+fn auto_join_ignores_ascii() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_row(vec!["Three", "Four"]);
+
+    table.load_preset(ASCII_FULL);
+    let without = table.to_string();
+    table.auto_join_borders(true);
+
+    assert_eq!(without, table.to_string());
+}
+
+/// Without the option, mismatching characters are rendered as configured.
+#[test]
+fn no_auto_join_by_default() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_row(vec!["Three", "Four"]);
+
+    table.load_preset(UTF8_FULL).set_style(TopBorder, '━');
+
+    assert!(table.to_string().starts_with("┌━━━━━━━━━┬━━━━━━━━━┐"));
+}
@@ -0,0 +1,110 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+use super::assert_table_line_width;
+
+/// "Shalom" in Hebrew, in logical order.
+const SHALOM: &str = "\u{5e9}\u{5dc}\u{5d5}\u{5dd}";
+/// "Shalom" in Hebrew, in visual order.
+const SHALOM_VISUAL: &str = "\u{5dd}\u{5d5}\u{5dc}\u{5e9}";
+
+#[test]
+fn logical_order_by_default() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Language", "Greeting"])
+        .add_row(vec!["Hebrew".to_string(), format!("{SHALOM} world")])
+        .add_row(vec!["English", "Hello world"]);
+
+    assert_eq!(table.bidi_mode(), BidiMode::Logical);
+
+    let expected = format!(
+        "
++----------+-------------+
+| Language | Greeting    |
++========================+
+| Hebrew   | {SHALOM} world  |
+|----------+-------------|
+| English  | Hello world |
++----------+-------------+"
+    );
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn visual_order() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Language", "Greeting"])
+        .add_row(vec!["Hebrew".to_string(), format!("{SHALOM} world")])
+        .add_row(vec!["English", "Hello world"]);
+
+    table.set_bidi_mode(BidiMode::Visual);
+
+    // The cell starts with a Hebrew word, which makes the whole line right-to-left.
+    // Hence, the Hebrew word is displayed on the right.
+    let expected = format!(
+        "
++----------+-------------+
+| Language | Greeting    |
++========================+
+| Hebrew   | world {SHALOM_VISUAL}  |
+|----------+-------------|
+| English  | Hello world |
++----------+-------------+"
+    );
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 26);
+}
+
+#[test]
+fn wrapped_lines_are_reordered_separately() {
+    let mut table = Table::new();
+    table
+        .set_bidi_mode(BidiMode::Visual)
+        .add_row(vec![format!("{SHALOM} {SHALOM}\u{5d0}")]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Absolute(Width::Fixed(7)));
+
+    let expected = format!(
+        "
++-------+
+| {SHALOM_VISUAL}  |
+| \u{5d0}{SHALOM_VISUAL} |
++-------+"
+    );
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn right_aligned_rtl_cells() {
+    let mut table = Table::new();
+    table
+        .set_bidi_mode(BidiMode::VisualRightAligned)
+        .set_header(vec!["Greeting", "Aligned"])
+        .add_row(vec![SHALOM, SHALOM])
+        .add_row(vec!["Hello", "Hello"]);
+    // Explicit alignments take precedence.
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Center);
+
+    let expected = format!(
+        "
++----------+---------+
+| Greeting | Aligned |
++====================+
+|     {SHALOM_VISUAL} |   {SHALOM_VISUAL}  |
+|----------+---------|
+| Hello    |  Hello  |
++----------+---------+"
+    );
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
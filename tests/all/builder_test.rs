@@ -0,0 +1,58 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+
+#[test]
+/// The builder creates the same table as the `&mut self` setters.
+fn builder_matches_setters() {
+    let built = Table::builder()
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_rows(vec![vec!["Three", "Four"], vec!["Five", "Six"]])
+        .apply_modifier(modifiers::UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30)
+        .set_constraints(vec![Absolute(Fixed(10))])
+        .configure_column(1, |column| {
+            column.set_padding((2, 0));
+        })
+        .build();
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_rows(vec![vec!["Three", "Four"], vec!["Five", "Six"]])
+        .apply_modifier(modifiers::UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30)
+        .set_constraints(vec![Absolute(Fixed(10))]);
+    table.column_mut(1).unwrap().set_padding((2, 0));
+
+    println!("{built}");
+    assert_eq!(table.to_string(), built.to_string());
+}
+
+#[test]
+fn builder_into_table() {
+    let table: Table = Table::builder()
+        .load_preset(presets::ASCII_MARKDOWN)
+        .configure(|table| {
+            table.set_header(vec!["Header"]);
+        })
+        // Columns that don't exist are ignored.
+        .configure_column(5, |column| {
+            column.set_padding((5, 5));
+        })
+        .add_row(vec!["Content"])
+        .into();
+
+    println!("{table}");
+    let expected = "
+| Header  |
+|---------|
+| Content |";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
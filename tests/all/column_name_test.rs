@@ -0,0 +1,98 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+
+fn named_column(name: &str) -> Column {
+    let mut column = Column::new(0);
+    column.set_name(name);
+
+    column
+}
+
+/// Columns are configured up front and the rows are added later on.
+#[test]
+fn column_names_as_header() {
+    let mut size = named_column("Size");
+    size.set_cell_alignment(CellAlignment::Right);
+
+    let mut table = Table::new();
+    table
+        .add_column(named_column("Name"))
+        .add_column(size)
+        .add_column(Column::new(0))
+        .use_column_names_as_header()
+        .add_row(vec!["notes.txt", "120 B", "rw"])
+        .add_row(vec!["image.png", "4 KB", "r"]);
+
+    assert_eq!(table.column(1).unwrap().index, 1);
+    assert_eq!(table.column(2).unwrap().index, 2);
+
+    println!("{table}");
+    let expected = "
++-----------+-------+----+
+| Name      |  Size |    |
++========================+
+| notes.txt | 120 B | rw |
+|-----------+-------+----|
+| image.png |  4 KB | r  |
++-----------+-------+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Names of the columns take precedence over the header text.
+#[test]
+fn lookup_by_column_name() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size"])
+        .add_row(vec!["notes.txt", "120 B"]);
+    table.column_mut(1).unwrap().set_name("Name");
+
+    assert_eq!(table.column_index_by_name("Name"), Some(1));
+    assert_eq!(table.column_by_name("Size").unwrap().index, 1);
+
+    table.set_constraint_for("Name", Hidden).unwrap();
+    assert_eq!(table.column(1).unwrap().constraint(), Some(&Hidden));
+
+    table.column_mut(1).unwrap().remove_name();
+    assert_eq!(table.column(1).unwrap().name(), None);
+    assert_eq!(table.column_index_by_name("Name"), Some(0));
+    table
+        .set_constraint_for("Name", UpperBoundary(Fixed(5)))
+        .unwrap();
+    assert_eq!(
+        table.column(0).unwrap().constraint(),
+        Some(&UpperBoundary(Fixed(5)))
+    );
+}
+
+/// Columns that are added after the rows get an empty cell in each existing row.
+#[test]
+fn add_column_after_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name"])
+        .add_row(vec!["notes.txt"])
+        .add_row(vec!["image.png"])
+        .add_column(named_column("Size"));
+
+    assert_eq!(table.column(1).unwrap().index, 1);
+    assert!(table.row_iter().all(|row| row.cell_count() == 2));
+    assert_eq!(table.header().unwrap().cell_count(), 2);
+
+    table.add_row(vec!["data.csv", "2 MB"]);
+    println!("{table}");
+    let expected = "
++-----------+------+
+| Name      |      |
++==================+
+| notes.txt |      |
+|-----------+------|
+| image.png |      |
+|-----------+------|
+| data.csv  | 2 MB |
++-----------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,120 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[test]
+fn reorder_columns() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size", "Modified"])
+        .add_row(vec!["notes.txt", "12K", "2024-01-01"])
+        .add_row(vec!["todo.md", "3K"]);
+
+    table.set_column_order(&[2, 0, 1]);
+
+    let expected = "
++------------+-----------+------+
+| Modified   | Name      | Size |
++===============================+
+| 2024-01-01 | notes.txt | 12K  |
+|------------+-----------+------|
+|            | todo.md   | 3K   |
++------------+-----------+------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The rows of the table aren't touched.
+    assert_eq!(
+        table.row(0).unwrap().cell_iter().next().unwrap().content(),
+        "notes.txt"
+    );
+}
+
+#[test]
+/// Columns that aren't part of the order aren't displayed.
+/// Unknown and repeated indices are ignored.
+fn project_columns() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size", "Modified"])
+        .add_row(vec!["notes.txt", "12K", "2024-01-01"])
+        .add_row(vec!["todo.md", "3K"]);
+    let unchanged = table.to_string();
+
+    table.set_column_order(&[1, 5, 0, 1]);
+
+    let expected = "
++------+-----------+
+| Size | Name      |
++==================+
+| 12K  | notes.txt |
+|------+-----------|
+| 3K   | todo.md   |
++------+-----------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.column_display_widths(), vec![Some(11), Some(6), None]);
+
+    table.remove_column_order();
+    assert_eq!(table.column_order(), None);
+    assert_eq!(table.to_string(), unchanged);
+}
+
+#[test]
+/// Column settings stay attached to their column.
+fn settings_follow_columns() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size", "Modified"])
+        .add_row(vec!["notes.txt", "12K", "2024-01-01"])
+        .add_row(vec!["todo.md", "3K"]);
+
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+    table.set_column_order(&[1, 0]);
+
+    let expected = "
++------+-----------+
+| Size | Name      |
++==================+
+|  12K | notes.txt |
+|------+-----------|
+|   3K | todo.md   |
++------+-----------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn frozen_layout_with_column_order() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size", "Modified"])
+        .add_row(vec!["notes.txt", "12K", "2024-01-01"])
+        .add_row(vec!["todo.md", "3K"]);
+
+    table.set_column_order(&[2, 0]);
+    let expected = table.to_string();
+
+    let layout = table.freeze_layout();
+    table.set_frozen_layout(layout).unwrap();
+    assert_eq!(expected, table.to_string());
+    assert_eq!(expected, table.render(&table.plan()));
+}
+
+#[test]
+fn html_with_column_order() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size", "Modified"])
+        .add_row(vec!["notes.txt", "12K", "2024-01-01"])
+        .add_row(vec!["todo.md", "3K"]);
+
+    table.set_column_order(&[1]);
+
+    let html = table.to_html();
+    assert!(html.contains("<th>Size</th>"));
+    assert!(!html.contains("Name"));
+}
@@ -0,0 +1,99 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+
+#[test]
+fn honored_constraints() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Name", "Description", "Secret"])
+        .add_row(vec![
+            "1",
+            "Apples",
+            "A rather long description of apples",
+            "x",
+        ]);
+
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .set_constraints(vec![
+            ContentWidth,
+            Boundaries {
+                lower: Fixed(5),
+                upper: Fixed(12),
+            },
+            UpperBoundary(Percentage(50)),
+            Hidden,
+        ]);
+
+    let report = table.constraint_report();
+    assert!(report.iter().all(|column| column.honored));
+    assert_eq!(
+        report[1],
+        ConstraintReport {
+            column_index: 1,
+            constraint: Some(Boundaries {
+                lower: Fixed(5),
+                upper: Fixed(12),
+            }),
+            min_width: Some(5),
+            max_width: Some(12),
+            width: Some(8),
+            honored: true,
+        }
+    );
+    // Percentages refer to the width without the borders.
+    assert_eq!(report[2].max_width, Some(18));
+    assert_eq!(report[3].width, None);
+}
+
+#[test]
+/// Percentages cannot be resolved without knowing the width of the table.
+fn unresolved_percentage_is_not_honored() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Name", "Description", "Secret"])
+        .add_row(vec![
+            "1",
+            "Apples",
+            "A rather long description of apples",
+            "x",
+        ]);
+
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(UpperBoundary(Percentage(10)));
+
+    let report = table.constraint_report();
+    assert_eq!(report[2].max_width, None);
+    assert!(!report[2].honored);
+    assert!(report[0].honored);
+}
+
+#[test]
+/// Columns that aren't displayed due to the column order cannot satisfy their constraint.
+fn undisplayed_column_is_not_honored() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Name", "Description", "Secret"])
+        .add_row(vec![
+            "1",
+            "Apples",
+            "A rather long description of apples",
+            "x",
+        ]);
+
+    table
+        .set_column_order(&[0, 2, 3])
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(LowerBoundary(Fixed(10)));
+
+    let report = table.constraint_report();
+    assert_eq!(report[1].width, None);
+    assert!(!report[1].honored);
+}
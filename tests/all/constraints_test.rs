@@ -355,3 +355,126 @@ fn empty_table(#[case] arrangement: ContentArrangement) {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+fn try_set_constraints() {
+    let mut table = get_constraint_table();
+
+    assert_eq!(
+        table.try_set_constraints(vec![Hidden, Hidden, Hidden, Hidden]),
+        Err(ConstraintError::TooManyConstraints {
+            constraints: 4,
+            columns: 3
+        })
+    );
+    // Nothing has been applied.
+    assert!(table
+        .column_iter()
+        .all(|column| column.constraint().is_none()));
+
+    assert_eq!(table.try_set_constraints(vec![Hidden, Hidden]), Ok(2));
+    assert!(table.column(2).unwrap().constraint().is_none());
+}
+
+#[test]
+fn set_single_constraint() {
+    let mut table = get_constraint_table();
+
+    table.set_constraint(2, Hidden).unwrap();
+    assert_eq!(table.column(2).unwrap().constraint(), Some(&Hidden));
+
+    assert_eq!(
+        table.set_constraint(3, Hidden).unwrap_err(),
+        ConstraintError::ColumnOutOfRange {
+            index: 3,
+            columns: 3
+        }
+    );
+}
+
+#[test]
+/// Columns can be addressed by the text of their header cell.
+fn set_constraint_by_name() {
+    let mut table = get_constraint_table();
+
+    assert_eq!(table.column_index_by_name("Header3"), Some(2));
+    table.set_constraint_for("Header3", Hidden).unwrap();
+    assert_eq!(table.column(2).unwrap().constraint(), Some(&Hidden));
+    assert_eq!(
+        table.column_by_name("Header3").unwrap().constraint(),
+        Some(&Hidden)
+    );
+
+    assert!(table.column_by_name("Header4").is_none());
+    assert_eq!(
+        table.set_constraint_for("Header4", Hidden).unwrap_err(),
+        ConstraintError::UnknownColumn
+    );
+
+    // Tables without a header don't have named columns.
+    let mut table = Table::new();
+    table.add_row(vec!["Header1"]);
+    assert_eq!(table.column_index_by_name("Header1"), None);
+}
+
+#[test]
+/// Without a detectable terminal, terminal fractions are resolved against the table width.
+fn terminal_fraction_without_terminal() {
+    let mut table = get_constraint_table();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .set_constraints(vec![
+            Absolute(TerminalFraction(1, 5)),
+            Absolute(TerminalFraction(1, 0)),
+        ]);
+
+    println!("{table}");
+    let expected = "
++--------+--------------+--------------+
+| smol   | Header2      | Header3      |
++======================================+
+| smol   | This is      | This is the  |
+|        | another text | third text   |
+|--------+--------------+--------------|
+| smol   | Now          | This is      |
+|        | add some     | awesome      |
+|        | multi line   |              |
+|        | stuff        |              |
++--------+--------------+--------------+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+/// Terminal fractions are resolved against the terminal, even if a table width is set.
+fn terminal_fraction() {
+    let mut table = get_constraint_table();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_terminal_size_for_tests((60, 20))
+        .set_width(40)
+        .set_constraints(vec![Absolute(TerminalFraction(1, 3))]);
+
+    println!("{table}");
+    let expected = "
++--------------------+--------+--------+
+| smol               | Header | Header |
+|                    | 2      | 3      |
++======================================+
+| smol               | This   | This   |
+|                    | is ano | is the |
+|                    | ther   | third  |
+|                    | text   | text   |
+|--------------------+--------+--------|
+| smol               | Now    | This   |
+|                    | add    | is awe |
+|                    | some   | some   |
+|                    | multi  |        |
+|                    | line   |        |
+|                    | stuff  |        |
++--------------------+--------+--------+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -4,7 +4,7 @@ use pretty_assertions::assert_eq;
 
 use comfy_table::ColumnConstraint::*;
 use comfy_table::Width::*;
-use comfy_table::{ContentArrangement, Row, Table};
+use comfy_table::{ContentArrangement, Row, Table, WidthSource};
 
 use super::assert_table_line_width;
 
@@ -144,10 +144,10 @@ fn table_with_truncate_indicator() {
     table
         .set_header(vec!["Header1", "Header2", "Head"])
         .set_content_arrangement(ContentArrangement::Dynamic)
-        .set_truncation_indicator("…")
         .set_width(35)
         .add_row(first_row)
         .add_row(second_row);
+    table.set_truncation_indicator("…").unwrap();
 
     // The first column will be wider than 6 chars.
     // The second column's content is wider than 6 chars. There should be a '…'.
@@ -405,3 +405,200 @@ fn polar_python_test_tbl_width_chars() {
     assert_table_line_width(table, 72);
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// The fallback width is used, if no other width is known.
+#[test]
+fn dynamic_fallback_width() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_fallback_width(25)
+        .add_row(vec!["This is a very long line with a lot of text", "Short"]);
+    #[cfg(feature = "tty")]
+    table.force_no_tty();
+
+    assert_eq!(table.width_source(), WidthSource::Fallback);
+    println!("{table}");
+    let expected = "
++-------------+---------+
+| Header1     | Header2 |
++=======================+
+| This is a   | Short   |
+| very long   |         |
+| line with a |         |
+| lot of text |         |
++-------------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // An explicit width always takes precedence.
+    table.set_width(40);
+    assert_eq!(table.width_source(), WidthSource::Fixed);
+    assert_table_line_width(&table, 40);
+}
+
+/// A simulated terminal size is used just like the size of a real terminal.
+#[cfg(feature = "test-util")]
+#[test]
+fn dynamic_simulated_terminal_size() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_fallback_width(80)
+        .set_terminal_size_for_tests((25, 10))
+        .add_row(vec!["This is a very long line with a lot of text", "Short"]);
+
+    assert_eq!(table.width_source(), WidthSource::Terminal);
+    assert_table_line_width(&table, 25);
+
+    // An explicit width always takes precedence.
+    table.set_width(40);
+    assert_eq!(table.width_source(), WidthSource::Fixed);
+    assert_table_line_width(&table, 40);
+}
+
+/// Columns that're sized to their data ignore the header's width.
+/// The header then wraps instead.
+#[test]
+fn size_to_data_only() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount in stock"])
+        .add_row(vec!["Apples", "12"])
+        .add_row(vec!["Pears", "7"]);
+    table.column_mut(1).unwrap().size_to_data_only(true);
+
+    println!("{table}");
+    let expected = "
++--------+----+
+| Name   | Am |
+|        | ou |
+|        | nt |
+|        | in |
+|        | st |
+|        | oc |
+|        | k  |
++=============+
+| Apples | 12 |
+|--------+----|
+| Pears  | 7  |
++--------+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The header wraps at the delimiter, if the column is wide enough.
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_width(40);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::LowerBoundary(Width::Fixed(8)));
+
+    println!("{table}");
+    let expected = "
++--------+--------+
+| Name   | Amount |
+|        | in     |
+|        | stock  |
++=================+
+| Apples | 12     |
+|--------+--------|
+| Pears  | 7      |
++--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Tables are expanded to their min width, regardless of the content arrangement.
+#[test]
+fn min_width() {
+    for arrangement in [
+        ContentArrangement::Disabled,
+        ContentArrangement::Dynamic,
+        ContentArrangement::DynamicFullWidth,
+    ] {
+        let mut table = Table::new();
+        table
+            .set_header(vec!["Id", "Name"])
+            .add_row(vec!["1", "Apples"])
+            .set_content_arrangement(arrangement)
+            .set_width(20)
+            .set_min_width(30);
+
+        println!("{table}");
+        assert_table_line_width(&table, 30);
+    }
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Name"])
+        .add_row(vec!["1", "Apples"])
+        .set_min_width(20);
+
+    let expected = "
++-------+----------+
+| Id    | Name     |
++==================+
+| 1     | Apples   |
++-------+----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Wider tables aren't touched.
+    table.set_min_width(5);
+    assert_table_line_width(&table, 15);
+
+    table.remove_min_width();
+    assert_eq!(table.min_width(), None);
+    assert_table_line_width(&table, 15);
+}
+
+/// The min width only applies to visible columns.
+#[test]
+fn min_width_with_hidden_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Name"])
+        .add_row(vec!["1", "Apples"])
+        .set_min_width(24);
+    table.column_mut(0).unwrap().set_constraint(Hidden);
+
+    println!("{table}");
+    assert_table_line_width(&table, 24);
+}
+
+/// The column widths match the ones that're used during rendering.
+#[test]
+fn column_display_widths() {
+    for arrangement in [
+        ContentArrangement::Disabled,
+        ContentArrangement::Dynamic,
+        ContentArrangement::DynamicFullWidth,
+    ] {
+        let mut table = Table::new();
+        table
+            .set_header(vec!["Id", "Name", "Description"])
+            .add_row(vec![
+                "1",
+                "Apples",
+                "Some very long description, which needs wrapping",
+            ])
+            .set_content_arrangement(arrangement)
+            .set_width(40);
+        table.column_mut(1).unwrap().set_constraint(Hidden);
+
+        let widths = table.column_display_widths();
+        assert_eq!(widths, table.plan().column_widths());
+        assert_eq!(widths[1], None);
+
+        // The top border consists of the visible columns and the vertical lines.
+        let visible: usize = widths
+            .iter()
+            .flatten()
+            .map(|width| usize::from(*width))
+            .sum();
+        let top_border = table.lines().next().unwrap();
+        assert_eq!(top_border.chars().count(), visible + 3);
+
+        table.set_min_width(60);
+        assert_eq!(table.column_display_widths(), table.plan().column_widths());
+    }
+}
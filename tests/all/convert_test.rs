@@ -0,0 +1,190 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_records_round_trip() {
+    let data = "name,description\nApples,\"red\ngreen\"\nPears,yellow\n";
+    let mut reader = csv::Reader::from_reader(data.as_bytes());
+    let header = reader.headers().unwrap().clone();
+    let records: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+
+    let mut table = Table::from_csv_records(records.clone());
+    table.set_header(&header);
+
+    println!("{table}");
+    let expected = "
++--------+-------------+
+| name   | description |
++======================+
+| Apples | red         |
+|        | green       |
+|--------+-------------|
+| Pears  | yellow      |
++--------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let mut expected_records = vec![header];
+    expected_records.extend(records);
+    assert_eq!(table.csv_records(), expected_records);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+/// Records with a differing amount of fields are accepted and errors are passed on.
+fn csv_reader() {
+    let data = "Apples,3,red\nPears,5\n";
+    let table = Table::from_csv_reader(data.as_bytes(), false).unwrap();
+
+    println!("{table}");
+    let expected = "
++--------+---+-----+
+| Apples | 3 | red |
+|--------+---+-----|
+| Pears  | 5 |     |
++--------+---+-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert!(table.header().is_none());
+
+    let data = b"name\n\xff\n";
+    assert!(Table::from_csv_reader(&data[..], true).is_err());
+}
+
+#[cfg(feature = "polars")]
+#[test]
+fn polars_dataframe() {
+    use polars::prelude::*;
+
+    let dataframe = df!(
+        "name" => ["Apples", "Pears"],
+        "amount" => [Some(3), None],
+    )
+    .unwrap();
+
+    let table = Table::from(&dataframe);
+
+    println!("{table}");
+    let expected = "
++--------+--------+
+| name   | amount |
++=================+
+| Apples | 3      |
+|--------+--------|
+| Pears  |        |
++--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializable_rows() {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    enum Kind {
+        Fruit,
+    }
+
+    #[derive(Serialize)]
+    struct Item {
+        name: String,
+        kind: Kind,
+        price: Option<f32>,
+        tags: Vec<&'static str>,
+        stock: BTreeMap<&'static str, u32>,
+    }
+
+    let items = vec![
+        Item {
+            name: "Apples".to_string(),
+            kind: Kind::Fruit,
+            price: Some(1.5),
+            tags: vec!["red", "green"],
+            stock: BTreeMap::from([("Berlin", 3), ("Paris", 5)]),
+        },
+        Item {
+            name: "Pears".to_string(),
+            kind: Kind::Fruit,
+            price: None,
+            tags: Vec::new(),
+            stock: BTreeMap::new(),
+        },
+    ];
+
+    let table = Table::from_serializable(&items).unwrap();
+    println!("{table}");
+    let expected = "
++--------+-------+-------+------------+-----------+
+| name   | kind  | price | tags       | stock     |
++=================================================+
+| Apples | Fruit | 1.5   | red, green | Berlin: 3 |
+|        |       |       |            | Paris: 5  |
+|--------+-------+-------+------------+-----------|
+| Pears  | Fruit |       |            |           |
++--------+-------+-------+------------+-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+/// An existing header is kept and values without field names are added as they are.
+fn serializable_row_with_header() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount"])
+        .add_serializable_row(("Apples", 3))
+        .unwrap();
+
+    println!("{table}");
+    let expected = "
++--------+--------+
+| Name   | Amount |
++=================+
+| Apples | 3      |
++--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Skipped fields are displayed as empty cells, so later values stay in their column.
+/// This also applies to the header, if the first row skips a field.
+#[cfg(feature = "serde")]
+#[test]
+fn serializable_rows_with_skipped_fields() {
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Item {
+        name: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        note: Option<&'static str>,
+        size: u32,
+    }
+
+    let items = vec![
+        Item {
+            name: "b",
+            note: None,
+            size: 2,
+        },
+        Item {
+            name: "a",
+            note: Some("new"),
+            size: 1,
+        },
+    ];
+
+    let table = Table::from_serializable(&items).unwrap();
+    println!("{table}");
+    let expected = "
++------+------+------+
+| name | note | size |
++====================+
+| b    |      | 2    |
+|------+------+------|
+| a    | new  | 1    |
++------+------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -59,3 +59,53 @@ fn full_custom_delimiters() {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+/// Content may be split after any of the column's delimiters, which are kept.
+fn column_delimiter_set() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Path", "Tags"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(35)
+        .add_row(vec![
+            "crate::utils::formatting::content_split",
+            "rust, tables, terminal, cli",
+        ]);
+    table.column_mut(0).unwrap().set_delimiters(&[':']);
+    table.column_mut(1).unwrap().set_delimiters(&[',', ' ']);
+
+    println!("{table}");
+    let expected = "
++----------------+----------------+
+| Path           | Tags           |
++=================================+
+| crate::utils:  | rust, tables,  |
+| :formatting::  | terminal, cli  |
+| content_split  |                |
++----------------+----------------+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.column(0).unwrap().delimiters(), &[':']);
+}
+
+#[test]
+/// Elements between delimiters are kept as a whole, if words may not be split.
+fn column_delimiter_set_keep_words() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_wrap_policy(WrapPolicy::KeepWords)
+        .set_width(10)
+        .add_row(vec!["a/b/comfy-table"]);
+    table.column_mut(0).unwrap().set_delimiters(&['/']);
+
+    println!("{table}");
+    let expected = "
++-------------+
+| a/b/        |
+| comfy-table |
++-------------+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,69 @@
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn debug_dump() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description", "Secret"])
+        .add_row(vec!["Apples", "Some very long text about apples", "a"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .set_constraints(vec![
+            LowerBoundary(Fixed(10)),
+            UpperBoundary(Fixed(20)),
+            Hidden,
+        ]);
+
+    let expected = format!(
+        "comfy-table {}
+arrangement: Dynamic
+overflow policy: Overflow
+width: 40 (Fixed)
+trim mode: None
+wrap policy: BreakWords
+delimiter: None
+style: \"||--+==+|-+||++++++\"
+header: true, rows: 1, groups: 0, footnotes: 0
+minimum width: 16
+layout: arranged
+column 0: padding=(1, 1) delimiter=None alignment=None constraint=Some(LowerBoundary(Fixed(10))) max_content_width=6 -> 8
+column 1: padding=(1, 1) delimiter=None alignment=None constraint=Some(UpperBoundary(Fixed(20))) max_content_width=32 -> 18
+column 2: padding=(1, 1) delimiter=None alignment=None constraint=Some(Hidden) max_content_width=6 -> hidden
+",
+        env!("CARGO_PKG_VERSION")
+    );
+    assert_eq!(expected, table.debug_dump());
+}
+
+#[test]
+fn debug_dump_overflow() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["Apples", "Pears", "Bananas"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_overflow_policy(OverflowPolicy::HideColumns)
+        .set_width(10);
+
+    let expected = format!(
+        "comfy-table {}
+arrangement: Dynamic
+overflow policy: HideColumns
+width: 10 (Fixed)
+trim mode: None
+wrap policy: BreakWords
+delimiter: None
+style: \"||--+==+|-+||++++++\"
+header: false, rows: 1, groups: 0, footnotes: 0
+minimum width: 13
+layout: overflow (HideColumns)
+column 0: padding=(1, 1) delimiter=None alignment=None constraint=None max_content_width=6
+column 1: padding=(1, 1) delimiter=None alignment=None constraint=None max_content_width=5
+column 2: padding=(1, 1) delimiter=None alignment=None constraint=None max_content_width=7
+",
+        env!("CARGO_PKG_VERSION")
+    );
+    assert_eq!(expected, table.debug_dump());
+}
@@ -0,0 +1,47 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[test]
+fn describe() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount", "Origin"])
+        .add_row(vec!["Apples", "3", "Local"])
+        .add_row(vec!["Pears", "4.5", ""])
+        .add_row(vec!["Bananas", "-1", "Imported"])
+        .add_row(vec!["Apples", "3", "Local"]);
+
+    let summary = table.describe();
+    println!("{summary}");
+    let expected = "
++-----------+-------+--------+--------+
+|           | Fruit | Amount | Origin |
++=====================================+
+| count     | 4     | 4      | 3      |
+|-----------+-------+--------+--------|
+| distinct  | 3     | 3      | 2      |
+|-----------+-------+--------+--------|
+| min width | 5     | 1      | 5      |
+|-----------+-------+--------+--------|
+| max width | 7     | 3      | 8      |
+|-----------+-------+--------+--------|
+| min       |       | -1     |        |
+|-----------+-------+--------+--------|
+| mean      |       | 2.38   |        |
+|-----------+-------+--------+--------|
+| max       |       | 4.5    |        |
++-----------+-------+--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &summary.to_string());
+}
+
+/// Columns without a header are named after their position.
+#[test]
+fn describe_without_header() {
+    let mut table = Table::new();
+    table.add_row(vec!["One"]);
+
+    let summary = table.describe();
+    assert_eq!(summary.cell_at(0, 1).unwrap().content(), "Column 1");
+    assert_eq!(summary.cell_at(5, 1).unwrap().content(), "");
+}
@@ -21,3 +21,85 @@ fn max_padding() {
 
     table.to_string();
 }
+
+/// Escape sequences don't take up any space, regardless of the enabled features.
+#[test]
+fn escape_sequences_are_not_measured() {
+    let link = "\x1b]8;;https://github.com\x1b\\Link\x1b]8;;\x1b\\";
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header"])
+        .add_row(vec![link])
+        .add_row(vec!["\x1b[31mRed\x1b[0m"]);
+
+    let expected = format!(
+        "
++--------+
+| Header |
++========+
+| {link}   |
+|--------|
+| \x1b[31mRed\x1b[0m    |
++--------+"
+    );
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// No panic, if a row's height is capped to zero lines.
+/// A single line is displayed instead.
+#[test]
+fn zero_max_height() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["One\nTwo"]);
+    row.max_height(0);
+    table.add_row(row);
+
+    let expected = "
++-----+
+| One |
++-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// No panic, if multi-byte characters are cut off to make room for the truncation indicator.
+#[test]
+fn truncate_multi_byte_characters() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["äääääääää\nööööööööö"]);
+    row.max_height(1);
+    table
+        .add_row(row)
+        .set_constraints(vec![ColumnConstraint::Absolute(Width::Fixed(10))]);
+
+    let expected = "
++----------+
+| äääää... |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Cells that're changed after being added are measured anew, even if arrangement is disabled.
+#[test]
+fn mutated_cells() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Disabled)
+        .set_header(vec!["Name", "Value"])
+        .add_row(vec!["A very long name", "1"]);
+
+    *table.cell_at_mut(1, 0).unwrap() = Cell::new("Short");
+    for row in table.row_iter_mut() {
+        row.add_cell(Cell::new("New column"));
+    }
+    table.discover_columns();
+    assert_eq!(table.column_max_content_widths(), vec![5, 5, 10]);
+
+    let expected = "
++-------+-------+------------+
+| Name  | Value |            |
++============================+
+| Short | 1     | New column |
++-------+-------+------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,132 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[test]
+/// Common prefixes and suffixes are replaced by the marker, while the header is kept.
+fn elide_common_prefix_and_suffix() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["File", "Size"])
+        .add_row(vec!["/home/user/projects/comfy-table/src/lib.rs", "2 KB"])
+        .add_row(vec![
+            "/home/user/projects/comfy-table/src/table.rs",
+            "40 KB",
+        ])
+        .add_row(vec!["/home/user/projects/comfy-table/README.md", "12 KB"]);
+
+    table.column_mut(0).unwrap().fit_to_pattern("…");
+    table.column_mut(1).unwrap().fit_to_pattern("…");
+
+    println!("{table}");
+    let expected = "
++---------------+------+
+| File          | Size |
++======================+
+| …src/lib.rs   | 2…   |
+|---------------+------|
+| …src/table.rs | 40…  |
+|---------------+------|
+| …README.md    | 12…  |
++---------------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Nothing is elided, if the common part isn't wider than the marker.
+fn keep_short_patterns() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["prefix-one"])
+        .add_row(vec!["prefix-two"]);
+    table.column_mut(0).unwrap().fit_to_pattern("[...]");
+
+    println!("{table}");
+    let expected = "
++----------+
+| [...]one |
+|----------|
+| [...]two |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.column_mut(0).unwrap().fit_to_pattern("<long marker>");
+    println!("{table}");
+    let expected = "
++------------+
+| prefix-one |
+|------------|
+| prefix-two |
++------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// At least a single character of each line is kept and single lines aren't elided.
+fn keep_distinct_content() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["https://example.com"])
+        .add_row(vec!["https://example.com/"]);
+    table.column_mut(0).unwrap().fit_to_pattern("…");
+
+    println!("{table}");
+    let expected = "
++-----+
+| …m  |
+|-----|
+| …m/ |
++-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let mut table = Table::new();
+    table.add_row(vec!["https://example.com"]);
+    table.column_mut(0).unwrap().fit_to_pattern("…");
+    let expected = "
++---------------------+
+| https://example.com |
++---------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Plans, frozen layouts and live tables use the elided content as well.
+fn elided_layouts() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["File", "Size"])
+        .add_row(vec!["/home/user/projects/comfy-table/src/lib.rs", "2 KB"])
+        .add_row(vec![
+            "/home/user/projects/comfy-table/src/table.rs",
+            "40 KB",
+        ])
+        .add_row(vec!["/home/user/projects/comfy-table/README.md", "12 KB"]);
+
+    table.column_mut(0).unwrap().fit_to_pattern("…");
+    let expected = table.to_string();
+
+    let plan = table.plan();
+    assert_eq!(plan.width(), 25);
+    assert_eq!(table.render(&plan), expected);
+
+    let mut other = Table::new();
+    other
+        .set_header(vec!["File", "Size"])
+        .add_row(vec!["/home/user/projects/comfy-table/src/lib.rs", "2 KB"])
+        .add_row(vec![
+            "/home/user/projects/comfy-table/src/table.rs",
+            "40 KB",
+        ])
+        .add_row(vec!["/home/user/projects/comfy-table/README.md", "12 KB"]);
+
+    other.column_mut(0).unwrap().fit_to_pattern("…");
+    other.set_frozen_layout(table.freeze_layout()).unwrap();
+    assert_eq!(other.to_string(), expected);
+
+    let mut live = LiveTable::new(table);
+    assert_eq!(live.render(), expected);
+    live.add_row(vec!["/home/user/Cargo.toml", "1 KB"]);
+    assert!(live
+        .render()
+        .contains("| …projects/comfy-table/src/lib.rs "));
+}
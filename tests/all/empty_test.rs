@@ -0,0 +1,102 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn empty_message() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount", "Price"])
+        .set_empty_message("No results");
+
+    println!("{table}");
+    let expected = "
++------+--------+-------+
+| Name | Amount | Price |
++=======================+
+| No results            |
++-----------------------+";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The message disappears, as soon as there're rows.
+    table.add_row(vec!["Apples", "3", "1.50"]);
+    assert!(!table.to_string().contains("No results"));
+}
+
+/// Long messages widen the table, hidden columns don't take up any space
+/// and the bottom border has no intersections.
+#[test]
+fn empty_message_utf8() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Name", "Hidden", "Amount", "Price"])
+        .set_constraint(1, ColumnConstraint::Hidden)
+        .unwrap()
+        .set_empty_message("There's nothing to see in this table");
+
+    println!("{table}");
+    let expected = "
+┌───────────┬─────────────┬────────────┐
+│ Name      ┆ Amount      ┆ Price      │
+╞═══════════╪═════════════╪════════════╡
+│ There's nothing to see in this table │
+└──────────────────────────────────────┘";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The message is displayed in a single line, as long as the table's width allows it.
+#[test]
+fn empty_message_arrangements() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["A", "B"])
+        .set_empty_message("No results found");
+
+    let expected = "
++---------+--------+
+| A       | B      |
++==================+
+| No results found |
++------------------+";
+    for arrangement in [ContentArrangement::Disabled, ContentArrangement::Dynamic] {
+        table.set_content_arrangement(arrangement).set_width(40);
+        println!("{table}");
+        assert_eq!(expected, "\n".to_string() + &table.to_string());
+    }
+
+    // Messages are only wrapped, if they don't fit into the table's width.
+    table.set_width(16);
+    println!("{table}");
+    let expected = "
++-------+------+
+| A     | B    |
++==============+
+| No results   |
+| found        |
++--------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn hide_when_empty() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount"])
+        .set_empty_message("No results")
+        .hide_when_empty(true);
+
+    assert_eq!(table.to_string(), "");
+    assert_eq!(table.lines().count(), 0);
+
+    table.add_row(vec!["Apples", "3"]);
+    let expected = "
++--------+--------+
+| Name   | Amount |
++=================+
+| Apples | 3      |
++--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,74 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+
+#[test]
+fn box_chars_are_kept_by_default() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["┌ Header ┐", "Plain"])
+        .add_row(vec!["a─b\n│c│", "x-y|z"]);
+
+    assert!(!table.escapes_border_chars());
+
+    let expected = "
+┌────────────┬───────┐
+│ ┌ Header ┐ ┆ Plain │
+╞════════════╪═══════╡
+│ a─b        ┆ x-y|z │
+│ │c│        ┆       │
+└────────────┴───────┘";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn escape_box_chars() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["┌ Header ┐", "Plain"])
+        .add_row(vec!["a─b\n│c│", "x-y|z"]);
+
+    table.escape_border_chars(true);
+
+    // ASCII characters aren't touched and the layout doesn't change.
+    let expected = "
+┌────────────┬───────┐
+│ \u{FFFD} Header \u{FFFD} ┆ Plain │
+╞════════════╪═══════╡
+│ a\u{FFFD}b        ┆ x-y|z │
+│ \u{FFFD}c\u{FFFD}        ┆       │
+└────────────┴───────┘";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The content itself is unchanged.
+    assert_eq!(
+        table.row(0).unwrap().cell_iter().next().unwrap().content(),
+        "a─b\n│c│"
+    );
+}
+
+#[test]
+fn escape_box_chars_in_sections() {
+    let mut table = Table::new();
+    table
+        .escape_border_chars(true)
+        .add_row(vec!["First row", "a"])
+        .add_section("═ Section ═")
+        .add_row(vec!["Second row", "b"]);
+
+    let expected = "
++------------+---+
+| First row  | a |
++================+
+| \u{FFFD} Section \u{FFFD}    |
++================+
+| Second row | b |
++------------+---+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,214 @@
+//! Feature flags must never change the layout of a table.
+//!
+//! These tests render a canonical set of tables and compare them against the same expectations,
+//! regardless of the enabled features. CI runs the test suite for every relevant combination of
+//! `tty` and `custom_styling`, which turns this file into a feature matrix.\
+//! Styled tables are compared after all ANSI escape sequences have been removed.
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+
+/// Remove all ANSI escape sequences from the rendered table.
+#[cfg(feature = "tty")]
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(character) = chars.next() {
+        if character != '\x1b' {
+            stripped.push(character);
+            continue;
+        }
+
+        // Skip everything up to and including the final byte of the sequence.
+        if chars.next() == Some('[') {
+            for character in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&character) {
+                    break;
+                }
+            }
+        }
+    }
+
+    stripped
+}
+
+/// Apply some styling to a cell, if styling is supported.
+#[allow(unused_mut)]
+fn style(mut cell: Cell, styled: bool) -> Cell {
+    #[cfg(feature = "tty")]
+    if styled {
+        cell = cell
+            .fg(Color::Green)
+            .bg(Color::Black)
+            .add_attribute(Attribute::Bold);
+    }
+    #[cfg(not(feature = "tty"))]
+    let _ = styled;
+
+    cell
+}
+
+/// Render the table, both with and without styling.
+#[allow(unused_mut)]
+fn render(build: fn(bool) -> Table) -> Vec<String> {
+    let mut renders = vec![build(false).to_string()];
+
+    #[cfg(feature = "tty")]
+    {
+        let mut table = build(true);
+        table.force_no_tty().enforce_styling();
+        let styled = table.to_string();
+        assert!(styled.contains('\x1b'));
+        renders.push(strip_ansi(&styled));
+    }
+
+    renders
+}
+
+fn assert_layout(build: fn(bool) -> Table, expected: &str) {
+    for render in render(build) {
+        println!("{render}");
+        assert_eq!(expected.trim_start(), render);
+    }
+}
+
+#[test]
+fn dynamic_arrangement() {
+    fn build(styled: bool) -> Table {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_width(40)
+            .set_header(vec![
+                style(Cell::new("Name"), styled),
+                style(Cell::new("Description"), styled),
+            ])
+            .add_row(vec![
+                style(Cell::new("Wrapping"), styled),
+                style(
+                    Cell::new("This is some long content that needs to be wrapped"),
+                    styled,
+                ),
+            ])
+            .add_row(vec![
+                style(Cell::new("Multi\nline"), styled),
+                style(
+                    Cell::new("Short").set_alignment(CellAlignment::Right),
+                    styled,
+                ),
+            ]);
+
+        table
+    }
+
+    let expected = "
+┌──────────┬───────────────────────────┐
+│ Name     ┆ Description               │
+╞══════════╪═══════════════════════════╡
+│ Wrapping ┆ This is some long content │
+│          ┆ that needs to be wrapped  │
+├╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+│ Multi    ┆                     Short │
+│ line     ┆                           │
+└──────────┴───────────────────────────┘";
+    assert_layout(build, expected);
+}
+
+#[test]
+fn wide_characters() {
+    fn build(styled: bool) -> Table {
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_width(20)
+            .set_header(vec![
+                style(Cell::new("名前"), styled),
+                style(Cell::new("Emoji"), styled),
+            ])
+            .add_row(vec![
+                style(Cell::new("コンフィテーブル"), styled),
+                style(Cell::new("😀😃😄"), styled).set_alignment(CellAlignment::Center),
+            ]);
+
+        table
+    }
+
+    let expected = "
++---------+--------+
+| 名前    | Emoji  |
++==================+
+| コンフ  | 😀😃😄 |
+| ィテー  |        |
+| ブル    |        |
++---------+--------+";
+    assert_layout(build, expected);
+}
+
+#[test]
+fn truncation_and_padding() {
+    fn build(styled: bool) -> Table {
+        let mut row = Row::from(vec![
+            style(Cell::new("First line\nSecond line"), styled),
+            style(Cell::new("Padded").set_padding((3, 3)), styled),
+        ]);
+        row.max_height(1);
+
+        let mut table = Table::new();
+        table.set_header(vec!["Truncated", "Padded"]).add_row(row);
+        table
+            .column_mut(0)
+            .unwrap()
+            .set_constraint(ColumnConstraint::Absolute(Width::Fixed(12)));
+
+        table
+    }
+
+    let expected = "
++------------+------------+
+| Truncated  | Padded     |
++=========================+
+| First l... |   Padded   |
++------------+------------+";
+    assert_layout(build, expected);
+}
+
+/// Content that's already styled has the same layout as the plain content.
+#[cfg(feature = "custom_styling")]
+#[test]
+fn styled_content() {
+    fn build(styled: bool) -> Table {
+        let content = |text: &str| {
+            if styled {
+                format!("\x1b[31m{text}\x1b[0m")
+            } else {
+                text.to_string()
+            }
+        };
+
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_width(25)
+            .set_header(vec![content("Name"), content("Description")])
+            .add_row(vec![
+                content("Styled"),
+                content("Some styled content, that is wrapped"),
+            ]);
+
+        table
+    }
+
+    let expected = "
++--------+--------------+
+| Name   | Description  |
++=======================+
+| Styled | Some styled  |
+|        | content,     |
+|        | that is      |
+|        | wrapped      |
++--------+--------------+";
+    assert_eq!(expected.trim_start(), build(false).to_string());
+    assert_eq!(expected.trim_start(), strip_ansi(&build(true).to_string()));
+}
@@ -0,0 +1,62 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+
+#[test]
+fn footnotes() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Country"),
+            Cell::new("Population").set_footnote_ref(1),
+        ])
+        .add_row(vec![Cell::new("Germany"), Cell::new("83 million")])
+        .add_row(vec![
+            Cell::new("France").set_footnote_ref(2),
+            Cell::new("68 million"),
+        ])
+        .add_footnote("Estimates from 2023.")
+        .add_footnote("Includes overseas departments and regions of France.");
+
+    println!("{table}");
+    let expected = "
+┌───────────┬───────────────┐
+│ Country   ┆ Population[1] │
+╞═══════════╪═══════════════╡
+│ Germany   ┆ 83 million    │
+├╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+│ France[2] ┆ 68 million    │
+└───────────┴───────────────┘
+[1] Estimates from 2023.
+[2] Includes overseas
+    departments and regions
+    of France.";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Footnote markers are taken into account during dynamic arrangement.
+#[test]
+fn footnote_marker_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(20)
+        .add_row(vec![
+            Cell::new("Some longer text").set_footnote_ref(1),
+            Cell::new("Text"),
+        ])
+        .add_footnote("A note");
+
+    println!("{table}");
+    let expected = "
++-----------+------+
+| Some      | Text |
+| longer    |      |
+| text[1]   |      |
++-----------+------+
+[1] A note";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.footnotes(), &["A note".to_string()]);
+}
@@ -0,0 +1,96 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+fn wrapping_table(rows: Vec<Vec<&str>>) -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30);
+    for row in rows {
+        table.add_row(row);
+    }
+
+    table
+}
+
+/// A frozen layout keeps the column widths of the table it has been created from.
+#[test]
+fn frozen_layout_is_applied() {
+    let reference = wrapping_table(vec![vec!["Some long name", "Short"]]);
+    let bytes = reference.freeze_layout().to_bytes();
+
+    let layout = FrozenLayout::from_bytes(&bytes).unwrap();
+    let mut table = wrapping_table(vec![vec!["Nils", "A description that's rather long"]]);
+    table.set_frozen_layout(layout).unwrap();
+
+    println!("{table}");
+    let expected = "
++-----------+-------------+
+| Name      | Description |
++=========================+
+| Nils      | A           |
+|           | description |
+|           | that's      |
+|           | rather long |
++-----------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn frozen_layout_schema_mismatch() {
+    let layout = wrapping_table(vec![]).freeze_layout();
+
+    let mut table = wrapping_table(vec![]);
+    table.set_width(40);
+    assert_eq!(
+        table.set_frozen_layout(layout.clone()).unwrap_err(),
+        LayoutError::SchemaMismatch
+    );
+
+    let mut table = wrapping_table(vec![]);
+    table.set_header(vec!["Name", "Summary"]);
+    assert!(table.set_frozen_layout(layout).is_err());
+}
+
+/// Changing the schema after loading a layout invalidates it.
+#[test]
+fn frozen_layout_is_invalidated() {
+    let reference = wrapping_table(vec![vec!["Some long name", "Short"]]);
+    let layout = reference.freeze_layout();
+
+    let mut table = wrapping_table(vec![vec!["Nils", "Short"]]);
+    let arranged = table.to_string();
+    table.set_frozen_layout(layout).unwrap();
+    assert_ne!(arranged, table.to_string());
+
+    table.set_width(31);
+    let mut expected = table.clone();
+    expected.clear_frozen_layout();
+    assert_eq!(expected.to_string(), table.to_string());
+}
+
+#[test]
+fn render_plan() {
+    let mut table = wrapping_table(vec![vec!["Some long name", "Short"]]);
+    table.add_footnote("A footnote");
+
+    let plan = table.plan();
+    assert_eq!(plan.column_widths(), &[Some(11), Some(13)]);
+    assert_eq!(plan.row_heights(), &[1, 2]);
+    assert_eq!(plan.width(), 27);
+    assert_eq!(plan.height(), table.lines().count());
+    assert_eq!(table.render(&plan), table.to_string());
+
+    // New rows are rendered with the planned column widths.
+    table.add_row(vec!["Name", "A very long description"]);
+    assert!(table.render(&plan).contains("| Name      | A very long |"));
+
+    // Changes to the schema invalidate the plan.
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+    assert_eq!(table.render(&plan), table.to_string());
+}
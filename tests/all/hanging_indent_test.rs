@@ -0,0 +1,104 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+
+use super::assert_table_line_width;
+
+/// Continuation lines are indented, while newlines of the user start unindented lines.
+#[test]
+fn hanging_indent() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Level", "Message"])
+        .add_row(vec![
+            "WARN",
+            "Connection to the database was lost\nRetrying in five seconds",
+        ])
+        .set_width(34)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    table.column_mut(1).unwrap().set_hanging_indent(4);
+
+    println!("{table}");
+    let expected = "
++-------+------------------------+
+| Level | Message                |
++================================+
+| WARN  | Connection to the      |
+|       |     database was lost  |
+|       | Retrying in five       |
+|       |     seconds            |
++-------+------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 34);
+}
+
+/// Indented lines are truncated just like any other line.
+#[test]
+fn hanging_indent_with_max_height() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["This is some very long text that gets truncated"]);
+    row.max_height(2);
+    table.add_row(row);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(16)))
+        .set_hanging_indent(2);
+
+    println!("{table}");
+    let expected = "
++----------------+
+| This is some   |
+|   very long... |
++----------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The indent is reduced for columns that are too narrow for it.
+#[test]
+fn hanging_indent_in_narrow_column() {
+    let mut table = Table::new();
+    table.add_row(vec!["abc"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(4)))
+        .set_hanging_indent(5);
+
+    println!("{table}");
+    let expected = "
++----+
+| ab |
+|  c |
++----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Words that are kept as a whole need space for the indent as well.
+#[test]
+fn hanging_indent_keeps_words() {
+    let mut table = Table::new();
+    table
+        .set_wrap_policy(WrapPolicy::KeepWords)
+        .add_row(vec!["Some extraordinarily long words"]);
+    let column = table.column_mut(0).unwrap();
+    column.set_hanging_indent(3);
+    assert_eq!(column.hanging_indent(), 3);
+
+    // The longest word is 15 characters wide, the indent 3 and the padding 2.
+    assert_eq!(table.min_possible_width(), 22);
+
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(10);
+    println!("{table}");
+    let expected = "
++--------------------+
+| Some               |
+|    extraordinarily |
+|    long words      |
++--------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,52 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+
+#[test]
+/// Only the header line disappears, the rest of the preset is kept.
+fn hide_header_separator() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Name", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_section("Vegetables")
+        .add_row(vec!["Carrots", "2"]);
+
+    table.hide_header_separator();
+
+    let expected = "
+┌─────────┬────────┐
+│ Name    ┆ Amount │
+│ Apples  ┆ 3      │
+╞═════════╪════════╡
+│ Vegetables       │
+╞═════════╪════════╡
+│ Carrots ┆ 2      │
+└─────────┴────────┘";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.style(TableComponent::HeaderLines), Some('═'));
+}
+
+#[test]
+fn show_header_separator_again() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Name", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_section("Vegetables")
+        .add_row(vec!["Carrots", "2"]);
+
+    let expected = table.to_string();
+
+    table.hide_header_separator();
+    assert!(!table.shows_header_separator());
+    assert_ne!(expected, table.to_string());
+
+    table.show_header_separator();
+    assert!(table.shows_header_separator());
+    assert_eq!(expected, table.to_string());
+}
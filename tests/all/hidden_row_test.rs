@@ -0,0 +1,142 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn hidden_row_is_not_rendered() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["A very long name for pears", "500"])
+        .add_row(vec!["Carrots", "2"]);
+
+    table.row_mut(1).unwrap().set_hidden(true);
+
+    let expected = "
++---------+--------+
+| Fruit   | Amount |
++==================+
+| Apples  | 3      |
+|---------+--------|
+| Carrots | 2      |
++---------+--------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The row is still part of the table.
+    assert_eq!(table.row_count(), 3);
+    assert!(table.row(1).unwrap().is_hidden());
+    assert_eq!(table.column_max_content_widths(), vec![7, 6]);
+}
+
+#[test]
+fn unhidden_row_is_rendered_again() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["A very long name for pears", "500"])
+        .add_row(vec!["Carrots", "2"]);
+
+    let expected = table.to_string();
+
+    table.row_mut(1).unwrap().set_hidden(true);
+    assert_ne!(expected, table.to_string());
+
+    table.row_mut(1).unwrap().set_hidden(false);
+    assert_eq!(expected, table.to_string());
+}
+
+#[test]
+fn hidden_rows_keep_groups_and_sections() {
+    let mut table = Table::new();
+    table
+        .begin_group("first")
+        .add_row(vec!["one"])
+        .add_row(vec!["two"])
+        .end_group()
+        .add_section("Next")
+        .begin_group("second")
+        .add_row(vec!["three"])
+        .end_group();
+    table.row_mut(1).unwrap().set_hidden(true);
+
+    let expected = "
++-------+
+| one   |
++=======+
+| Next  |
++=======+
+| three |
++-------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn rendered_rows_refer_to_original_index() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["A very long name for pears", "500"])
+        .add_row(vec!["Carrots", "2"]);
+
+    table.row_mut(0).unwrap().set_hidden(true);
+
+    let indices: Vec<Option<usize>> = table
+        .rendered_rows()
+        .iter()
+        .map(|row| row.row_index)
+        .collect();
+    assert_eq!(indices, vec![None, Some(1), Some(2)]);
+}
+
+#[test]
+fn truncated_content_refers_to_original_index() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["hidden"])
+        .add_row(Row::from(vec!["one\ntwo"]).max_height(1).clone());
+    table.row_mut(0).unwrap().set_hidden(true);
+
+    let truncated = table.truncated_content();
+    assert_eq!(truncated.len(), 1);
+    assert_eq!(truncated[0].row_index, 1);
+}
+
+#[test]
+fn all_rows_hidden_shows_empty_message() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["A very long name for pears", "500"])
+        .add_row(vec!["Carrots", "2"]);
+
+    table.set_empty_message("Nothing matches");
+    for row in table.row_iter_mut() {
+        row.set_hidden(true);
+    }
+
+    let expected = "
++--------+--------+
+| Fruit  | Amount |
++=================+
+| Nothing matches |
++-----------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert!(!table.is_empty());
+}
+
+#[test]
+fn hidden_rows_are_skipped_in_html() {
+    let mut table = Table::new();
+    table.add_row(vec!["one"]).add_row(vec!["two"]);
+    table.row_mut(0).unwrap().set_hidden(true);
+
+    let html = table.to_html();
+    assert!(!html.contains("one"));
+    assert!(html.contains("two"));
+}
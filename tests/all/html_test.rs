@@ -0,0 +1,66 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn html_export() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Secret", "Amount"])
+        .begin_group("Local")
+        .add_row(vec!["Apples", "a", "3"])
+        .add_row(vec!["Pears\n<ripe>", "b", "5"])
+        .begin_group("Imported")
+        .add_row(vec!["Bananas", "c", "4"])
+        .end_group();
+    table
+        .set_constraint(1, ColumnConstraint::Hidden)
+        .unwrap()
+        .column_mut(2)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Center);
+
+    let expected = "<table>
+  <thead>
+    <tr>
+      <th>Fruit</th>
+      <th style=\"text-align: center\">Amount</th>
+    </tr>
+  </thead>
+  <tbody>
+    <tr>
+      <td>Apples</td>
+      <td style=\"text-align: center\">3</td>
+    </tr>
+    <tr>
+      <td>Pears<br>&lt;ripe&gt;</td>
+      <td style=\"text-align: center\">5</td>
+    </tr>
+  </tbody>
+  <tbody>
+    <tr>
+      <td>Bananas</td>
+      <td style=\"text-align: center\">4</td>
+    </tr>
+  </tbody>
+</table>
+";
+    assert_eq!(expected, table.to_html());
+}
+
+#[test]
+fn html_links() {
+    let mut table = Table::new();
+    table.add_row(vec![
+        Cell::new("Issue #42").set_url("https://example.com/?a=1&b=2")
+    ]);
+
+    let expected = "<table>
+  <tbody>
+    <tr>
+      <td><a href=\"https://example.com/?a=1&amp;b=2\">Issue #42</a></td>
+    </tr>
+  </tbody>
+</table>
+";
+    assert_eq!(expected, table.to_html());
+}
@@ -96,3 +96,32 @@ fn styled_text_only_table() {
 └─────────────────────────────────────────┴─────────────────────────────────────────┘";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+/// Raw cells are split without terminating or resuming any styles.
+/// The escape sequences are still ignored when measuring the content.
+fn raw_ansi_cell() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![
+            Cell::new("\x1b[31mred text\nstill red\x1b[0m").raw_ansi(true),
+            Cell::new("\x1b[31mred text\nstill red\x1b[0m"),
+        ])
+        .add_row(vec![
+            Cell::new("\x1b[32mgreen splitted").raw_ansi(true),
+            Cell::new("\x1b[32mgreen splitted"),
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(27);
+
+    println!("{table}");
+    let expected = "
++------------+------------+
+| \x1b[31mred text   | \x1b[31mred text\x1b[0m   |
+| still red\x1b[0m  | \x1b[31mstill red\x1b[0m  |
+|------------+------------|
+| \x1b[32mgreen      | \x1b[32mgreen\x1b[0m      |
+| splitted   | \x1b[32msplitted\x1b[0m   |
++------------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
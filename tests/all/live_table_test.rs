@@ -0,0 +1,112 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// The live table always renders exactly the same as the wrapped table.
+#[test]
+fn live_table_matches_table() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Service", "Status", "Load"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .add_row(vec!["database", "up", "12%"])
+        .add_row(vec!["webserver", "up", "3%"]);
+
+    let mut live = LiveTable::new(table);
+    assert_eq!(live.render(), live.table().to_string());
+
+    // The widths of the columns stay the same.
+    assert!(live.update_cell(1, 2, "4%").is_some());
+    assert_eq!(live.render(), live.table().to_string());
+
+    // The status column gets wider.
+    live.update_cell(0, 1, "degraded performance, investigating");
+    assert_eq!(live.render(), live.table().to_string());
+
+    live.add_row(vec!["cache", "up", "1%"]);
+    assert_eq!(live.render(), live.table().to_string());
+
+    // The new row has more cells than the table has columns.
+    let previous = live.update_row(1, vec!["webserver", "up", "5%", "restarted"]);
+    assert_eq!(previous.unwrap().cell_count(), 3);
+    assert_eq!(live.table().column_iter().count(), 4);
+    assert_eq!(live.render(), live.table().to_string());
+
+    live.table_mut().set_width(30);
+    assert_eq!(live.render(), live.table().to_string());
+
+    // Nothing is changed for invalid positions.
+    assert!(live.update_cell(10, 0, "missing").is_none());
+    assert!(live.update_row(10, vec!["missing"]).is_none());
+    assert_eq!(live.render(), live.table().to_string());
+}
+
+#[test]
+fn live_table_rerenders_dirty_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Service", "Status"])
+        .add_row(vec!["database", "up"])
+        .add_row(vec!["webserver", "up"]);
+
+    let mut live = LiveTable::new(table);
+    live.render();
+    live.update_cell(1, 1, "down");
+
+    let expected = "
++-----------+--------+
+| Service   | Status |
++====================+
+| database  | up     |
+|-----------+--------|
+| webserver | down   |
++-----------+--------+";
+    assert_eq!(expected, "\n".to_string() + &live.render());
+}
+
+/// Sections don't line up with the cached rows, so the whole table is rendered.
+#[test]
+fn live_table_with_sections() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Service", "Status"])
+        .add_section("Backend")
+        .add_row(vec!["database", "up"]);
+
+    let mut live = LiveTable::new(table);
+    live.render();
+    live.table_mut().add_section("Frontend");
+    live.add_row(vec!["webserver", "up"]);
+    live.update_cell(1, 1, "down");
+    assert_eq!(live.render(), live.table().to_string());
+}
+
+/// Aligned decimal points depend on all rows of a column, so the whole table is rendered.
+/// The title and caption are drawn just like for the wrapped table.
+#[test]
+fn live_table_with_prepared_content() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Service", "Load"])
+        .set_title("Services")
+        .set_caption("Updated every second")
+        .add_row(vec!["database", "1.5"])
+        .add_row(vec!["webserver", "12.25"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_number_format(NumberFormat::new().align_decimal_point());
+
+    let mut live = LiveTable::new(table);
+    assert_eq!(live.render(), live.table().to_string());
+
+    live.update_cell(1, 1, "3.125");
+    assert_eq!(live.render(), live.table().to_string());
+
+    live.table_mut()
+        .column_mut(1)
+        .unwrap()
+        .remove_number_format();
+    live.update_cell(0, 1, "2");
+    assert_eq!(live.render(), live.table().to_string());
+}
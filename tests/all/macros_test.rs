@@ -0,0 +1,61 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[test]
+fn row_macro() {
+    let r = 1;
+    let row = row![
+        "Text",
+        r,
+        r + 1,
+        Cell::new("Cell").set_alignment(CellAlignment::Center),
+    ];
+
+    let mut table = Table::new();
+    table.add_row(row);
+    let expected = "
++------+---+---+------+
+| Text | 1 | 2 | Cell |
++------+---+---+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    assert_eq!(row![].cell_count(), 0);
+}
+
+#[test]
+/// The alignment shorthands are applied to the cells.
+fn alignment_shorthands() {
+    let mut table = table!(
+        ["Left aligned", "Centered", "Right aligned"],
+        [l -> "a", c -> "b", r -> "c"],
+    );
+    table.set_header(row!["Header", "Header", "Header"]);
+
+    println!("{table}");
+    let expected = "
++--------------+----------+---------------+
+| Header       | Header   | Header        |
++=========================================+
+| Left aligned | Centered | Right aligned |
+|--------------+----------+---------------|
+| a            |     b    |             c |
++--------------+----------+---------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+#[cfg(feature = "tty")]
+fn bold_shorthand() {
+    let mut table = table!([b -> "Bold", "Normal"]);
+    table.force_no_tty().enforce_styling();
+
+    let line = table.lines().nth(1).unwrap();
+    assert_eq!(line, "|\u{1b}[1m Bold \u{1b}[0m| Normal |");
+}
+
+#[test]
+fn empty_table_macro() {
+    let table = table!();
+    assert_eq!(table.row_count(), 0);
+}
@@ -0,0 +1,121 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn numbered_table(rows: usize) -> Table {
+    let mut table = Table::new();
+    table.set_header(vec!["Description", "Amount"]);
+    for index in 0..rows {
+        table.add_row(vec![format!("Row {index}"), index.to_string()]);
+    }
+
+    table
+}
+
+#[test]
+fn table_that_fits_is_untouched() {
+    let mut table = numbered_table(2);
+    let expected = table.to_string();
+
+    table.set_max_height(7);
+    assert_eq!(table.to_string(), expected);
+    assert_eq!(table.lines().count(), 7);
+}
+
+#[test]
+fn rows_are_replaced_by_indicator() {
+    let mut table = numbered_table(10);
+    table.set_max_height(9);
+
+    let expected = "
++-------------+--------+
+| Description | Amount |
++======================+
+| Row 0       | 0      |
+|-------------+--------|
+| Row 1       | 1      |
++======================+
+| ... 8 more rows      |
++----------------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.lines().count(), 9);
+}
+
+/// Only a few rows are drawn for a short preview of a large table.
+#[test]
+fn preview_of_many_rows() {
+    let mut table = numbered_table(10_000);
+    table.set_max_height(9);
+
+    let expected = "
++-------------+--------+
+| Description | Amount |
++======================+
+| Row 0       | 0      |
+|-------------+--------|
+| Row 1       | 1      |
++======================+
+| ... 9998 more rows   |
++----------------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn header_and_indicator_are_always_displayed() {
+    let mut table = numbered_table(10);
+    table.set_max_height(1);
+
+    let expected = "
++-------------+--------+
+| Description | Amount |
++======================+
+| ... 10 more rows     |
++----------------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn utf8_indicator_and_custom_indicator() {
+    let mut table = numbered_table(5);
+    table.load_preset(UTF8_FULL).set_max_height(7);
+
+    let expected = "
+┌─────────────┬────────┐
+│ Description ┆ Amount │
+╞═════════════╪════════╡
+│ Row 0       ┆ 0      │
+╞═════════════╪════════╡
+│ … 4 more rows        │
+└──────────────────────┘";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_max_height_indicator("{{{rows}}} hidden");
+    assert_eq!(table.max_height_indicator(), Some("{{{rows}}} hidden"));
+    assert!(table.to_string().contains("│ {4} hidden           │"));
+
+    table.remove_max_height();
+    assert_eq!(table.max_height(), None);
+    assert_eq!(table.lines().count(), 13);
+}
+
+#[test]
+fn hidden_rows_are_not_counted() {
+    let mut table = numbered_table(4);
+    table.row_mut(0).unwrap().set_hidden(true);
+    table.set_max_height(7);
+
+    let expected = "
++-------------+--------+
+| Description | Amount |
++======================+
+| Row 1       | 1      |
++======================+
+| ... 2 more rows      |
++----------------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,94 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+
+use super::assert_table_line_width;
+
+#[test]
+/// Words may be split at any character by default, so only the widest character matters.
+fn break_words() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Key", "Value"])
+        .add_row(vec!["path", "コンフィ"]);
+
+    let widths: Vec<usize> = table
+        .column_iter()
+        .map(|column| column.min_possible_width(&table))
+        .collect();
+    assert_eq!(widths, vec![3, 4]);
+    assert_eq!(table.min_possible_width(), 10);
+}
+
+#[test]
+/// The table can be rendered with its minimum possible width without splitting any words.
+fn keep_words_fit() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Key", "Value"])
+        .add_row(vec![
+            "path",
+            "see comfy_table::utils::formatting for details",
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_wrap_policy(WrapPolicy::KeepWords);
+
+    let min_width = table.min_possible_width();
+    // "comfy_table::utils::formatting" plus padding, "path" plus padding and three borders.
+    assert_eq!(min_width, 41);
+
+    table.set_width(min_width as u16);
+    println!("{table}");
+    let expected = "
++------+--------------------------------+
+| Key  | Value                          |
++=======================================+
+| path | see                            |
+|      | comfy_table::utils::formatting |
+|      | for details                    |
++------+--------------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, min_width);
+}
+
+#[test]
+/// Preformatted content can't be split, truncated content doesn't need to fit.
+fn preformatted_and_truncated_cells() {
+    let mut table = Table::new();
+    table.add_row(vec![
+        Cell::new("let x = 1;").preformatted(true),
+        Cell::new("A very long line, that is cut off").set_truncate(true),
+    ]);
+
+    let widths: Vec<usize> = table
+        .column_iter()
+        .map(|column| column.min_possible_width(&table))
+        .collect();
+    assert_eq!(widths, vec![12, 3]);
+}
+
+#[test]
+/// Hidden columns don't take any space and constraints are respected.
+fn constraints() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Hidden", "Lower", "Upper", "Fixed"])
+        .add_row(vec!["One", "Two", "Three", "Four"])
+        .set_wrap_policy(WrapPolicy::KeepWords)
+        .set_constraints(vec![
+            Hidden,
+            LowerBoundary(Fixed(10)),
+            UpperBoundary(Fixed(4)),
+            Absolute(Fixed(3)),
+        ]);
+
+    let widths: Vec<usize> = table
+        .column_iter()
+        .map(|column| column.min_possible_width(&table))
+        .collect();
+    assert_eq!(widths, vec![0, 10, 4, 3]);
+    // Three visible columns with four borders.
+    assert_eq!(table.min_possible_width(), 21);
+}
@@ -3,24 +3,74 @@ use unicode_width::UnicodeWidthStr;
 
 mod add_predicate;
 mod alignment_test;
+mod auto_join_test;
+#[cfg(feature = "unicode-bidi")]
+mod bidi_test;
+mod builder_test;
+mod column_name_test;
+mod column_order_test;
 #[cfg(feature = "tty")]
 mod combined_test;
+mod constraint_report_test;
 mod constraints_test;
 mod content_arrangement_test;
+#[cfg(any(feature = "csv", feature = "polars", feature = "serde"))]
+mod convert_test;
 mod counts;
 mod custom_delimiter_test;
+mod debug_dump_test;
+mod describe_test;
 mod edge_cases;
+mod elision_test;
+mod empty_test;
+mod escape_border_chars_test;
+mod feature_matrix_test;
+mod footnotes_test;
+mod frozen_layout_test;
+mod hanging_indent_test;
+mod header_separator_test;
+mod hidden_row_test;
 mod hidden_test;
+mod html_test;
 #[cfg(feature = "custom_styling")]
 mod inner_style_test;
+mod live_table_test;
+#[cfg(feature = "macros")]
+mod macros_test;
+mod max_height_test;
+mod min_possible_width_test;
 mod modifiers_test;
+mod number_format_test;
+mod overflow_test;
 mod padding_test;
+mod pagination_test;
+mod parse_test;
+mod preformatted_test;
 mod presets_test;
+#[cfg(feature = "integration_test")]
 mod property_test;
+#[cfg(feature = "regex")]
+mod regex_split_test;
+mod render_verified_test;
+mod rendered_rows_test;
+mod row_group_test;
+mod row_mutation_test;
+mod row_separator_test;
+mod section_test;
 mod simple_test;
+mod split_by_width_test;
+mod style_layer_test;
 #[cfg(feature = "tty")]
 mod styling_test;
+mod template_test;
+mod theme_test;
+mod title_test;
+mod to_row_test;
+mod truncation_test;
 mod utf_8_characters;
+mod viewport_test;
+mod width_function_test;
+mod wrap_policy_test;
 
 pub fn assert_table_line_width(table: &Table, count: usize) {
     for line in table.lines() {
@@ -32,3 +32,34 @@ fn utf8_round_corners() {
     println!("{table}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// Only the masked components are taken from a preset, independent of the loading order.
+#[test]
+fn load_preset_partial() {
+    let mut table = get_preset_table();
+    table
+        .load_preset(ASCII_FULL)
+        .load_preset_partial(UTF8_FULL, ComponentMask::HORIZONTAL_LINES)
+        .load_preset_partial(ASCII_MARKDOWN, ComponentMask::VERTICAL_LINES);
+    let expected = "
++─────────+─────────+───────────+
+| Header1 | Header2 | Header3   |
++═════════=═════════=═══════════+
+| One One | One Two | One Three |
+|╌╌╌╌╌╌╌╌╌+╌╌╌╌╌╌╌╌╌+╌╌╌╌╌╌╌╌╌╌╌|
+| One One | One Two | One Three |
++─────────+─────────+───────────+";
+
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Whitespaces in the preset remove the masked components.
+    table.load_preset_partial(NOTHING, ComponentMask::CORNERS | ComponentMask::BORDERS);
+    assert_eq!(table.style(TableComponent::TopLeftCorner), None);
+    assert_eq!(table.style(TableComponent::LeftBorder), None);
+    assert_eq!(table.style(TableComponent::MiddleIntersections), Some('+'));
+
+    let mask: ComponentMask = vec![TableComponent::TopBorder].into_iter().collect();
+    assert!(!(!mask).contains(TableComponent::TopBorder));
+    assert!((!mask).contains(TableComponent::BottomBorder));
+}
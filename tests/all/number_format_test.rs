@@ -0,0 +1,100 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn precision_and_thousands_separator() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Item", "Price"])
+        .add_row(vec!["Rent", "1250"])
+        .add_row(vec!["Coffee", "3.5"])
+        .add_row(vec!["Car", "-21000.125"])
+        .add_row(vec!["Gift", "n/a"]);
+
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_number_format(NumberFormat::new().precision(2).thousands_separator(','));
+
+    let expected = "
++--------+------------+
+| Item   | Price      |
++=====================+
+| Rent   | 1,250.00   |
+|--------+------------|
+| Coffee | 3.50       |
+|--------+------------|
+| Car    | -21,000.13 |
+|--------+------------|
+| Gift   | n/a        |
++--------+------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn aligned_decimal_points() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Item", "Price"])
+        .add_row(vec!["Rent", "1250"])
+        .add_row(vec!["Coffee", "3.5"])
+        .add_row(vec!["Car", "-21000.125"])
+        .add_row(vec!["Gift", "n/a"]);
+
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_number_format(NumberFormat::new().align_decimal_point());
+
+    // The numbers are padded, so their decimal points line up regardless of the alignment.
+    let expected = "
++--------+------------+
+| Item   | Price      |
++=====================+
+| Rent   |   1250     |
+|--------+------------|
+| Coffee |      3.5   |
+|--------+------------|
+| Car    | -21000.125 |
+|--------+------------|
+| Gift   | n/a        |
++--------+------------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn removed_number_format() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Item", "Price"])
+        .add_row(vec!["Rent", "1250"])
+        .add_row(vec!["Coffee", "3.5"])
+        .add_row(vec!["Car", "-21000.125"])
+        .add_row(vec!["Gift", "n/a"]);
+    let unchanged = table.to_string();
+
+    let column = table.column_mut(1).unwrap();
+    column.set_number_format(NumberFormat::new().precision(1));
+    assert_eq!(
+        column.number_format(),
+        Some(&NumberFormat::new().precision(1))
+    );
+
+    column.remove_number_format();
+    assert_eq!(column.number_format(), None);
+    assert_eq!(table.to_string(), unchanged);
+}
+
+#[test]
+fn number_format_in_html() {
+    let mut table = Table::new();
+    table.set_header(vec!["Price"]).add_row(vec!["1234.5"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_number_format(NumberFormat::new().precision(2).thousands_separator('.'));
+
+    assert!(table.to_html().contains("<td>1.234.50</td>"));
+}
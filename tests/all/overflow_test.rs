@@ -0,0 +1,139 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+/// By default, a table that doesn't fit simply becomes wider than the available width.
+#[test]
+fn overflow() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Age", "City", "Country"])
+        .add_row(vec!["Joe", "42", "Berlin", "Germany"])
+        .add_row(vec!["Jane", "23", "Paris", "France"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(12);
+
+    println!("{table}");
+    let expected = "
++---+---+---+---+
+| N | A | C | C |
+| a | g | i | o |
+| m | e | t | u |
+| e |   | y | n |
+|   |   |   | t |
+|   |   |   | r |
+|   |   |   | y |
++===============+
+| J | 4 | B | G |
+| o | 2 | e | e |
+| e |   | r | r |
+|   |   | l | m |
+|   |   | i | a |
+|   |   | n | n |
+|   |   |   | y |
+|---+---+---+---|
+| J | 2 | P | F |
+| a | 3 | a | r |
+| n |   | r | a |
+| e |   | i | n |
+|   |   | s | c |
+|   |   |   | e |
++---+---+---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn hide_columns() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Age", "City", "Country"])
+        .add_row(vec!["Joe", "42", "Berlin", "Germany"])
+        .add_row(vec!["Jane", "23", "Paris", "France"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(12);
+
+    table.set_overflow_policy(OverflowPolicy::HideColumns);
+
+    println!("{table}");
+    let expected = "
++-----+----+
+| Nam | Ag |
+| e   | e  |
++==========+
+| Joe | 42 |
+|-----+----|
+| Jan | 23 |
+| e   |    |
++-----+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn vertical_layout() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Age", "City", "Country"])
+        .add_row(vec!["Joe", "42", "Berlin", "Germany"])
+        .add_row(vec!["Jane", "23", "Paris", "France"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(12);
+
+    table
+        .set_overflow_policy(OverflowPolicy::Vertical)
+        .set_width(20);
+    table.column_mut(3).unwrap().set_padding((3, 3));
+
+    println!("{table}");
+    let expected = "
++---------+--------+
+| Name    | Joe    |
+|---------+--------|
+| Age     | 42     |
+|---------+--------|
+| City    | Berlin |
+|---------+--------|
+| Country | German |
+|         | y      |
++---------+--------+
++---------+--------+
+| Name    | Jane   |
+|---------+--------|
+| Age     | 23     |
+|---------+--------|
+| City    | Paris  |
+|---------+--------|
+| Country | France |
++---------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn try_render() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Age", "City", "Country"])
+        .add_row(vec!["Joe", "42", "Berlin", "Germany"])
+        .add_row(vec!["Jane", "23", "Paris", "France"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(12);
+
+    let error = table.try_render(12).unwrap_err();
+    assert_eq!(
+        error,
+        TooNarrowError {
+            width: 12,
+            min_width: 17
+        }
+    );
+
+    let rendered = table.try_render(40).unwrap();
+    let expected = "
++------+-----+--------+---------+
+| Name | Age | City   | Country |
++===============================+
+| Joe  | 42  | Berlin | Germany |
+|------+-----+--------+---------|
+| Jane | 23  | Paris  | France  |
++------+-----+--------+---------+";
+    assert_eq!(expected, "\n".to_string() + &rendered);
+}
@@ -35,3 +35,139 @@ fn custom_padding() {
 +-------------------+-----------+-----------+";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+/// Cells can override the padding of their column.
+/// The column is widened to fit the padded cell.
+fn cell_padding() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec![
+            Cell::new("Indented").set_padding((4, 1)),
+            Cell::new("Two"),
+        ])
+        .add_row(vec![
+            Cell::new("One"),
+            Cell::new("Tight").set_padding((0, 0)),
+        ]);
+
+    println!("{table}");
+    let expected = "
++-------------+---------+
+| Header1     | Header2 |
++=======================+
+|    Indented | Two     |
+|-------------+---------|
+| One         |Tight    |
++-------------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Cell padding is respected when content is split by the dynamic arrangement.
+/// The padding is reduced, if it doesn't leave any space for the content.
+fn cell_padding_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec![
+            Cell::new("Some long text").set_padding((3, 0)),
+            Cell::new("Content").set_padding((20, 20)),
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(25);
+
+    println!("{table}");
+    let expected = "
++-----------+-----------+
+| Header1   | Header2   |
++=======================+
+|   Some    |          C|
+|   long    |          o|
+|   text    |          n|
+|           |          t|
+|           |          e|
+|           |          n|
+|           |          t|
++-----------+-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+fn padding_collapse_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size", "Type"])
+        .add_row(vec!["comfy", "12kb", "crate"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .allow_padding_collapse(true);
+    table.column_mut(0).unwrap().set_padding((2, 2));
+
+    table
+}
+
+#[test]
+/// Paddings are reduced column by column, before any content is split.
+fn padding_collapse() {
+    let mut table = padding_collapse_table();
+    assert!(table.allows_padding_collapse());
+
+    // The table fits, the paddings are kept.
+    table.set_width(26);
+    println!("{table}");
+    let expected = "
++---------+------+-------+
+|  Name   | Size | Type  |
++========================+
+|  comfy  | 12kb | crate |
++---------+------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Four spaces are missing. The larger side of each padding is reduced first.
+    table.set_width(22);
+    println!("{table}");
+    let expected = "
++-------+-----+------+
+| Name  |Size |Type  |
++====================+
+| comfy |12kb |crate |
++-------+-----+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Content is only split, once all paddings are gone.
+fn padding_collapse_before_content() {
+    let mut table = padding_collapse_table();
+    table.set_width(15);
+
+    println!("{table}");
+    let expected = "
++----+----+---+
+|Name|Size|Typ|
+|    |    |e  |
++=============+
+|comf|12kb|cra|
+|y   |    |te |
++----+----+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Columns with an absolute width keep their padding.
+fn padding_collapse_absolute_width() {
+    let mut table = padding_collapse_table();
+    table
+        .set_width(20)
+        .set_constraint(1, ColumnConstraint::Absolute(Width::Fixed(6)))
+        .unwrap();
+
+    println!("{table}");
+    let expected = "
++-----+------+-----+
+|Name | Size |Type |
++==================+
+|comfy| 12kb |crate|
++-----+------+-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,111 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[test]
+/// The columns keep the width of the whole table on every page.
+fn pages_have_stable_widths() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_row(vec!["Strawberries", "120"])
+        .add_row(vec!["Plums", "7"]);
+
+    let expected = "
++--------------+--------+
+| Name         | Amount |
++=======================+
+| Apples       | 3      |
+|--------------+--------|
+| Pears        | 5      |
++--------------+--------+";
+    println!("{}", table.render_page(0, 2));
+    assert_eq!(expected, "\n".to_string() + &table.render_page(0, 2));
+
+    let expected = "
++--------------+--------+
+| Name         | Amount |
++=======================+
+| Strawberries | 120    |
+|--------------+--------|
+| Plums        | 7      |
++--------------+--------+";
+    println!("{}", table.render_page(2, 2));
+    assert_eq!(expected, "\n".to_string() + &table.render_page(2, 2));
+}
+
+#[test]
+/// Pages that reach beyond the last row only contain the remaining rows.
+fn page_out_of_range() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_row(vec!["Strawberries", "120"])
+        .add_row(vec!["Plums", "7"]);
+
+    let expected = "
++--------------+--------+
+| Name         | Amount |
++=======================+
+| Plums        | 7      |
++--------------+--------+";
+    println!("{}", table.render_page(3, 10));
+    assert_eq!(expected, "\n".to_string() + &table.render_page(3, 10));
+
+    let expected = "
++--------------+--------+
+| Name         | Amount |
++=======================+
++--------------+--------+";
+    println!("{}", table.render_page(10, 10));
+    assert_eq!(expected, "\n".to_string() + &table.render_page(10, 10));
+}
+
+#[test]
+/// Hidden rows are skipped, but still don't influence the column widths.
+fn page_skips_hidden_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_row(vec!["Strawberries", "120"])
+        .add_row(vec!["Plums", "7"]);
+
+    table.row_mut(2).unwrap().set_hidden(true);
+
+    let expected = "
++--------+--------+
+| Name   | Amount |
++=================+
+| Plums  | 7      |
++--------+--------+";
+    println!("{}", table.render_page(2, 2));
+    assert_eq!(expected, "\n".to_string() + &table.render_page(2, 2));
+}
+
+#[test]
+/// A dynamically arranged table is arranged for all of its rows.
+fn dynamic_page_matches_full_table() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Amount"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_row(vec!["Strawberries", "120"])
+        .add_row(vec!["Plums", "7"]);
+
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(20);
+
+    let full: Vec<String> = table.lines().collect();
+    let page = table.render_page(2, 1);
+    // The header and the width of the columns are identical.
+    assert_eq!(page.lines().next().unwrap(), full[0]);
+    assert_eq!(page.lines().nth(1).unwrap(), full[1]);
+}
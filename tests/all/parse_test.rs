@@ -0,0 +1,192 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::*;
+use comfy_table::*;
+
+type Content = (Option<Vec<String>>, Vec<Vec<String>>);
+
+fn content(table: &Table) -> Content {
+    let cells = |row: &Row| row.cell_iter().map(Cell::content).collect();
+    (
+        table.header().map(cells),
+        table.row_iter().map(cells).collect(),
+    )
+}
+
+fn preset_table(preset: &str, multi_line: bool) -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(preset)
+        .set_header(vec!["Name", "Description", "Count"])
+        .add_row(vec!["comfy-table", "Beautiful tables", "1"])
+        .add_row(vec!["", "Empty cells", ""]);
+    if multi_line {
+        table.add_row(vec!["Multi\nline", "Some text", "2"]);
+    }
+
+    table
+}
+
+/// Tables with lines between the rows can be restored completely.
+#[test]
+fn round_trip() {
+    for preset in [ASCII_FULL, ASCII_NO_BORDERS, UTF8_FULL, UTF8_NO_BORDERS] {
+        let table = preset_table(preset, true);
+        let rendered = table.to_string();
+        println!("{rendered}");
+
+        let parsed = Table::parse(&rendered, preset).unwrap();
+        assert_eq!(content(&table), content(&parsed));
+        assert_eq!(rendered, parsed.clone().load_preset(preset).to_string());
+    }
+}
+
+/// Without lines between rows, each line is a row.
+#[test]
+fn round_trip_condensed() {
+    for preset in [ASCII_FULL_CONDENSED, UTF8_FULL_CONDENSED, ASCII_MARKDOWN] {
+        let table = preset_table(preset, false);
+        let rendered = table.to_string();
+        println!("{rendered}");
+
+        let parsed = Table::parse(&rendered, preset).unwrap();
+        assert_eq!(content(&table), content(&parsed));
+    }
+
+    let table = preset_table(UTF8_FULL_CONDENSED, true);
+    let parsed = Table::parse(&table.to_string(), UTF8_FULL_CONDENSED).unwrap();
+    assert_eq!(parsed.row_count(), 4);
+}
+
+/// Columns are detected via the intersections of horizontal lines, if there're no vertical lines.
+#[test]
+fn columns_from_intersections() {
+    let mut table = Table::new();
+    table
+        .load_preset("||--+==+ -+||++++++")
+        .set_header(vec!["Name", "Count"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "12"]);
+    println!("{table}");
+
+    let parsed = Table::parse(&table.to_string(), "||--+==+ -+||++++++").unwrap();
+    assert_eq!(content(&table), content(&parsed));
+}
+
+/// Content may contain the same characters as the borders.
+#[test]
+fn border_characters_in_content() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Operator", "Meaning"])
+        .add_row(vec!["a | b", "Or"])
+        .add_row(vec!["--", "Decrement"])
+        .add_row(vec!["+", "Add"]);
+    println!("{table}");
+
+    let parsed = Table::parse(&table.to_string(), ASCII_FULL).unwrap();
+    assert_eq!(content(&table), content(&parsed));
+}
+
+/// Vertical lines in the content don't create additional columns, even if they're found in
+/// every line of a table with a single column.
+#[test]
+fn vertical_lines_in_content() {
+    let mut table = Table::new();
+    table.set_header(vec!["x|y"]).add_row(vec!["a|b"]);
+    println!("{table}");
+
+    let parsed = Table::parse(&table.to_string(), ASCII_FULL).unwrap();
+    assert_eq!(content(&table), content(&parsed));
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["x|y", "z"])
+        .add_row(vec!["|", "a | b"])
+        .add_row(vec!["c|", "|d"]);
+    println!("{table}");
+
+    let parsed = Table::parse(&table.to_string(), ASCII_FULL).unwrap();
+    assert_eq!(content(&table), content(&parsed));
+}
+
+/// Only the padding is removed, whitespaces at the start of the content are kept.
+#[test]
+fn leading_whitespaces() {
+    for preset in [ASCII_FULL, UTF8_FULL_CONDENSED, ASCII_NO_BORDERS] {
+        let mut table = Table::new();
+        table
+            .load_preset(preset)
+            .set_header(vec!["Name", "Value"])
+            .add_row(vec!["  indented", "   3"])
+            .add_row(vec!["plain", " 1\n  2"]);
+        println!("{table}");
+
+        let parsed = Table::parse(&table.to_string(), preset).unwrap();
+        if preset == UTF8_FULL_CONDENSED {
+            // Without lines between rows, each line is a row of its own.
+            assert_eq!(
+                parsed.row(2).unwrap().cell_iter().nth(1).unwrap().content(),
+                "  2"
+            );
+        } else {
+            assert_eq!(content(&table), content(&parsed));
+        }
+    }
+}
+
+#[test]
+fn wide_characters() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["名前", "Emoji"])
+        .add_row(vec!["コンフィテーブル", "😀😃"]);
+
+    let parsed = Table::parse(&table.to_string(), UTF8_FULL).unwrap();
+    assert_eq!(content(&table), content(&parsed));
+}
+
+#[test]
+fn sections_and_footnotes() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Count"])
+        .add_row(vec!["Apples", "3"])
+        .add_section("Vegetables")
+        .add_row(vec!["Carrots", "7"])
+        .add_footnote("Counted yesterday");
+    println!("{table}");
+
+    let parsed = Table::parse(&table.to_string(), ASCII_FULL).unwrap();
+    assert_eq!(content(&table), content(&parsed));
+    assert_eq!(parsed.sections().len(), 1);
+    assert_eq!(parsed.sections()[0].title().content(), "Vegetables");
+    assert_eq!(parsed.sections()[0].row_index(), 1);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(Table::parse("", ASCII_FULL).unwrap_err(), ParseError::Empty);
+    assert_eq!(
+        Table::parse("+---+\n+---+", ASCII_FULL).unwrap_err(),
+        ParseError::Empty
+    );
+    assert_eq!(
+        Table::parse("Some text\n| a |", ASCII_FULL).unwrap_err(),
+        ParseError::MissingBorder { line: 0 }
+    );
+    // Only one line of the second row has a vertical line at the column boundary.
+    assert_eq!(
+        Table::parse(
+            "+---+---+\n| a | b |\n+---+---+\n| a   b |\n| c | d |\n+---+---+",
+            ASCII_FULL
+        )
+        .unwrap_err(),
+        ParseError::InconsistentColumns { line: 4 }
+    );
+    assert_eq!(
+        Table::parse("+---+---+---+\n| a | b   c |\n+---+---+---+", ASCII_FULL).unwrap_err(),
+        ParseError::InconsistentColumns { line: 1 }
+    );
+}
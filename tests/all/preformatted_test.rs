@@ -0,0 +1,81 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+use super::assert_table_line_width;
+
+/// Preformatted content keeps its whitespaces and ignores alignment.
+#[test]
+fn preformatted_content() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Diagram"])
+        .add_row(vec![
+            Cell::new("Tree"),
+            Cell::new("   /\\\n  /  \\\n /    \\\n   ||").preformatted(true),
+        ])
+        .add_row(vec![
+            Cell::new("Code"),
+            Cell::new("fn main() {\n    println!(\"hi\");\n}")
+                .preformatted(true)
+                .set_alignment(CellAlignment::Right),
+        ]);
+
+    println!("{table}");
+    let expected = "
++------+---------------------+
+| Name | Diagram             |
++============================+
+| Tree |    /\\               |
+|      |   /  \\              |
+|      |  /    \\             |
+|      |    ||               |
+|------+---------------------|
+| Code | fn main() {         |
+|      |     println!(\"hi\"); |
+|      | }                   |
++------+---------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Preformatted content isn't split in dynamic arrangement, even if the table becomes wider.
+#[test]
+fn preformatted_content_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30)
+        .add_row(vec![
+            Cell::new("This is some long text that should be wrapped"),
+            Cell::new("| a | b | c |").preformatted(true),
+        ]);
+
+    println!("{table}");
+    let expected = "
++------------+---------------+
+| This is    | | a | b | c | |
+| some long  |               |
+| text that  |               |
+| should be  |               |
+| wrapped    |               |
++------------+---------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 30);
+}
+
+/// Preformatted content is cut off, if an upper boundary forces the column to be smaller.
+/// The cut is marked by the width truncation indicator.
+#[test]
+fn preformatted_content_upper_boundary() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::new("a long   spaced line").preformatted(true)])
+        .set_constraints(vec![ColumnConstraint::UpperBoundary(Width::Fixed(10))]);
+
+    println!("{table}");
+    let expected = "
++----------+
+| a lon... |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -173,6 +173,22 @@ fn test_utf8_no_borders() {
     assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
 }
 
+#[test]
+fn test_utf8_borders_only() {
+    let mut table = get_preset_table();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    println!("{table}");
+    let expected = "
+┌───────────────┐
+│ Hello   there │
+╞═══════════════╡
+│ a       b     │
+│ c       d     │
+└───────────────┘";
+    println!("{expected}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
 #[test]
 fn test_utf8_horizontal_only() {
     let mut table = get_preset_table();
@@ -202,3 +218,65 @@ fn test_nothing() {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
 }
+
+#[test]
+/// Style maps created from presets render just like the presets themselves.
+fn style_maps_match_presets() {
+    for preset in [
+        ASCII_FULL,
+        ASCII_MARKDOWN,
+        UTF8_FULL_CONDENSED,
+        UTF8_BORDERS_ONLY,
+        NOTHING,
+    ] {
+        let mut expected = get_preset_table();
+        expected.load_preset(preset);
+
+        let map = StyleMap::from_preset(preset);
+        let mut table = get_preset_table();
+        table.load_style_map(&map);
+
+        assert_eq!(expected.to_string(), table.to_string());
+        assert_eq!(table.style_map(), map);
+        assert_eq!(table.current_style_as_preset(), preset);
+    }
+}
+
+#[test]
+fn const_style_map() {
+    const CORNERS: StyleMap = StyleMap::from_array([Some('#'); TableComponent::COUNT])
+        .remove_style(TableComponent::TopBorder)
+        .remove_style(TableComponent::BottomBorder);
+
+    assert_eq!(CORNERS.style(TableComponent::TopLeftCorner), Some('#'));
+    assert_eq!(CORNERS.style(TableComponent::TopBorder), None);
+}
+
+#[test]
+fn preset_for_terminal_capabilities() {
+    let capabilities = |is_tty, utf8, legacy_console| TerminalCapabilities {
+        is_tty,
+        utf8,
+        legacy_console,
+    };
+
+    assert_eq!(capabilities(true, true, false).preset(), UTF8_FULL);
+    assert_eq!(capabilities(true, false, false).preset(), ASCII_FULL);
+    assert_eq!(capabilities(true, true, true).preset(), ASCII_FULL);
+    assert_eq!(capabilities(false, true, false).preset(), NOTHING);
+
+    let mut table = get_preset_table();
+    table.load_preset_for(capabilities(true, false, false));
+    assert_eq!(table.current_style_as_preset(), ASCII_FULL);
+}
+
+/// Tables that aren't displayed in a terminal don't have any borders.
+#[cfg(feature = "tty")]
+#[test]
+fn auto_preset_without_tty() {
+    let mut table = get_preset_table();
+    table.force_no_tty().auto_preset();
+
+    assert!(!table.terminal_capabilities().is_tty);
+    assert_eq!(table.current_style_as_preset(), NOTHING);
+}
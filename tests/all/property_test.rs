@@ -1,160 +1,7 @@
 use ::proptest::prelude::*;
-use comfy_table::ColumnConstraint::*;
-use comfy_table::Width::*;
+use comfy_table::strategies::*;
 use comfy_table::*;
-
-/// Pick any of the three existing ContentArrangement types for the table.
-fn content_arrangement() -> impl Strategy<Value = ContentArrangement> {
-    prop_oneof![
-        Just(ContentArrangement::Disabled),
-        Just(ContentArrangement::Dynamic),
-        Just(ContentArrangement::DynamicFullWidth),
-    ]
-}
-
-/// Each cell can have any alignment.
-fn cell_alignment() -> impl Strategy<Value = Option<CellAlignment>> {
-    prop_oneof![
-        Just(None),
-        Just(Some(CellAlignment::Left)),
-        Just(Some(CellAlignment::Right)),
-        Just(Some(CellAlignment::Center)),
-    ]
-}
-
-/// Any Column can have any constellation of ColumnConstraints
-fn column_constraint() -> impl Strategy<Value = Option<ColumnConstraint>> {
-    prop_oneof![
-        Just(None),
-        Just(Some(ColumnConstraint::ContentWidth)),
-        Just(Some(ColumnConstraint::Hidden)),
-        any::<u16>().prop_map(|width| { Some(Absolute(Fixed(width))) }),
-        any::<u16>().prop_map(|width| { Some(LowerBoundary(Fixed(width))) }),
-        any::<u16>().prop_map(|width| { Some(UpperBoundary(Fixed(width))) }),
-        (0u16..200u16).prop_map(|percentage| { Some(Absolute(Percentage(percentage))) }),
-        (0u16..200u16).prop_map(|percentage| { Some(LowerBoundary(Percentage(percentage))) }),
-        (0u16..200u16).prop_map(|percentage| { Some(UpperBoundary(Percentage(percentage))) }),
-    ]
-}
-
-/// We test the Row::max_height with a few values.
-fn max_height() -> impl Strategy<Value = Option<usize>> {
-    prop_oneof![
-        Just(None),
-        Just(Some(0)),
-        Just(Some(1)),
-        Just(Some(5)),
-        Just(Some(100))
-    ]
-}
-
-prop_compose! {
-    /// Returns the dimensions of the table, i.e. the amount of rows and columns.
-    fn dimensions()(columns in 1u16..10u16, rows in 1u16..10u16)
-                    -> (u16, u16) {
-       (columns, rows)
-   }
-}
-
-/// Returns all data needed to build the final table.
-/// 1. A matrix of cells Row[Column[Cell]].
-/// 2. Constraints for all columns.
-/// 3. The alignment for each cell.
-/// 3. The alignment for each column.
-#[allow(clippy::type_complexity)]
-fn columns_and_rows() -> impl Strategy<
-    Value = (
-        Vec<Vec<String>>,
-        Vec<Option<ColumnConstraint>>,
-        Vec<Option<CellAlignment>>,
-        Vec<Option<CellAlignment>>,
-    ),
-> {
-    dimensions().prop_flat_map(|(column_count, row_count)| {
-        let mut rows = Vec::new();
-        let mut cell_alignments = Vec::new();
-        for _i in 0..row_count {
-            // Create the max amount of possibly needed cell alignments
-            for _j in 0..column_count {
-                cell_alignments.push(cell_alignment());
-            }
-            // Add a strategy that creates random cell content with a length of 0 to column_count
-            //
-            // UTF-8 characters completely break table alignment in edge-case situations (e.g. 1 space columns).
-            // UTF-8 characters can be multiple characters wide, which conflicts with the 1 space
-            // column fallback, as well as fixed-width-, percental- and max-column-constraints.
-            // As a result, we cannot check this with proptest, as this is inherently broken.
-            rows.push(::proptest::collection::vec(
-                "[A-Za-z_]*",
-                0..column_count as usize,
-            ));
-        }
-        let mut constraints = Vec::new();
-        let mut column_alignments = Vec::new();
-        for _i in 0..column_count {
-            constraints.push(column_constraint());
-            column_alignments.push(cell_alignment());
-        }
-
-        (rows, constraints, cell_alignments, column_alignments)
-    })
-}
-
-/// We test the Row::max_height with a few values.
-fn table_width() -> impl Strategy<Value = u16> {
-    0..1000u16
-}
-
-prop_compose! {
-    /// The ultimate test
-    /// This creates a table from a combination of all "random" selectors above.
-    fn table()
-        (arrangement in content_arrangement(),
-        max_height in max_height(),
-        (rows, constraints, cell_alignments, column_alignments) in columns_and_rows()) -> Table {
-
-        let mut table = Table::new();
-        if let Some(height) = max_height {
-            for row in table.row_iter_mut() {
-                row.max_height(height);
-            }
-        }
-
-        let mut cell_alignments = cell_alignments.iter();
-        for row in rows.iter() {
-            // Convert a vector of Strings to a vector of Cells and
-            // set the content alignment for each cell
-            let row: Vec<Cell> = row.iter().map(|content| {
-                let mut cell = Cell::new(content.clone());
-                if let Some(alignment) = cell_alignments.next().unwrap() {
-                    cell = cell.set_alignment(*alignment);
-                }
-                cell
-            }).collect();
-
-            // Add the row to the table
-            table.add_row(row);
-        }
-
-        for (column_index, column) in table.column_iter_mut().enumerate() {
-            // Add the alignment for all columns
-            let alignment = column_alignments.get(column_index).unwrap();
-            if let Some(alignment) = alignment {
-                column.set_cell_alignment(*alignment);
-            }
-
-            // Set constraints for all columns
-            let constraint = constraints.get(column_index).unwrap();
-            if let Some(constraint) = constraint {
-                column.set_constraint(*constraint);
-            }
-
-        }
-
-        table.set_content_arrangement(arrangement);
-        table
-    }
-}
+use unicode_width::UnicodeWidthStr;
 
 proptest! {
     #![proptest_config({
@@ -178,14 +25,14 @@ proptest! {
         // Get the length of the very first line.
         // We're lateron going to ensure, that all lines have the same length.
         let line_length = if let Some(line) = line_iter.next() {
-            line.trim().len()
+            line.trim().width()
         } else {
             0
         };
 
         // Make sure all lines have the same length
         for line in line_iter {
-            if line.len() != line_length {
+            if line.width() != line_length {
                 return build_error(&formatted, "Each line of a printed table has to have the same length!");
             }
         }
@@ -193,32 +40,25 @@ proptest! {
         // Make sure that the table is within its width, if arrangement isn't enabled.
         // This is a bit tricky.
         // A table can be larger than the specified width, if the user forces it to be larger.
-        #[cfg(feature = "integration_test")]
-        {
-            let current_arrangement = table.content_arrangement();
-            match current_arrangement {
-                ContentArrangement::Disabled => (),
-                _ => {
-                    let expected_max = determine_max_table_width(&table);
-
-                    // A line can be a bit longer than u16::MAX due to formatting and borders.
-                    let actual: u16 = line_length.try_into().unwrap_or(u16::MAX);
-                    if actual > expected_max {
-                        return build_error(
-                            &formatted,
-                            &format!("Expected table to be smaller than line length!\n\
-                            Actual: {actual}, Expected max: {expected_max}\n\
-                            Arrangement: {current_arrangement:?}"
-                        ));
-                    }
+        let current_arrangement = table.content_arrangement();
+        match current_arrangement {
+            ContentArrangement::Disabled => (),
+            _ => {
+                let expected_max = determine_max_table_width(&table);
+
+                // A line can be a bit longer than u16::MAX due to formatting and borders.
+                let actual: u16 = line_length.try_into().unwrap_or(u16::MAX);
+                if actual > expected_max {
+                    return build_error(
+                        &formatted,
+                        &format!("Expected table to be smaller than line length!\n\
+                        Actual: {actual}, Expected max: {expected_max}\n\
+                        Arrangement: {current_arrangement:?}"
+                    ));
                 }
             }
         }
 
-        #[cfg(feature = "integration_test")]
-        // Only run this test, if the `integration_test` is enabled.
-        // Without this flag, we don't have access to some util functions in comfy_table, that
-        // aren't exposed by default.
         enforce_constraints(&table, formatted, lines)?
     }
 }
@@ -231,7 +71,6 @@ fn build_error(table: &str, context: &str) -> Result<(), TestCaseError> {
 
 /// The user can actually force a table to be longer than the specified `table.width()`
 /// by specifying [ColumnConstraint]s.
-#[cfg(feature = "integration_test")]
 fn determine_max_table_width(table: &Table) -> u16 {
     use comfy_table::utils::arrangement::helper::count_border_columns;
     let table_width = table.width().unwrap();
@@ -294,7 +133,6 @@ fn determine_max_table_width(table: &Table) -> u16 {
 }
 
 /// Enforce that Column constraints are enforced as expected in `Dynamic` mode.
-#[cfg(feature = "integration_test")]
 fn enforce_constraints(
     table: &Table,
     formatted: String,
@@ -343,7 +181,7 @@ fn enforce_constraints(
                 None => continue,
             };
             // Get the actual length of the part.
-            let actual = part.len();
+            let actual = part.width();
 
             match constraint {
                 ColumnConstraint::Hidden => panic!("This shouldn't happen"),
@@ -439,7 +277,6 @@ fn enforce_constraints(
 }
 
 /// Resolve an absolute value from a given boundary
-#[cfg(feature = "integration_test")]
 pub fn absolute_width(table: &Table, width: &Width) -> u16 {
     use comfy_table::utils::arrangement::constraint::absolute_value_from_width;
 
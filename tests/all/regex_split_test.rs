@@ -0,0 +1,60 @@
+use pretty_assertions::assert_eq;
+use regex::Regex;
+
+use comfy_table::*;
+
+#[test]
+fn split_after_comma() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Tags"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(20)
+        .add_row(vec!["alpha, beta, gamma, delta, epsilon"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_split_regex(Regex::new(", ").unwrap());
+
+    println!("{table}");
+    let expected = "
++---------------+
+| Tags          |
++===============+
+| alpha, beta,  |
+| gamma, delta, |
+| epsilon       |
++---------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn split_after_slash() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Path", "Size"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(24)
+        .add_row(vec!["/usr/share/some/quite_long_file.txt", "42"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_split_regex(Regex::new("/").unwrap());
+
+    println!("{table}");
+    let expected = "
++---------------+------+
+| Path          | Size |
++======================+
+| /usr/share/   | 42   |
+| some/quite_lo |      |
+| ng_file.txt   |      |
++---------------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Without the regex, the column's delimiter is used again.
+    let column = table.column_mut(0).unwrap();
+    column.remove_split_regex();
+    assert!(column.split_regex().is_none());
+    assert!(table.to_string().contains("| /usr/share/so | 42   |"));
+}
@@ -0,0 +1,126 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+/// A width function that measures single characters differently than whole strings.
+/// This produces ragged tables, as the columns are arranged by character.
+fn inconsistent_width(text: &str) -> usize {
+    if text == "é" {
+        return 2;
+    }
+    text.chars().count()
+}
+
+#[test]
+fn well_formed_table() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description"])
+        .add_row(vec!["コンフィ", "A table\nlibrary"])
+        .set_title("A rather long title")
+        .add_footnote("Footnotes aren't padded");
+
+    assert_eq!(table.render_verified(), Ok(table.to_string()));
+}
+
+#[test]
+fn uneven_width() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Letter"])
+        .add_row(vec!["é"])
+        .set_width_function(inconsistent_width);
+
+    println!("{table}");
+    let error = table.render_verified().unwrap_err();
+    assert_eq!(
+        error.issues,
+        vec![OutputIssue::UnevenWidth {
+            line: 3,
+            content: "| é     |".to_string(),
+            width: 9,
+            expected: 10,
+        }]
+    );
+}
+
+#[test]
+fn control_character() {
+    let mut table = Table::new();
+    table
+        .set_title("Title")
+        .set_header(vec!["Name"])
+        .add_row(vec!["Bell\u{7}"]);
+
+    let error = table.render_verified().unwrap_err();
+    assert_eq!(
+        error.issues,
+        vec![OutputIssue::ControlCharacter {
+            line: 4,
+            content: "| Bell\u{7} |".to_string(),
+            character: '\u{7}',
+        }]
+    );
+}
+
+#[test]
+fn ansi_escape_sequences_are_ignored() {
+    let mut table = Table::new();
+    table.add_row(vec!["\u{1b}[31mRed\u{1b}[0m"]);
+
+    assert!(table.render_verified().is_ok());
+}
+
+/// The records of the vertical layout are verified one by one.
+#[test]
+fn vertical_layout() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Comment"])
+        .add_row(vec!["First", "Short"])
+        .add_row(vec!["Second", "Bell\u{7}"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_overflow_policy(OverflowPolicy::Vertical)
+        .set_width(14);
+
+    println!("{table}");
+    let error = table.render_verified().unwrap_err();
+    assert_eq!(error.issues.len(), 1);
+    match &error.issues[0] {
+        OutputIssue::ControlCharacter { line, content, .. } => {
+            assert_eq!(table.lines().nth(*line).as_ref(), Some(content));
+        }
+        issue => panic!("Expected a control character, got {issue:?}"),
+    }
+}
+
+/// Rendering a malformed table doesn't panic.
+#[test]
+fn display_does_not_panic() {
+    let mut table = Table::new();
+    table.add_row(vec!["Bell\u{7}"]);
+
+    assert_eq!(table.to_string(), "+-------+\n| Bell\u{7} |\n+-------+");
+}
+
+/// Identical lines are reported with their own line numbers.
+#[test]
+fn duplicate_rows() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["Bell\u{7}"])
+        .add_row(vec!["Bell\u{7}"])
+        .add_row(vec!["Bell\u{7}"]);
+
+    println!("{table}");
+    let error = table.render_verified().unwrap_err();
+    let lines: Vec<usize> = error
+        .issues
+        .iter()
+        .map(|issue| match issue {
+            OutputIssue::ControlCharacter { line, .. } => *line,
+            issue => panic!("Expected a control character, got {issue:?}"),
+        })
+        .collect();
+    assert_eq!(lines, vec![1, 3, 5]);
+}
@@ -0,0 +1,104 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+
+/// Joining the lines of all rows must result in the rendered table.
+fn joined(rows: &[RenderedRow]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for row in rows {
+        lines.extend(row.separator_above.clone());
+        lines.extend(row.physical_lines.clone());
+        lines.extend(row.separator_below.clone());
+    }
+
+    lines
+}
+
+#[test]
+fn rendered_rows_match_lines() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two\nlines"])
+        .add_row(vec!["Three", "Four"]);
+
+    let rows = table.rendered_rows();
+    assert_eq!(joined(&rows), table.lines().collect::<Vec<_>>());
+
+    table.load_preset(UTF8_FULL);
+    let rows = table.rendered_rows();
+    assert_eq!(joined(&rows), table.lines().collect::<Vec<_>>());
+}
+
+#[test]
+fn rendered_rows_metadata() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two\nlines"])
+        .add_row(vec!["Three", "Four"]);
+
+    let rows = table.rendered_rows();
+    assert_eq!(rows.len(), 3);
+
+    assert!(rows[0].is_header);
+    assert_eq!(rows[0].row_index, None);
+    assert!(rows[0].separator_above.is_some());
+
+    assert!(!rows[1].is_header);
+    assert_eq!(rows[1].row_index, Some(0));
+    assert_eq!(rows[1].physical_lines.len(), 2);
+    assert_eq!(rows[1].separator_above, None);
+    assert_eq!(
+        rows[1].separator_below.as_deref(),
+        Some("|---------+---------|")
+    );
+
+    assert_eq!(rows[2].row_index, Some(1));
+    assert_eq!(rows[2].physical_lines, vec!["| Three   | Four    |"]);
+    assert_eq!(
+        rows[2].separator_below.as_deref(),
+        Some("+---------+---------+")
+    );
+}
+
+#[test]
+fn rendered_rows_without_borders() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two\nlines"])
+        .add_row(vec!["Three", "Four"]);
+
+    table.load_preset(comfy_table::presets::NOTHING);
+
+    let rows = table.rendered_rows();
+    assert_eq!(joined(&rows), table.lines().collect::<Vec<_>>());
+    assert!(rows
+        .iter()
+        .all(|row| row.separator_above.is_none() && row.separator_below.is_none()));
+}
+
+#[test]
+fn rendered_rows_with_section() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two\nlines"])
+        .add_row(vec!["Three", "Four"]);
+
+    table.add_section("Section");
+    table.add_row(vec!["Five", "Six"]);
+
+    let rows = table.rendered_rows();
+    assert_eq!(joined(&rows), table.lines().collect::<Vec<_>>());
+    let indices: Vec<Option<usize>> = rows.iter().map(|row| row.row_index).collect();
+    assert_eq!(indices, vec![None, Some(0), Some(1), None, Some(2)]);
+}
+
+#[test]
+fn rendered_rows_of_empty_table() {
+    let table = Table::new();
+    assert!(table.rendered_rows().is_empty());
+}
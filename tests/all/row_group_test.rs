@@ -0,0 +1,84 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+
+#[test]
+fn group_ranges() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Total", "12"])
+        .begin_group("Local")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .begin_group("Imported")
+        .add_row(vec!["Bananas", "4"])
+        .end_group()
+        .add_row(vec!["Average", "4"]);
+
+    let groups = table.groups();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name(), "Local");
+    assert_eq!(groups[0].rows(), 1..3);
+    assert_eq!(groups[1].name(), "Imported");
+    assert_eq!(groups[1].rows(), 3..4);
+}
+
+/// Groups are separated by a header-style line.
+#[test]
+fn group_separators() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Total", "12"])
+        .begin_group("Local")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .begin_group("Imported")
+        .add_row(vec!["Bananas", "4"])
+        .end_group()
+        .add_row(vec!["Average", "4"]);
+
+    table.load_preset(UTF8_FULL);
+
+    println!("{table}");
+    let expected = "
+┌─────────┬────────┐
+│ Fruit   ┆ Amount │
+╞═════════╪════════╡
+│ Total   ┆ 12     │
+╞═════════╪════════╡
+│ Apples  ┆ 3      │
+├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+│ Pears   ┆ 5      │
+╞═════════╪════════╡
+│ Bananas ┆ 4      │
+╞═════════╪════════╡
+│ Average ┆ 4      │
+└─────────┴────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Tables without header and without horizontal lines still separate their groups.
+#[test]
+fn group_separators_without_header() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL_CONDENSED)
+        .begin_group("First")
+        .add_row(vec!["One", "Two"])
+        .add_row(vec!["Three", "Four"])
+        .begin_group("Second")
+        .add_row(vec!["Five", "Six"]);
+
+    println!("{table}");
+    let expected = "
+┌───────┬──────┐
+│ One   ┆ Two  │
+│ Three ┆ Four │
+╞═══════╪══════╡
+│ Five  ┆ Six  │
+└───────┴──────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,208 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+fn first_cells(table: &Table) -> Vec<String> {
+    table
+        .row_iter()
+        .map(|row| row.cell_iter().next().unwrap().content())
+        .collect()
+}
+
+#[test]
+fn insert_row() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Total", "12"])
+        .begin_group("Local")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .end_group()
+        .add_section("Imported")
+        .add_row(vec!["Bananas", "4"]);
+
+    table.insert_row(0, vec!["Average", "4"]);
+    table.insert_row(2, vec!["Plums", "2"]);
+
+    assert_eq!(
+        first_cells(&table),
+        vec!["Average", "Total", "Plums", "Apples", "Pears", "Bananas"]
+    );
+    // Rows inserted at the start of a group belong to the group.
+    assert_eq!(table.groups()[0].rows(), 2..5);
+    assert_eq!(table.sections()[0].row_index(), 5);
+
+    println!("{table}");
+    let expected = "
++---------+--------+
+| Fruit   | Amount |
++==================+
+| Average | 4      |
+|---------+--------|
+| Total   | 12     |
++==================+
+| Plums   | 2      |
+|---------+--------|
+| Apples  | 3      |
+|---------+--------|
+| Pears   | 5      |
++==================+
+| Imported         |
++==================+
+| Bananas | 4      |
++---------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// New columns are discovered, just like when adding rows.
+/// Sections stay attached to the row they precede.
+/// Rows that are inserted right at a section's index are displayed above it.
+#[test]
+fn insert_row_at_section() {
+    let mut table = Table::new();
+    table
+        .add_section("Fruit")
+        .add_row(vec!["Apples"])
+        .add_section("Vegetables")
+        .add_row(vec!["Carrots"]);
+    table
+        .insert_row(0, vec!["Total"])
+        .insert_row(2, vec!["Pears"]);
+
+    assert_eq!(
+        first_cells(&table),
+        vec!["Total", "Apples", "Pears", "Carrots"]
+    );
+    let anchors: Vec<usize> = table
+        .sections()
+        .iter()
+        .map(|section| section.row_index())
+        .collect();
+    assert_eq!(anchors, vec![1, 3]);
+
+    println!("{table}");
+    let expected = "
++------------+
+| Total      |
++============+
+| Fruit      |
++============+
+| Apples     |
+|------------|
+| Pears      |
++============+
+| Vegetables |
++============+
+| Carrots    |
++------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn insert_row_with_new_column() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["One"])
+        .insert_row(0, vec!["Zero", "Extra"]);
+
+    assert_eq!(table.column_count(), 2);
+    assert_eq!(table.column_max_content_widths(), vec![4, 5]);
+}
+
+/// Appending a row to an open group extends the group, just like adding it.
+#[test]
+fn insert_row_into_open_group() {
+    let mut table = Table::new();
+    table.begin_group("Group").add_row(vec!["One"]);
+    table.insert_row(1, vec!["Two"]);
+    assert_eq!(table.groups()[0].rows(), 0..2);
+
+    table.end_group().insert_row(2, vec!["Three"]);
+    assert_eq!(table.groups()[0].rows(), 0..2);
+}
+
+#[test]
+fn remove_row() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Total", "12"])
+        .begin_group("Local")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .end_group()
+        .add_section("Imported")
+        .add_row(vec!["Bananas", "4"]);
+
+    let removed = table.remove_row(1).unwrap();
+    assert_eq!(removed.cell_iter().next().unwrap().content(), "Apples");
+    assert!(table.remove_row(3).is_none());
+
+    assert_eq!(first_cells(&table), vec!["Total", "Pears", "Bananas"]);
+    assert_eq!(table.groups()[0].rows(), 1..2);
+    assert_eq!(table.sections()[0].row_index(), 2);
+
+    // Removing the last row of a group leaves an empty group, which isn't displayed.
+    table.remove_row(1);
+    assert_eq!(table.groups()[0].rows(), 1..1);
+
+    println!("{table}");
+    let expected = "
++---------+--------+
+| Fruit   | Amount |
++==================+
+| Total   | 12     |
++==================+
+| Imported         |
++==================+
+| Bananas | 4      |
++---------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Max content widths are calculated from the remaining rows only.
+#[test]
+fn remove_row_updates_widths() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["A very long value"])
+        .add_row(vec!["Short"]);
+    assert_eq!(table.column_max_content_widths(), vec![17]);
+
+    table.remove_row(0);
+    assert_eq!(table.column_max_content_widths(), vec![5]);
+}
+
+#[test]
+fn clear_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Total", "12"])
+        .begin_group("Local")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .end_group()
+        .add_section("Imported")
+        .add_row(vec!["Bananas", "4"]);
+
+    table.clear_rows();
+
+    assert!(table.is_empty());
+    assert!(table.groups().is_empty());
+    assert!(table.sections().is_empty());
+
+    // Rows that're added afterwards don't belong to any group.
+    table.add_row(vec!["Cherries", "9"]);
+    assert!(table.groups().is_empty());
+
+    println!("{table}");
+    let expected = "
++----------+--------+
+| Fruit    | Amount |
++===================+
+| Cherries | 9      |
++----------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
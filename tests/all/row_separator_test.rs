@@ -0,0 +1,105 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::{ASCII_MARKDOWN, NOTHING, UTF8_FULL, UTF8_FULL_CONDENSED};
+use comfy_table::*;
+use unicode_width::UnicodeWidthStr;
+
+/// Rows can suppress the line after them and use a custom character for it.
+#[test]
+fn suppressed_and_custom_separators() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Fruit", "Count"]);
+
+    let mut row = Row::from(vec!["Apples", "3"]);
+    row.set_separator_after(false);
+    table.add_row(row);
+    let mut row = Row::from(vec!["Pears", "5"]);
+    row.set_separator_char('═');
+    table.add_row(row);
+    table.add_row(vec!["Carrots", "2"]);
+
+    println!("{table}");
+    let expected = "
+┌─────────┬───────┐
+│ Fruit   ┆ Count │
+╞═════════╪═══════╡
+│ Apples  ┆ 3     │
+│ Pears   ┆ 5     │
+├═════════┼═══════┤
+│ Carrots ┆ 2     │
+└─────────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Enforced separators fall back to the header line's style,
+/// if the style doesn't have any horizontal lines.
+#[test]
+fn enforced_separator() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_header(vec!["Fruit", "Count"])
+        .add_row(vec!["Apples", "3"]);
+
+    let mut row = Row::from(vec!["Pears", "5"]);
+    row.set_separator_after(true);
+    table.add_row(row);
+    table.add_row(vec!["Carrots", "2"]);
+
+    println!("{table}");
+    let expected = "
+┌─────────┬───────┐
+│ Fruit   ┆ Count │
+╞═════════╪═══════╡
+│ Apples  ┆ 3     │
+│ Pears   ┆ 5     │
+╞═════════╪═══════╡
+│ Carrots ┆ 2     │
+└─────────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The header row may suppress the header line as well.
+/// The last row is always followed by the bottom border only.
+#[test]
+fn header_and_last_row() {
+    let mut table = Table::new();
+    let mut header = Row::from(vec!["Fruit", "Count"]);
+    header.set_separator_after(false);
+    let mut last = Row::from(vec!["Carrots", "2"]);
+    last.set_separator_after(true).set_separator_char('-');
+    table
+        .load_preset(ASCII_MARKDOWN)
+        .set_header(header)
+        .add_row(vec!["Pears", "5"])
+        .add_row(last);
+
+    println!("{table}");
+    let expected = "
+| Fruit   | Count |
+| Pears   | 5     |
+| Carrots | 2     |";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Without vertical lines, enforced separators don't have any intersections and are as wide
+/// as the lines of the content.
+#[test]
+fn enforced_separator_without_vertical_lines() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["Apples", "3"]);
+    row.set_separator_after(true);
+    table
+        .load_preset(NOTHING)
+        .add_row(row)
+        .add_row(vec!["Pears", "5"]);
+
+    println!("{table}");
+    let lines: Vec<String> = table.lines().collect();
+    assert_eq!(lines.len(), 3);
+    for line in lines.iter() {
+        assert_eq!(line.width(), 11);
+    }
+}
@@ -0,0 +1,221 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[test]
+fn section_positions() {
+    let mut table = Table::new();
+    table
+        .add_section("Fruit")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_section(Cell::new("Vegetables").set_alignment(CellAlignment::Right))
+        .add_row(vec!["Carrots", "2"])
+        .add_section("Nothing else");
+
+    let sections = table.sections();
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].title().content(), "Fruit");
+    assert_eq!(sections[0].row_index(), 0);
+    assert_eq!(sections[1].row_index(), 2);
+    assert_eq!(sections[2].row_index(), 3);
+}
+
+/// Sections span over all columns.
+/// The top and bottom borders don't have any intersections next to them.
+#[test]
+fn sections() {
+    let mut table = Table::new();
+    table
+        .add_section("Fruit")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_section(Cell::new("Vegetables").set_alignment(CellAlignment::Right))
+        .add_row(vec!["Carrots", "2"])
+        .add_section("Nothing else");
+
+    println!("{table}");
+    let expected = "
++--------------+
+| Fruit        |
++==============+
+| Apples   | 3 |
+|----------+---|
+| Pears    | 5 |
++==============+
+|   Vegetables |
++==============+
+| Carrots  | 2 |
++==============+
+| Nothing else |
++--------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Sections are surrounded by the normal horizontal lines, if there's no header line.
+#[test]
+fn sections_without_header_line() {
+    let mut table = Table::new();
+    table
+        .add_section("Fruit")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_section(Cell::new("Vegetables").set_alignment(CellAlignment::Right))
+        .add_row(vec!["Carrots", "2"])
+        .add_section("Nothing else");
+
+    table
+        .remove_style(TableComponent::LeftHeaderIntersection)
+        .remove_style(TableComponent::HeaderLines)
+        .remove_style(TableComponent::MiddleHeaderIntersections)
+        .remove_style(TableComponent::RightHeaderIntersection);
+
+    println!("{table}");
+    let expected = "
++--------------+
+| Fruit        |
+|----------+---|
+| Apples   | 3 |
+|----------+---|
+| Pears    | 5 |
+|----------+---|
+|   Vegetables |
+|----------+---|
+| Carrots  | 2 |
+|----------+---|
+| Nothing else |
++--------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn sections_to_html() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Fruit", "Amount"])
+        .add_section(Cell::new("Local <3").set_alignment(CellAlignment::Center))
+        .add_row(vec!["Apples", "3"]);
+
+    let expected = "<table>
+  <thead>
+    <tr>
+      <th>Fruit</th>
+      <th>Amount</th>
+    </tr>
+  </thead>
+  <tbody>
+    <tr>
+      <th colspan=\"2\" style=\"text-align: center\">Local &lt;3</th>
+    </tr>
+    <tr>
+      <td>Apples</td>
+      <td>3</td>
+    </tr>
+  </tbody>
+</table>
+";
+    assert_eq!(expected, table.to_html());
+}
+
+/// Only sections that precede rows of the viewport are displayed.
+#[test]
+fn sections_in_viewport() {
+    let mut table = Table::new();
+    table
+        .add_section("Fruit")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_section(Cell::new("Vegetables").set_alignment(CellAlignment::Right))
+        .add_row(vec!["Carrots", "2"])
+        .add_section("Nothing else");
+
+    let view = Viewport::new(1..3, 0..2);
+    println!("{}", table.render_scrollable(view.clone()));
+    let expected = "
++----------+---+
+| Pears    | 5 |
++==============+
+|   Vegetables |
++==============+
+| Carrots  | 2 |
++==============+
+| Nothing else |
++--------------+";
+    assert_eq!(expected, "\n".to_string() + &table.render_scrollable(view));
+}
+
+/// Sections aren't rows of the table.
+#[test]
+fn sections_in_render_plan() {
+    let mut table = Table::new();
+    table
+        .add_section("Fruit")
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Pears", "5"])
+        .add_section(Cell::new("Vegetables").set_alignment(CellAlignment::Right))
+        .add_row(vec!["Carrots", "2"])
+        .add_section("Nothing else");
+
+    assert_eq!(table.row_count(), 3);
+    assert_eq!(table.plan().row_heights(), &[1, 1, 1]);
+}
+
+#[test]
+/// The columns of a dynamically arranged table grow, so that wide sections don't wrap.
+fn dynamic_arrangement_fits_sections() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .add_section("Vegetables and fruit")
+        .add_row(vec!["Carrots", "2"]);
+
+    let expected = "
++----------------------+
+| Vegetables and fruit |
++======================+
+| Carrots      | 2     |
++--------------+-------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// The columns only grow as far as the table's width allows.
+fn dynamic_arrangement_wraps_sections_at_width() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(20)
+        .add_section("Vegetables and fruit")
+        .add_row(vec!["Carrots", "2"]);
+
+    let expected = "
++------------------+
+| Vegetables and   |
+| fruit            |
++==================+
+| Carrots    | 2   |
++------------+-----+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Without a width limit, the columns always grow until sections fit.
+#[test]
+fn disabled_arrangement_fits_sections() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Disabled)
+        .add_section("Fruits and vegetables")
+        .add_row(vec!["Apples", "3"]);
+
+    let expected = "
++-----------------------+
+| Fruits and vegetables |
++=======================+
+| Apples       | 3      |
++--------------+--------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
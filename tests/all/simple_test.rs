@@ -101,3 +101,67 @@ fn lines() {
 
     assert_eq!(actual.collect::<Vec<String>>(), expected);
 }
+
+#[test]
+fn add_row_aligned() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Phone", "Mail"])
+        .add_row_aligned(vec![
+            (2, Cell::new("nils@example.com")),
+            (0, Cell::new("Nils")),
+        ])
+        .add_row_aligned(vec![(1, Cell::new("0123")), (1, Cell::new("4567"))]);
+
+    println!("{table}");
+    let expected = "
++------+-------+------------------+
+| Name | Phone | Mail             |
++=================================+
+| Nils |       | nils@example.com |
+|------+-------+------------------|
+|      | 4567  |                  |
++------+-------+------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Cells are addressed by their position in the grid, including the header.
+#[test]
+fn cell_at() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_row(vec!["Three"]);
+
+    assert_eq!(table.cell_at(0, 0).unwrap().content(), "Header1");
+    assert_eq!(table.cell_at(2, 0).unwrap().content(), "Three");
+    // The last row is missing a cell.
+    assert!(table.cell_at(2, 1).is_none());
+    assert!(table.cell_at(3, 0).is_none());
+
+    *table.cell_at_mut(1, 1).unwrap() = Cell::new("Changed");
+    assert_eq!(
+        table.row(0).unwrap().cell_iter().nth(1).unwrap().content(),
+        "Changed"
+    );
+}
+
+/// Rendering into a buffer replaces its previous content.
+#[test]
+fn render_into() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .add_footnote("A footnote");
+
+    let mut buffer = "previous content".to_string();
+    let mut scratch = RenderScratch::new();
+    table.render_into_with_scratch(&mut buffer, &mut scratch);
+    assert_eq!(buffer, table.to_string());
+
+    table.add_row(vec!["Three", "Four"]);
+    table.render_into_with_scratch(&mut buffer, &mut scratch);
+    assert_eq!(buffer, table.to_string());
+}
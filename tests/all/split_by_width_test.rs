@@ -0,0 +1,162 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+use super::assert_table_line_width;
+
+/// Tables that fit into the width aren't split.
+#[test]
+fn no_split() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Q1", "Q2", "Region", "Q3", "Q4"])
+        .add_row(vec!["100", "200", "North", "300", "400"])
+        .add_row(vec!["500", "600", "South", "700", "800"]);
+
+    let chunks = table.split_by_width(100);
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].to_string(), table.to_string());
+}
+
+/// Key columns keep their position in every chunk.
+#[test]
+fn split_with_key_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Q1", "Q2", "Region", "Q3", "Q4"])
+        .add_row(vec!["100", "200", "North", "300", "400"])
+        .add_row(vec!["500", "600", "South", "700", "800"]);
+
+    table.column_mut(2).unwrap().set_key_column(true);
+    assert!(table.column(2).unwrap().is_key_column());
+
+    let chunks: Vec<String> = table
+        .split_by_width(20)
+        .iter()
+        .map(|chunk| "\n".to_string() + &chunk.to_string())
+        .collect();
+
+    let expected = vec![
+        "
++-----+--------+
+| Q1  | Region |
++==============+
+| 100 | North  |
+|-----+--------|
+| 500 | South  |
++-----+--------+",
+        "
++-----+--------+
+| Q2  | Region |
++==============+
+| 200 | North  |
+|-----+--------|
+| 600 | South  |
++-----+--------+",
+        "
++--------+-----+
+| Region | Q3  |
++==============+
+| North  | 300 |
+|--------+-----|
+| South  | 700 |
++--------+-----+",
+        "
++--------+-----+
+| Region | Q4  |
++==============+
+| North  | 400 |
+|--------+-----|
+| South  | 800 |
++--------+-----+",
+    ];
+    assert_eq!(expected, chunks);
+}
+
+/// Hidden columns aren't displayed in any chunk.
+#[test]
+fn split_without_key_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Q1", "Q2", "Region", "Q3", "Q4"])
+        .add_row(vec!["100", "200", "North", "300", "400"])
+        .add_row(vec!["500", "600", "South", "700", "800"]);
+
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let chunks: Vec<String> = table
+        .split_by_width(21)
+        .iter()
+        .map(|chunk| "\n".to_string() + &chunk.to_string())
+        .collect();
+
+    let expected = vec![
+        "
++-----+--------+
+| Q1  | Region |
++==============+
+| 100 | North  |
+|-----+--------|
+| 500 | South  |
++-----+--------+",
+        "
++-----+-----+
+| Q3  | Q4  |
++===========+
+| 300 | 400 |
+|-----+-----|
+| 700 | 800 |
++-----+-----+",
+    ];
+    assert_eq!(expected, chunks);
+}
+
+/// Columns that are too wide by themselves get a chunk of their own.
+/// Dynamic arrangements wrap their content to the width.
+#[test]
+fn split_too_wide_column() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["key", "This is a very long text", "short"]);
+    table.column_mut(0).unwrap().set_key_column(true);
+
+    let chunks: Vec<String> = table
+        .split_by_width(20)
+        .iter()
+        .map(|chunk| "\n".to_string() + &chunk.to_string())
+        .collect();
+
+    let expected = vec![
+        "
++-----+------------+
+| key | This is a  |
+|     | very long  |
+|     | text       |
++-----+------------+",
+        "
++-----+-------+
+| key | short |
++-----+-------+",
+    ];
+    assert_eq!(expected, chunks);
+}
+
+/// The min width doesn't affect how the columns are distributed.
+#[test]
+fn split_by_width_with_min_width() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "January", "February", "March"])
+        .add_row(vec!["Rent", "800", "800", "820"])
+        .set_min_width(30);
+
+    let chunks = table.split_by_width(30);
+    assert_eq!(chunks.len(), 2);
+    for chunk in chunks {
+        assert_table_line_width(&chunk, 30);
+    }
+}
@@ -0,0 +1,96 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::{ASCII_FULL, UTF8_FULL};
+use comfy_table::*;
+
+#[test]
+/// Components are taken from the topmost layer that sets them.
+fn layers_resolve_top_down() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Service", "Status"])
+        .add_row(vec!["db", "down"]);
+
+    let original = table.to_string();
+
+    let mut bottom = StyleLayer::new();
+    bottom
+        .set_style(TableComponent::TopBorder, '=')
+        .set_style(TableComponent::BottomBorder, '=');
+    let mut top = StyleLayer::new();
+    top.set_style(TableComponent::TopBorder, '!')
+        .remove_style(TableComponent::HeaderLines)
+        .remove_style(TableComponent::LeftHeaderIntersection)
+        .remove_style(TableComponent::MiddleHeaderIntersections)
+        .remove_style(TableComponent::RightHeaderIntersection);
+
+    table.push_style_layer(bottom).push_style_layer(top);
+    let expected = "
+┌!!!!!!!!!┬!!!!!!!!┐
+│ Service ┆ Status │
+│ db      ┆ down   │
+└=========┴========┘";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.style_layers().len(), 2);
+
+    // The table's own style isn't touched.
+    assert_eq!(table.style(TableComponent::TopBorder), Some('─'));
+
+    table.pop_style_layer();
+    let expected = "
+┌=========┬========┐
+│ Service ┆ Status │
+╞═════════╪════════╡
+│ db      ┆ down   │
+└=========┴========┘";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.pop_style_layer();
+    assert_eq!(original, table.to_string());
+    assert!(table.pop_style_layer().is_none());
+}
+
+#[test]
+fn layer_from_table_style() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Service", "Status"])
+        .add_row(vec!["db", "down"]);
+
+    table.push_style_layer(TableStyle::from_preset(ASCII_FULL).into());
+
+    let mut expected = Table::new();
+    expected
+        .load_preset(ASCII_FULL)
+        .set_header(vec!["Service", "Status"])
+        .add_row(vec!["db", "down"]);
+    assert_eq!(expected.to_string(), table.to_string());
+}
+
+#[cfg(feature = "tty")]
+#[test]
+fn layer_overrides_body_style() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Service", "Status"])
+        .add_row(vec!["db", "down"]);
+
+    table.force_no_tty().enforce_styling();
+    table.style_body(|style| style.fg(Color::Green));
+    let green = table.to_string();
+
+    let mut error_view = StyleLayer::new();
+    error_view.set_body_style(CellStyle::new().fg(Color::Red));
+    table.push_style_layer(error_view);
+    let red = table.to_string();
+    assert_ne!(green, red);
+    assert!(red.contains("\u{1b}[38;5;9m"));
+
+    table.pop_style_layer();
+    assert_eq!(green, table.to_string());
+}
@@ -87,3 +87,208 @@ fn styled_text_only_table() {
 
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+fn header_and_body_style() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .style_header(|style| style.bold().fg(Color::Cyan))
+        .style_body(|style| style.fg(Color::Grey))
+        .set_header(vec![
+            Cell::new("Header1"),
+            Cell::new("Header2").fg(Color::Red),
+        ])
+        .add_row(vec![
+            Cell::new("One"),
+            Cell::new("Two").add_attribute(Attribute::Bold),
+        ])
+        .force_no_tty()
+        .enforce_styling();
+
+    // Styles are applied during rendering, the cells themselves are untouched.
+    assert_eq!(
+        table.header_style(),
+        &CellStyle::new().bold().fg(Color::Cyan)
+    );
+    assert_eq!(
+        table.header().unwrap().cell_iter().next(),
+        Some(&Cell::new("Header1"))
+    );
+
+    println!("{table}");
+    let expected = "
+┌─────────┬─────────┐
+│\u{1b}[38;5;14m\u{1b}[1m Header1 \u{1b}[0m┆\u{1b}[38;5;9m\u{1b}[1m Header2 \u{1b}[0m│
+╞═════════╪═════════╡
+│\u{1b}[38;5;7m One     \u{1b}[39m┆\u{1b}[38;5;7m\u{1b}[1m Two     \u{1b}[0m│
+└─────────┴─────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The header style survives replacing the header.
+    table.set_header(vec!["New header"]);
+    assert!(table
+        .to_string()
+        .contains("\u{1b}[38;5;14m\u{1b}[1m New header \u{1b}[0m"));
+}
+
+#[test]
+fn class_styles() {
+    let mut table = Table::new();
+    table
+        .style_body(|style| style.fg(Color::Grey))
+        .set_class_style("warning", CellStyle::new().fg(Color::Yellow).bold())
+        .add_row(vec![
+            Cell::new("Normal"),
+            Cell::new("Warning").set_class("warning"),
+            Cell::new("Error").set_class("warning").fg(Color::Red),
+            Cell::new("Unknown").set_class("unknown"),
+        ])
+        .force_no_tty()
+        .enforce_styling();
+
+    println!("{table}");
+    let expected = "
++--------+---------+-------+---------+
+|\u{1b}[38;5;7m Normal \u{1b}[39m|\u{1b}[38;5;11m\u{1b}[1m Warning \u{1b}[0m|\u{1b}[38;5;9m\u{1b}[1m Error \u{1b}[0m|\u{1b}[38;5;7m Unknown \u{1b}[39m|
++--------+---------+-------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Colors and attributes can be passed as comfy-table's own types and via their aliases.
+#[test]
+fn cell_color_and_attribute() {
+    let own = Cell::new("Content")
+        .fg(CellColor::Red)
+        .add_attribute(CellAttribute::Bold);
+    let alias = Cell::new("Content")
+        .fg(Color::Red)
+        .add_attribute(Attribute::Bold);
+
+    let mut table = Table::new();
+    table.add_row(vec![own]).enforce_styling();
+    let mut other = Table::new();
+    other.add_row(vec![alias]).enforce_styling();
+
+    assert_eq!(table.to_string(), other.to_string());
+}
+
+/// Each line of a hyperlink is wrapped in OSC 8 escape sequences, which don't take up any space.
+/// Whitespace around the text isn't part of the link.
+#[test]
+fn hyperlinks() {
+    let mut table = Table::new();
+    table
+        .set_width(16)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .enforce_styling()
+        .add_row(vec![
+            Cell::new("/var/log/some.log").set_url("file:///var/log/some.log"),
+            Cell::new("x"),
+        ]);
+
+    println!("{table}");
+    let expected = "
++----------+---+
+| \u{1b}]8;;file:///var/log/some.log\u{1b}\\/var/log\u{1b}]8;;\u{1b}\\ | x |
+| \u{1b}]8;;file:///var/log/some.log\u{1b}\\/some.lo\u{1b}]8;;\u{1b}\\ |   |
+| \u{1b}]8;;file:///var/log/some.log\u{1b}\\g\u{1b}]8;;\u{1b}\\        |   |
++----------+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Links are only added, if the table is styled.
+/// Control characters are removed from the URL.
+#[test]
+fn hyperlinks_without_styling() {
+    let cell = Cell::new("#42").set_url("https://example.com/\u{1b}\\42");
+    assert_eq!(cell.url(), Some("https://example.com/\\42"));
+
+    let mut table = Table::new();
+    table.force_no_tty().add_row(vec![cell]);
+
+    let expected = "
++-----+
+| #42 |
++-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Rows alternate their background color, while hidden rows are skipped.
+#[test]
+fn zebra_stripes() {
+    let mut table = Table::new();
+    table.force_no_tty().enforce_styling().style_text_only();
+    table
+        .set_zebra_stripes(Color::Blue, Color::Red)
+        .set_header(vec!["Header"])
+        .add_row(vec!["a"])
+        .add_row(vec!["hidden"])
+        .add_row(vec!["b"])
+        .add_row(vec![Cell::new("c").bg(Color::Green)]);
+    table.row_mut(1).unwrap().set_hidden(true);
+
+    let lines: Vec<String> = table.lines().collect();
+    println!("{}", lines.join("\n"));
+    // The header isn't striped.
+    assert_eq!(lines[1], "| Header |");
+    assert!(lines[3].contains("\u{1b}[48;5;12ma"));
+    assert!(lines[5].contains("\u{1b}[48;5;9mb"));
+    // Styles of the cells themselves take precedence.
+    assert!(lines[7].contains("\u{1b}[48;5;10mc"));
+
+    table.remove_zebra_stripes();
+    assert_eq!(table.zebra_stripes(), None);
+    assert!(!table.to_string().contains("\u{1b}[48;5;12m"));
+}
+
+/// Border components are styled separately, while the content is left untouched.
+#[test]
+fn styled_borders() {
+    let mut table = Table::new();
+    table.force_no_tty().enforce_styling();
+    table
+        .set_style_colored(TableComponent::HeaderLines, '=', Color::DarkGrey)
+        .set_border_style(
+            TableComponent::LeftBorder,
+            CellStyle::new().fg(Color::Red).bold(),
+        )
+        .set_header(vec!["Header"])
+        .add_row(vec!["Content"]);
+
+    let lines: Vec<String> = table.lines().collect();
+    println!("{}", lines.join("\n"));
+    assert_eq!(lines[0], "+---------+");
+    assert_eq!(lines[1], "\u{1b}[38;5;9m\u{1b}[1m|\u{1b}[0m Header  |");
+    assert_eq!(lines[2], "+\u{1b}[38;5;8m=========\u{1b}[39m+");
+    assert_eq!(lines[3], "\u{1b}[38;5;9m\u{1b}[1m|\u{1b}[0m Content |");
+
+    table
+        .remove_border_style(TableComponent::HeaderLines)
+        .remove_border_style(TableComponent::LeftBorder);
+    assert_eq!(table.border_style(TableComponent::HeaderLines), None);
+    assert!(!table.to_string().contains('\u{1b}'));
+}
+
+/// The style types are reachable via the `style` module as well.
+#[test]
+fn style_module_paths() {
+    use comfy_table::style::{CellAttribute, CellColor};
+
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::new("Content")
+            .fg(CellColor::Red)
+            .add_attribute(CellAttribute::Bold)])
+        .enforce_styling();
+
+    let mut expected = Table::new();
+    expected
+        .add_row(vec![Cell::new("Content")
+            .fg(comfy_table::CellColor::Red)
+            .add_attribute(comfy_table::CellAttribute::Bold)])
+        .enforce_styling();
+
+    assert!(table.to_string().contains("\u{1b}[38;5;9m"));
+    assert_eq!(table.to_string(), expected.to_string());
+}
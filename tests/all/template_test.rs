@@ -0,0 +1,97 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+fn no_values() -> std::iter::Empty<(&'static str, &'static str)> {
+    std::iter::empty()
+}
+
+/// Column totals include plain numeric cells and the values of template cells.
+#[test]
+fn column_totals() {
+    let mut table = Table::new();
+    table
+        .set_header(vec![
+            Cell::new("Fruit"),
+            Cell::new_template("Amount ({total})", no_values()),
+        ])
+        .add_row(vec![
+            Cell::new("Apples"),
+            Cell::new_template("{value} ({percent}%)", [("value", "3")]),
+        ])
+        .add_row(vec![
+            Cell::new("Pears"),
+            Cell::new_template("{value} ({percent}%)", [("value", "5")]),
+        ])
+        .add_row(vec!["Plums", "2"]);
+
+    println!("{table}");
+    let expected = "
++--------+-------------+
+| Fruit  | Amount (10) |
++======================+
+| Apples | 3 (30%)     |
+|--------+-------------|
+| Pears  | 5 (50%)     |
+|--------+-------------|
+| Plums  | 2           |
++--------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The content of a template cell only contains its own values until the table is rendered.
+#[test]
+fn content_before_rendering() {
+    let cell = Cell::new_template("{value} ({percent}%) {{braces}}", [("value", 3)]);
+
+    assert_eq!(cell.content(), "3 ({percent}%) {braces}");
+    assert_eq!(cell.template(), Some("{value} ({percent}%) {{braces}}"));
+    assert_eq!(Cell::new("plain").template(), None);
+}
+
+/// Placeholders that cannot be resolved are kept.
+#[test]
+fn unresolved_placeholders() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::new_template(
+            "{percent}% {unknown}",
+            [("value", "none")],
+        )])
+        .add_row(vec![Cell::new_template("{percent}%", [("value", 0)])]);
+
+    println!("{table}");
+    let expected = "
++----------------------+
+| {percent}% {unknown} |
+|----------------------|
+| {percent}%           |
++----------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Totals are calculated anew on each rendering.
+#[test]
+fn totals_follow_changes() {
+    let mut table = Table::new();
+    table
+        .set_header(vec![
+            Cell::new("Fruit"),
+            Cell::new_template("Amount ({total})", no_values()),
+        ])
+        .add_row(vec![
+            Cell::new("Apples"),
+            Cell::new_template("{value} ({percent}%)", [("value", "3")]),
+        ])
+        .add_row(vec![
+            Cell::new("Pears"),
+            Cell::new_template("{value} ({percent}%)", [("value", "5")]),
+        ])
+        .add_row(vec!["Plums", "2"]);
+
+    table.add_row(vec!["Cherries", "10"]);
+
+    let first_row = table.lines().nth(3).unwrap();
+    assert_eq!(first_row, "| Apples   | 3 (15%)     |");
+    assert!(table.to_html().contains("<th>Amount (20)</th>"));
+}
@@ -0,0 +1,74 @@
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::{ASCII_FULL, UTF8_FULL};
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn theme_with_preset_and_modifiers() {
+    let theme = Theme::new()
+        .preset(UTF8_FULL)
+        .modifier(UTF8_ROUND_CORNERS)
+        // Modifiers are applied in order, so this one wins for the bottom right corner.
+        .modifier("                  +");
+
+    let mut table = Table::new();
+    table
+        .apply_theme(&theme)
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Apples", "3"]);
+
+    let expected = "
+╭────────┬────────╮
+│ Fruit  ┆ Amount │
+╞════════╪════════╡
+│ Apples ┆ 3      │
+╰────────┴────────+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn empty_theme_changes_nothing() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Fruit", "Amount"])
+        .add_row(vec!["Apples", "3"]);
+    let expected = table.to_string();
+
+    table.apply_theme(&Theme::new());
+    assert_eq!(table.to_string(), expected);
+    assert_eq!(table.current_style_as_preset(), UTF8_FULL);
+
+    table.apply_theme(&Theme::new().preset(ASCII_FULL));
+    assert_eq!(table.current_style_as_preset(), ASCII_FULL);
+}
+
+#[cfg(feature = "tty")]
+#[test]
+fn theme_with_styles() {
+    let header_style = CellStyle::new().bold().bg(Color::DarkBlue);
+    let body_style = CellStyle::new().fg(Color::Grey);
+    let theme = Theme::new()
+        .header_style(header_style.clone())
+        .body_style(body_style.clone())
+        .zebra_stripes(Color::Reset, Color::DarkGrey);
+
+    let mut table = Table::new();
+    table
+        .style_header(|style| style.italic())
+        .apply_theme(&theme);
+
+    // The styles of the theme replace the previous styles.
+    assert_eq!(table.header_style(), &header_style);
+    assert_eq!(table.body_style(), &body_style);
+    assert_eq!(
+        table.zebra_stripes(),
+        Some((CellColor::Reset, CellColor::DarkGrey))
+    );
+
+    // The same theme can be applied to several tables.
+    let mut other = Table::new();
+    other.apply_theme(&theme);
+    assert_eq!(other.header_style(), table.header_style());
+}
@@ -0,0 +1,174 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+
+use super::assert_table_line_width;
+
+#[test]
+fn title_alignment() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Company", "Q1", "Q2"])
+        .add_row(vec!["ACME", "1000", "2000"]);
+
+    table
+        .set_title("Revenue")
+        .set_title_alignment(CellAlignment::Right)
+        .set_caption("Preliminary")
+        .set_caption_alignment(CellAlignment::Left);
+    println!("{table}");
+
+    let expected = "
+                  Revenue
++---------+------+------+
+| Company | Q1   | Q2   |
++=======================+
+| ACME    | 1000 | 2000 |
++---------+------+------+
+Preliminary";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.remove_title().remove_caption();
+    assert_eq!(table.title(), None);
+    assert_eq!(table.caption(), None);
+    assert_table_line_width(&table, 25);
+}
+
+/// Long titles and captions widen the table, as long as there's no width limit.
+/// Otherwise, they're wrapped to the width of the table.
+#[test]
+fn long_title() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Company", "Q1", "Q2"])
+        .add_row(vec!["ACME", "1000", "2000"]);
+
+    table
+        .set_title("The revenue of all companies in the first half of the year")
+        .set_caption("Caption\nwith newline")
+        .add_footnote("A footnote");
+    println!("{table}");
+
+    let expected = "
+The revenue of all companies in the first half of the year
++--------------------+-----------------+-----------------+
+| Company            | Q1              | Q2              |
++========================================================+
+| ACME               | 1000            | 2000            |
++--------------------+-----------------+-----------------+
+                          Caption
+                       with newline
+[1] A footnote";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(25);
+    println!("{table}");
+    let expected = "
+    The revenue of all
+  companies in the first
+     half of the year
++---------+------+------+
+| Company | Q1   | Q2   |
++=======================+
+| ACME    | 1000 | 2000 |
++---------+------+------+
+         Caption
+       with newline
+[1] A footnote";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn title_in_border() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Company", "Q1", "Q2"])
+        .add_row(vec!["ACME", "1000", "2000"]);
+
+    table
+        .load_preset(UTF8_FULL)
+        .set_title("Revenue")
+        .set_title_in_border(true);
+    println!("{table}");
+
+    let expected = "
+┌─────── Revenue ┬──────┐
+│ Company ┆ Q1   ┆ Q2   │
+╞═════════╪══════╪══════╡
+│ ACME    ┆ 1000 ┆ 2000 │
+└─────────┴──────┴──────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The table grows, so that long titles fit into the top border.
+    table.set_title("Quarterly revenue report");
+    println!("{table}");
+    let expected = "
+┌─ Quarterly revenue report ─┐
+│ Company   ┆ Q1     ┆ Q2    │
+╞═══════════╪════════╪═══════╡
+│ ACME      ┆ 1000   ┆ 2000  │
+└───────────┴────────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Titles that don't fit into the table's width are displayed above the table.
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(25);
+    println!("{table}");
+    let lines: Vec<String> = table.lines().collect();
+    assert_eq!(lines[0].trim(), "Quarterly revenue report");
+    assert!(lines[1].starts_with("┌──"));
+    assert_table_line_width(&table, 25);
+}
+
+/// Rendered rows don't include the title, unless it's part of the top border.
+#[test]
+fn title_in_rendered_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Company", "Q1", "Q2"])
+        .add_row(vec!["ACME", "1000", "2000"]);
+
+    table.set_title("Revenue").set_caption("Caption");
+
+    let rows = table.rendered_rows();
+    assert_eq!(
+        rows[0].separator_above.as_deref(),
+        Some("+---------+------+------+")
+    );
+    assert_eq!(rows[1].physical_lines, vec!["| ACME    | 1000 | 2000 |"]);
+
+    table.set_title_in_border(true);
+    let rows = table.rendered_rows();
+    assert_eq!(
+        rows[0].separator_above.as_deref(),
+        Some("+------- Revenue +------+")
+    );
+}
+
+/// The title and caption are only displayed once in the vertical overflow layout.
+#[test]
+fn title_with_vertical_overflow() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Company", "Q1", "Q2"])
+        .add_row(vec!["ACME", "1000", "2000"]);
+
+    table
+        .add_row(vec!["Initech", "10", "20"])
+        .set_title("Revenue")
+        .set_caption("Caption")
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_overflow_policy(OverflowPolicy::Vertical)
+        .set_width(12);
+    println!("{table}");
+
+    let rendered = table.to_string();
+    assert_eq!(rendered.matches("Revenue").count(), 1);
+    assert_eq!(rendered.matches("Caption").count(), 1);
+    assert_eq!(rendered.lines().next().unwrap().trim(), "Revenue");
+    assert_eq!(rendered.lines().last().unwrap().trim(), "Caption");
+}
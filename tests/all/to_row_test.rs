@@ -0,0 +1,69 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+struct Fruit {
+    name: &'static str,
+    amount: usize,
+}
+
+impl ToRow for Fruit {
+    fn to_row(&self) -> Row {
+        Row::from(vec![self.name.to_string(), self.amount.to_string()])
+    }
+}
+
+struct Comment(&'static str);
+
+impl ToRow for Comment {
+    fn to_row(&self) -> Row {
+        Row::from(vec![self.0])
+    }
+}
+
+#[test]
+fn add_items_of_the_same_type() {
+    let fruits = vec![
+        Fruit {
+            name: "Apples",
+            amount: 3,
+        },
+        Fruit {
+            name: "Pears",
+            amount: 12,
+        },
+    ];
+
+    let mut table = Table::new();
+    table.set_header(vec!["Fruit", "Amount"]).add_items(&fruits);
+
+    let expected = "
++--------+--------+
+| Fruit  | Amount |
++=================+
+| Apples | 3      |
+|--------+--------|
+| Pears  | 12     |
++--------+--------+";
+    println!("{table}");
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn add_trait_objects() {
+    let fruit = Fruit {
+        name: "Apples",
+        amount: 3,
+    };
+    let comment = Comment("Sold out");
+
+    let mut table = Table::new();
+    table.add(&fruit).add(&comment as &dyn ToRow);
+
+    let items: Vec<Box<dyn ToRow>> = vec![Box::new(Comment("Pears")), Box::new(fruit)];
+    table.add_items(items);
+
+    assert_eq!(table.row_count(), 4);
+    assert_eq!(table.row(1).unwrap().cell_count(), 1);
+    assert_eq!(table.row(3).unwrap().cell_count(), 2);
+}
@@ -0,0 +1,357 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+
+#[test]
+fn default_truncation_indicator() {
+    let table = Table::new();
+    assert_eq!(table.truncation_indicator(), "...");
+}
+
+/// Indicators that wouldn't fit into the narrowest column that displays them are rejected.
+#[test]
+fn wide_truncation_indicator() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec![
+        "This is a long text that will be truncated",
+        "This is another long text",
+    ]);
+    row.max_height(1);
+    table.set_header(vec!["Header1", "Header2"]).add_row(row);
+
+    for column in table.column_iter_mut() {
+        column.set_constraint(Absolute(Fixed(12)));
+    }
+
+    let error = table.set_truncation_indicator("(truncated)").unwrap_err();
+    assert_eq!(
+        error,
+        TruncationIndicatorError {
+            indicator: "(truncated)".to_string(),
+            width: 11,
+        }
+    );
+    assert!(table.set_width_truncation_indicator("(truncated)").is_err());
+    assert_eq!(table.truncation_indicator(), "...");
+
+    let column = table.column_mut(1).unwrap();
+    assert!(column.set_truncation_indicator("(more...)").is_err());
+    column.set_truncation_indicator("(more)").unwrap();
+
+    println!("{table}");
+    let expected = "
++------------+------------+
+| Header1    | Header2    |
++=========================+
+| This is... | This(more) |
++------------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Narrow columns never display an indicator, not even a narrow one.
+#[test]
+fn truncation_indicator_in_two_wide_column() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["Truncated", "Cut off"]);
+    row.max_height(1);
+    table
+        .add_row(row)
+        .set_truncation_indicator("…")
+        .unwrap()
+        .set_width_truncation_indicator("…")
+        .unwrap()
+        .set_constraints(vec![Absolute(Fixed(4)), Absolute(Fixed(4))]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_truncation_indicator("(more)")
+        .unwrap();
+    table.add_row(vec![Cell::new("Preformatted").preformatted(true)]);
+
+    println!("{table}");
+    let expected = "
++----+----+
+| Tr | Cu |
+|----+----|
+| Pr |    |
++----+----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn column_truncation_indicator() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec![
+        "This is a long text that will be truncated",
+        "This is another long text",
+    ]);
+    row.max_height(1);
+    table.set_header(vec!["Header1", "Header2"]).add_row(row);
+
+    for column in table.column_iter_mut() {
+        column.set_constraint(Absolute(Fixed(12)));
+    }
+
+    table.set_truncation_indicator("…").unwrap();
+    let column = table.column_mut(1).unwrap();
+    column.set_truncation_indicator(" [+]").unwrap();
+    assert_eq!(column.truncation_indicator(), Some(" [+]"));
+
+    println!("{table}");
+    let expected = "
++------------+------------+
+| Header1    | Header2    |
++=========================+
+| This is a… | This i [+] |
++------------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.column_mut(1).unwrap().remove_truncation_indicator();
+    assert!(table.to_string().contains("| This is…   |"));
+}
+
+/// The default indicators follow the loaded preset, until they're set explicitly.
+#[test]
+fn indicator_defaults_follow_preset() {
+    let mut table = Table::new();
+    table.load_preset(presets::UTF8_FULL);
+    assert_eq!(table.truncation_indicator(), "…");
+    assert_eq!(table.width_truncation_indicator(), "…");
+
+    table.load_preset(presets::ASCII_FULL);
+    assert_eq!(table.truncation_indicator(), "...");
+    assert_eq!(table.width_truncation_indicator(), "...");
+
+    table.set_width_truncation_indicator(">").unwrap();
+    table.load_preset(presets::UTF8_FULL);
+    assert_eq!(table.truncation_indicator(), "…");
+    assert_eq!(table.width_truncation_indicator(), ">");
+}
+
+/// Line and width truncation use their own indicators.
+#[test]
+fn separate_line_and_width_indicators() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec![
+        Cell::new("first line\nsecond line"),
+        Cell::new("a preformatted line").preformatted(true),
+    ]);
+    row.max_height(1);
+    table
+        .add_row(row)
+        .set_constraints(vec![Absolute(Fixed(12)), Absolute(Fixed(12))]);
+    table.set_truncation_indicator("+").unwrap();
+    table.set_width_truncation_indicator(">").unwrap();
+
+    println!("{table}");
+    let expected = "
++------------+------------+
+| first lin+ | a preform> |
++------------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Truncated cells are displayed on a single line, while other cells are still split.
+fn truncated_cells() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description"])
+        .add_row(vec![
+            Cell::new("A rather long name").set_truncate(true),
+            Cell::new("This description is split"),
+        ])
+        .add_row(vec![
+            Cell::new("First line\nSecond line").set_truncate(true),
+            Cell::new("Short"),
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30);
+
+    println!("{table}");
+    let expected = "
++--------------+-------------+
+| Name         | Description |
++============================+
+| A rather ... | This        |
+|              | description |
+|              | is split    |
+|--------------+-------------|
+| First lin... | Short       |
++--------------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// The indicator is left out in narrow columns.
+fn truncated_cell_in_narrow_column() {
+    let mut table = Table::new();
+    table.add_row(vec![Cell::new("Truncated").set_truncate(true)]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(6)));
+
+    println!("{table}");
+    let expected = "
++------+
+| Trun |
++------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Truncated cells are numbered and their full content can be looked up.
+fn truncation_markers() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Notes"])
+        .set_truncation_markers(true)
+        .set_constraints(vec![
+            ColumnConstraint::Absolute(Width::Fixed(12)),
+            ColumnConstraint::UpperBoundary(Width::Fixed(18)),
+        ]);
+    let rows = vec![
+        vec!["Apples", "Red and green\nGrown locally"],
+        vec!["Pears", "Yellow"],
+        vec!["Golden\nplums", "Sweet, juicy and\nready to eat"],
+    ];
+    for cells in rows {
+        let mut row = Row::from(cells);
+        row.max_height(1);
+        table.add_row(row);
+    }
+
+    println!("{table}");
+    let expected = "
++------------+------------------+
+| Name       | Notes            |
++===============================+
+| Apples     | Red and g...[+1] |
+|------------+------------------|
+| Pears      | Yellow           |
+|------------+------------------|
+| Gol...[+2] | Sweet, ju...[+3] |
++------------+------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let truncated = table.truncated_content();
+    let cells: Vec<(usize, usize, usize, &str)> = truncated
+        .iter()
+        .map(|cell| {
+            (
+                cell.marker,
+                cell.row_index,
+                cell.column_index,
+                cell.content.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        cells,
+        vec![
+            (1, 0, 1, "Red and green\nGrown locally"),
+            (2, 2, 0, "Golden\nplums"),
+            (3, 2, 1, "Sweet, juicy and\nready to eat"),
+        ]
+    );
+
+    // Live tables number their cells the same way.
+    let mut live = LiveTable::new(table.clone());
+    assert_eq!(live.render(), table.to_string());
+}
+
+#[test]
+/// The marker is left out, if the column is too narrow, but the content is still collected.
+fn truncation_marker_in_narrow_column() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["Some\ncontent"]);
+    row.max_height(1);
+    table.add_row(row).set_truncation_markers(true);
+
+    println!("{table}");
+    let expected = "
++---------+
+| Some... |
++---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.truncated_content().len(), 1);
+}
+
+#[test]
+/// The built-in truncation modes keep different parts of the line.
+fn truncation_modes() {
+    let expected = [
+        (TruncationMode::End, "| Hello world, h... |"),
+        (TruncationMode::Start, "| ..., how are you? |"),
+        (TruncationMode::Middle, "| Hello w...re you? |"),
+        (TruncationMode::WordBoundary, "| Hello world,...   |"),
+    ];
+
+    for (mode, expected) in expected {
+        let mut table = Table::new();
+        table
+            .set_truncation_strategy(Box::new(mode))
+            .add_row(vec![
+                Cell::new("Hello world, how are you?").set_truncate(true)
+            ]);
+        table
+            .column_mut(0)
+            .unwrap()
+            .set_constraint(Absolute(Fixed(19)));
+
+        println!("{table}");
+        assert_eq!(table.lines().nth(1).unwrap(), expected);
+    }
+}
+
+#[test]
+/// Strategies also apply to preformatted content, which is cut off with the width indicator.
+fn truncation_strategy_preformatted() {
+    let mut table = Table::new();
+    table
+        .set_truncation_strategy(Box::new(TruncationMode::Middle))
+        .set_width_truncation_indicator(">")
+        .unwrap()
+        .add_row(vec![Cell::new("0123456789").preformatted(true)]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(9)));
+
+    assert_eq!(table.lines().nth(1).unwrap(), "| 012>789 |");
+}
+
+#[derive(Debug)]
+struct TooWide;
+
+impl TruncationStrategy for TooWide {
+    fn truncate(&self, line: &str, _width: usize, indicator: &str, _: WidthFunction) -> String {
+        format!("{indicator}{line}{indicator}")
+    }
+}
+
+#[test]
+/// Results of custom strategies that are too wide are cut off, so the borders stay in place.
+fn too_wide_truncation_strategy() {
+    let mut table = Table::new();
+    table
+        .set_truncation_strategy(Box::new(TooWide))
+        .add_row(vec![Cell::new("Truncated content").set_truncate(true)]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(10)));
+
+    println!("{table}");
+    let expected = "
++----------+
+| ...Trunc |
++----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert!(table.truncation_strategy().is_some());
+    table.remove_truncation_strategy();
+    assert!(table.to_string().contains("| Trunc... |"));
+}
@@ -2,6 +2,8 @@ use pretty_assertions::assert_eq;
 
 use comfy_table::*;
 
+use super::assert_table_line_width;
+
 #[test]
 /// UTF-8 symbols that are longer than a single character are properly handled.
 /// This means, that comfy-table detects that they're longer than 1 character and styles/arranges
@@ -56,3 +58,108 @@ fn multi_character_utf8_word_splitting() {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+#[test]
+/// Border characters that're wider than a single column keep the table aligned.
+/// Narrower characters of the same border are padded to the width of the widest one.
+fn wide_border_characters() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .set_style(TableComponent::VerticalLines, '║')
+        .set_style(TableComponent::LeftBorder, '🟦')
+        .set_style(TableComponent::TopBorder, '━');
+
+    println!("{table}");
+    let expected = "
++━━━━━━━━━━+━━━━━━━━━+
+🟦 Header1 ║ Header2 |
++====================+
+🟦 One     ║ Two     |
++----------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Wide border characters are taken into account when the content is arranged.
+fn wide_border_characters_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["Some long text", "More text"])
+        .set_style(TableComponent::VerticalLines, '表')
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(24);
+
+    println!("{table}");
+    assert_table_line_width(&table, 24);
+}
+
+#[test]
+/// Characters that are wider than the whole column cannot be displayed.
+/// They're cut off, so the borders of the table stay aligned.
+fn character_wider_than_column() {
+    let mut table = Table::new();
+    table.add_row(vec!["コ", "a"]).set_constraints(vec![
+        ColumnConstraint::Absolute(Width::Fixed(3)),
+        ColumnConstraint::Absolute(Width::Fixed(3)),
+    ]);
+
+    println!("{table}");
+    let expected = "
++---+---+
+|   | a |
++---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 9);
+}
+
+#[test]
+/// Emojis that consist of several characters joined by zero width joiners or followed by a
+/// variation selector are split as a whole, so the borders stay aligned.
+fn grapheme_cluster_splitting() {
+    let family = "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+    let heart = "\u{2764}\u{fe0f}";
+    let mut table = Table::new();
+    table
+        .add_row(vec![format!("{family}{heart}{family}{heart}")])
+        .set_constraints(vec![ColumnConstraint::Absolute(Width::Fixed(6))]);
+
+    println!("{table}");
+    let expected = format!(
+        "
++------+
+| {family}{heart} |
+| {family}{heart} |
++------+"
+    );
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 8);
+
+    // Truncated content is cut between grapheme clusters as well.
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::new(family.repeat(5)).set_truncate(true)])
+        .set_constraints(vec![ColumnConstraint::Absolute(Width::Fixed(10))]);
+
+    println!("{table}");
+    let expected = format!(
+        "
++----------+
+| {family}{family}...  |
++----------+"
+    );
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 12);
+
+    table.set_truncation_strategy(Box::new(TruncationMode::Start));
+    println!("{table}");
+    let expected = format!(
+        "
++----------+
+| ...{family}{family}  |
++----------+"
+    );
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,64 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+#[test]
+/// The header and frozen columns are always displayed.
+/// Row groups that're only partially visible are still separated.
+fn viewport_with_frozen_columns() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "A", "B", "C"])
+        .begin_group("First")
+        .add_row(vec!["Row 0", "a0", "b0", "c0"])
+        .add_row(vec!["Row 1", "a1", "b1", "c1"])
+        .begin_group("Second")
+        .add_row(vec!["Row 2", "a2", "b2", "c2"])
+        .add_row(vec!["Row 3", "a3", "b3", "c3"]);
+
+    let view = Viewport::new(1..3, 2..3).set_frozen_columns(1);
+    let expected = "
++-------+----+
+| Name  | B  |
++============+
+| Row 1 | b1 |
++============+
+| Row 2 | b2 |
++-------+----+";
+    println!("{}", table.render_scrollable(view.clone()));
+    assert_eq!(expected, "\n".to_string() + &table.render_scrollable(view));
+}
+
+#[test]
+/// Ranges that reach beyond the table are clamped to the table's size.
+fn viewport_out_of_range() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "A", "B", "C"])
+        .begin_group("First")
+        .add_row(vec!["Row 0", "a0", "b0", "c0"])
+        .add_row(vec!["Row 1", "a1", "b1", "c1"])
+        .begin_group("Second")
+        .add_row(vec!["Row 2", "a2", "b2", "c2"])
+        .add_row(vec!["Row 3", "a3", "b3", "c3"]);
+
+    let view = Viewport::new(3..10, 3..10);
+    let expected = "
++----+
+| C  |
++====+
+| c3 |
++----+";
+    println!("{}", table.render_scrollable(view.clone()));
+    assert_eq!(expected, "\n".to_string() + &table.render_scrollable(view));
+
+    // A viewport without any rows still shows the header.
+    let view = Viewport::new(10..20, 0..2);
+    let expected = "
++------+---+
+| Name | A |
++==========+
++------+---+";
+    println!("{}", table.render_scrollable(view.clone()));
+    assert_eq!(expected, "\n".to_string() + &table.render_scrollable(view));
+}
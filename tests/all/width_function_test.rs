@@ -0,0 +1,85 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::*;
+
+/// A terminal that renders every character in a single cell.
+fn narrow(text: &str) -> usize {
+    text.chars().count()
+}
+
+#[test]
+fn custom_width_function() {
+    let mut table = Table::new();
+    table
+        .set_width_function(narrow)
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["✅", "漢字"]);
+
+    println!("{table}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
++===================+
+| ✅       | 漢字      |
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn custom_width_function_word_splitting() {
+    let mut table = Table::new();
+    table
+        .set_width_function(narrow)
+        .set_width(8)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["test"])
+        .add_row(vec!["漢字漢字漢字"]);
+
+    println!("{table}");
+    let expected = "
++------+
+| test |
++======+
+| 漢字漢字 |
+| 漢字   |
++------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.column_max_content_widths(), vec![6]);
+}
+
+/// The width function of one table mustn't leak into other tables.
+#[test]
+fn width_function_is_scoped_to_table() {
+    let mut custom = Table::new();
+    custom.set_width_function(narrow).add_row(vec!["漢字"]);
+    let mut default = Table::new();
+    default.add_row(vec!["漢字"]);
+
+    assert_eq!(custom.to_string().lines().next(), Some("+----+"));
+    assert_eq!(default.to_string().lines().next(), Some("+------+"));
+    assert_eq!(default.column_max_content_widths(), vec![4]);
+
+    default.set_width_function(narrow).remove_width_function();
+    assert!(default.width_function().is_none());
+    assert_eq!(default.column_max_content_widths(), vec![4]);
+}
+
+/// The minimum width of the table is determined with the custom width function as well.
+#[test]
+fn try_render_with_custom_width_function() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["漢字"])
+        .set_constraints(vec![ColumnConstraint::ContentWidth]);
+
+    let error = table.try_render(6).unwrap_err();
+    assert_eq!(error.min_width, 8);
+
+    table.set_width_function(narrow);
+    let expected = "
++----+
+| 漢字 |
++----+";
+    assert_eq!(expected, "\n".to_string() + &table.try_render(6).unwrap());
+}
@@ -0,0 +1,93 @@
+use pretty_assertions::assert_eq;
+
+use comfy_table::ColumnConstraint::*;
+use comfy_table::Width::*;
+use comfy_table::*;
+
+fn path_table(wrap_policy: WrapPolicy) -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Key", "Value"])
+        .add_row(vec![
+            "path",
+            "see comfy_table::utils::formatting for details",
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_wrap_policy(wrap_policy)
+        .set_width(28);
+
+    table
+}
+
+#[test]
+/// Words are broken at whichever character the line is full.
+fn break_words() {
+    let table = path_table(WrapPolicy::BreakWords);
+
+    println!("{table}");
+    let expected = "
++------+-------------------+
+| Key  | Value             |
++==========================+
+| path | see comfy_table:: |
+|      | utils::formatting |
+|      | for details       |
++------+-------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Words are never broken, columns are widened instead.
+fn keep_words() {
+    let mut table = path_table(WrapPolicy::KeepWords);
+    table.set_width(20);
+
+    println!("{table}");
+    let expected = "
++------+--------------------------------+
+| Key  | Value                          |
++=======================================+
+| path | see                            |
+|      | comfy_table::utils::formatting |
+|      | for details                    |
++------+--------------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Words are cut off, if a constraint forces the column to be narrower than the word.
+fn keep_words_with_constraint() {
+    let mut table = path_table(WrapPolicy::KeepWords);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(UpperBoundary(Fixed(15)));
+
+    println!("{table}");
+    let expected = "
++------+---------------+
+| Key  | Value         |
++======================+
+| path | see           |
+|      | comfy_tabl... |
+|      | for details   |
++------+---------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+/// Broken words end with a hyphen.
+fn hyphenate() {
+    let table = path_table(WrapPolicy::Hyphenate);
+
+    println!("{table}");
+    let expected = "
++------+-------------------+
+| Key  | Value             |
++==========================+
+| path | see comfy_table:- |
+|      | :utils::formatti- |
+|      | ng for details    |
++------+-------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}